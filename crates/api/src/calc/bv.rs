@@ -0,0 +1,787 @@
+//! Battle Value 2.0 for BattleMechs, following the TechManual procedure:
+//! defensive rating × defensive factor + offensive rating × speed factor,
+//! adjusted by the cockpit modifier.
+
+use crate::{
+    calc::mech::{dec, ResolvedMech, ResolvedMount},
+    db::models::DbEquipment,
+};
+
+/// BV contribution of a single weapon mount after heat and facing adjustments.
+#[derive(Debug, Clone)]
+pub struct WeaponBv {
+    pub equipment_slug: String,
+    pub location: String,
+    pub is_rear: bool,
+    pub base_bv: f64,
+    pub heat: i32,
+    pub bv: f64,
+    pub exceeds_heat: bool,
+}
+
+/// Full BV2 breakdown. Intermediate values are unrounded; `battle_value` is final.
+#[derive(Debug, Clone, Default)]
+pub struct BvBreakdown {
+    pub battle_value: i32,
+    pub defensive_rating: f64,
+    pub offensive_rating: f64,
+
+    pub armor_bv: f64,
+    pub structure_bv: f64,
+    pub gyro_bv: f64,
+    pub defensive_equipment_bv: f64,
+    pub explosive_penalty: f64,
+    pub target_movement_modifier: i32,
+    pub defensive_factor: f64,
+
+    pub weapon_bv: f64,
+    pub ammo_bv: f64,
+    pub offensive_equipment_bv: f64,
+    pub weight_bv: f64,
+    pub heat_efficiency: i32,
+    pub speed_factor: f64,
+    pub cockpit_modifier: f64,
+
+    pub weapons: Vec<WeaponBv>,
+    pub warnings: Vec<String>,
+}
+
+// ── Equipment classification ─────────────────────────────────────────────────
+
+fn is_weapon(e: &DbEquipment) -> bool {
+    e.category.ends_with("_weapon") || (e.category == "equipment" && e.damage.is_some())
+}
+
+fn is_ammo(e: &DbEquipment) -> bool {
+    e.category == "ammunition"
+}
+
+fn is_defensive(e: &DbEquipment) -> bool {
+    let s = e.slug.as_str();
+    s.contains("anti-missile")
+        || s.contains("antimissile")
+        || s.contains("ecm")
+        || s.contains("probe")
+}
+
+fn is_case(e: &DbEquipment) -> bool {
+    e.slug.contains("case")
+}
+
+fn is_case_ii(e: &DbEquipment) -> bool {
+    e.slug.contains("case-ii")
+}
+
+fn is_targeting_computer(e: &DbEquipment) -> bool {
+    e.category == "targeting_computer" || e.slug.contains("targeting-computer")
+}
+
+/// Explosive components: all ammo except Gauss slugs, plus Gauss rifles themselves.
+/// Returns the BV penalty per critical slot (15 for ammo, 1 for Gauss weapons).
+fn explosive_penalty_per_slot(e: &DbEquipment) -> Option<f64> {
+    let gauss = e.slug.contains("gauss");
+    if is_ammo(e) {
+        (!gauss).then_some(15.0)
+    } else if gauss && is_weapon(e) {
+        Some(1.0)
+    } else {
+        None
+    }
+}
+
+/// Heat generated per turn for BV purposes (Ultra ×2, Rotary ×6).
+fn bv_heat(e: &DbEquipment) -> i32 {
+    let heat = e.heat.unwrap_or(0);
+    let s = e.slug.to_ascii_lowercase();
+    if s.contains("rotary") {
+        heat * 6
+    } else if s.contains("ultra") {
+        heat * 2
+    } else {
+        heat
+    }
+}
+
+/// Direct-fire weapons benefit from a targeting computer.
+fn is_direct_fire(e: &DbEquipment) -> bool {
+    matches!(e.category.as_str(), "energy_weapon" | "ballistic_weapon")
+        || (e.category == "equipment" && !e.slug.contains("mrm") && e.damage.is_some())
+}
+
+// ── Movement modifiers ───────────────────────────────────────────────────────
+
+/// Target movement modifier for a given number of hexes moved.
+pub fn tmm_for_mp(mp: i32) -> i32 {
+    match mp {
+        i32::MIN..=2 => 0,
+        3..=4 => 1,
+        5..=6 => 2,
+        7..=9 => 3,
+        10..=17 => 4,
+        18..=24 => 5,
+        _ => 6,
+    }
+}
+
+fn round2(v: f64) -> f64 {
+    (v * 100.0).round() / 100.0
+}
+
+// ── Calculation ──────────────────────────────────────────────────────────────
+
+/// Whether ammunition/Gauss explosions in `location` are contained without
+/// destroying the unit (no BV penalty).
+fn location_protected(mech: &ResolvedMech, location: &str) -> bool {
+    if mech.has_equipment_in(location, is_case_ii) {
+        return true;
+    }
+    let engine_survives_torso_loss = mech.engine.st_crits < 3;
+    match location {
+        "left_torso" | "right_torso" => {
+            (mech.is_clan() || mech.has_equipment_in(location, is_case))
+                && engine_survives_torso_loss
+        }
+        "left_arm" | "right_arm" => {
+            let torso = if location == "left_arm" {
+                "left_torso"
+            } else {
+                "right_torso"
+            };
+            mech.has_equipment_in(location, is_case) || location_protected(mech, torso)
+        }
+        _ => false,
+    }
+}
+
+fn slots_of(m: &ResolvedMount) -> f64 {
+    (m.equipment.crits.unwrap_or(1).max(1) * m.mount.quantity) as f64
+}
+
+pub fn calculate(mech: &ResolvedMech) -> BvBreakdown {
+    let mut out = BvBreakdown::default();
+    let spec = &mech.spec;
+    let tonnage = spec.tonnage as f64;
+
+    for m in &mech.mounts {
+        if m.equipment.bv.is_none()
+            && (is_weapon(&m.equipment) || is_ammo(&m.equipment) || is_defensive(&m.equipment))
+        {
+            out.warnings.push(format!(
+                "equipment '{}' has no BV data; counted as 0",
+                m.equipment.slug
+            ));
+        }
+    }
+
+    // ── Defensive ────────────────────────────────────────────────────────────
+    out.armor_bv = spec.armor_points as f64 * 2.5 * dec(mech.armor.bv_multiplier, 1.0);
+    out.structure_bv = mech.structure_points() as f64
+        * 1.5
+        * dec(mech.structure.bv_multiplier, 1.0)
+        * dec(mech.engine.bv_multiplier, 1.0);
+    out.gyro_bv = tonnage * dec(mech.gyro.bv_multiplier, 0.5);
+
+    for m in &mech.mounts {
+        let e = &m.equipment;
+        if is_defensive(e) {
+            out.defensive_equipment_bv += e.bv.unwrap_or(0) as f64 * m.mount.quantity as f64;
+        }
+        if let Some(per_slot) = explosive_penalty_per_slot(e) {
+            if !location_protected(mech, &m.mount.location) {
+                out.explosive_penalty += per_slot * slots_of(m);
+            }
+        }
+    }
+
+    let run = mech.run_mp();
+    let jump = spec.jump_mp;
+    let tmm = tmm_for_mp(run).max(if jump > 0 { tmm_for_mp(jump) + 1 } else { 0 });
+    out.target_movement_modifier = tmm;
+    out.defensive_factor = 1.0 + tmm as f64 / 10.0;
+
+    let base_def = (out.armor_bv + out.structure_bv + out.gyro_bv + out.defensive_equipment_bv
+        - out.explosive_penalty)
+        .max(1.0);
+    out.defensive_rating = base_def * out.defensive_factor;
+
+    // ── Offensive ────────────────────────────────────────────────────────────
+    let has_tc = mech.has_equipment(is_targeting_computer);
+    let movement_heat = if jump > 0 { jump.max(3) } else { 2 };
+    out.heat_efficiency = 6 + mech.heat_dissipation() - movement_heat;
+
+    let mut weapons: Vec<WeaponBv> = Vec::new();
+    for m in &mech.mounts {
+        let e = &m.equipment;
+        if !is_weapon(e) || is_defensive(e) {
+            continue;
+        }
+        let mut base = e.bv.unwrap_or(0) as f64;
+        if has_tc && is_direct_fire(e) {
+            base *= 1.25;
+        }
+        if m.mount.is_rear {
+            base *= 0.5;
+        }
+        for _ in 0..m.mount.quantity {
+            weapons.push(WeaponBv {
+                equipment_slug: e.slug.clone(),
+                location: m.mount.location.clone(),
+                is_rear: m.mount.is_rear,
+                base_bv: base,
+                heat: bv_heat(e),
+                bv: base,
+                exceeds_heat: false,
+            });
+        }
+    }
+    weapons.sort_by(|a, b| {
+        b.base_bv
+            .partial_cmp(&a.base_bv)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.heat.cmp(&b.heat))
+    });
+
+    apply_heat_efficiency(&mut weapons, out.heat_efficiency);
+    out.weapon_bv = weapons.iter().map(|w| w.bv).sum();
+
+    for m in &mech.mounts {
+        let e = &m.equipment;
+        if is_ammo(e) {
+            let mut ammo = e.bv.unwrap_or(0) as f64 * m.mount.quantity as f64;
+            // Ammo can never be worth more than the weapons that fire it.
+            if let Some(weapon_id) = e.ammo_for_id {
+                let cap = weapons_bv_for(mech, weapon_id);
+                ammo = ammo.min(cap);
+            }
+            out.ammo_bv += ammo;
+        } else if !is_weapon(e) && !is_defensive(e) {
+            out.offensive_equipment_bv += e.bv.unwrap_or(0) as f64 * m.mount.quantity as f64;
+        }
+    }
+
+    let weight_mult = mech.myomer_property("bv_weight_multiplier").unwrap_or(1.0);
+    out.weight_bv = tonnage * weight_mult;
+
+    let speed_mp = run as f64 + (jump as f64 / 2.0).round();
+    out.speed_factor = round2(((speed_mp - 5.0) / 10.0 + 1.0).powf(1.2));
+
+    out.offensive_rating =
+        (out.weapon_bv + out.ammo_bv + out.offensive_equipment_bv + out.weight_bv)
+            * out.speed_factor;
+
+    // ── Final ────────────────────────────────────────────────────────────────
+    out.cockpit_modifier = dec(mech.cockpit.bv_multiplier, 1.0);
+    let total = (out.defensive_rating + out.offensive_rating).round();
+    out.battle_value = (total * out.cockpit_modifier).round() as i32;
+    out.weapons = weapons;
+    out
+}
+
+/// Halve weapons fired past the heat efficiency. `weapons` must be sorted by
+/// descending BV; the weapon that first crosses the threshold still counts in
+/// full, everything after it counts at half.
+fn apply_heat_efficiency(weapons: &mut [WeaponBv], heat_efficiency: i32) {
+    let mut heat_total = 0;
+    let mut over = false;
+    for w in weapons {
+        if over {
+            w.bv = w.base_bv * 0.5;
+            w.exceeds_heat = true;
+        }
+        heat_total += w.heat;
+        if heat_total > heat_efficiency {
+            over = true;
+        }
+    }
+}
+
+/// Total BV of mounted weapons with the given equipment id.
+fn weapons_bv_for(mech: &ResolvedMech, equipment_id: i32) -> f64 {
+    mech.mounts
+        .iter()
+        .filter(|m| m.equipment.id == equipment_id)
+        .map(|m| m.equipment.bv.unwrap_or(0) as f64 * m.mount.quantity as f64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{PgPool, Row};
+
+    use super::*;
+    use crate::calc::mech::{self, MechConfig, MechSpec, Mount};
+
+    /// Build a calculator spec for a stored mech.
+    async fn stored_spec(pool: &PgPool, unit_slug: &str) -> (MechSpec, i32) {
+        let row = sqlx::query(
+            r#"SELECT u.id, u.tonnage::int4 AS tonnage, u.tech_base::text AS tech_base, u.bv,
+                      md.config, md.engine_rating, md.walk_mp, COALESCE(md.jump_mp, 0) AS jump_mp,
+                      md.heat_sink_count, et.slug AS engine, at.slug AS armor,
+                      st.slug AS structure, gt.slug AS gyro, ct.slug AS cockpit,
+                      ht.slug AS heatsink, mt.slug AS myomer,
+                      (SELECT SUM(COALESCE(l.armor_points, 0) + COALESCE(l.rear_armor, 0))::int4
+                       FROM unit_locations l WHERE l.unit_id = u.id) AS armor_points
+               FROM units u
+               JOIN unit_mech_data md ON md.unit_id = u.id
+               JOIN engine_types et ON et.id = md.engine_type_id
+               JOIN armor_types at ON at.id = md.armor_type_id
+               JOIN structure_types st ON st.id = md.structure_type_id
+               JOIN gyro_types gt ON gt.id = md.gyro_type_id
+               JOIN cockpit_types ct ON ct.id = md.cockpit_type_id
+               JOIN heatsink_types ht ON ht.id = md.heatsink_type_id
+               JOIN myomer_types mt ON mt.id = md.myomer_type_id
+               WHERE u.slug = $1"#,
+        )
+        .bind(unit_slug)
+        .fetch_one(pool)
+        .await
+        .unwrap_or_else(|e| panic!("{unit_slug}: {e}"));
+
+        let mounts = sqlx::query(
+            r#"SELECT e.slug, l.location::text AS location, l.is_rear_facing, l.quantity
+               FROM unit_loadout l
+               JOIN equipment e ON e.id = l.equipment_id
+               WHERE l.unit_id = $1 AND l.location IS NOT NULL
+               ORDER BY l.id"#,
+        )
+        .bind(row.get::<i32, _>("id"))
+        .fetch_all(pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|m| Mount {
+            equipment_slug: m.get("slug"),
+            location: m.get("location"),
+            quantity: m.get("quantity"),
+            is_rear: m.get("is_rear_facing"),
+        })
+        .collect();
+
+        let spec = MechSpec {
+            tonnage: row.get("tonnage"),
+            tech_base: row.get("tech_base"),
+            config: MechConfig::parse(row.get("config")).unwrap(),
+            engine_type: row.get("engine"),
+            engine_rating: row.get("engine_rating"),
+            walk_mp: row.get("walk_mp"),
+            jump_mp: row.get("jump_mp"),
+            armor_type: row.get("armor"),
+            armor_points: row.get("armor_points"),
+            structure_type: row.get("structure"),
+            gyro_type: row.get("gyro"),
+            cockpit_type: row.get("cockpit"),
+            heatsink_type: row.get("heatsink"),
+            heat_sink_count: row.get("heat_sink_count"),
+            myomer_type: row.get("myomer"),
+            mounts,
+        };
+        (spec, row.get("bv"))
+    }
+
+    /// Canon mechs without ammunition (ammo has no seeded BV), covering
+    /// jump jets, XL and light engines, double heat sinks, ECM, active probes,
+    /// targeting computers and a Clan tech base.
+    const CANON_SAMPLE: &[&str] = &[
+        "wasp-wsp-1",
+        "spider-sdr-5v",
+        "phoenix-hawk-pxh-2",
+        "black-knight-bl-6-knt",
+        "marauder-mad-3d",
+        "warhammer-whm-6d",
+        "awesome-aws-9q",
+        "hermes-her-4k",
+        "crab-crb-30",
+        "assassin-asn-30-alice",
+        "griffin-grf-1e2-sparky-2-0",
+        "verfolger-vr6-t",
+        "stealth-sth-1d-anna",
+        "great-wyrm-aemelia",
+    ];
+
+    /// Compares against the MUL BV stored on each unit. Needs `DATABASE_URL`
+    /// pointing at a database set up with `sqlx migrate run`, `./seed/load.sh`
+    /// and `scraper equipment-seed --file data/equipment_stats.json` (which also
+    /// converts the dump's loadout quantities and sets the BV multipliers);
+    /// run with `cargo test -p api -- --ignored`.
+    #[tokio::test]
+    #[ignore = "needs a seeded database in DATABASE_URL"]
+    async fn reproduces_stored_bv_of_canon_mechs() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL");
+        let pool = PgPool::connect(&url).await.unwrap();
+        let mut mismatches = Vec::new();
+        for slug in CANON_SAMPLE {
+            let (spec, stored) = stored_spec(&pool, slug).await;
+            let mech = mech::resolve(&pool, spec).await.unwrap();
+            let bv = calculate(&mech);
+            assert!(bv.warnings.is_empty(), "{slug}: {:?}", bv.warnings);
+            if bv.battle_value != stored {
+                mismatches.push(format!(
+                    "{slug}: calculated {} stored {stored}",
+                    bv.battle_value
+                ));
+            }
+        }
+        assert!(mismatches.is_empty(), "{mismatches:#?}");
+    }
+
+    // ── Fixed inputs ─────────────────────────────────────────────────────────
+
+    use rust_decimal::Decimal;
+
+    use crate::db::models::{
+        DbArmorType, DbCockpitType, DbEngineType, DbGyroType, DbHeatsinkType, DbInternalStructure,
+        DbMyomerType, DbStructureType, DbTechDates, DbTechRatings,
+    };
+
+    const CATALOG_JSON: &str = include_str!("../../../../data/equipment_catalog.json");
+
+    fn ratings() -> DbTechRatings {
+        DbTechRatings {
+            tech_rating: None,
+            availability_sl: None,
+            availability_sw: None,
+            availability_clan: None,
+            availability_da: None,
+        }
+    }
+
+    fn dates() -> DbTechDates {
+        DbTechDates {
+            prototype_year: None,
+            production_year: None,
+            common_year: None,
+            extinction_year: None,
+            reintro_year: None,
+        }
+    }
+
+    /// Equipment row for a catalog slug; panics if the catalog has no such slug.
+    fn equipment(slug: &str) -> DbEquipment {
+        let catalog: Vec<serde_json::Value> = serde_json::from_str(CATALOG_JSON).unwrap();
+        let entry = catalog
+            .iter()
+            .find(|e| e["slug"] == slug)
+            .unwrap_or_else(|| panic!("{slug} is not in the equipment catalog"));
+        DbEquipment {
+            id: 0,
+            slug: slug.to_string(),
+            name: entry["name"].as_str().unwrap().to_string(),
+            internal_name: entry["internal_name"].as_str().map(str::to_string),
+            category: entry["category"].as_str().unwrap().to_string(),
+            tech_base: entry["tech_base"].as_str().unwrap().to_string(),
+            rules_level: "standard".to_string(),
+            tonnage: None,
+            crits: Some(1),
+            damage: None,
+            heat: Some(1),
+            range_min: None,
+            range_short: None,
+            range_medium: None,
+            range_long: None,
+            bv: None,
+            intro_year: None,
+            source_book: None,
+            description: None,
+            observed_locations: None,
+            ammo_for_id: None,
+            stats_source: None,
+            ratings: ratings(),
+            total_count: None,
+        }
+    }
+
+    /// A 50-ton mech with the given tech base, engine side torso crits and
+    /// `(equipment slug, location)` mounts.
+    fn mech_with(tech_base: &str, engine_st_crits: i16, mounts: &[(&str, &str)]) -> ResolvedMech {
+        let mounts = mounts
+            .iter()
+            .map(|&(slug, location)| ResolvedMount {
+                mount: Mount {
+                    equipment_slug: slug.to_string(),
+                    location: location.to_string(),
+                    quantity: 1,
+                    is_rear: false,
+                },
+                equipment: equipment(slug),
+            })
+            .collect();
+        ResolvedMech {
+            spec: MechSpec {
+                tonnage: 50,
+                tech_base: tech_base.to_string(),
+                config: MechConfig::Biped,
+                engine_type: "test".to_string(),
+                engine_rating: 200,
+                walk_mp: None,
+                jump_mp: 0,
+                armor_type: "standard".to_string(),
+                armor_points: 0,
+                structure_type: "standard".to_string(),
+                gyro_type: "standard".to_string(),
+                cockpit_type: "standard".to_string(),
+                heatsink_type: "single".to_string(),
+                heat_sink_count: 10,
+                myomer_type: "standard".to_string(),
+                mounts: Vec::new(),
+            },
+            engine: DbEngineType {
+                id: 0,
+                slug: "test".to_string(),
+                name: "Test Engine".to_string(),
+                tech_base: tech_base.to_string(),
+                rules_level: "standard".to_string(),
+                weight_multiplier: Decimal::ONE,
+                ct_crits: 6,
+                st_crits: engine_st_crits,
+                intro_year: None,
+                bv_multiplier: Decimal::ONE,
+                dates: dates(),
+                ratings: ratings(),
+            },
+            armor: DbArmorType {
+                id: 0,
+                slug: "standard".to_string(),
+                name: "Standard".to_string(),
+                tech_base: tech_base.to_string(),
+                rules_level: "standard".to_string(),
+                points_per_ton: Decimal::from(16),
+                crits: 0,
+                intro_year: None,
+                bv_multiplier: Decimal::ONE,
+                dates: dates(),
+                ratings: ratings(),
+            },
+            structure: DbStructureType {
+                id: 0,
+                slug: "standard".to_string(),
+                name: "Standard".to_string(),
+                tech_base: tech_base.to_string(),
+                rules_level: "standard".to_string(),
+                weight_fraction: Decimal::new(1, 1),
+                crits: 0,
+                intro_year: None,
+                bv_multiplier: Decimal::ONE,
+                dates: dates(),
+                ratings: ratings(),
+            },
+            gyro: DbGyroType {
+                id: 0,
+                slug: "standard".to_string(),
+                name: "Standard".to_string(),
+                tech_base: None,
+                rules_level: "standard".to_string(),
+                weight_multiplier: Decimal::ONE,
+                crits: 4,
+                is_superheavy_only: false,
+                intro_year: None,
+                bv_multiplier: Decimal::new(5, 1),
+                dates: dates(),
+                ratings: ratings(),
+            },
+            cockpit: DbCockpitType {
+                id: 0,
+                slug: "standard".to_string(),
+                name: "Standard".to_string(),
+                tech_base: None,
+                rules_level: "standard".to_string(),
+                weight: 3,
+                crits: 1,
+                intro_year: None,
+                bv_multiplier: Decimal::ONE,
+                dates: dates(),
+                ratings: ratings(),
+            },
+            heatsink: DbHeatsinkType {
+                id: 0,
+                slug: "single".to_string(),
+                name: "Single".to_string(),
+                tech_base: tech_base.to_string(),
+                rules_level: "standard".to_string(),
+                dissipation: 1,
+                crits: 1,
+                weight: Decimal::ONE,
+                intro_year: None,
+                dates: dates(),
+                ratings: ratings(),
+            },
+            myomer: DbMyomerType {
+                id: 0,
+                slug: "standard".to_string(),
+                name: "Standard".to_string(),
+                tech_base: None,
+                rules_level: "standard".to_string(),
+                intro_year: None,
+                properties: serde_json::json!({}),
+                dates: dates(),
+                ratings: ratings(),
+            },
+            internal: DbInternalStructure {
+                tonnage: 50,
+                head: 3,
+                center_torso: 16,
+                side_torso: 12,
+                arm: 8,
+                leg: 12,
+            },
+            mounts,
+        }
+    }
+
+    fn weapon(base_bv: f64, heat: i32) -> WeaponBv {
+        WeaponBv {
+            equipment_slug: "test".to_string(),
+            location: "right_arm".to_string(),
+            is_rear: false,
+            base_bv,
+            heat,
+            bv: base_bv,
+            exceeds_heat: false,
+        }
+    }
+
+    #[test]
+    fn tmm_table() {
+        let expected = [
+            0, 0, 0, 1, 1, 2, 2, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 6,
+        ];
+        for (mp, tmm) in expected.into_iter().enumerate() {
+            assert_eq!(tmm_for_mp(mp as i32), tmm, "{mp} MP");
+        }
+        assert_eq!(tmm_for_mp(-1), 0);
+        assert_eq!(tmm_for_mp(40), 6);
+    }
+
+    #[test]
+    fn heat_threshold_halves_weapons_after_crossing() {
+        // Efficiency 10: 8 heat fits, the next weapon crosses to 16 and still
+        // counts in full, everything after it is halved.
+        let mut weapons = vec![
+            weapon(176.0, 8),
+            weapon(123.0, 8),
+            weapon(46.0, 3),
+            weapon(9.0, 1),
+        ];
+        apply_heat_efficiency(&mut weapons, 10);
+        let bv: Vec<f64> = weapons.iter().map(|w| w.bv).collect();
+        assert_eq!(bv, [176.0, 123.0, 23.0, 4.5]);
+        assert_eq!(
+            weapons.iter().map(|w| w.exceeds_heat).collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+
+        let mut cool = vec![weapon(46.0, 3), weapon(46.0, 3)];
+        apply_heat_efficiency(&mut cool, 6);
+        assert!(cool.iter().all(|w| !w.exceeds_heat && w.bv == 46.0));
+    }
+
+    #[test]
+    fn location_protection() {
+        // IS standard engine: CASE protects a side torso, and an arm through it
+        let cased = mech_with("inner_sphere", 0, &[("case", "left_torso")]);
+        assert!(location_protected(&cased, "left_torso"));
+        assert!(location_protected(&cased, "left_arm"));
+        assert!(!location_protected(&cased, "right_torso"));
+        assert!(!location_protected(&cased, "center_torso"));
+
+        // IS XL engine: losing a side torso destroys the mech, CASE or not
+        let xl = mech_with("inner_sphere", 3, &[("case", "left_torso")]);
+        assert!(!location_protected(&xl, "left_torso"));
+        assert!(!location_protected(&xl, "left_arm"));
+
+        // CASE II protects any location regardless of engine
+        let case_ii = mech_with("inner_sphere", 3, &[("case-ii", "center_torso")]);
+        assert!(location_protected(&case_ii, "center_torso"));
+        assert!(!location_protected(&case_ii, "left_torso"));
+
+        // Clan side torsos are protected with a Clan XL engine (two side crits)
+        let clan = mech_with("clan", 2, &[]);
+        assert!(location_protected(&clan, "right_torso"));
+        assert!(location_protected(&clan, "right_arm"));
+        assert!(!location_protected(&clan, "head"));
+
+        // CASE in an arm protects the arm only
+        let arm = mech_with("inner_sphere", 3, &[("clan-case", "right_arm")]);
+        assert!(location_protected(&arm, "right_arm"));
+        assert!(!location_protected(&arm, "right_torso"));
+    }
+
+    #[test]
+    fn run_mp_with_masc_tsm_and_supercharger() {
+        // 50 tons with a 200 engine: walk 4
+        let run = |myomer: &str, mounts: &[(&str, &str)]| {
+            let mut mech = mech_with("inner_sphere", 0, mounts);
+            mech.myomer.slug = myomer.to_string();
+            mech.run_mp()
+        };
+        assert_eq!(run("standard", &[]), 6);
+        assert_eq!(run("tsm", &[]), 8);
+        assert_eq!(run("standard", &[("masc", "left_torso")]), 8);
+        assert_eq!(run("masc", &[]), 8);
+        assert_eq!(run("standard", &[("clan-masc", "left_torso")]), 8);
+        assert_eq!(run("standard", &[("supercharger", "center_torso")]), 8);
+        // TSM adds its walking MP before MASC doubles it
+        assert_eq!(run("tsm", &[("masc", "left_torso")]), 10);
+        assert_eq!(
+            run("standard", &[("masc", "left_torso"), ("supercharger", "center_torso")]),
+            10
+        );
+        assert_eq!(
+            run("tsm", &[("masc", "left_torso"), ("clan-supercharger", "center_torso")]),
+            13
+        );
+    }
+
+    /// The classification checks match on slug substrings; pin them to the
+    /// catalog slugs so a rename shows up here.
+    #[test]
+    fn classifies_catalog_slugs() {
+        for slug in ["case", "clan-case", "case-ii"] {
+            assert!(is_case(&equipment(slug)), "{slug}");
+        }
+        assert!(is_case_ii(&equipment("case-ii")));
+        assert!(!is_case_ii(&equipment("case")));
+
+        for slug in [
+            "anti-missile-system",
+            "clan-anti-missile-system",
+            "guardian-ecm-suite",
+            "angel-ecm-suite",
+            "clan-ecm-suite",
+            "beagle-active-probe",
+            "bloodhound-active-probe",
+            "clan-active-probe",
+        ] {
+            assert!(is_defensive(&equipment(slug)), "{slug}");
+        }
+        for slug in [
+            "medium-laser",
+            "ppc",
+            "lrm-20",
+            "targeting-computer",
+            "case",
+        ] {
+            assert!(!is_defensive(&equipment(slug)), "{slug}");
+        }
+
+        assert!(is_targeting_computer(&equipment("targeting-computer")));
+        assert!(is_targeting_computer(&equipment("clan-targeting-computer")));
+
+        let heat = |slug: &str| bv_heat(&equipment(slug));
+        assert_eq!(heat("autocannon-5"), 1);
+        assert_eq!(heat("ultra-ac-5"), 2);
+        assert_eq!(heat("clan-ultra-ac-20"), 2);
+        assert_eq!(heat("rotary-ac-5"), 6);
+        assert_eq!(heat("clan-rotary-ac-2"), 6);
+
+        assert_eq!(
+            explosive_penalty_per_slot(&equipment("ac-2-ammo")),
+            Some(15.0)
+        );
+        assert_eq!(explosive_penalty_per_slot(&equipment("gauss-ammo")), None);
+        assert_eq!(
+            explosive_penalty_per_slot(&equipment("gauss-rifle")),
+            Some(1.0)
+        );
+        assert_eq!(explosive_penalty_per_slot(&equipment("medium-laser")), None);
+    }
+}
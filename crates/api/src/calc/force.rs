@@ -0,0 +1,183 @@
+//! Random force assembly against a point budget. Sampling is driven by a
+//! seeded SplitMix64 generator, so the same pool, constraints and seed always
+//! produce the same forces.
+
+use std::collections::HashSet;
+
+use crate::calc::rng::SplitMix64;
+
+/// A unit eligible for the force, with its cost in the chosen point system.
+#[derive(Debug, Clone)]
+pub struct PoolUnit {
+    pub value: i32,
+    pub role: Option<String>,
+}
+
+/// Constraints for one build. Role counts are minimums; other slots are open.
+#[derive(Debug, Clone)]
+pub struct ForceRequest {
+    pub budget: i32,
+    pub unit_count: usize,
+    pub roles: Vec<(String, usize)>,
+    pub seed: u64,
+    pub max_results: usize,
+}
+
+/// One assembled force: indices into the pool, in slot order.
+#[derive(Debug, Clone)]
+pub struct Force {
+    pub members: Vec<usize>,
+    pub total: i32,
+}
+
+impl Force {
+    /// Signed distance from the budget (positive = over budget).
+    pub fn difference(&self, budget: i32) -> i32 {
+        self.total - budget
+    }
+}
+
+/// Sampling attempts per requested result, and the overall cap.
+const ATTEMPTS_PER_RESULT: usize = 50;
+const MAX_ATTEMPTS: usize = 2000;
+
+/// Open slots retry a random pick this many times to stay inside the budget.
+const PICK_RETRIES: usize = 8;
+
+// ── Assembly ─────────────────────────────────────────────────────────────────
+
+/// Candidates for one slot, sorted by ascending value for best-fit lookups.
+struct SlotSet {
+    sorted: Vec<usize>,
+}
+
+impl SlotSet {
+    fn new(pool: &[PoolUnit], role: Option<&str>) -> Self {
+        let mut sorted: Vec<usize> = (0..pool.len())
+            .filter(|&i| role.is_none_or(|r| pool[i].role.as_deref() == Some(r)))
+            .collect();
+        sorted.sort_by_key(|&i| (pool[i].value, i));
+        Self { sorted }
+    }
+
+    fn random(&self, rng: &mut SplitMix64, taken: &HashSet<usize>) -> Option<usize> {
+        if self.sorted.is_empty() {
+            return None;
+        }
+        for _ in 0..32 {
+            let i = self.sorted[rng.below(self.sorted.len())];
+            if !taken.contains(&i) {
+                return Some(i);
+            }
+        }
+        // Mostly taken (small role sets): pick among the free ones directly.
+        let free: Vec<usize> = self
+            .sorted
+            .iter()
+            .copied()
+            .filter(|i| !taken.contains(i))
+            .collect();
+        (!free.is_empty()).then(|| free[rng.below(free.len())])
+    }
+
+    /// The untaken unit whose value is closest to `target`.
+    fn closest(&self, pool: &[PoolUnit], target: i32, taken: &HashSet<usize>) -> Option<usize> {
+        let pos = self.sorted.partition_point(|&i| pool[i].value < target);
+        let below = self.sorted[..pos].iter().rev().find(|i| !taken.contains(i));
+        let above = self.sorted[pos..].iter().find(|i| !taken.contains(i));
+        match (below, above) {
+            (Some(&b), Some(&a)) => {
+                if target - pool[b].value <= pool[a].value - target {
+                    Some(b)
+                } else {
+                    Some(a)
+                }
+            }
+            (b, a) => b.or(a).copied(),
+        }
+    }
+}
+
+/// Check that the pool can satisfy the role requirements at all.
+/// Returns the first role with too few units as (role, available).
+pub fn unmet_role(pool: &[PoolUnit], roles: &[(String, usize)]) -> Option<(String, usize)> {
+    roles.iter().find_map(|(role, count)| {
+        let available = pool
+            .iter()
+            .filter(|u| u.role.as_deref() == Some(role))
+            .count();
+        (available < *count).then(|| (role.clone(), available))
+    })
+}
+
+/// Assemble up to `max_results` distinct forces, ranked by distance from the
+/// budget (under-budget first on ties).
+pub fn build(pool: &[PoolUnit], req: &ForceRequest) -> Vec<Force> {
+    if pool.len() < req.unit_count || req.unit_count == 0 {
+        return Vec::new();
+    }
+
+    // Role slots first, open slots last so the final best-fit pick can draw
+    // from the whole pool.
+    let mut slots: Vec<SlotSet> = Vec::new();
+    for (role, count) in &req.roles {
+        for _ in 0..*count {
+            slots.push(SlotSet::new(pool, Some(role)));
+        }
+    }
+    while slots.len() < req.unit_count {
+        slots.push(SlotSet::new(pool, None));
+    }
+    let min_value = pool.iter().map(|u| u.value).min().unwrap_or(0);
+
+    let mut rng = SplitMix64::new(req.seed);
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    let mut forces: Vec<Force> = Vec::new();
+    let attempts = (req.max_results * ATTEMPTS_PER_RESULT).min(MAX_ATTEMPTS);
+
+    'attempt: for _ in 0..attempts {
+        let mut taken: HashSet<usize> = HashSet::new();
+        let mut members: Vec<usize> = Vec::with_capacity(slots.len());
+        let mut remaining = req.budget;
+
+        for (n, slot) in slots.iter().enumerate() {
+            let slots_left = (slots.len() - n - 1) as i32;
+            let pick = if slots_left == 0 {
+                slot.closest(pool, remaining, &taken)
+            } else {
+                // Leave room for the cheapest possible units in later slots.
+                let ceiling = remaining - min_value * slots_left;
+                let mut pick = slot.random(&mut rng, &taken);
+                for _ in 0..PICK_RETRIES {
+                    match pick {
+                        Some(i) if pool[i].value > ceiling => pick = slot.random(&mut rng, &taken),
+                        _ => break,
+                    }
+                }
+                pick
+            };
+            let Some(i) = pick else {
+                continue 'attempt;
+            };
+            taken.insert(i);
+            members.push(i);
+            remaining -= pool[i].value;
+        }
+
+        let mut key = members.clone();
+        key.sort_unstable();
+        if seen.insert(key) {
+            forces.push(Force {
+                total: req.budget - remaining,
+                members,
+            });
+        }
+    }
+
+    forces.sort_by_key(|f| {
+        let diff = f.difference(req.budget);
+        (diff.abs(), diff > 0, f.total)
+    });
+    forces.truncate(req.max_results);
+    forces
+}
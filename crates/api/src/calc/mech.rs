@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+
+use rust_decimal::prelude::ToPrimitive;
+use sqlx::PgPool;
+
+use crate::{
+    db::{
+        construction,
+        models::{
+            DbArmorType, DbCockpitType, DbEngineType, DbEquipment, DbGyroType, DbHeatsinkType,
+            DbInternalStructure, DbMyomerType, DbStructureType,
+        },
+    },
+    error::AppError,
+};
+
+// ── Input ────────────────────────────────────────────────────────────────────
+
+/// Location values accepted for mounted equipment (matches `location_name_enum`).
+pub const MECH_LOCATIONS: &[&str] = &[
+    "head",
+    "center_torso",
+    "left_torso",
+    "right_torso",
+    "left_arm",
+    "right_arm",
+    "left_leg",
+    "right_leg",
+    "front_left_leg",
+    "front_right_leg",
+    "rear_left_leg",
+    "rear_right_leg",
+    "center_leg",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MechConfig {
+    Biped,
+    Quad,
+    Tripod,
+}
+
+impl MechConfig {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "biped" | "lam" => Some(Self::Biped),
+            "quad" | "quadvee" => Some(Self::Quad),
+            "tripod" => Some(Self::Tripod),
+            _ => None,
+        }
+    }
+
+    /// Locations that exist on this chassis configuration.
+    pub fn locations(self) -> &'static [&'static str] {
+        match self {
+            Self::Biped => &MECH_LOCATIONS[..8],
+            Self::Quad => &[
+                "head",
+                "center_torso",
+                "left_torso",
+                "right_torso",
+                "front_left_leg",
+                "front_right_leg",
+                "rear_left_leg",
+                "rear_right_leg",
+            ],
+            Self::Tripod => &[
+                "head",
+                "center_torso",
+                "left_torso",
+                "right_torso",
+                "left_arm",
+                "right_arm",
+                "left_leg",
+                "right_leg",
+                "center_leg",
+            ],
+        }
+    }
+}
+
+/// A piece of equipment placed on a mech, as supplied by the caller.
+#[derive(Debug, Clone)]
+pub struct Mount {
+    pub equipment_slug: String,
+    pub location: String,
+    pub quantity: i32,
+    pub is_rear: bool,
+}
+
+/// Unresolved mech configuration: construction component slugs plus mounts.
+#[derive(Debug, Clone)]
+pub struct MechSpec {
+    pub tonnage: i32,
+    pub tech_base: String,
+    pub config: MechConfig,
+    pub engine_type: String,
+    pub engine_rating: i32,
+    pub walk_mp: Option<i32>,
+    pub jump_mp: i32,
+    pub armor_type: String,
+    pub armor_points: i32,
+    pub structure_type: String,
+    pub gyro_type: String,
+    pub cockpit_type: String,
+    pub heatsink_type: String,
+    pub heat_sink_count: i32,
+    pub myomer_type: String,
+    pub mounts: Vec<Mount>,
+}
+
+// ── Resolved ─────────────────────────────────────────────────────────────────
+
+/// A mount joined with its equipment row.
+#[derive(Debug, Clone)]
+pub struct ResolvedMount {
+    pub mount: Mount,
+    pub equipment: DbEquipment,
+}
+
+/// A mech configuration with every slug resolved against the reference tables.
+#[derive(Debug, Clone)]
+pub struct ResolvedMech {
+    pub spec: MechSpec,
+    pub engine: DbEngineType,
+    pub armor: DbArmorType,
+    pub structure: DbStructureType,
+    pub gyro: DbGyroType,
+    pub cockpit: DbCockpitType,
+    pub heatsink: DbHeatsinkType,
+    pub myomer: DbMyomerType,
+    pub internal: DbInternalStructure,
+    pub mounts: Vec<ResolvedMount>,
+}
+
+fn require<T>(row: Option<T>, kind: &str, slug: &str) -> Result<T, AppError> {
+    row.ok_or_else(|| AppError::Validation(format!("unknown {kind} '{slug}'")))
+}
+
+/// Resolve all component and equipment slugs in `spec`. Equipment slugs replaced
+/// by the equipment catalog are rewritten to their current slug.
+/// Unknown slugs, locations or out-of-range tonnage are reported as validation errors.
+pub async fn resolve(pool: &PgPool, mut spec: MechSpec) -> Result<ResolvedMech, AppError> {
+    if spec.tonnage < 10 || spec.tonnage > 200 || spec.tonnage % 5 != 0 {
+        return Err(AppError::Validation(format!(
+            "tonnage must be a multiple of 5 between 10 and 200, got {}",
+            spec.tonnage
+        )));
+    }
+    if spec.engine_rating <= 0 || spec.engine_rating % 5 != 0 {
+        return Err(AppError::Validation(format!(
+            "engine rating must be a positive multiple of 5, got {}",
+            spec.engine_rating
+        )));
+    }
+    let allowed = spec.config.locations();
+    for m in &spec.mounts {
+        if !allowed.contains(&m.location.as_str()) {
+            return Err(AppError::Validation(format!(
+                "location '{}' is not valid for this configuration (expected one of: {})",
+                m.location,
+                allowed.join(", ")
+            )));
+        }
+        if m.quantity < 1 {
+            return Err(AppError::Validation(format!(
+                "quantity for '{}' must be at least 1",
+                m.equipment_slug
+            )));
+        }
+    }
+
+    let (engine, armor, structure, gyro, cockpit, heatsink, myomer, internal) = tokio::try_join!(
+        construction::get_engine_type_by_slug(pool, &spec.engine_type),
+        construction::get_armor_type_by_slug(pool, &spec.armor_type),
+        construction::get_structure_type_by_slug(pool, &spec.structure_type),
+        construction::get_gyro_type_by_slug(pool, &spec.gyro_type),
+        construction::get_cockpit_type_by_slug(pool, &spec.cockpit_type),
+        construction::get_heatsink_type_by_slug(pool, &spec.heatsink_type),
+        construction::get_myomer_type_by_slug(pool, &spec.myomer_type),
+        construction::get_internal_structure(pool, spec.tonnage as i16),
+    )?;
+
+    let mut slugs: Vec<String> = spec
+        .mounts
+        .iter()
+        .map(|m| m.equipment_slug.clone())
+        .collect();
+    slugs.sort();
+    slugs.dedup();
+    let current = crate::db::equipment::current_slugs(pool, &slugs).await?;
+    if !current.is_empty() {
+        for m in &mut spec.mounts {
+            if let Some(slug) = current.get(&m.equipment_slug) {
+                m.equipment_slug = slug.clone();
+            }
+        }
+        slugs = spec.mounts.iter().map(|m| m.equipment_slug.clone()).collect();
+        slugs.sort();
+        slugs.dedup();
+    }
+    let by_slug: HashMap<String, DbEquipment> = crate::db::equipment::get_by_slugs(pool, &slugs)
+        .await?
+        .into_iter()
+        .map(|e| (e.slug.clone(), e))
+        .collect();
+
+    let mut mounts = Vec::with_capacity(spec.mounts.len());
+    for m in &spec.mounts {
+        let equipment = require(
+            by_slug.get(&m.equipment_slug).cloned(),
+            "equipment",
+            &m.equipment_slug,
+        )?;
+        mounts.push(ResolvedMount {
+            mount: m.clone(),
+            equipment,
+        });
+    }
+
+    Ok(ResolvedMech {
+        engine: require(engine, "engine type", &spec.engine_type)?,
+        armor: require(armor, "armor type", &spec.armor_type)?,
+        structure: require(structure, "structure type", &spec.structure_type)?,
+        gyro: require(gyro, "gyro type", &spec.gyro_type)?,
+        cockpit: require(cockpit, "cockpit type", &spec.cockpit_type)?,
+        heatsink: require(heatsink, "heat sink type", &spec.heatsink_type)?,
+        myomer: require(myomer, "myomer type", &spec.myomer_type)?,
+        internal: internal.ok_or_else(|| {
+            AppError::Validation(format!(
+                "no internal structure table for {} tons",
+                spec.tonnage
+            ))
+        })?,
+        mounts,
+        spec,
+    })
+}
+
+// ── Derived values ───────────────────────────────────────────────────────────
+
+/// Equipment catalog slugs of MASC and superchargers.
+const MASC_SLUGS: &[&str] = &["masc", "clan-masc"];
+const SUPERCHARGER_SLUGS: &[&str] = &["supercharger", "clan-supercharger"];
+
+impl ResolvedMech {
+    pub fn walk_mp(&self) -> i32 {
+        self.spec
+            .walk_mp
+            .unwrap_or(self.spec.engine_rating / self.spec.tonnage)
+    }
+
+    /// Run MP as Battle Value counts it: walk × 1.5 rounded up, walk × 2 with
+    /// MASC or a supercharger, walk × 2.5 rounded up with both. TSM adds one
+    /// walking MP before the multiplier.
+    pub fn run_mp(&self) -> i32 {
+        let mut walk = self.walk_mp();
+        if self.myomer.slug == "tsm" {
+            walk += 1;
+        }
+        let masc = self.myomer.slug == "masc"
+            || self.has_equipment(|e| MASC_SLUGS.contains(&e.slug.as_str()));
+        let supercharger = self.has_equipment(|e| SUPERCHARGER_SLUGS.contains(&e.slug.as_str()));
+        match (masc, supercharger) {
+            (true, true) => (walk * 5 + 1) / 2,
+            (true, false) | (false, true) => walk * 2,
+            (false, false) => (walk * 3 + 1) / 2,
+        }
+    }
+
+    pub fn is_clan(&self) -> bool {
+        self.spec.tech_base == "clan"
+    }
+
+    pub fn has_equipment(&self, pred: impl Fn(&DbEquipment) -> bool) -> bool {
+        self.mounts.iter().any(|m| pred(&m.equipment))
+    }
+
+    pub fn has_equipment_in(&self, location: &str, pred: impl Fn(&DbEquipment) -> bool) -> bool {
+        self.mounts
+            .iter()
+            .any(|m| m.mount.location == location && pred(&m.equipment))
+    }
+
+    /// Total internal structure points for the configuration.
+    pub fn structure_points(&self) -> i32 {
+        let is = &self.internal;
+        let torso = is.head as i32 + is.center_torso as i32 + 2 * is.side_torso as i32;
+        match self.spec.config {
+            MechConfig::Biped => torso + 2 * is.arm as i32 + 2 * is.leg as i32,
+            MechConfig::Quad => torso + 4 * is.leg as i32,
+            MechConfig::Tripod => torso + 2 * is.arm as i32 + 3 * is.leg as i32,
+        }
+    }
+
+    /// Internal structure points for a single location.
+    pub fn structure_points_at(&self, location: &str) -> i32 {
+        let is = &self.internal;
+        (match location {
+            "head" => is.head,
+            "center_torso" => is.center_torso,
+            "left_torso" | "right_torso" => is.side_torso,
+            "left_arm" | "right_arm" => is.arm,
+            _ => is.leg,
+        }) as i32
+    }
+
+    /// Heat dissipated per turn by all heat sinks.
+    pub fn heat_dissipation(&self) -> i32 {
+        self.spec.heat_sink_count * self.heatsink.dissipation as i32
+    }
+
+    pub fn myomer_property(&self, key: &str) -> Option<f64> {
+        self.myomer.properties.get(key).and_then(|v| v.as_f64())
+    }
+}
+
+/// Numeric value of a `NUMERIC` column, defaulting to `default` if unrepresentable.
+pub fn dec(d: rust_decimal::Decimal, default: f64) -> f64 {
+    d.to_f64().unwrap_or(default)
+}
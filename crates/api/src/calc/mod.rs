@@ -0,0 +1,6 @@
+pub mod bv;
+pub mod force;
+pub mod mech;
+pub mod rat;
+pub mod rng;
+pub mod validate;
@@ -0,0 +1,119 @@
+//! Random Assignment Tables built from weighted availability, plus seeded
+//! draws against the same weights.
+
+use crate::calc::rng::SplitMix64;
+
+/// 2d6 results ordered from most to least likely, with their odds out of 36.
+const TWO_D6_ROWS: [(i32, i32); 11] = [
+    (7, 6),
+    (6, 5),
+    (8, 5),
+    (5, 4),
+    (9, 4),
+    (4, 3),
+    (10, 3),
+    (3, 2),
+    (11, 2),
+    (2, 1),
+    (12, 1),
+];
+
+/// One row of a roll table: rolls `low..=high` select `member`.
+#[derive(Debug, Clone)]
+pub struct TableRow {
+    pub low: i32,
+    pub high: i32,
+    pub member: usize,
+    /// Chance of landing on this row.
+    pub probability: f64,
+}
+
+/// Indices ordered by descending weight; ties keep input order.
+fn by_weight(weights: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..weights.len()).filter(|&i| weights[i] > 0.0).collect();
+    order.sort_by(|&a, &b| weights[b].total_cmp(&weights[a]).then(a.cmp(&b)));
+    order
+}
+
+/// Normalised cumulative weight after each entry of `order`.
+fn cumulative(weights: &[f64], order: &[usize]) -> Vec<f64> {
+    let total: f64 = order.iter().map(|&i| weights[i]).sum();
+    let mut acc = 0.0;
+    order
+        .iter()
+        .map(|&i| {
+            acc += weights[i];
+            acc / total
+        })
+        .collect()
+}
+
+/// 2d6 table: the most common results go to the most common units. Each row
+/// takes the unit whose share of the weight covers the row's midpoint, so
+/// dominant units can fill several rows and rare ones may not appear.
+pub fn two_d6(weights: &[f64]) -> Vec<TableRow> {
+    let order = by_weight(weights);
+    if order.is_empty() {
+        return Vec::new();
+    }
+    let cum = cumulative(weights, &order);
+    let mut rows = Vec::with_capacity(TWO_D6_ROWS.len());
+    let mut covered = 0.0;
+    for (roll, odds) in TWO_D6_ROWS {
+        let p = odds as f64 / 36.0;
+        let mid = covered + p / 2.0;
+        covered += p;
+        let k = cum.partition_point(|&c| c < mid).min(order.len() - 1);
+        rows.push(TableRow {
+            low: roll,
+            high: roll,
+            member: order[k],
+            probability: p,
+        });
+    }
+    rows.sort_by_key(|r| r.low);
+    rows
+}
+
+/// Percentage (d100) table, most common units first. Units whose share rounds
+/// to less than one percent are dropped.
+pub fn percentage(weights: &[f64]) -> Vec<TableRow> {
+    let order = by_weight(weights);
+    let cum = cumulative(weights, &order);
+    let mut rows = Vec::new();
+    let mut low = 1;
+    for (k, &i) in order.iter().enumerate() {
+        let high = if k + 1 == order.len() {
+            100
+        } else {
+            (cum[k] * 100.0).round() as i32
+        };
+        if high >= low {
+            rows.push(TableRow {
+                low,
+                high,
+                member: i,
+                probability: (high - low + 1) as f64 / 100.0,
+            });
+            low = high + 1;
+        }
+    }
+    rows
+}
+
+/// Draw `count` members with replacement, proportional to weight.
+pub fn roll(weights: &[f64], count: usize, seed: u64) -> Vec<usize> {
+    let order = by_weight(weights);
+    if order.is_empty() {
+        return Vec::new();
+    }
+    let cum = cumulative(weights, &order);
+    let mut rng = SplitMix64::new(seed);
+    (0..count)
+        .map(|_| {
+            let r = rng.next_f64();
+            let k = cum.partition_point(|&c| c <= r).min(order.len() - 1);
+            order[k]
+        })
+        .collect()
+}
@@ -0,0 +1,596 @@
+//! BattleMech construction checks following the TechManual: weight budget,
+//! critical slot allocation, armor maximums, heat sinks, tech base and
+//! availability by year.
+
+use std::collections::HashMap;
+
+use crate::{
+    calc::mech::{dec, ResolvedMech, ResolvedMount},
+    db::models::DbEquipment,
+};
+
+/// Armor assigned to one location.
+#[derive(Debug, Clone)]
+pub struct ArmorAllocation {
+    pub location: String,
+    pub front: i32,
+    pub rear: i32,
+}
+
+/// Design details that only matter for validation (the BV calculator ignores them).
+#[derive(Debug, Clone)]
+pub struct DesignRules {
+    pub armor: Vec<ArmorAllocation>,
+    pub year: Option<i32>,
+    pub lower_arm_actuators: bool,
+    pub hand_actuators: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub code: &'static str,
+    pub message: String,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WeightReport {
+    pub structure: f64,
+    pub engine: f64,
+    pub gyro: f64,
+    pub cockpit: f64,
+    pub heat_sinks: f64,
+    pub armor: f64,
+    pub jump_jets: f64,
+    pub equipment: f64,
+    pub total: f64,
+    pub maximum: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LocationReport {
+    pub location: String,
+    pub slots_total: i32,
+    pub slots_fixed: i32,
+    pub slots_equipment: i32,
+    pub armor_front: i32,
+    pub armor_rear: i32,
+    pub armor_max: i32,
+    pub structure_points: i32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HeatReport {
+    pub heat_sink_count: i32,
+    pub minimum_heat_sinks: i32,
+    pub engine_heat_sink_capacity: i32,
+    pub dissipation: i32,
+    pub weapon_heat: i32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DesignReport {
+    pub weight: WeightReport,
+    pub locations: Vec<LocationReport>,
+    pub unallocated_slots: i32,
+    pub free_slots: i32,
+    pub heat: HeatReport,
+    pub walk_mp: i32,
+    pub run_mp: i32,
+    pub jump_mp: i32,
+    pub armor_points: i32,
+    pub errors: Vec<Issue>,
+    pub warnings: Vec<Issue>,
+}
+
+impl DesignReport {
+    fn error(&mut self, code: &'static str, message: String, location: Option<&str>) {
+        self.errors.push(Issue {
+            code,
+            message,
+            location: location.map(str::to_string),
+        });
+    }
+
+    fn warn(&mut self, code: &'static str, message: String, location: Option<&str>) {
+        self.warnings.push(Issue {
+            code,
+            message,
+            location: location.map(str::to_string),
+        });
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Round up to the next half ton, tolerating float noise.
+fn half_ton(v: f64) -> f64 {
+    ((v * 2.0) - 1e-9).ceil().max(0.0) / 2.0
+}
+
+fn is_torso(location: &str) -> bool {
+    location.ends_with("_torso")
+}
+
+fn is_arm(location: &str) -> bool {
+    location.ends_with("_arm")
+}
+
+fn slots_in(location: &str) -> i32 {
+    if location == "head" || location.ends_with("_leg") {
+        6
+    } else {
+        12
+    }
+}
+
+/// Jump jet weight per jet by tonnage class (standard jump jets).
+fn jump_jet_weight(tonnage: i32) -> f64 {
+    match tonnage {
+        ..=55 => 0.5,
+        56..=85 => 1.0,
+        _ => 2.0,
+    }
+}
+
+/// Per-item tonnage, with TechManual defaults for ammunition (1 ton, or ½ for half bins).
+fn item_tonnage(e: &DbEquipment) -> Option<f64> {
+    match e.tonnage {
+        Some(t) => Some(dec(t, 0.0)),
+        None if e.category == "ammunition" => Some(if e.slug.contains("half") { 0.5 } else { 1.0 }),
+        None => None,
+    }
+}
+
+/// Per-item critical slots; ammunition and jump jets default to 1.
+fn item_crits(e: &DbEquipment, heat_sink_crits: i32) -> Option<i32> {
+    match e.crits {
+        Some(c) => Some(c),
+        None if e.category == "ammunition" || e.category == "jump_jet" => Some(1),
+        None if e.category == "heat_sink" => Some(heat_sink_crits),
+        None => None,
+    }
+}
+
+/// True if a component slug is explicitly the Inner Sphere variant of a dual-tech item.
+fn is_is_only_slug(slug: &str) -> bool {
+    slug.ends_with("-is") || slug.starts_with("is")
+}
+
+// ── Validation ───────────────────────────────────────────────────────────────
+
+pub fn validate(
+    mech: &ResolvedMech,
+    rules: &DesignRules,
+    engine_weight: Option<f64>,
+) -> DesignReport {
+    let mut r = DesignReport::default();
+    let spec = &mech.spec;
+    let tonnage = spec.tonnage;
+    let rating = spec.engine_rating;
+    let locations = spec.config.locations();
+
+    // ── Movement ─────────────────────────────────────────────────────────────
+    r.walk_mp = rating / tonnage;
+    r.run_mp = mech.run_mp();
+    r.jump_mp = spec.jump_mp;
+    if rating % tonnage != 0 {
+        r.warn(
+            "engine_rating_remainder",
+            format!("engine rating {rating} is not a multiple of tonnage {tonnage}; walk MP rounds down to {}", r.walk_mp),
+            None,
+        );
+    }
+    if r.walk_mp < 1 {
+        r.error(
+            "walk_mp_too_low",
+            format!("engine rating {rating} gives less than 1 walk MP"),
+            None,
+        );
+    }
+    if spec.jump_mp > r.walk_mp {
+        r.error(
+            "jump_exceeds_walk",
+            format!("jump MP {} exceeds walk MP {}", spec.jump_mp, r.walk_mp),
+            None,
+        );
+    }
+    if mech.gyro.is_superheavy_only && tonnage <= 100 {
+        r.error(
+            "superheavy_gyro",
+            format!(
+                "gyro '{}' is only legal on superheavy mechs",
+                mech.gyro.slug
+            ),
+            None,
+        );
+    }
+
+    // ── Weight ───────────────────────────────────────────────────────────────
+    let w = &mut r.weight;
+    w.maximum = tonnage as f64;
+    w.structure = half_ton(tonnage as f64 * dec(mech.structure.weight_fraction, 0.1));
+    w.gyro = half_ton((rating as f64 / 100.0).ceil() * dec(mech.gyro.weight_multiplier, 1.0));
+    w.cockpit = mech.cockpit.weight as f64;
+    w.heat_sinks = (spec.heat_sink_count - 10).max(0) as f64 * dec(mech.heatsink.weight, 1.0);
+    w.jump_jets = spec.jump_mp as f64 * jump_jet_weight(tonnage);
+
+    let armor_points: i32 = rules.armor.iter().map(|a| a.front + a.rear).sum();
+    r.armor_points = armor_points;
+    r.weight.armor = half_ton(armor_points as f64 / dec(mech.armor.points_per_ton, 16.0));
+
+    match engine_weight {
+        Some(std_weight) => {
+            r.weight.engine = half_ton(std_weight * dec(mech.engine.weight_multiplier, 1.0));
+        }
+        None => r.error(
+            "invalid_engine_rating",
+            format!(
+                "no engine weight table entry for rating {rating} (valid: 10-400 in steps of 5)"
+            ),
+            None,
+        ),
+    }
+
+    let mut missing_weight: Vec<&str> = Vec::new();
+    for m in &mech.mounts {
+        let e = &m.equipment;
+        // Jump jets and heat sinks are weighed from jumpMp / heatSinkCount;
+        // mounting them only places their critical slots.
+        if e.category == "jump_jet" || e.category == "heat_sink" {
+            continue;
+        }
+        match item_tonnage(e) {
+            Some(t) => r.weight.equipment += t * m.mount.quantity as f64,
+            None => missing_weight.push(&e.slug),
+        }
+    }
+    missing_weight.sort_unstable();
+    missing_weight.dedup();
+    for slug in missing_weight {
+        r.warn(
+            "missing_weight_data",
+            format!("equipment '{slug}' has no tonnage data; counted as 0"),
+            None,
+        );
+    }
+
+    let w = &mut r.weight;
+    w.total = w.structure
+        + w.engine
+        + w.gyro
+        + w.cockpit
+        + w.heat_sinks
+        + w.armor
+        + w.jump_jets
+        + w.equipment;
+    let (total, maximum) = (w.total, w.maximum);
+    if total > maximum {
+        r.error(
+            "overweight",
+            format!(
+                "design weighs {total} tons, {} over the {maximum}-ton limit",
+                total - maximum
+            ),
+            None,
+        );
+    } else if total < maximum {
+        r.warn(
+            "underweight",
+            format!("{} tons unallocated", maximum - total),
+            None,
+        );
+    }
+
+    // ── Critical slots ───────────────────────────────────────────────────────
+    let torso_cockpit = mech.cockpit.slug == "torso-mounted";
+    let mut fixed: HashMap<&str, i32> = HashMap::new();
+    for &loc in locations {
+        let n = match loc {
+            "head" if torso_cockpit => 3,
+            "head" => mech.cockpit.crits as i32,
+            "center_torso" => {
+                mech.engine.ct_crits as i32
+                    + mech.gyro.crits as i32
+                    + if torso_cockpit { 2 } else { 0 }
+            }
+            "left_torso" | "right_torso" => mech.engine.st_crits as i32,
+            l if is_arm(l) => {
+                2 + rules.lower_arm_actuators as i32
+                    + (rules.lower_arm_actuators && rules.hand_actuators) as i32
+            }
+            _ => 4,
+        };
+        fixed.insert(loc, n);
+    }
+    if rules.hand_actuators && !rules.lower_arm_actuators {
+        r.warn(
+            "hand_without_lower_arm",
+            "hand actuators require lower arm actuators; hands ignored".into(),
+            None,
+        );
+    }
+
+    let heat_sink_crits = mech.heatsink.crits as i32;
+    let mut used: HashMap<&str, i32> = HashMap::new();
+    let mut placed_jump_jets = 0;
+    let mut placed_heat_sinks = 0;
+    let mut missing_crits: Vec<&str> = Vec::new();
+    for m in &mech.mounts {
+        let e = &m.equipment;
+        let Some(c) = item_crits(e, heat_sink_crits) else {
+            missing_crits.push(&e.slug);
+            continue;
+        };
+        *used.entry(m.mount.location.as_str()).or_default() += c * m.mount.quantity;
+        match e.category.as_str() {
+            "jump_jet" => placed_jump_jets += m.mount.quantity,
+            "heat_sink" => placed_heat_sinks += m.mount.quantity,
+            _ => {}
+        }
+        if m.mount.is_rear && !is_torso(&m.mount.location) {
+            r.warn(
+                "rear_mount",
+                format!("'{}' is rear-facing in {}", e.slug, m.mount.location),
+                Some(&m.mount.location),
+            );
+        }
+    }
+    missing_crits.sort_unstable();
+    missing_crits.dedup();
+    for slug in missing_crits {
+        r.warn(
+            "missing_crit_data",
+            format!("equipment '{slug}' has no critical slot data; counted as 0"),
+            None,
+        );
+    }
+
+    let engine_hs_capacity = rating / 25;
+    let external_hs = (spec.heat_sink_count - engine_hs_capacity).max(0);
+    let unallocated = (external_hs - placed_heat_sinks).max(0) * heat_sink_crits
+        + (spec.jump_mp - placed_jump_jets).max(0)
+        + mech.structure.crits as i32
+        + mech.armor.crits as i32;
+    r.unallocated_slots = unallocated;
+
+    // ── Armor ────────────────────────────────────────────────────────────────
+    let mut armor_by_loc: HashMap<&str, (i32, i32)> = HashMap::new();
+    for a in &rules.armor {
+        if !locations.contains(&a.location.as_str()) {
+            r.error(
+                "invalid_location",
+                format!(
+                    "armor location '{}' is not valid for this configuration",
+                    a.location
+                ),
+                Some(&a.location),
+            );
+            continue;
+        }
+        if a.front < 0 || a.rear < 0 {
+            r.error(
+                "negative_armor",
+                format!("armor in {} must not be negative", a.location),
+                Some(&a.location),
+            );
+        }
+        if a.rear > 0 && !is_torso(&a.location) {
+            r.error(
+                "rear_armor_not_allowed",
+                format!("{} has no rear armor", a.location),
+                Some(&a.location),
+            );
+        }
+        let entry = armor_by_loc.entry(a.location.as_str()).or_default();
+        entry.0 += a.front;
+        entry.1 += a.rear;
+    }
+
+    let mut free_total = 0;
+    for &loc in locations {
+        let structure = mech.structure_points_at(loc);
+        let armor_max = if loc == "head" { 9 } else { structure * 2 };
+        let (front, rear) = armor_by_loc.get(loc).copied().unwrap_or_default();
+        let slots_total = slots_in(loc);
+        let slots_fixed = fixed[loc];
+        let slots_equipment = used.get(loc).copied().unwrap_or(0);
+        let free = slots_total - slots_fixed - slots_equipment;
+        if free < 0 {
+            r.error(
+                "crits_exceeded",
+                format!(
+                    "{loc} uses {} of {} critical slots",
+                    slots_fixed + slots_equipment,
+                    slots_total
+                ),
+                Some(loc),
+            );
+        }
+        if front + rear > armor_max {
+            r.error(
+                "armor_exceeded",
+                format!(
+                    "{loc} has {} armor points, maximum is {armor_max}",
+                    front + rear
+                ),
+                Some(loc),
+            );
+        }
+        free_total += free.max(0);
+        r.locations.push(LocationReport {
+            location: loc.to_string(),
+            slots_total,
+            slots_fixed,
+            slots_equipment,
+            armor_front: front,
+            armor_rear: rear,
+            armor_max,
+            structure_points: structure,
+        });
+    }
+    r.free_slots = free_total - unallocated;
+    if r.free_slots < 0 {
+        r.error(
+            "total_crits_exceeded",
+            format!("{unallocated} slots for structure, armor, heat sinks and jump jets do not fit in {free_total} free slots"),
+            None,
+        );
+    }
+
+    // ── Heat ─────────────────────────────────────────────────────────────────
+    let fusion = !matches!(mech.engine.slug.as_str(), "ice" | "fuel-cell");
+    r.heat = HeatReport {
+        heat_sink_count: spec.heat_sink_count,
+        minimum_heat_sinks: if fusion { 10 } else { 0 },
+        engine_heat_sink_capacity: engine_hs_capacity,
+        dissipation: mech.heat_dissipation(),
+        weapon_heat: mech
+            .mounts
+            .iter()
+            .map(|m| m.equipment.heat.unwrap_or(0) * m.mount.quantity)
+            .sum(),
+    };
+    if spec.heat_sink_count < r.heat.minimum_heat_sinks {
+        r.error(
+            "heat_sink_minimum",
+            format!(
+                "{} heat sinks installed, fusion engines require at least 10",
+                spec.heat_sink_count
+            ),
+            None,
+        );
+    }
+    if r.heat.weapon_heat > r.heat.dissipation {
+        r.warn(
+            "heat_overload",
+            format!(
+                "weapons generate {} heat against {} dissipation",
+                r.heat.weapon_heat, r.heat.dissipation
+            ),
+            None,
+        );
+    }
+
+    // ── Tech base & availability ─────────────────────────────────────────────
+    let components: [(&str, &str, Option<&str>, Option<i32>); 7] = [
+        (
+            "engine",
+            &mech.engine.slug,
+            Some(&mech.engine.tech_base),
+            mech.engine.intro_year,
+        ),
+        (
+            "armor",
+            &mech.armor.slug,
+            Some(&mech.armor.tech_base),
+            mech.armor.intro_year,
+        ),
+        (
+            "structure",
+            &mech.structure.slug,
+            Some(&mech.structure.tech_base),
+            mech.structure.intro_year,
+        ),
+        (
+            "heat sink",
+            &mech.heatsink.slug,
+            Some(&mech.heatsink.tech_base),
+            mech.heatsink.intro_year,
+        ),
+        (
+            "gyro",
+            &mech.gyro.slug,
+            mech.gyro.tech_base.as_deref(),
+            mech.gyro.intro_year,
+        ),
+        (
+            "cockpit",
+            &mech.cockpit.slug,
+            mech.cockpit.tech_base.as_deref(),
+            mech.cockpit.intro_year,
+        ),
+        (
+            "myomer",
+            &mech.myomer.slug,
+            mech.myomer.tech_base.as_deref(),
+            mech.myomer.intro_year,
+        ),
+    ];
+    for (kind, slug, tech_base, intro_year) in components {
+        check_tech(
+            &mut r,
+            &spec.tech_base,
+            kind,
+            slug,
+            tech_base,
+            is_is_only_slug(slug),
+        );
+        check_year(&mut r, rules.year, kind, slug, intro_year);
+    }
+    let mut seen: Vec<&str> = Vec::new();
+    for ResolvedMount { equipment: e, .. } in &mech.mounts {
+        if seen.contains(&e.slug.as_str()) {
+            continue;
+        }
+        seen.push(&e.slug);
+        let is_only = e.category.ends_with("_weapon")
+            || is_is_only_slug(&e.slug)
+            || e.internal_name.as_deref().is_some_and(|n| n.starts_with("IS"));
+        check_tech(
+            &mut r,
+            &spec.tech_base,
+            "equipment",
+            &e.slug,
+            Some(&e.tech_base),
+            is_only,
+        );
+        check_year(&mut r, rules.year, "equipment", &e.slug, e.intro_year);
+    }
+
+    r
+}
+
+fn check_tech(
+    r: &mut DesignReport,
+    design: &str,
+    kind: &str,
+    slug: &str,
+    tech_base: Option<&str>,
+    inner_sphere_only: bool,
+) {
+    let Some(tb) = tech_base else { return };
+    let mismatch = match design {
+        "inner_sphere" => tb == "clan",
+        // Generic items (standard armor, fusion engines, jump jets) are stored as
+        // Inner Sphere tech but are shared by Clan designs.
+        "clan" => tb == "inner_sphere" && inner_sphere_only,
+        _ => false,
+    };
+    if mismatch {
+        r.error(
+            "tech_base_mismatch",
+            format!("{kind} '{slug}' is {tb} technology but the design tech base is {design} (use techBase: MIXED)"),
+            None,
+        );
+    }
+}
+
+fn check_year(
+    r: &mut DesignReport,
+    year: Option<i32>,
+    kind: &str,
+    slug: &str,
+    intro_year: Option<i32>,
+) {
+    let (Some(year), Some(intro)) = (year, intro_year) else {
+        return;
+    };
+    if intro > year {
+        r.error(
+            "not_yet_available",
+            format!("{kind} '{slug}' is introduced in {intro}, after {year}"),
+            None,
+        );
+    }
+}
@@ -8,17 +8,22 @@ use crate::{
     error::AppError,
 };
 
+/// Tech progression and rating columns shared by every construction type table.
+pub const TECH_COLUMNS: &str = "prototype_year, production_year, common_year, extinction_year, reintro_year, \
+                            tech_rating, availability_sl, availability_sw, availability_clan, availability_da";
+
 pub async fn list_engine_types(
     pool: &PgPool,
     tech_base: Option<&str>,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbEngineType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  weight_multiplier, ct_crits, st_crits, intro_year
-           FROM engine_types WHERE TRUE"#,
-    );
+                  weight_multiplier, ct_crits, st_crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM engine_types WHERE TRUE"#
+    ));
     if let Some(tb) = tech_base {
         builder.push(" AND tech_base::text = ");
         builder.push_bind(tb);
@@ -36,12 +41,13 @@ pub async fn list_armor_types(
     tech_base: Option<&str>,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbArmorType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  points_per_ton, crits, intro_year
-           FROM armor_types WHERE TRUE"#,
-    );
+                  points_per_ton, crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM armor_types WHERE TRUE"#
+    ));
     if let Some(tb) = tech_base {
         builder.push(" AND tech_base::text = ");
         builder.push_bind(tb);
@@ -59,12 +65,13 @@ pub async fn list_structure_types(
     tech_base: Option<&str>,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbStructureType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  weight_fraction, crits, intro_year
-           FROM structure_types WHERE TRUE"#,
-    );
+                  weight_fraction, crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM structure_types WHERE TRUE"#
+    ));
     if let Some(tb) = tech_base {
         builder.push(" AND tech_base::text = ");
         builder.push_bind(tb);
@@ -82,12 +89,13 @@ pub async fn list_heatsink_types(
     tech_base: Option<&str>,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbHeatsinkType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  dissipation, crits, weight, intro_year
-           FROM heatsink_types WHERE TRUE"#,
-    );
+                  dissipation, crits, weight, intro_year,
+                  {TECH_COLUMNS}
+           FROM heatsink_types WHERE TRUE"#
+    ));
     if let Some(tb) = tech_base {
         builder.push(" AND tech_base::text = ");
         builder.push_bind(tb);
@@ -104,12 +112,13 @@ pub async fn list_gyro_types(
     pool: &PgPool,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbGyroType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  weight_multiplier, crits, is_superheavy_only, intro_year
-           FROM gyro_types WHERE TRUE"#,
-    );
+                  weight_multiplier, crits, is_superheavy_only, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM gyro_types WHERE TRUE"#
+    ));
     if let Some(rl) = rules_level {
         builder.push(" AND rules_level::text = ");
         builder.push_bind(rl);
@@ -122,12 +131,13 @@ pub async fn list_cockpit_types(
     pool: &PgPool,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbCockpitType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  weight, crits, intro_year
-           FROM cockpit_types WHERE TRUE"#,
-    );
+                  weight, crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM cockpit_types WHERE TRUE"#
+    ));
     if let Some(rl) = rules_level {
         builder.push(" AND rules_level::text = ");
         builder.push_bind(rl);
@@ -140,12 +150,13 @@ pub async fn list_myomer_types(
     pool: &PgPool,
     rules_level: Option<&str>,
 ) -> Result<Vec<DbMyomerType>, AppError> {
-    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
         r#"SELECT id, slug, name, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
-                  intro_year, properties
-           FROM myomer_types WHERE TRUE"#,
-    );
+                  intro_year, properties,
+                  {TECH_COLUMNS}
+           FROM myomer_types WHERE TRUE"#
+    ));
     if let Some(rl) = rules_level {
         builder.push(" AND rules_level::text = ");
         builder.push_bind(rl);
@@ -194,3 +205,137 @@ pub async fn list_all_internal_structure(
     .await?;
     Ok(rows)
 }
+
+// ── Lookups by slug ──────────────────────────────────────────────────────────
+
+pub async fn get_engine_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbEngineType>, AppError> {
+    let row = sqlx::query_as::<_, DbEngineType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  weight_multiplier, ct_crits, st_crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM engine_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_armor_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbArmorType>, AppError> {
+    let row = sqlx::query_as::<_, DbArmorType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  points_per_ton, crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM armor_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_structure_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbStructureType>, AppError> {
+    let row = sqlx::query_as::<_, DbStructureType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  weight_fraction, crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM structure_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_heatsink_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbHeatsinkType>, AppError> {
+    let row = sqlx::query_as::<_, DbHeatsinkType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  dissipation, crits, weight, intro_year,
+                  {TECH_COLUMNS}
+           FROM heatsink_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_gyro_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbGyroType>, AppError> {
+    let row = sqlx::query_as::<_, DbGyroType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  weight_multiplier, crits, is_superheavy_only, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM gyro_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_cockpit_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbCockpitType>, AppError> {
+    let row = sqlx::query_as::<_, DbCockpitType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  weight, crits, intro_year, bv_multiplier,
+                  {TECH_COLUMNS}
+           FROM cockpit_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_myomer_type_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<DbMyomerType>, AppError> {
+    let row = sqlx::query_as::<_, DbMyomerType>(&format!(
+        r#"SELECT id, slug, name, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  intro_year, properties,
+                  {TECH_COLUMNS}
+           FROM myomer_types WHERE slug = $1"#
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_engine_weight(
+    pool: &PgPool,
+    rating: i16,
+) -> Result<Option<DbEngineWeight>, AppError> {
+    let row = sqlx::query_as::<_, DbEngineWeight>(
+        "SELECT rating, standard_weight FROM engine_weight_table WHERE rating = $1",
+    )
+    .bind(rating)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
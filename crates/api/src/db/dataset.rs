@@ -0,0 +1,56 @@
+use sqlx::PgPool;
+
+use crate::{
+    db::models::{DbOmniEquipment, DbUnitChange},
+    error::AppError,
+};
+
+/// Snapshot id of a dataset version, if one was recorded.
+pub async fn get_snapshot_id(pool: &PgPool, version: &str) -> Result<Option<i32>, AppError> {
+    let id = sqlx::query_scalar::<_, i32>("SELECT id FROM dataset_snapshots WHERE version = $1")
+        .bind(version)
+        .fetch_optional(pool)
+        .await?;
+    Ok(id)
+}
+
+/// Units added, removed or changed between two snapshots, ordered by name.
+pub async fn list_changes(pool: &PgPool, from_id: i32, to_id: i32) -> Result<Vec<DbUnitChange>, AppError> {
+    let rows = sqlx::query_as::<_, DbUnitChange>(
+        r#"SELECT COALESCE(t.unit_slug, f.unit_slug) AS slug,
+                  COALESCE(t.full_name, f.full_name) AS full_name,
+                  f.unit_slug IS NOT NULL AS in_from,
+                  t.unit_slug IS NOT NULL AS in_to,
+                  f.core_hash IS DISTINCT FROM t.core_hash AS core_changed,
+                  f.armor_hash IS DISTINCT FROM t.armor_hash AS armor_changed,
+                  f.loadout_hash IS DISTINCT FROM t.loadout_hash AS loadout_changed,
+                  f.bv AS bv_from, t.bv AS bv_to,
+                  f.loadout AS loadout_from, t.loadout AS loadout_to
+           FROM (SELECT * FROM dataset_snapshot_units WHERE snapshot_id = $1) f
+           FULL JOIN (SELECT * FROM dataset_snapshot_units WHERE snapshot_id = $2) t
+                  ON t.unit_slug = f.unit_slug
+           WHERE f.content_hash IS DISTINCT FROM t.content_hash OR f.bv IS DISTINCT FROM t.bv
+           ORDER BY 2, 1"#,
+    )
+    .bind(from_id)
+    .bind(to_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Items of a snapshot loadout (`[{equipment, name, location, quantity, rear}]`).
+pub fn loadout_items(loadout: Option<&serde_json::Value>) -> Vec<DbOmniEquipment> {
+    loadout
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .map(|item| DbOmniEquipment {
+            equipment_slug: item["equipment"].as_str().unwrap_or_default().to_string(),
+            equipment_name: item["name"].as_str().unwrap_or_default().to_string(),
+            location: item["location"].as_str().map(str::to_string),
+            quantity: item["quantity"].as_i64().unwrap_or(1) as i32,
+            is_rear_facing: item["rear"].as_bool().unwrap_or(false),
+        })
+        .collect()
+}
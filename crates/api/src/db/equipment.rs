@@ -1,18 +1,28 @@
+use std::collections::HashMap;
+
 use sqlx::PgPool;
 
-use crate::{db::models::DbEquipment, error::AppError};
+use crate::{
+    db::models::{DbEquipment, DbTechProgression},
+    error::AppError,
+};
 
 pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<DbEquipment>, AppError> {
     let row = sqlx::query_as::<_, DbEquipment>(
-        r#"SELECT id, slug, name,
+        r#"SELECT id, slug, name, internal_name,
                   category::text AS category, tech_base::text AS tech_base,
                   rules_level::text AS rules_level,
                   tonnage, crits, damage, heat,
                   range_min, range_short, range_medium, range_long, bv, intro_year,
                   source_book, description,
                   observed_locations, ammo_for_id, stats_source,
+                  tech_rating, availability_sl, availability_sw, availability_clan, availability_da,
                   NULL::bigint AS total_count
-           FROM equipment WHERE slug = $1"#,
+           FROM equipment
+           WHERE slug = $1
+              OR id = (SELECT equipment_id FROM equipment_slug_aliases WHERE alias = $1)
+           ORDER BY slug <> $1
+           LIMIT 1"#,
     )
     .bind(slug)
     .fetch_optional(pool)
@@ -20,6 +30,44 @@ pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<DbEquipment
     Ok(row)
 }
 
+/// Map slugs that were replaced by an equipment catalog slug to their
+/// current slug. Slugs that are current or unknown are not returned.
+pub async fn current_slugs(
+    pool: &PgPool,
+    slugs: &[String],
+) -> Result<HashMap<String, String>, AppError> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        r#"SELECT a.alias, e.slug
+           FROM equipment_slug_aliases a
+           JOIN equipment e ON e.id = a.equipment_id
+           WHERE a.alias = ANY($1)
+             AND NOT EXISTS (SELECT 1 FROM equipment c WHERE c.slug = a.alias)"#,
+    )
+    .bind(slugs)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+pub async fn get_by_slugs(pool: &PgPool, slugs: &[String]) -> Result<Vec<DbEquipment>, AppError> {
+    let rows = sqlx::query_as::<_, DbEquipment>(
+        r#"SELECT id, slug, name, internal_name,
+                  category::text AS category, tech_base::text AS tech_base,
+                  rules_level::text AS rules_level,
+                  tonnage, crits, damage, heat,
+                  range_min, range_short, range_medium, range_long, bv, intro_year,
+                  source_book, description,
+                  observed_locations, ammo_for_id, stats_source,
+                  tech_rating, availability_sl, availability_sw, availability_clan, availability_da,
+                  NULL::bigint AS total_count
+           FROM equipment WHERE slug = ANY($1)"#,
+    )
+    .bind(slugs)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 pub struct EquipmentFilter<'a> {
     pub name_search: Option<&'a str>,
     pub category: Option<&'a str>,
@@ -29,6 +77,8 @@ pub struct EquipmentFilter<'a> {
     pub max_crits: Option<i32>,
     pub observed_location: Option<&'a str>,
     pub ammo_for_slug: Option<&'a str>,
+    pub year: Option<i32>,
+    pub legal_in_era: Option<&'a str>,
 }
 
 pub async fn search(
@@ -48,11 +98,12 @@ pub async fn search(
     };
 
     let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
-        r#"SELECT id, slug, name, category::text AS category, tech_base::text AS tech_base,
+        r#"SELECT id, slug, name, internal_name, category::text AS category, tech_base::text AS tech_base,
                   rules_level::text AS rules_level, tonnage, crits, damage, heat,
                   range_min, range_short, range_medium, range_long, bv, intro_year,
                   source_book, description,
                   observed_locations, ammo_for_id, stats_source,
+                  tech_rating, availability_sl, availability_sw, availability_clan, availability_da,
                   COUNT(*) OVER() AS total_count
            FROM equipment WHERE TRUE"#,
     );
@@ -91,6 +142,49 @@ pub async fn search(
         builder.push(" AND ammo_for_id = ");
         builder.push_bind(weapon_id);
     }
+    if let Some(y) = filter.year {
+        // In service in any tech base, or by intro year without progression data
+        builder.push(
+            r#" AND (EXISTS (
+                SELECT 1 FROM equipment_tech_progression tp
+                WHERE tp.equipment_id = equipment.id
+                  AND COALESCE(tp.prototype_year, tp.production_year, tp.common_year) <= "#,
+        );
+        builder.push_bind(y);
+        builder.push(" AND (tp.extinction_year IS NULL OR tp.extinction_year > ");
+        builder.push_bind(y);
+        builder.push(" OR (tp.reintro_year IS NOT NULL AND tp.reintro_year <= ");
+        builder.push_bind(y);
+        builder.push(
+            r#"))
+            ) OR (NOT EXISTS (
+                SELECT 1 FROM equipment_tech_progression tp WHERE tp.equipment_id = equipment.id
+            ) AND intro_year <= "#,
+        );
+        builder.push_bind(y);
+        builder.push("))");
+    }
+    if let Some(era) = filter.legal_in_era {
+        // Rated available (not X) in the era's TechManual period and, when
+        // the first prototype or introduction year is known, introduced by its end
+        builder.push(
+            r#" AND EXISTS (
+                SELECT 1 FROM eras e
+                WHERE e.slug = "#,
+        );
+        builder.push_bind(era);
+        builder.push(
+            r#"
+                  AND CASE WHEN e.start_year < 2781 THEN availability_sl
+                           WHEN e.start_year < 3050 THEN availability_sw
+                           WHEN e.start_year < 3081 THEN availability_clan
+                           ELSE availability_da END <> 'X'
+                  AND (COALESCE(
+                          (SELECT MIN(COALESCE(tp.prototype_year, tp.production_year, tp.common_year))
+                           FROM equipment_tech_progression tp WHERE tp.equipment_id = equipment.id),
+                          intro_year) > e.end_year) IS NOT TRUE)"#,
+        );
+    }
     if let Some(aid) = after_id {
         builder.push(" AND id > ");
         builder.push_bind(aid);
@@ -110,3 +204,20 @@ pub async fn search(
 
     Ok((rows, total_count, has_next))
 }
+
+pub async fn get_tech_progression_batch(
+    pool: &PgPool,
+    equipment_ids: &[i32],
+) -> Result<Vec<DbTechProgression>, AppError> {
+    let rows = sqlx::query_as::<_, DbTechProgression>(
+        r#"SELECT equipment_id, tech_base::text AS tech_base,
+                  prototype_year, production_year, common_year, extinction_year, reintro_year
+           FROM equipment_tech_progression
+           WHERE equipment_id = ANY($1)
+           ORDER BY equipment_id, equipment_tech_progression.tech_base"#,
+    )
+    .bind(equipment_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
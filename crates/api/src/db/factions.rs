@@ -1,6 +1,9 @@
 use sqlx::PgPool;
 
-use crate::{db::models::DbFaction, error::AppError};
+use crate::{
+    db::models::{DbFaction, DbFactionName, DbRelatedFaction},
+    error::AppError,
+};
 
 pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<DbFaction>, AppError> {
     let row = sqlx::query_as!(
@@ -15,6 +18,18 @@ pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<DbFaction>,
     Ok(row)
 }
 
+pub async fn get_batch(pool: &PgPool, ids: &[i32]) -> Result<Vec<DbFaction>, AppError> {
+    let rows = sqlx::query_as::<_, DbFaction>(
+        r#"SELECT id, slug, name, short_name, faction_type, is_clan,
+                  founding_year, dissolution_year, description
+           FROM factions WHERE id = ANY($1)"#,
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 pub async fn list(
     pool: &PgPool,
     faction_type: Option<&str>,
@@ -70,3 +85,96 @@ pub async fn list(
         .await?;
     Ok(rows)
 }
+
+const RELATED_FACTION_COLUMNS: &str = "f.id, f.slug, f.name, f.short_name, f.faction_type, f.is_clan, \
+                                       f.founding_year, f.dissolution_year, f.description, \
+                                       r.start_year, r.end_year";
+
+/// The faction a faction sat inside in `year`, preferring the most specific
+/// (bounded) relation. Without a year, the current parent.
+pub async fn get_parent(
+    pool: &PgPool,
+    faction_id: i32,
+    year: Option<i32>,
+) -> Result<Option<DbRelatedFaction>, AppError> {
+    let row = sqlx::query_as::<_, DbRelatedFaction>(&format!(
+        r#"SELECT {RELATED_FACTION_COLUMNS}
+           FROM faction_relations r
+           JOIN factions f ON f.id = r.related_faction_id
+           WHERE r.faction_id = $1 AND r.relation = 'parent'
+             AND CASE WHEN $2::int IS NULL THEN r.end_year IS NULL
+                      ELSE (r.start_year IS NULL OR r.start_year <= $2)
+                       AND (r.end_year IS NULL OR r.end_year >= $2) END
+           ORDER BY r.start_year DESC NULLS LAST, f.name
+           LIMIT 1"#
+    ))
+    .bind(faction_id)
+    .bind(year)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+/// Every faction above a faction in the parent hierarchy, nearest first.
+/// With a year, only relations covering that year are followed.
+pub async fn get_ancestors(
+    pool: &PgPool,
+    faction_id: i32,
+    year: Option<i32>,
+) -> Result<Vec<DbRelatedFaction>, AppError> {
+    let rows = sqlx::query_as::<_, DbRelatedFaction>(&format!(
+        r#"WITH RECURSIVE lineage AS (
+               SELECT r.related_faction_id AS id, r.start_year, r.end_year, 1 AS depth
+               FROM faction_relations r
+               WHERE r.faction_id = $1 AND r.relation = 'parent'
+                 AND ($2::int IS NULL OR ((r.start_year IS NULL OR r.start_year <= $2)
+                                          AND (r.end_year IS NULL OR r.end_year >= $2)))
+               UNION ALL
+               SELECT r.related_faction_id, r.start_year, r.end_year, l.depth + 1
+               FROM lineage l
+               JOIN faction_relations r ON r.faction_id = l.id AND r.relation = 'parent'
+               WHERE l.depth < 10
+                 AND ($2::int IS NULL OR ((r.start_year IS NULL OR r.start_year <= $2)
+                                          AND (r.end_year IS NULL OR r.end_year >= $2)))
+           )
+           SELECT {RELATED_FACTION_COLUMNS}
+           FROM (SELECT DISTINCT ON (id) * FROM lineage ORDER BY id, depth) r
+           JOIN factions f ON f.id = r.id
+           WHERE f.id <> $1
+           ORDER BY r.depth, f.name"#
+    ))
+    .bind(faction_id)
+    .bind(year)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Factions that took over from a faction, by year of succession.
+pub async fn list_successors(pool: &PgPool, faction_id: i32) -> Result<Vec<DbRelatedFaction>, AppError> {
+    let rows = sqlx::query_as::<_, DbRelatedFaction>(&format!(
+        r#"SELECT {RELATED_FACTION_COLUMNS}
+           FROM faction_relations r
+           JOIN factions f ON f.id = r.related_faction_id
+           WHERE r.faction_id = $1 AND r.relation = 'successor'
+           ORDER BY r.start_year NULLS FIRST, f.name"#
+    ))
+    .bind(faction_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Names a faction went by, oldest first.
+pub async fn list_names(pool: &PgPool, faction_id: i32) -> Result<Vec<DbFactionName>, AppError> {
+    let rows = sqlx::query_as::<_, DbFactionName>(
+        r#"SELECT name, short_name, start_year, end_year
+           FROM faction_names
+           WHERE faction_id = $1
+           ORDER BY start_year NULLS FIRST"#,
+    )
+    .bind(faction_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
@@ -1,7 +1,10 @@
 pub mod construction;
+pub mod dataset;
 pub mod equipment;
 pub mod eras;
 pub mod factions;
 pub mod metadata;
 pub mod models;
+pub mod planets;
+pub mod provenance;
 pub mod units;
@@ -13,6 +13,51 @@ pub struct DbMetadata {
     pub created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, FromRow)]
+pub struct DbImportRun {
+    pub id: i32,
+    pub source: String,
+    pub version: Option<String>,
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub processed: i32,
+    pub imported: i32,
+    pub skipped: i32,
+    pub error_count: i32,
+    pub error_messages: Vec<String>,
+}
+
+/// One unit that differs between two dataset snapshots. The `from_*`
+/// columns are null for added units and the `to_*` columns for removed ones.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbUnitChange {
+    pub slug: String,
+    pub full_name: String,
+    pub in_from: bool,
+    pub in_to: bool,
+    pub core_changed: bool,
+    pub armor_changed: bool,
+    pub loadout_changed: bool,
+    pub bv_from: Option<i32>,
+    pub bv_to: Option<i32>,
+    pub loadout_from: Option<serde_json::Value>,
+    pub loadout_to: Option<serde_json::Value>,
+}
+
+/// Import runs behind one unit's rows, plus the per-field sources on `units`.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbUnitProvenance {
+    pub unit_id: i32,
+    pub import_run_id: Option<i32>,
+    pub mul_import_run_id: Option<i32>,
+    pub locations_run_id: Option<i32>,
+    pub loadout_run_id: Option<i32>,
+    pub availability_run_ids: Vec<i32>,
+    pub bv_source: Option<String>,
+    pub intro_year_source: Option<String>,
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct DbRuleset {
     pub id: i32,
@@ -46,6 +91,58 @@ pub struct DbFaction {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone, FromRow)]
+pub struct DbPlanet {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub x: f64,
+    pub y: f64,
+    pub spectral_type: Option<String>,
+    pub population: Option<i64>,
+    pub technology: Option<String>,
+    pub industry: Option<String>,
+    pub raw_materials: Option<String>,
+    pub output: Option<String>,
+    pub agriculture: Option<String>,
+    pub description: Option<String>,
+}
+
+/// One span of a planet's ownership. `faction_id` is null while unowned.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbPlanetOwnership {
+    pub planet_id: i32,
+    pub faction_id: Option<i32>,
+    pub start_year: i32,
+    pub end_year: Option<i32>,
+}
+
+/// A manufacturer with a factory on a planet.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbPlanetFactory {
+    pub slug: String,
+    pub name: String,
+    pub source: String,
+}
+
+/// A faction linked to another by a parent or successor relation, with the
+/// years the relation covers.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbRelatedFaction {
+    #[sqlx(flatten)]
+    pub faction: DbFaction,
+    pub start_year: Option<i32>,
+    pub end_year: Option<i32>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbFactionName {
+    pub name: String,
+    pub short_name: Option<String>,
+    pub start_year: Option<i32>,
+    pub end_year: Option<i32>,
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct DbFactionEra {
     pub id: i32,
@@ -90,11 +187,28 @@ pub struct DbUnit {
     pub total_count: Option<i64>,
 }
 
+/// A unit eligible for force building, with its cost in the requested point system.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbForcePoolUnit {
+    #[sqlx(flatten)]
+    pub unit: DbUnit,
+    pub value: i32,
+}
+
+/// A unit with its weighted availability for one faction and era.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbRatEntry {
+    #[sqlx(flatten)]
+    pub unit: DbUnit,
+    pub weight: f64,
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct DbEquipment {
     pub id: i32,
     pub slug: String,
     pub name: String,
+    pub internal_name: Option<String>,
     pub category: String,
     pub tech_base: String,
     pub rules_level: String,
@@ -113,9 +227,43 @@ pub struct DbEquipment {
     pub observed_locations: Option<Vec<String>>,
     pub ammo_for_id: Option<i32>,
     pub stats_source: Option<String>,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
     pub total_count: Option<i64>,
 }
 
+/// TechManual tech rating and availability ratings per era period.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbTechRatings {
+    pub tech_rating: Option<String>,
+    pub availability_sl: Option<String>,
+    pub availability_sw: Option<String>,
+    pub availability_clan: Option<String>,
+    pub availability_da: Option<String>,
+}
+
+/// Tech progression years of a construction type (one tech base per row).
+#[derive(Debug, Clone, FromRow)]
+pub struct DbTechDates {
+    pub prototype_year: Option<i32>,
+    pub production_year: Option<i32>,
+    pub common_year: Option<i32>,
+    pub extinction_year: Option<i32>,
+    pub reintro_year: Option<i32>,
+}
+
+/// An equipment item's tech progression in one tech base.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbTechProgression {
+    pub equipment_id: i32,
+    pub tech_base: String,
+    pub prototype_year: Option<i32>,
+    pub production_year: Option<i32>,
+    pub common_year: Option<i32>,
+    pub extinction_year: Option<i32>,
+    pub reintro_year: Option<i32>,
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct DbLocation {
     pub id: i32,
@@ -134,12 +282,62 @@ pub struct DbLoadoutEntry {
     pub location: Option<String>,
     pub quantity: i32,
     pub is_rear_facing: bool,
+    pub is_omnipod: bool,
     pub notes: Option<String>,
     // Joined from equipment
     pub equipment_slug: String,
     pub equipment_name: String,
 }
 
+#[derive(Debug, Clone, FromRow)]
+pub struct DbUnitFluff {
+    pub overview: Option<String>,
+    pub capabilities: Option<String>,
+    pub deployment: Option<String>,
+    pub history: Option<String>,
+    pub notes: Option<String>,
+    pub image_file: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbUnitManufacturer {
+    pub slug: String,
+    pub name: String,
+    pub factory: Option<String>,
+    pub system: Option<String>,
+}
+
+/// A weapon or equipment line on a record sheet: mounts of one item in one
+/// location and arc, with the equipment's combat stats.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbSheetEquipment {
+    pub name: String,
+    pub category: String,
+    pub location: Option<String>,
+    pub is_rear: bool,
+    pub count: i32,
+    pub damage: Option<String>,
+    pub heat: Option<i32>,
+    pub range_min: Option<i32>,
+    pub range_short: Option<i32>,
+    pub range_medium: Option<i32>,
+    pub range_long: Option<i32>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbCriticalSlot {
+    pub id: i32,
+    pub unit_id: i32,
+    pub location: String,
+    pub slot_index: i16,
+    pub content: Option<String>,
+    pub equipment_id: Option<i32>,
+    pub is_rear: bool,
+    pub is_omnipod: bool,
+    // Joined from equipment
+    pub equipment_slug: Option<String>,
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct DbQuirk {
     pub id: i32,
@@ -172,6 +370,201 @@ pub struct DbMechData {
     pub gyro_type_id: Option<i32>,
     pub cockpit_type_id: Option<i32>,
     pub myomer_type_id: Option<i32>,
+    pub is_environmentally_sealed: bool,
+    pub industrial_equipment: Vec<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbProtoMechData {
+    pub unit_id: i32,
+    pub config: String,
+    pub is_ultraheavy: bool,
+    pub walk_mp: Option<i32>,
+    pub jump_mp: Option<i32>,
+    pub has_main_gun: bool,
+    pub armor_type: Option<String>,
+    pub has_interface_cockpit: bool,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbLamData {
+    pub unit_id: i32,
+    pub lam_type: String,
+    pub fuel: Option<i32>,
+    pub airmech_cruise_mp: Option<i32>,
+    pub airmech_flank_mp: Option<i32>,
+    pub fighter_safe_thrust: Option<i32>,
+    pub fighter_max_thrust: Option<i32>,
+    pub landing_gear_slots: i32,
+    pub avionics_slots: i32,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbQuadVeeData {
+    pub unit_id: i32,
+    pub motive_type: String,
+    pub conversion_slots: i32,
+    pub motive_slots: i32,
+}
+
+/// The fixed base chassis shared by a group of OmniMech configurations,
+/// keyed by the configuration it was loaded for.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbOmniBase {
+    pub unit_id: i32,
+    pub id: i32,
+    pub chassis_id: i32,
+    pub reference_unit_id: Option<i32>,
+    pub tonnage: rust_decimal::Decimal,
+    pub engine_rating: Option<i32>,
+    pub engine_type: Option<String>,
+    pub walk_mp: Option<i32>,
+    pub structure_type: Option<String>,
+    pub armor_type: Option<String>,
+    pub heat_sink_type: Option<String>,
+    pub gyro_type: Option<String>,
+    pub cockpit_type: Option<String>,
+}
+
+/// Mounts of one equipment item in one location, fixed or pod-mounted.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbOmniEquipment {
+    pub equipment_slug: String,
+    pub equipment_name: String,
+    pub location: Option<String>,
+    pub quantity: i32,
+    pub is_rear_facing: bool,
+}
+
+/// Critical slot usage of one location on an OmniMech configuration.
+#[derive(Debug, Clone, FromRow)]
+pub struct DbPodSpace {
+    pub location: String,
+    pub total_slots: i32,
+    pub fixed_slots: i32,
+    pub pod_slots: i32,
+    pub empty_slots: i32,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbVehicleData {
+    pub unit_id: i32,
+    pub motion_type: String,
+    pub cruise_mp: Option<i32>,
+    pub engine_type: Option<String>,
+    pub armor_type: Option<String>,
+    pub structure_type: Option<String>,
+    pub turret_type: String,
+    pub fuel: Option<rust_decimal::Decimal>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbInfantryData {
+    pub unit_id: i32,
+    pub infantry_kind: String,
+    pub trooper_count: i32,
+    pub squad_size: Option<i32>,
+    pub squad_count: Option<i32>,
+    pub weight_class: Option<String>,
+    pub chassis_type: Option<String>,
+    pub motive_type: String,
+    pub ground_mp: Option<i32>,
+    pub jump_mp: Option<i32>,
+    pub umu_mp: Option<i32>,
+    pub vtol_mp: Option<i32>,
+    pub armor_per_trooper: Option<i32>,
+    pub armor_type: Option<String>,
+    pub left_manipulator: Option<String>,
+    pub right_manipulator: Option<String>,
+    pub is_anti_mech: bool,
+    pub primary_weapon: Option<String>,
+    pub secondary_weapon: Option<String>,
+    pub secondary_count: Option<i32>,
+    pub armor_kit: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbAeroData {
+    pub unit_id: i32,
+    pub safe_thrust: Option<i32>,
+    pub structural_integrity: Option<i32>,
+    pub fuel_points: Option<i32>,
+    pub heat_sink_count: Option<i32>,
+    pub heat_sink_type: Option<String>,
+    pub cockpit_type: Option<String>,
+    pub engine_type: Option<String>,
+    pub armor_type: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbAlphaStrike {
+    pub unit_id: i32,
+    pub as_type: Option<String>,
+    pub size: Option<i16>,
+    pub movement: Option<String>,
+    pub move_inches: Option<i16>,
+    pub jump_inches: Option<i16>,
+    pub tmm: Option<i16>,
+    pub armor: Option<i16>,
+    pub structure: Option<i16>,
+    pub threshold: Option<i16>,
+    pub damage_short: Option<i16>,
+    pub damage_short_min: bool,
+    pub damage_medium: Option<i16>,
+    pub damage_medium_min: bool,
+    pub damage_long: Option<i16>,
+    pub damage_long_min: bool,
+    pub damage_extreme: Option<i16>,
+    pub damage_extreme_min: bool,
+    pub overheat: i16,
+    pub point_value: Option<i32>,
+    pub specials: Vec<String>,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbAeroWeaponBay {
+    pub unit_id: i32,
+    pub location: String,
+    pub bay_number: i16,
+    pub quantity: i32,
+    // Joined from equipment
+    pub equipment_slug: String,
+    pub equipment_name: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbLargeCraftData {
+    pub unit_id: i32,
+    pub craft_type: String,
+    pub design_type: Option<String>,
+    pub crew: Option<i32>,
+    pub officers: Option<i32>,
+    pub gunners: Option<i32>,
+    pub passengers: Option<i32>,
+    pub marines: Option<i32>,
+    pub battle_armor_troops: Option<i32>,
+    pub other_passengers: Option<i32>,
+    pub life_boats: Option<i32>,
+    pub escape_pods: Option<i32>,
+    pub collar_type: Option<String>,
+    pub docking_collars: i32,
+    pub has_kf_drive: bool,
+    pub drive_core_type: Option<String>,
+    pub jump_range: Option<i32>,
+    pub has_lithium_fusion: bool,
+    pub has_jump_sail: bool,
+    pub has_hpg: bool,
+    pub grav_decks: i32,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DbTransportBay {
+    pub unit_id: i32,
+    pub bay_number: Option<i16>,
+    pub bay_type: String,
+    pub size: rust_decimal::Decimal,
+    pub doors: i16,
 }
 
 // ── Construction Reference ───────────────────────────────────────────────
@@ -187,6 +580,11 @@ pub struct DbEngineType {
     pub ct_crits: i16,
     pub st_crits: i16,
     pub intro_year: Option<i32>,
+    pub bv_multiplier: rust_decimal::Decimal,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -199,6 +597,11 @@ pub struct DbArmorType {
     pub points_per_ton: rust_decimal::Decimal,
     pub crits: i16,
     pub intro_year: Option<i32>,
+    pub bv_multiplier: rust_decimal::Decimal,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -211,6 +614,11 @@ pub struct DbStructureType {
     pub weight_fraction: rust_decimal::Decimal,
     pub crits: i16,
     pub intro_year: Option<i32>,
+    pub bv_multiplier: rust_decimal::Decimal,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -224,6 +632,10 @@ pub struct DbHeatsinkType {
     pub crits: i16,
     pub weight: rust_decimal::Decimal,
     pub intro_year: Option<i32>,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -237,6 +649,11 @@ pub struct DbGyroType {
     pub crits: i16,
     pub is_superheavy_only: bool,
     pub intro_year: Option<i32>,
+    pub bv_multiplier: rust_decimal::Decimal,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -249,6 +666,11 @@ pub struct DbCockpitType {
     pub weight: i16,
     pub crits: i16,
     pub intro_year: Option<i32>,
+    pub bv_multiplier: rust_decimal::Decimal,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -260,6 +682,10 @@ pub struct DbMyomerType {
     pub rules_level: String,
     pub intro_year: Option<i32>,
     pub properties: serde_json::Value,
+    #[sqlx(flatten)]
+    pub dates: DbTechDates,
+    #[sqlx(flatten)]
+    pub ratings: DbTechRatings,
 }
 
 #[derive(Debug, Clone, FromRow)]
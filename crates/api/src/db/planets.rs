@@ -0,0 +1,102 @@
+use sqlx::PgPool;
+
+use crate::{
+    db::models::{DbPlanet, DbPlanetFactory, DbPlanetOwnership},
+    error::AppError,
+};
+
+const PLANET_COLUMNS: &str = "p.id, p.slug, p.name, p.x, p.y, p.spectral_type, p.population, \
+                              p.technology, p.industry, p.raw_materials, p.output, p.agriculture, \
+                              p.description";
+
+pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<DbPlanet>, AppError> {
+    let row = sqlx::query_as::<_, DbPlanet>(&format!(
+        "SELECT {PLANET_COLUMNS} FROM planets p WHERE p.slug = $1"
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+/// Planets owned by a faction in `year` (the current owner without a year),
+/// ordered by name. With `include_children`, planets of factions under it in
+/// the parent hierarchy that year count too.
+pub async fn list_owned_by(
+    pool: &PgPool,
+    faction_slug: &str,
+    year: Option<i32>,
+    include_children: bool,
+) -> Result<Vec<DbPlanet>, AppError> {
+    let rows = sqlx::query_as::<_, DbPlanet>(&format!(
+        r#"WITH RECURSIVE owners AS (
+               SELECT f.id, 0 AS depth FROM factions f WHERE f.slug = $1
+               UNION ALL
+               SELECT r.faction_id, o.depth + 1
+               FROM owners o
+               JOIN faction_relations r ON r.related_faction_id = o.id AND r.relation = 'parent'
+               WHERE $3 AND o.depth < 10
+                 AND CASE WHEN $2::int IS NULL THEN r.end_year IS NULL
+                          ELSE (r.start_year IS NULL OR r.start_year <= $2)
+                           AND (r.end_year IS NULL OR r.end_year >= $2) END
+           )
+           SELECT {PLANET_COLUMNS}
+           FROM planets p
+           WHERE EXISTS (
+               SELECT 1 FROM planet_ownership po
+               WHERE po.planet_id = p.id
+                 AND po.faction_id IN (SELECT id FROM owners)
+                 AND CASE WHEN $2::int IS NULL THEN po.end_year IS NULL
+                          ELSE po.start_year <= $2 AND (po.end_year IS NULL OR po.end_year >= $2) END
+           )
+           ORDER BY p.name"#
+    ))
+    .bind(faction_slug)
+    .bind(year)
+    .bind(include_children)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Planets inside the square of half-width `radius` light years around a point.
+pub async fn list_in_box(pool: &PgPool, x: f64, y: f64, radius: f64) -> Result<Vec<DbPlanet>, AppError> {
+    let rows = sqlx::query_as::<_, DbPlanet>(&format!(
+        r#"SELECT {PLANET_COLUMNS} FROM planets p
+           WHERE p.x BETWEEN $1 - $3 AND $1 + $3
+             AND p.y BETWEEN $2 - $3 AND $2 + $3"#
+    ))
+    .bind(x)
+    .bind(y)
+    .bind(radius)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_ownership_batch(pool: &PgPool, planet_ids: &[i32]) -> Result<Vec<DbPlanetOwnership>, AppError> {
+    let rows = sqlx::query_as::<_, DbPlanetOwnership>(
+        r#"SELECT planet_id, faction_id, start_year, end_year
+           FROM planet_ownership
+           WHERE planet_id = ANY($1)
+           ORDER BY planet_id, start_year"#,
+    )
+    .bind(planet_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn list_factories(pool: &PgPool, planet_id: i32) -> Result<Vec<DbPlanetFactory>, AppError> {
+    let rows = sqlx::query_as::<_, DbPlanetFactory>(
+        r#"SELECT m.slug, m.name, pf.source
+           FROM planet_factories pf
+           JOIN manufacturers m ON m.id = pf.manufacturer_id
+           WHERE pf.planet_id = $1
+           ORDER BY m.name"#,
+    )
+    .bind(planet_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
@@ -0,0 +1,70 @@
+use sqlx::PgPool;
+
+use crate::{
+    db::models::{DbImportRun, DbUnitProvenance},
+    error::AppError,
+};
+
+pub const IMPORT_SOURCES: &[&str] = &["megamek", "mul", "equipment_seed", "forcegen", "planets"];
+
+const IMPORT_RUN_COLUMNS: &str = "id, source, version, status, started_at, finished_at, \
+                                  processed, imported, skipped, error_count, error_messages";
+
+/// Most recent import runs, newest first, optionally for one source.
+pub async fn list_import_runs(
+    pool: &PgPool,
+    source: Option<&str>,
+    limit: i64,
+) -> Result<Vec<DbImportRun>, AppError> {
+    let rows = sqlx::query_as::<_, DbImportRun>(&format!(
+        "SELECT {IMPORT_RUN_COLUMNS} FROM import_runs
+         WHERE ($1::text IS NULL OR source = $1)
+         ORDER BY id DESC
+         LIMIT $2"
+    ))
+    .bind(source)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_import_runs_batch(pool: &PgPool, ids: &[i32]) -> Result<Vec<DbImportRun>, AppError> {
+    let rows = sqlx::query_as::<_, DbImportRun>(&format!(
+        "SELECT {IMPORT_RUN_COLUMNS} FROM import_runs WHERE id = ANY($1)"
+    ))
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_unit_provenance(pool: &PgPool, unit_id: i32) -> Result<Option<DbUnitProvenance>, AppError> {
+    let row = sqlx::query_as::<_, DbUnitProvenance>(
+        r#"SELECT u.id AS unit_id,
+                  u.last_import_run_id AS import_run_id,
+                  u.mul_import_run_id,
+                  (SELECT MAX(last_import_run_id) FROM unit_locations WHERE unit_id = u.id) AS locations_run_id,
+                  (SELECT MAX(last_import_run_id) FROM unit_loadout WHERE unit_id = u.id) AS loadout_run_id,
+                  ARRAY(SELECT DISTINCT last_import_run_id FROM unit_availability
+                        WHERE unit_id = u.id AND last_import_run_id IS NOT NULL
+                        ORDER BY last_import_run_id) AS availability_run_ids,
+                  u.bv_source,
+                  u.intro_year_source
+           FROM units u
+           WHERE u.id = $1"#,
+    )
+    .bind(unit_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+/// Run that last wrote an equipment item's stats.
+pub async fn get_equipment_stats_run_id(pool: &PgPool, equipment_id: i32) -> Result<Option<i32>, AppError> {
+    let id = sqlx::query_scalar::<_, Option<i32>>("SELECT stats_import_run_id FROM equipment WHERE id = $1")
+        .bind(equipment_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(id.flatten())
+}
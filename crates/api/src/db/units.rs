@@ -1,7 +1,10 @@
 use sqlx::PgPool;
 
 use crate::{
-    db::models::{DbLoadoutEntry, DbLocation, DbMechData, DbQuirk, DbUnit, DbUnitChassis},
+    db::models::{
+        DbAeroData, DbAeroWeaponBay, DbAlphaStrike, DbCriticalSlot, DbForcePoolUnit, DbInfantryData, DbLamData, DbLargeCraftData, DbRatEntry, DbLoadoutEntry, DbLocation, DbUnitFluff, DbUnitManufacturer, DbMechData, DbOmniBase, DbOmniEquipment, DbPodSpace, DbProtoMechData, DbQuadVeeData, DbQuirk, DbSheetEquipment, DbTransportBay, DbUnit, DbUnitChassis,
+        DbVehicleData,
+    },
     error::AppError,
 };
 
@@ -40,19 +43,54 @@ pub async fn get_by_ids(pool: &PgPool, slugs: &[String]) -> Result<Vec<DbUnit>,
     Ok(rows)
 }
 
+/// Every chassis `unit_type` the importer writes.
+pub const UNIT_TYPES: &[&str] = &[
+    "mech",
+    "industrialmech",
+    "protomech",
+    "vehicle",
+    "fighter",
+    "small_craft",
+    "dropship",
+    "jumpship",
+    "warship",
+    "space_station",
+    "battle_armor",
+    "infantry",
+    "other",
+];
+
 pub struct UnitFilter<'a> {
     pub name_search: Option<&'a str>,
+    pub unit_type: Option<&'a str>,
     pub tech_base: Option<&'a str>,
     pub rules_level: Option<&'a str>,
     pub tonnage_min: Option<f64>,
     pub tonnage_max: Option<f64>,
     pub faction_slug: Option<&'a str>,
+    pub include_parent_factions: bool,
     pub era_slug: Option<&'a str>,
+    pub year: Option<i32>,
     pub is_omnimech: Option<bool>,
     pub config: Option<&'a str>,
     pub engine_type: Option<&'a str>,
     pub has_jump: Option<bool>,
     pub role: Option<&'a str>,
+    pub motion_type: Option<&'a str>,
+    pub safe_thrust_min: Option<i32>,
+    pub safe_thrust_max: Option<i32>,
+    pub structural_integrity_min: Option<i32>,
+    pub structural_integrity_max: Option<i32>,
+    pub pv_min: Option<i32>,
+    pub pv_max: Option<i32>,
+    pub as_specials: Option<&'a [String]>,
+    pub troopers_min: Option<i32>,
+    pub troopers_max: Option<i32>,
+    pub jump_mp_min: Option<i32>,
+    pub umu_mp_min: Option<i32>,
+    pub manipulator: Option<&'a str>,
+    pub is_anti_mech: Option<bool>,
+    pub manufacturer: Option<&'a str>,
 }
 
 pub async fn search(
@@ -65,6 +103,18 @@ pub async fn search(
         || filter.config.is_some()
         || filter.engine_type.is_some()
         || filter.has_jump.is_some();
+    let has_aero_filter = filter.safe_thrust_min.is_some()
+        || filter.safe_thrust_max.is_some()
+        || filter.structural_integrity_min.is_some()
+        || filter.structural_integrity_max.is_some();
+    let has_as_filter =
+        filter.pv_min.is_some() || filter.pv_max.is_some() || filter.as_specials.is_some();
+    let has_infantry_filter = filter.troopers_min.is_some()
+        || filter.troopers_max.is_some()
+        || filter.jump_mp_min.is_some()
+        || filter.umu_mp_min.is_some()
+        || filter.manipulator.is_some()
+        || filter.is_anti_mech.is_some();
 
     let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
         r#"SELECT u.id, u.slug, u.chassis_id, u.variant, u.full_name,
@@ -79,6 +129,18 @@ pub async fn search(
     if has_mech_filter {
         builder.push(" JOIN unit_mech_data md ON md.unit_id = u.id");
     }
+    if filter.motion_type.is_some() {
+        builder.push(" JOIN unit_vehicle_data vd ON vd.unit_id = u.id");
+    }
+    if has_aero_filter {
+        builder.push(" JOIN unit_aero_data ad ON ad.unit_id = u.id");
+    }
+    if has_as_filter {
+        builder.push(" JOIN unit_alpha_strike asx ON asx.unit_id = u.id");
+    }
+    if has_infantry_filter {
+        builder.push(" JOIN unit_infantry_data idd ON idd.unit_id = u.id");
+    }
 
     builder.push(" WHERE TRUE");
 
@@ -89,6 +151,13 @@ pub async fn search(
         builder.push_bind(name);
         builder.push(" || '%')");
     }
+    if let Some(ut) = filter.unit_type {
+        builder.push(
+            " AND EXISTS (SELECT 1 FROM unit_chassis uc WHERE uc.id = u.chassis_id AND uc.unit_type = ",
+        );
+        builder.push_bind(ut);
+        builder.push(")");
+    }
     if let Some(tb) = filter.tech_base {
         builder.push(" AND u.tech_base::text = ");
         builder.push_bind(tb);
@@ -106,12 +175,12 @@ pub async fn search(
         builder.push_bind(max);
     }
     if let Some(faction) = filter.faction_slug {
-        builder.push(r#" AND EXISTS (
-            SELECT 1 FROM unit_availability ua
-            JOIN factions f ON f.id = ua.faction_id
-            WHERE ua.unit_id = u.id AND f.slug = "#);
-        builder.push_bind(faction);
-        builder.push(")");
+        builder.push(" AND ");
+        push_faction_availability(&mut builder, faction, filter.include_parent_factions, filter.year);
+    }
+    if let Some(year) = filter.year {
+        builder.push(" AND ");
+        push_in_service(&mut builder, year);
     }
     if let Some(era) = filter.era_slug {
         builder.push(r#" AND EXISTS (
@@ -144,6 +213,82 @@ pub async fn search(
         builder.push(" AND u.role = ");
         builder.push_bind(role);
     }
+    if let Some(mt) = filter.motion_type {
+        builder.push(" AND vd.motion_type = ");
+        builder.push_bind(mt.to_lowercase());
+    }
+    if let Some(min) = filter.safe_thrust_min {
+        builder.push(" AND ad.safe_thrust >= ");
+        builder.push_bind(min);
+    }
+    if let Some(max) = filter.safe_thrust_max {
+        builder.push(" AND ad.safe_thrust <= ");
+        builder.push_bind(max);
+    }
+    if let Some(min) = filter.structural_integrity_min {
+        builder.push(" AND ad.structural_integrity >= ");
+        builder.push_bind(min);
+    }
+    if let Some(max) = filter.structural_integrity_max {
+        builder.push(" AND ad.structural_integrity <= ");
+        builder.push_bind(max);
+    }
+    if let Some(min) = filter.pv_min {
+        builder.push(" AND asx.point_value >= ");
+        builder.push_bind(min);
+    }
+    if let Some(max) = filter.pv_max {
+        builder.push(" AND asx.point_value <= ");
+        builder.push_bind(max);
+    }
+    // A special matches by exact text or by its code with the numeric part
+    // stripped ("IF1" → "IF", "LRM1/1/1" → "LRM").
+    for special in filter.as_specials.unwrap_or_default() {
+        builder.push(
+            r#" AND EXISTS (
+            SELECT 1 FROM unnest(asx.specials) sp
+            WHERE upper(sp) = upper("#,
+        );
+        builder.push_bind(special);
+        builder.push(") OR upper(regexp_replace(sp, '[0-9*/-].*$', '')) = upper(");
+        builder.push_bind(special);
+        builder.push("))");
+    }
+    if let Some(min) = filter.troopers_min {
+        builder.push(" AND idd.trooper_count >= ");
+        builder.push_bind(min);
+    }
+    if let Some(max) = filter.troopers_max {
+        builder.push(" AND idd.trooper_count <= ");
+        builder.push_bind(max);
+    }
+    if let Some(min) = filter.jump_mp_min {
+        builder.push(" AND idd.jump_mp >= ");
+        builder.push_bind(min);
+    }
+    if let Some(min) = filter.umu_mp_min {
+        builder.push(" AND idd.umu_mp >= ");
+        builder.push_bind(min);
+    }
+    if let Some(m) = filter.manipulator {
+        builder.push(" AND (idd.left_manipulator ILIKE '%' || ");
+        builder.push_bind(m);
+        builder.push(" || '%' OR idd.right_manipulator ILIKE '%' || ");
+        builder.push_bind(m);
+        builder.push(" || '%')");
+    }
+    if let Some(am) = filter.is_anti_mech {
+        builder.push(" AND idd.is_anti_mech = ");
+        builder.push_bind(am);
+    }
+    if let Some(m) = filter.manufacturer {
+        builder.push(r#" AND EXISTS (
+            SELECT 1 FROM unit_manufacturers um
+            JOIN manufacturers m ON m.id = um.manufacturer_id
+            WHERE um.unit_id = u.id AND um.system IS NULL AND m.name ILIKE '%' || "#);
+        builder.push_bind(m);
+        builder.push(" || '%')");
+    }
     if let Some(aid) = after_id {
         builder.push(" AND u.id > ");
         builder.push_bind(aid);
@@ -164,6 +309,196 @@ pub async fn search(
     Ok((rows, total_count, has_next))
 }
 
+/// Push a condition on `u.id` matching units the faction fields: any
+/// availability row, or with `year` only rows of the era covering it. With
+/// `include_parents`, availability of the faction's ancestors counts too,
+/// each only in eras overlapping the years every relation on the path covers
+/// (and covering `year` itself when given).
+fn push_faction_availability(
+    builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    faction_slug: &str,
+    include_parents: bool,
+    year: Option<i32>,
+) {
+    if include_parents {
+        builder.push(r#"u.id IN (
+            WITH RECURSIVE lineage AS (
+                SELECT f.id, NULL::int AS start_year, NULL::int AS end_year, 0 AS depth
+                FROM factions f WHERE f.slug = "#);
+        builder.push_bind(faction_slug.to_string());
+        builder.push(r#"
+                UNION ALL
+                SELECT r.related_faction_id, GREATEST(l.start_year, r.start_year),
+                       LEAST(l.end_year, r.end_year), l.depth + 1
+                FROM lineage l
+                JOIN faction_relations r ON r.faction_id = l.id AND r.relation = 'parent'
+                WHERE l.depth < 10
+            )
+            SELECT ua.unit_id FROM unit_availability ua
+            JOIN lineage l ON l.id = ua.faction_id
+            JOIN eras e ON e.id = ua.era_id
+            WHERE (l.start_year IS NULL OR e.end_year IS NULL OR e.end_year >= l.start_year)
+              AND (l.end_year IS NULL OR e.start_year <= l.end_year)"#);
+        if let Some(y) = year {
+            builder.push(" AND (l.start_year IS NULL OR l.start_year <= ");
+            builder.push_bind(y);
+            builder.push(") AND (l.end_year IS NULL OR l.end_year >= ");
+            builder.push_bind(y);
+            builder.push(")");
+        }
+    } else {
+        builder.push(r#"u.id IN (
+            SELECT ua.unit_id FROM unit_availability ua
+            JOIN factions f ON f.id = ua.faction_id
+            JOIN eras e ON e.id = ua.era_id
+            WHERE f.slug = "#);
+        builder.push_bind(faction_slug.to_string());
+    }
+    if let Some(y) = year {
+        builder.push(" AND e.start_year <= ");
+        builder.push_bind(y);
+        builder.push(" AND (e.end_year IS NULL OR e.end_year >= ");
+        builder.push_bind(y);
+        builder.push(")");
+    }
+    builder.push(")");
+}
+
+/// Push a condition matching units in service in `year`: introduced by then
+/// and not extinct, or reintroduced by then. Units without an introduction
+/// year never match.
+fn push_in_service(builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, year: i32) {
+    builder.push("u.intro_year <= ");
+    builder.push_bind(year);
+    builder.push(" AND (u.extinction_year IS NULL OR u.extinction_year > ");
+    builder.push_bind(year);
+    builder.push(" OR (u.reintro_year IS NOT NULL AND u.reintro_year <= ");
+    builder.push_bind(year);
+    builder.push("))");
+}
+
+/// Whether a unit is in service in `year` and, with a faction, fielded by it
+/// in the era covering that year (see `search` for `include_parents`).
+pub async fn is_available_in(
+    pool: &PgPool,
+    unit_id: i32,
+    year: i32,
+    faction_slug: Option<&str>,
+    include_parents: bool,
+) -> Result<bool, AppError> {
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+        "SELECT EXISTS (SELECT 1 FROM units u WHERE u.id = ",
+    );
+    builder.push_bind(unit_id);
+    builder.push(" AND ");
+    push_in_service(&mut builder, year);
+    if let Some(faction) = faction_slug {
+        builder.push(" AND ");
+        push_faction_availability(&mut builder, faction, include_parents, Some(year));
+    }
+    builder.push(")");
+    let available: bool = builder.build_query_scalar().fetch_one(pool).await?;
+    Ok(available)
+}
+
+/// Units available to a faction in an era that have a cost in the chosen point
+/// system (BV, or Alpha Strike PV when `use_pv` is set).
+pub async fn get_force_pool(
+    pool: &PgPool,
+    faction_slug: &str,
+    era_slug: &str,
+    use_pv: bool,
+    unit_type: Option<&str>,
+) -> Result<Vec<DbForcePoolUnit>, AppError> {
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+        r#"SELECT u.id, u.slug, u.chassis_id, u.variant, u.full_name,
+                  u.tech_base::text AS tech_base, u.rules_level::text AS rules_level,
+                  u.tonnage, u.bv, u.cost, u.intro_year, u.extinction_year,
+                  u.reintro_year, u.source_book, u.description,
+                  u.mul_id, u.role, u.clan_name, NULL::bigint AS total_count,"#,
+    );
+    if use_pv {
+        builder.push(" asx.point_value AS value");
+    } else {
+        builder.push(" u.bv AS value");
+    }
+    builder.push(
+        r#" FROM unit_availability ua
+           JOIN factions f ON f.id = ua.faction_id
+           JOIN eras e ON e.id = ua.era_id
+           JOIN units u ON u.id = ua.unit_id
+           JOIN unit_chassis c ON c.id = u.chassis_id"#,
+    );
+    if use_pv {
+        builder.push(" JOIN unit_alpha_strike asx ON asx.unit_id = u.id AND asx.point_value > 0");
+    }
+    builder.push(" WHERE f.slug = ");
+    builder.push_bind(faction_slug);
+    builder.push(" AND e.slug = ");
+    builder.push_bind(era_slug);
+    if !use_pv {
+        builder.push(" AND u.bv > 0");
+    }
+    if let Some(ut) = unit_type {
+        builder.push(" AND c.unit_type = ");
+        builder.push_bind(ut);
+    }
+    builder.push(" ORDER BY u.id");
+
+    let rows = builder
+        .build_query_as::<DbForcePoolUnit>()
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// Units with a weighted availability rating for a faction in an era,
+/// most common first. Tonnage bounds are inclusive min, exclusive max.
+pub async fn get_rat_entries(
+    pool: &PgPool,
+    faction_slug: &str,
+    era_slug: &str,
+    unit_type: Option<&str>,
+    tonnage: Option<(f64, Option<f64>)>,
+) -> Result<Vec<DbRatEntry>, AppError> {
+    let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+        r#"SELECT u.id, u.slug, u.chassis_id, u.variant, u.full_name,
+                  u.tech_base::text AS tech_base, u.rules_level::text AS rules_level,
+                  u.tonnage, u.bv, u.cost, u.intro_year, u.extinction_year,
+                  u.reintro_year, u.source_book, u.description,
+                  u.mul_id, u.role, u.clan_name, NULL::bigint AS total_count,
+                  ua.weight
+           FROM unit_availability ua
+           JOIN factions f ON f.id = ua.faction_id
+           JOIN eras e ON e.id = ua.era_id
+           JOIN units u ON u.id = ua.unit_id
+           JOIN unit_chassis c ON c.id = u.chassis_id
+           WHERE ua.weight > 0 AND f.slug = "#,
+    );
+    builder.push_bind(faction_slug);
+    builder.push(" AND e.slug = ");
+    builder.push_bind(era_slug);
+    if let Some(ut) = unit_type {
+        builder.push(" AND c.unit_type = ");
+        builder.push_bind(ut);
+    }
+    if let Some((min, max)) = tonnage {
+        builder.push(" AND u.tonnage >= ");
+        builder.push_bind(min);
+        if let Some(max) = max {
+            builder.push(" AND u.tonnage < ");
+            builder.push_bind(max);
+        }
+    }
+    builder.push(" ORDER BY ua.weight DESC, u.full_name, u.id");
+
+    let rows = builder
+        .build_query_as::<DbRatEntry>()
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
 pub async fn get_chassis_by_slug(
     pool: &PgPool,
     slug: &str,
@@ -227,7 +562,7 @@ pub async fn get_loadout(pool: &PgPool, unit_id: i32) -> Result<Vec<DbLoadoutEnt
         DbLoadoutEntry,
         r#"SELECT ul.id, ul.unit_id, ul.equipment_id,
                   ul.location::text AS location,
-                  ul.quantity, ul.is_rear_facing, ul.notes,
+                  ul.quantity, ul.is_rear_facing, ul.is_omnipod, ul.notes,
                   e.slug AS equipment_slug, e.name AS equipment_name
            FROM unit_loadout ul
            JOIN equipment e ON e.id = ul.equipment_id
@@ -240,6 +575,74 @@ pub async fn get_loadout(pool: &PgPool, unit_id: i32) -> Result<Vec<DbLoadoutEnt
     Ok(rows)
 }
 
+/// Weapons and equipment for a record sheet inventory. Units with a critical
+/// hit table are counted from their slots (slots / crits per item); others fall
+/// back to the loadout. Heat sinks, ammunition, jump jets and construction
+/// components are left out.
+pub async fn get_sheet_equipment(
+    pool: &PgPool,
+    unit_id: i32,
+) -> Result<Vec<DbSheetEquipment>, AppError> {
+    let rows = sqlx::query_as::<_, DbSheetEquipment>(
+        r#"WITH items AS (
+               SELECT id, name, category::text AS category, damage, heat,
+                      range_min, range_short, range_medium, range_long,
+                      GREATEST(COALESCE(crits, 1), 1) AS crits
+               FROM equipment
+               WHERE category::text LIKE '%_weapon'
+                  OR category IN ('equipment', 'targeting_computer')
+           ),
+           from_slots AS (
+               SELECT s.equipment_id, s.location::text AS location, s.is_rear,
+                      CEIL(COUNT(*)::numeric / i.crits)::int4 AS count,
+                      MIN(s.id) AS ord
+               FROM unit_critical_slots s
+               JOIN items i ON i.id = s.equipment_id
+               WHERE s.unit_id = $1
+               GROUP BY s.equipment_id, s.location, s.is_rear, i.crits
+           ),
+           from_loadout AS (
+               SELECT l.equipment_id, l.location::text AS location,
+                      l.is_rear_facing AS is_rear,
+                      l.quantity AS count,
+                      l.id AS ord
+               FROM unit_loadout l
+               JOIN items i ON i.id = l.equipment_id
+               WHERE l.unit_id = $1
+                 AND NOT EXISTS (SELECT 1 FROM unit_critical_slots s WHERE s.unit_id = $1)
+           )
+           SELECT i.name, i.category, x.location, x.is_rear, x.count, i.damage, i.heat,
+                  i.range_min, i.range_short, i.range_medium, i.range_long
+           FROM (SELECT * FROM from_slots UNION ALL SELECT * FROM from_loadout) x
+           JOIN items i ON i.id = x.equipment_id
+           ORDER BY i.category = 'equipment', x.ord"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_critical_slots(
+    pool: &PgPool,
+    unit_id: i32,
+) -> Result<Vec<DbCriticalSlot>, AppError> {
+    let rows = sqlx::query_as::<_, DbCriticalSlot>(
+        r#"SELECT cs.id, cs.unit_id, cs.location::text AS location,
+                  cs.slot_index, cs.content, cs.equipment_id,
+                  cs.is_rear, cs.is_omnipod,
+                  e.slug AS equipment_slug
+           FROM unit_critical_slots cs
+           LEFT JOIN equipment e ON e.id = cs.equipment_id
+           WHERE cs.unit_id = $1
+           ORDER BY cs.location, cs.slot_index"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 pub async fn get_mech_data_batch(
     pool: &PgPool,
     unit_ids: &[i32],
@@ -249,7 +652,8 @@ pub async fn get_mech_data_batch(
                   walk_mp, jump_mp, heat_sink_count, heat_sink_type,
                   structure_type, armor_type, gyro_type, cockpit_type, myomer_type,
                   engine_type_id, armor_type_id, structure_type_id, heatsink_type_id,
-                  gyro_type_id, cockpit_type_id, myomer_type_id
+                  gyro_type_id, cockpit_type_id, myomer_type_id,
+                  is_environmentally_sealed, industrial_equipment
            FROM unit_mech_data WHERE unit_id = ANY($1)"#,
     )
     .bind(unit_ids)
@@ -258,6 +662,308 @@ pub async fn get_mech_data_batch(
     Ok(rows)
 }
 
+pub async fn get_protomech_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbProtoMechData>, AppError> {
+    let rows = sqlx::query_as::<_, DbProtoMechData>(
+        r#"SELECT unit_id, config, is_ultraheavy, walk_mp, jump_mp,
+                  has_main_gun, armor_type, has_interface_cockpit
+           FROM unit_protomech_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_lam_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbLamData>, AppError> {
+    let rows = sqlx::query_as::<_, DbLamData>(
+        r#"SELECT unit_id, lam_type, fuel, airmech_cruise_mp, airmech_flank_mp,
+                  fighter_safe_thrust, fighter_max_thrust, landing_gear_slots, avionics_slots
+           FROM unit_lam_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_quadvee_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbQuadVeeData>, AppError> {
+    let rows = sqlx::query_as::<_, DbQuadVeeData>(
+        r#"SELECT unit_id, motive_type, conversion_slots, motive_slots
+           FROM unit_quadvee_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// ── OmniMech configurations ──────────────────────────────────────────────────
+
+pub async fn get_omni_base_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbOmniBase>, AppError> {
+    let rows = sqlx::query_as::<_, DbOmniBase>(
+        r#"SELECT md.unit_id, ob.id, ob.chassis_id, ob.reference_unit_id, ob.tonnage,
+                  ob.engine_rating, ob.engine_type, ob.walk_mp, ob.structure_type,
+                  ob.armor_type, ob.heat_sink_type, ob.gyro_type, ob.cockpit_type
+           FROM unit_mech_data md
+           JOIN omni_bases ob ON ob.id = md.omni_base_id
+           WHERE md.unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Fixed equipment of an OmniMech base chassis.
+pub async fn get_omni_base_equipment(
+    pool: &PgPool,
+    omni_base_id: i32,
+) -> Result<Vec<DbOmniEquipment>, AppError> {
+    let rows = sqlx::query_as::<_, DbOmniEquipment>(
+        r#"SELECT e.slug AS equipment_slug, e.name AS equipment_name,
+                  obe.location::text AS location, obe.quantity, obe.is_rear_facing
+           FROM omni_base_equipment obe
+           JOIN equipment e ON e.id = obe.equipment_id
+           WHERE obe.omni_base_id = $1
+           ORDER BY obe.location, obe.id"#,
+    )
+    .bind(omni_base_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Pod-mounted equipment of an OmniMech configuration. Units with a critical
+/// hit table are counted from their pod slots (slots / crits per item); others
+/// fall back to pod loadout entries.
+pub async fn get_pod_equipment(
+    pool: &PgPool,
+    unit_id: i32,
+) -> Result<Vec<DbOmniEquipment>, AppError> {
+    let rows = sqlx::query_as::<_, DbOmniEquipment>(
+        r#"WITH from_slots AS (
+               SELECT s.equipment_id, s.location, s.is_rear,
+                      CEIL(COUNT(*)::numeric / GREATEST(COALESCE(e.crits, 1), 1))::int4 AS quantity
+               FROM unit_critical_slots s
+               JOIN equipment e ON e.id = s.equipment_id
+               WHERE s.unit_id = $1 AND s.is_omnipod
+               GROUP BY s.equipment_id, s.location, s.is_rear, e.crits
+           ),
+           from_loadout AS (
+               SELECT l.equipment_id, l.location, l.is_rear_facing AS is_rear, l.quantity
+               FROM unit_loadout l
+               WHERE l.unit_id = $1 AND l.is_omnipod
+                 AND NOT EXISTS (SELECT 1 FROM unit_critical_slots s WHERE s.unit_id = $1)
+           )
+           SELECT e.slug AS equipment_slug, e.name AS equipment_name,
+                  x.location::text AS location, x.quantity, x.is_rear AS is_rear_facing
+           FROM (SELECT * FROM from_slots UNION ALL SELECT * FROM from_loadout) x
+           JOIN equipment e ON e.id = x.equipment_id
+           ORDER BY x.location, e.name"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Critical slot usage per location, split into fixed, pod and empty slots.
+pub async fn get_pod_space(pool: &PgPool, unit_id: i32) -> Result<Vec<DbPodSpace>, AppError> {
+    let rows = sqlx::query_as::<_, DbPodSpace>(
+        r#"SELECT location::text AS location,
+                  COUNT(*)::int4 AS total_slots,
+                  COUNT(*) FILTER (WHERE content IS NOT NULL AND NOT is_omnipod)::int4 AS fixed_slots,
+                  COUNT(*) FILTER (WHERE content IS NOT NULL AND is_omnipod)::int4 AS pod_slots,
+                  COUNT(*) FILTER (WHERE content IS NULL)::int4 AS empty_slots
+           FROM unit_critical_slots
+           WHERE unit_id = $1
+           GROUP BY location
+           ORDER BY location"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// All configurations built on an OmniMech base chassis.
+pub async fn get_omni_configurations(
+    pool: &PgPool,
+    omni_base_id: i32,
+) -> Result<Vec<DbUnit>, AppError> {
+    let rows = sqlx::query_as::<_, DbUnit>(
+        r#"SELECT u.id, u.slug, u.chassis_id, u.variant, u.full_name,
+                  u.tech_base::text AS tech_base, u.rules_level::text AS rules_level,
+                  u.tonnage, u.bv, u.cost, u.intro_year, u.extinction_year,
+                  u.reintro_year, u.source_book, u.description,
+                  u.mul_id, u.role, u.clan_name, NULL::bigint AS total_count
+           FROM units u
+           JOIN unit_mech_data md ON md.unit_id = u.id
+           WHERE md.omni_base_id = $1
+           ORDER BY u.variant"#,
+    )
+    .bind(omni_base_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_vehicle_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbVehicleData>, AppError> {
+    let rows = sqlx::query_as::<_, DbVehicleData>(
+        r#"SELECT unit_id, motion_type, cruise_mp, engine_type, armor_type,
+                  structure_type, turret_type, fuel
+           FROM unit_vehicle_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_infantry_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbInfantryData>, AppError> {
+    let rows = sqlx::query_as::<_, DbInfantryData>(
+        r#"SELECT unit_id, infantry_kind, trooper_count, squad_size, squad_count,
+                  weight_class, chassis_type, motive_type, ground_mp, jump_mp, umu_mp, vtol_mp,
+                  armor_per_trooper, armor_type, left_manipulator, right_manipulator,
+                  is_anti_mech, primary_weapon, secondary_weapon, secondary_count, armor_kit
+           FROM unit_infantry_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_aero_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbAeroData>, AppError> {
+    let rows = sqlx::query_as::<_, DbAeroData>(
+        r#"SELECT unit_id, safe_thrust, structural_integrity, fuel_points,
+                  heat_sink_count, heat_sink_type, cockpit_type, engine_type, armor_type
+           FROM unit_aero_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_alpha_strike_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbAlphaStrike>, AppError> {
+    let rows = sqlx::query_as::<_, DbAlphaStrike>(
+        r#"SELECT unit_id, as_type, size, movement, move_inches, jump_inches, tmm,
+                  armor, structure, threshold,
+                  damage_short, damage_short_min, damage_medium, damage_medium_min,
+                  damage_long, damage_long_min, damage_extreme, damage_extreme_min,
+                  overheat, point_value, specials, source
+           FROM unit_alpha_strike WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_aero_weapon_bays(
+    pool: &PgPool,
+    unit_id: i32,
+) -> Result<Vec<DbAeroWeaponBay>, AppError> {
+    let rows = sqlx::query_as::<_, DbAeroWeaponBay>(
+        r#"SELECT wb.unit_id, wb.location::text AS location, wb.bay_number, wb.quantity,
+                  e.slug AS equipment_slug, e.name AS equipment_name
+           FROM unit_aero_weapon_bays wb
+           JOIN equipment e ON e.id = wb.equipment_id
+           WHERE wb.unit_id = $1
+           ORDER BY wb.location, wb.bay_number, wb.id"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_large_craft_data_batch(
+    pool: &PgPool,
+    unit_ids: &[i32],
+) -> Result<Vec<DbLargeCraftData>, AppError> {
+    let rows = sqlx::query_as::<_, DbLargeCraftData>(
+        r#"SELECT unit_id, craft_type, design_type, crew, officers, gunners, passengers,
+                  marines, battle_armor_troops, other_passengers, life_boats, escape_pods,
+                  collar_type, docking_collars, has_kf_drive, drive_core_type, jump_range,
+                  has_lithium_fusion, has_jump_sail, has_hpg, grav_decks
+           FROM unit_large_craft_data WHERE unit_id = ANY($1)"#,
+    )
+    .bind(unit_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_transport_bays(
+    pool: &PgPool,
+    unit_id: i32,
+) -> Result<Vec<DbTransportBay>, AppError> {
+    let rows = sqlx::query_as::<_, DbTransportBay>(
+        r#"SELECT unit_id, bay_number, bay_type, size, doors
+           FROM unit_transport_bays
+           WHERE unit_id = $1
+           ORDER BY sort_order"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_fluff(pool: &PgPool, unit_id: i32) -> Result<Option<DbUnitFluff>, AppError> {
+    let row = sqlx::query_as::<_, DbUnitFluff>(
+        r#"SELECT overview, capabilities, deployment, history, notes, image_file
+           FROM unit_fluff WHERE unit_id = $1"#,
+    )
+    .bind(unit_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+pub async fn get_manufacturers(
+    pool: &PgPool,
+    unit_id: i32,
+) -> Result<Vec<DbUnitManufacturer>, AppError> {
+    let rows = sqlx::query_as::<_, DbUnitManufacturer>(
+        r#"SELECT m.slug, m.name, um.factory, um.system
+           FROM unit_manufacturers um
+           JOIN manufacturers m ON m.id = um.manufacturer_id
+           WHERE um.unit_id = $1
+           ORDER BY um.sort_order"#,
+    )
+    .bind(unit_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 pub async fn get_quirks(pool: &PgPool, unit_id: i32) -> Result<Vec<DbQuirk>, AppError> {
     let rows = sqlx::query_as!(
         DbQuirk,
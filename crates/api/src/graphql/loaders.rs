@@ -4,10 +4,11 @@ use async_graphql::dataloader::Loader;
 
 use crate::db::{
     models::{
-        DbArmorType, DbCockpitType, DbEngineType, DbEquipment, DbGyroType, DbHeatsinkType,
-        DbMechData, DbMyomerType, DbStructureType,
+        DbAeroData, DbAlphaStrike, DbArmorType, DbCockpitType, DbEngineType, DbEquipment, DbFaction, DbGyroType, DbHeatsinkType,
+        DbImportRun, DbInfantryData, DbLamData, DbLargeCraftData, DbMechData, DbMyomerType, DbOmniBase, DbPlanetOwnership, DbProtoMechData, DbQuadVeeData, DbStructureType, DbTechProgression, DbVehicleData,
     },
-    units,
+    construction::TECH_COLUMNS,
+    equipment, factions, planets, provenance, units,
 };
 
 // ── MechData Loader ──────────────────────────────────────────────────────────
@@ -26,6 +27,150 @@ impl Loader<i32> for MechDataLoader {
     }
 }
 
+// ── ProtoMechData Loader ─────────────────────────────────────────────────────
+
+pub struct ProtoMechDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for ProtoMechDataLoader {
+    type Value = DbProtoMechData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbProtoMechData>, async_graphql::Error> {
+        let rows = units::get_protomech_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── LamData Loader ───────────────────────────────────────────────────────────
+
+pub struct LamDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for LamDataLoader {
+    type Value = DbLamData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbLamData>, async_graphql::Error> {
+        let rows = units::get_lam_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── QuadVeeData Loader ───────────────────────────────────────────────────────
+
+pub struct QuadVeeDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for QuadVeeDataLoader {
+    type Value = DbQuadVeeData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbQuadVeeData>, async_graphql::Error> {
+        let rows = units::get_quadvee_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── OmniBase Loader ──────────────────────────────────────────────────────────
+
+pub struct OmniBaseLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for OmniBaseLoader {
+    type Value = DbOmniBase;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbOmniBase>, async_graphql::Error> {
+        let rows = units::get_omni_base_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── VehicleData Loader ───────────────────────────────────────────────────────
+
+pub struct VehicleDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for VehicleDataLoader {
+    type Value = DbVehicleData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbVehicleData>, async_graphql::Error> {
+        let rows = units::get_vehicle_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── InfantryData Loader ──────────────────────────────────────────────────────
+
+pub struct InfantryDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for InfantryDataLoader {
+    type Value = DbInfantryData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbInfantryData>, async_graphql::Error> {
+        let rows = units::get_infantry_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── LargeCraftData Loader ────────────────────────────────────────────────────
+
+pub struct LargeCraftDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for LargeCraftDataLoader {
+    type Value = DbLargeCraftData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbLargeCraftData>, async_graphql::Error> {
+        let rows = units::get_large_craft_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── AeroData Loader ──────────────────────────────────────────────────────────
+
+pub struct AeroDataLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for AeroDataLoader {
+    type Value = DbAeroData;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbAeroData>, async_graphql::Error> {
+        let rows = units::get_aero_data_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
+// ── AlphaStrike Loader ───────────────────────────────────────────────────────
+
+pub struct AlphaStrikeLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for AlphaStrikeLoader {
+    type Value = DbAlphaStrike;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbAlphaStrike>, async_graphql::Error> {
+        let rows = units::get_alpha_strike_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.unit_id, r)).collect())
+    }
+}
+
 // ── Ammo-For Loader (ammo → weapon) ─────────────────────────────────────────
 
 pub struct AmmoForLoader {
@@ -41,13 +186,14 @@ impl Loader<i32> for AmmoForLoader {
         keys: &[i32],
     ) -> Result<HashMap<i32, DbEquipment>, async_graphql::Error> {
         let rows = sqlx::query_as::<_, DbEquipment>(
-            r#"SELECT id, slug, name,
+            r#"SELECT id, slug, name, internal_name,
                       category::text AS category, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
                       tonnage, crits, damage, heat,
                       range_min, range_short, range_medium, range_long, bv, intro_year,
                       source_book, description,
                       observed_locations, ammo_for_id, stats_source,
+                      tech_rating, availability_sl, availability_sw, availability_clan, availability_da,
                       NULL::bigint AS total_count
                FROM equipment WHERE id = ANY($1)"#,
         )
@@ -73,13 +219,14 @@ impl Loader<i32> for AmmoTypesLoader {
         keys: &[i32],
     ) -> Result<HashMap<i32, Vec<DbEquipment>>, async_graphql::Error> {
         let rows = sqlx::query_as::<_, DbEquipment>(
-            r#"SELECT id, slug, name,
+            r#"SELECT id, slug, name, internal_name,
                       category::text AS category, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
                       tonnage, crits, damage, heat,
                       range_min, range_short, range_medium, range_long, bv, intro_year,
                       source_book, description,
                       observed_locations, ammo_for_id, stats_source,
+                      tech_rating, availability_sl, availability_sw, availability_clan, availability_da,
                       NULL::bigint AS total_count
                FROM equipment WHERE ammo_for_id = ANY($1)
                ORDER BY name"#,
@@ -109,12 +256,13 @@ impl Loader<i32> for EngineTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbEngineType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbEngineType>(
+        let rows = sqlx::query_as::<_, DbEngineType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      weight_multiplier, ct_crits, st_crits, intro_year
-               FROM engine_types WHERE id = ANY($1)"#,
-        )
+                      weight_multiplier, ct_crits, st_crits, intro_year, bv_multiplier,
+                      {TECH_COLUMNS}
+               FROM engine_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
@@ -131,12 +279,13 @@ impl Loader<i32> for ArmorTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbArmorType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbArmorType>(
+        let rows = sqlx::query_as::<_, DbArmorType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      points_per_ton, crits, intro_year
-               FROM armor_types WHERE id = ANY($1)"#,
-        )
+                      points_per_ton, crits, intro_year, bv_multiplier,
+                      {TECH_COLUMNS}
+               FROM armor_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
@@ -153,12 +302,13 @@ impl Loader<i32> for StructureTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbStructureType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbStructureType>(
+        let rows = sqlx::query_as::<_, DbStructureType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      weight_fraction, crits, intro_year
-               FROM structure_types WHERE id = ANY($1)"#,
-        )
+                      weight_fraction, crits, intro_year, bv_multiplier,
+                      {TECH_COLUMNS}
+               FROM structure_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
@@ -175,12 +325,13 @@ impl Loader<i32> for HeatsinkTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbHeatsinkType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbHeatsinkType>(
+        let rows = sqlx::query_as::<_, DbHeatsinkType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      dissipation, crits, weight, intro_year
-               FROM heatsink_types WHERE id = ANY($1)"#,
-        )
+                      dissipation, crits, weight, intro_year,
+                      {TECH_COLUMNS}
+               FROM heatsink_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
@@ -197,12 +348,13 @@ impl Loader<i32> for GyroTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbGyroType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbGyroType>(
+        let rows = sqlx::query_as::<_, DbGyroType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      weight_multiplier, crits, is_superheavy_only, intro_year
-               FROM gyro_types WHERE id = ANY($1)"#,
-        )
+                      weight_multiplier, crits, is_superheavy_only, intro_year, bv_multiplier,
+                      {TECH_COLUMNS}
+               FROM gyro_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
@@ -219,12 +371,13 @@ impl Loader<i32> for CockpitTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbCockpitType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbCockpitType>(
+        let rows = sqlx::query_as::<_, DbCockpitType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      weight, crits, intro_year
-               FROM cockpit_types WHERE id = ANY($1)"#,
-        )
+                      weight, crits, intro_year, bv_multiplier,
+                      {TECH_COLUMNS}
+               FROM cockpit_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
@@ -241,15 +394,94 @@ impl Loader<i32> for MyomerTypeLoader {
     type Error = async_graphql::Error;
 
     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbMyomerType>, async_graphql::Error> {
-        let rows = sqlx::query_as::<_, DbMyomerType>(
+        let rows = sqlx::query_as::<_, DbMyomerType>(&format!(
             r#"SELECT id, slug, name, tech_base::text AS tech_base,
                       rules_level::text AS rules_level,
-                      intro_year, properties
-               FROM myomer_types WHERE id = ANY($1)"#,
-        )
+                      intro_year, properties,
+                      {TECH_COLUMNS}
+               FROM myomer_types WHERE id = ANY($1)"#
+        ))
         .bind(keys)
         .fetch_all(&self.pool)
         .await?;
         Ok(rows.into_iter().map(|r| (r.id, r)).collect())
     }
 }
+
+// ── ImportRun Loader ─────────────────────────────────────────────────────────
+
+pub struct ImportRunLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for ImportRunLoader {
+    type Value = DbImportRun;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbImportRun>, async_graphql::Error> {
+        let rows = provenance::get_import_runs_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.id, r)).collect())
+    }
+}
+
+// ── Faction Loader ───────────────────────────────────────────────────────────
+
+pub struct FactionLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for FactionLoader {
+    type Value = DbFaction;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, DbFaction>, async_graphql::Error> {
+        let rows = factions::get_batch(&self.pool, keys).await?;
+        Ok(rows.into_iter().map(|r| (r.id, r)).collect())
+    }
+}
+
+// ── Planet Ownership Loader (planet → ownership history) ────────────────────
+
+pub struct PlanetOwnershipLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for PlanetOwnershipLoader {
+    type Value = Vec<DbPlanetOwnership>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[i32],
+    ) -> Result<HashMap<i32, Vec<DbPlanetOwnership>>, async_graphql::Error> {
+        let rows = planets::get_ownership_batch(&self.pool, keys).await?;
+        let mut map: HashMap<i32, Vec<DbPlanetOwnership>> = HashMap::new();
+        for row in rows {
+            map.entry(row.planet_id).or_default().push(row);
+        }
+        Ok(map)
+    }
+}
+
+// ── Tech Progression Loader (equipment → progression per tech base) ─────────
+
+pub struct TechProgressionLoader {
+    pub pool: sqlx::PgPool,
+}
+
+impl Loader<i32> for TechProgressionLoader {
+    type Value = Vec<DbTechProgression>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[i32],
+    ) -> Result<HashMap<i32, Vec<DbTechProgression>>, async_graphql::Error> {
+        let rows = equipment::get_tech_progression_batch(&self.pool, keys).await?;
+        let mut map: HashMap<i32, Vec<DbTechProgression>> = HashMap::new();
+        for row in rows {
+            map.entry(row.equipment_id).or_default().push(row);
+        }
+        Ok(map)
+    }
+}
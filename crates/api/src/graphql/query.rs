@@ -1,23 +1,34 @@
+use std::collections::{HashMap, HashSet};
+
 use async_graphql::{Context, Object, SimpleObject};
 
 use rust_decimal::prelude::ToPrimitive;
 
 use crate::{
-    db::{construction, equipment, eras, factions, metadata, units},
+    calc,
+    db::{construction, dataset, equipment, eras, factions, metadata, models::DbUnit, planets, provenance, units},
     error::AppError,
     graphql::{
         pagination::{decode_cursor, encode_cursor, PageInfo},
         types::{
+            bv::{BattleValueGql, MechConfigInput},
             construction::{
                 ArmorTypeGql, CockpitTypeGql, ConstructionReferenceGql, EngineTypeGql,
                 EngineWeightGql, GyroTypeGql, HeatsinkTypeGql, InternalStructureGql,
                 MyomerTypeGql, RulesLevelFilter, StructureTypeGql, TechBaseFilter,
             },
+            dataset::{DatasetChangesGql, UnitChangeGql},
             equipment::EquipmentGql,
             era::EraGql,
             faction::FactionGql,
+            force::{BudgetType, ForceBuildGql, ForceConstraintsInput, ForceGql, ForceMemberGql},
             metadata::{DatasetMetadataGql, RulesetGql},
+            omni::{diff_pods, ConfigurationDiffGql},
+            planet::{within_jumps, PlanetGql, PlanetJumpGql, JUMP_RANGE_LY},
+            provenance::ImportRunGql,
+            rat::{RandomAssignmentTableGql, RatRowGql, RatTableType, RolledUnitGql, WeightClass},
             unit::{UnitChassisGql, UnitGql},
+            validation::{MechDesignInput, MechDesignValidationGql},
         },
     },
     state::AppState,
@@ -104,6 +115,82 @@ impl QueryRoot {
         Ok(rows.into_iter().map(RulesetGql::from).collect())
     }
 
+    /// Recent scraper import runs (MegaMek, MUL, equipment seed, force generator, planets), newest first.
+    async fn import_runs(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Filter by source. One of: megamek, mul, equipment_seed, forcegen, planets.")] source: Option<String>,
+        #[graphql(desc = "Maximum runs returned. Default 20, max 100.")] limit: Option<i32>,
+    ) -> Result<Vec<ImportRunGql>, AppError> {
+        if let Some(s) = source.as_deref() {
+            if !provenance::IMPORT_SOURCES.contains(&s) {
+                return Err(AppError::Validation(format!("unknown import source '{s}'")));
+            }
+        }
+        let limit = limit.unwrap_or(20).clamp(1, 100) as i64;
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = provenance::list_import_runs(&state.pool, source.as_deref(), limit).await?;
+        Ok(rows.into_iter().map(ImportRunGql).collect())
+    }
+
+    /// Units added, removed and changed (stats, armor, loadout, BV) between two imported MegaMek versions.
+    #[graphql(complexity = 20)]
+    async fn dataset_changes(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Dataset version to compare from (e.g. \"0.50.10\").")] from_version: String,
+        #[graphql(desc = "Dataset version to compare to (e.g. \"0.50.11\").")] to_version: String,
+    ) -> Result<DatasetChangesGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let mut snapshot_ids = Vec::with_capacity(2);
+        for version in [&from_version, &to_version] {
+            let id = dataset::get_snapshot_id(&state.pool, version)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("dataset version '{version}' not found")))?;
+            snapshot_ids.push(id);
+        }
+        let rows = dataset::list_changes(&state.pool, snapshot_ids[0], snapshot_ids[1]).await?;
+
+        let slugs: Vec<String> = rows.iter().map(|r| r.slug.clone()).collect();
+        let mut current: HashMap<String, DbUnit> = units::get_by_ids(&state.pool, &slugs)
+            .await?
+            .into_iter()
+            .map(|u| (u.slug.clone(), u))
+            .collect();
+
+        let mut changes = DatasetChangesGql {
+            from_version,
+            to_version,
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        };
+        for row in rows {
+            let (loadout_added, loadout_removed) = diff_pods(
+                dataset::loadout_items(row.loadout_from.as_ref()),
+                dataset::loadout_items(row.loadout_to.as_ref()),
+            );
+            let change = UnitChangeGql {
+                unit: current.remove(&row.slug).map(UnitGql),
+                slug: row.slug,
+                full_name: row.full_name,
+                core_changed: row.core_changed,
+                armor_changed: row.armor_changed,
+                loadout_changed: row.loadout_changed,
+                bv_from: row.bv_from,
+                bv_to: row.bv_to,
+                loadout_added,
+                loadout_removed,
+            };
+            match (row.in_from, row.in_to) {
+                (false, _) => changes.added.push(change),
+                (_, false) => changes.removed.push(change),
+                _ => changes.changed.push(change),
+            }
+        }
+        Ok(changes)
+    }
+
     // ── Units ───────────────────────────────────────────────────────────────
 
     /// Look up a single unit variant by its slug.
@@ -133,24 +220,76 @@ impl QueryRoot {
         Ok(rows.into_iter().map(UnitGql).collect())
     }
 
+    /// Compare the pod-mounted equipment of two OmniMech configurations.
+    #[graphql(complexity = 10)]
+    async fn diff_configurations(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Slug of the configuration to compare from (e.g. \"mad-cat-prime\").")] a: String,
+        #[graphql(desc = "Slug of the configuration to compare to.")] b: String,
+    ) -> Result<ConfigurationDiffGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let mut configs = Vec::with_capacity(2);
+        for slug in [&a, &b] {
+            let unit = units::get_by_slug(&state.pool, slug)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("unit '{slug}' not found")))?;
+            let base = units::get_omni_base_batch(&state.pool, &[unit.id])
+                .await?
+                .pop()
+                .ok_or_else(|| AppError::Validation(format!("'{slug}' is not an OmniMech")))?;
+            let pods = units::get_pod_equipment(&state.pool, unit.id).await?;
+            configs.push((unit, base.id, pods));
+        }
+        let (unit_b, base_b, pods_b) = configs.pop().unwrap();
+        let (unit_a, base_a, pods_a) = configs.pop().unwrap();
+        let (added, removed) = diff_pods(pods_a, pods_b);
+        Ok(ConfigurationDiffGql {
+            a: UnitGql(unit_a),
+            b: UnitGql(unit_b),
+            same_base: base_a == base_b,
+            added,
+            removed,
+        })
+    }
+
     /// Paginated, filterable search across all unit variants. Returns a cursor-based connection.
+    #[allow(clippy::too_many_arguments)]
     async fn units(
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "Items per page. Default 20, max 100.")] first: Option<i32>,
         #[graphql(desc = "Opaque cursor from a previous pageInfo.endCursor. Omit for the first page.")] after: Option<String>,
         #[graphql(desc = "Case-insensitive substring match against the unit's full name.")] name_search: Option<String>,
+        #[graphql(desc = "Filter by chassis unit type. One of: mech, industrialmech, protomech, vehicle, fighter, small_craft, dropship, jumpship, warship, space_station, battle_armor, infantry, other.")] unit_type: Option<String>,
         #[graphql(desc = "Filter by technology base. One of: inner_sphere, clan, mixed, primitive.")] tech_base: Option<String>,
         #[graphql(desc = "Filter by rules level. One of: introductory, standard, advanced, experimental, unofficial.")] rules_level: Option<String>,
         #[graphql(desc = "Minimum tonnage filter (inclusive). Weight in metric tons.")] tonnage_min: Option<f64>,
         #[graphql(desc = "Maximum tonnage filter (inclusive). Weight in metric tons.")] tonnage_max: Option<f64>,
         #[graphql(desc = "Filter to units available to this faction. Lowercase, hyphen-separated slug (e.g. \"clan-wolf\").")] faction_slug: Option<String>,
+        #[graphql(desc = "With factionSlug, also match availability inherited from the faction's parents (e.g. \"general\" and \"federated-commonwealth\" for \"davion\"), each limited to eras the parent relation covers. Default false.")] include_parent_factions: Option<bool>,
         #[graphql(desc = "Filter to units available in this era. Lowercase, hyphen-separated slug (e.g. \"clan-invasion\").")] era_slug: Option<String>,
+        #[graphql(desc = "Filter to units in service in this in-universe year (e.g. 3052): introduced by then and not extinct, or reintroduced. With factionSlug, only availability in the era covering the year counts. Units without an introduction year are excluded.")] year: Option<i32>,
         #[graphql(desc = "Filter to OmniMechs only (true) or non-OmniMechs (false).")] is_omnimech: Option<bool>,
         #[graphql(desc = "Filter by chassis config. One of: Biped, Quad, Tripod, LAM.")] config: Option<String>,
         #[graphql(desc = "Filter by engine type (e.g. \"XL Engine\", \"Fusion Engine\").")] engine_type: Option<String>,
         #[graphql(desc = "Filter to jump-capable mechs (true) or non-jumpers (false).")] has_jump: Option<bool>,
         #[graphql(desc = "Filter by tactical role (e.g. \"Juggernaut\", \"Sniper\", \"Striker\"). Case-sensitive, from Master Unit List.")] role: Option<String>,
+        #[graphql(desc = "Filter vehicles by motive type. One of: tracked, wheeled, hover, vtol, naval, hydrofoil, submarine, wige, rail, maglev.")] motion_type: Option<String>,
+        #[graphql(desc = "Minimum safe thrust (inclusive). Aerospace units only.")] safe_thrust_min: Option<i32>,
+        #[graphql(desc = "Maximum safe thrust (inclusive). Aerospace units only.")] safe_thrust_max: Option<i32>,
+        #[graphql(desc = "Minimum structural integrity (inclusive). Aerospace units only.")] structural_integrity_min: Option<i32>,
+        #[graphql(desc = "Maximum structural integrity (inclusive). Aerospace units only.")] structural_integrity_max: Option<i32>,
+        #[graphql(desc = "Minimum Alpha Strike Point Value (inclusive). Units without a PV are excluded.")] pv_min: Option<i32>,
+        #[graphql(desc = "Maximum Alpha Strike Point Value (inclusive). Units without a PV are excluded.")] pv_max: Option<i32>,
+        #[graphql(desc = "Alpha Strike special abilities the unit must have, all of them (e.g. [\"CASE\", \"IF\"]). Case-insensitive; a code matches numbered forms too, so \"IF\" matches \"IF1\" and \"LRM\" matches \"LRM1/1/1\".")] as_specials: Option<Vec<String>>,
+        #[graphql(desc = "Minimum troopers per squad, point or platoon (inclusive). Battle armor and infantry only.")] troopers_min: Option<i32>,
+        #[graphql(desc = "Maximum troopers per squad, point or platoon (inclusive). Battle armor and infantry only.")] troopers_max: Option<i32>,
+        #[graphql(desc = "Minimum jump MP (inclusive). Battle armor and infantry only.")] jump_mp_min: Option<i32>,
+        #[graphql(desc = "Minimum UMU (underwater) MP (inclusive). Battle armor only.")] umu_mp_min: Option<i32>,
+        #[graphql(desc = "Battle armor manipulator on either arm. Case-insensitive substring match (e.g. \"Battle Claw\", \"Vibro\").")] manipulator: Option<String>,
+        #[graphql(desc = "Filter to battle armor and infantry able to make anti-'Mech leg and swarm attacks (true) or not (false).")] is_anti_mech: Option<bool>,
+        #[graphql(desc = "Unit manufacturer name. Case-insensitive substring match (e.g. \"Defiance\"). Component manufacturers are not searched.")] manufacturer: Option<String>,
     ) -> Result<UnitConnection, AppError> {
         let state = ctx.data::<AppState>().unwrap();
         let first = first.unwrap_or(20).clamp(1, 100) as i64;
@@ -159,19 +298,43 @@ impl QueryRoot {
             .and_then(decode_cursor)
             .map(|(_, id)| id);
 
+        if let Some(ut) = unit_type.as_deref() {
+            if !units::UNIT_TYPES.contains(&ut) {
+                return Err(AppError::Validation(format!("unknown unit type '{ut}'")));
+            }
+        }
+
         let filter = units::UnitFilter {
             name_search: name_search.as_deref(),
+            unit_type: unit_type.as_deref(),
             tech_base: tech_base.as_deref(),
             rules_level: rules_level.as_deref(),
             tonnage_min,
             tonnage_max,
             faction_slug: faction_slug.as_deref(),
+            include_parent_factions: include_parent_factions.unwrap_or(false),
             era_slug: era_slug.as_deref(),
+            year,
             is_omnimech,
             config: config.as_deref(),
             engine_type: engine_type.as_deref(),
             has_jump,
             role: role.as_deref(),
+            motion_type: motion_type.as_deref(),
+            safe_thrust_min,
+            safe_thrust_max,
+            structural_integrity_min,
+            structural_integrity_max,
+            pv_min,
+            pv_max,
+            as_specials: as_specials.as_deref(),
+            troopers_min,
+            troopers_max,
+            jump_mp_min,
+            umu_mp_min,
+            manipulator: manipulator.as_deref(),
+            is_anti_mech,
+            manufacturer: manufacturer.as_deref(),
         };
 
         let (rows, total_count, has_next) =
@@ -220,7 +383,7 @@ impl QueryRoot {
     async fn all_chassis(
         &self,
         ctx: &Context<'_>,
-        #[graphql(desc = "Filter by unit type (e.g. \"BattleMech\", \"Vehicle\", \"AeroSpaceFighter\").")] unit_type: Option<String>,
+        #[graphql(desc = "Filter by unit type (e.g. \"mech\", \"protomech\", \"vehicle\", \"dropship\").")] unit_type: Option<String>,
         #[graphql(desc = "Filter by technology base. One of: inner_sphere, clan, mixed, primitive.")] tech_base: Option<String>,
     ) -> Result<Vec<UnitChassisGql>, AppError> {
         let state = ctx.data::<AppState>().unwrap();
@@ -243,19 +406,22 @@ impl QueryRoot {
     }
 
     /// Paginated, filterable search across all equipment items. Returns a cursor-based connection.
+    #[allow(clippy::too_many_arguments)]
     async fn all_equipment(
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "Items per page. Default 20, max 100.")] first: Option<i32>,
         #[graphql(desc = "Opaque cursor from a previous pageInfo.endCursor. Omit for the first page.")] after: Option<String>,
         #[graphql(desc = "Case-insensitive substring match against the equipment name.")] name_search: Option<String>,
-        #[graphql(desc = "Filter by equipment category in snake_case. One of: energy_weapon, ballistic_weapon, missile_weapon, ammo, physical_weapon, equipment, armor, structure, engine, targeting_system, myomer, heat_sink, jump_jet, communications.")] category: Option<String>,
+        #[graphql(desc = "Filter by equipment category in snake_case. One of: energy_weapon, ballistic_weapon, missile_weapon, physical_weapon, ammunition, equipment, armor, structure, engine, gyro, cockpit, actuator, heat_sink, jump_jet, targeting_computer, targeting_system, communications, myomer.")] category: Option<String>,
         #[graphql(desc = "Filter by technology base. One of: inner_sphere, clan, mixed, primitive.")] tech_base: Option<String>,
         #[graphql(desc = "Filter by rules level. One of: introductory, standard, advanced, experimental, unofficial.")] rules_level: Option<String>,
         #[graphql(desc = "Filter to equipment weighing at most this many tons. Only matches items with known tonnage.")] max_tonnage: Option<f64>,
         #[graphql(desc = "Filter to equipment consuming at most this many critical slots. Only matches items with known crits.")] max_crits: Option<i32>,
         #[graphql(desc = "Filter to equipment observed in this location across existing units (e.g. \"right_arm\").")] observed_location: Option<String>,
         #[graphql(desc = "Filter to ammo types compatible with this weapon slug (e.g. \"autocannon-10\").")] ammo_for_slug: Option<String>,
+        #[graphql(desc = "Filter to equipment in service in this in-universe year (e.g. 3052) in any tech base: prototyped or produced by then and not extinct, or reintroduced. Items without tech progression fall back to introYear; items with neither are excluded.")] year: Option<i32>,
+        #[graphql(desc = "Filter to equipment legal in this era (slug, e.g. \"clan-invasion\"): its TechManual availability rating for the era's period (Star League to 2780, Succession Wars to 3049, Clan Invasion to 3080, Dark Age after) is not X, and it was prototyped by the era's end. Unrated equipment is excluded.")] legal_in_era: Option<String>,
     ) -> Result<EquipmentConnection, AppError> {
         let state = ctx.data::<AppState>().unwrap();
         let first = first.unwrap_or(20).clamp(1, 100) as i64;
@@ -264,6 +430,12 @@ impl QueryRoot {
             .and_then(decode_cursor)
             .map(|(_, id)| id);
 
+        if let Some(era) = legal_in_era.as_deref() {
+            if eras::get_by_slug(&state.pool, era).await?.is_none() {
+                return Err(AppError::Validation(format!("unknown era '{era}'")));
+            }
+        }
+
         let filter = equipment::EquipmentFilter {
             name_search: name_search.as_deref(),
             category: category.as_deref(),
@@ -273,6 +445,8 @@ impl QueryRoot {
             max_crits,
             observed_location: observed_location.as_deref(),
             ammo_for_slug: ammo_for_slug.as_deref(),
+            year,
+            legal_in_era: legal_in_era.as_deref(),
         };
 
         let (rows, total_count, has_next) = equipment::search(
@@ -372,6 +546,82 @@ impl QueryRoot {
         Ok(rows.into_iter().map(EraGql).collect())
     }
 
+    // ── Planets ─────────────────────────────────────────────────────────────
+
+    /// Look up a single planet by its slug.
+    async fn planet(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Lowercase, hyphen-separated planet identifier (e.g. \"hesperus-ii\").")] slug: String,
+    ) -> Result<Option<PlanetGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let row = planets::get_by_slug(&state.pool, &slug).await?;
+        Ok(row.map(PlanetGql))
+    }
+
+    /// Planets a faction held in a given year, ordered by name.
+    #[graphql(complexity = 10)]
+    async fn planets_owned_by(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Faction slug (e.g. \"davion\").")] faction_slug: String,
+        #[graphql(desc = "In-universe year (e.g. 3025). Omit for current owners.")] year: Option<i32>,
+        #[graphql(desc = "Also include planets of factions under this one in the parent hierarchy that year (e.g. \"steiner\" and \"davion\" for \"federated-commonwealth\" in 3040). Default false.")] include_child_factions: Option<bool>,
+    ) -> Result<Vec<PlanetGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        if factions::get_by_slug(&state.pool, &faction_slug).await?.is_none() {
+            return Err(AppError::Validation(format!("unknown faction '{faction_slug}'")));
+        }
+        let rows = planets::list_owned_by(
+            &state.pool,
+            &faction_slug,
+            year,
+            include_child_factions.unwrap_or(false),
+        )
+        .await?;
+        Ok(rows.into_iter().map(PlanetGql).collect())
+    }
+
+    /// Planets reachable from a planet in at most `jumps` jumps of 30 light years, nearest first. Optionally only those a faction held in a given year.
+    #[graphql(complexity = 10)]
+    async fn planets_within_jumps(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Slug of the origin planet (e.g. \"hesperus-ii\").")] slug: String,
+        #[graphql(desc = "Maximum number of jumps, 1-10.")] jumps: i32,
+        #[graphql(desc = "Only planets held by this faction (slug) in `year`.")] faction_slug: Option<String>,
+        #[graphql(desc = "In-universe year for the factionSlug filter. Omit for current owners.")] year: Option<i32>,
+    ) -> Result<Vec<PlanetJumpGql>, AppError> {
+        if !(1..=10).contains(&jumps) {
+            return Err(AppError::Validation("jumps must be between 1 and 10".into()));
+        }
+        let state = ctx.data::<AppState>().unwrap();
+        let origin = planets::get_by_slug(&state.pool, &slug)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("planet '{slug}' not found")))?;
+        let candidates = planets::list_in_box(
+            &state.pool,
+            origin.x,
+            origin.y,
+            JUMP_RANGE_LY * jumps as f64,
+        )
+        .await?;
+        let mut reachable = within_jumps(&origin, candidates, jumps);
+
+        if let Some(faction) = faction_slug.as_deref() {
+            if factions::get_by_slug(&state.pool, faction).await?.is_none() {
+                return Err(AppError::Validation(format!("unknown faction '{faction}'")));
+            }
+            let owned: HashSet<i32> = planets::list_owned_by(&state.pool, faction, year, false)
+                .await?
+                .into_iter()
+                .map(|p| p.id)
+                .collect();
+            reachable.retain(|r| owned.contains(&r.planet.0.id));
+        }
+        Ok(reachable)
+    }
+
     // ── Construction Reference ───────────────────────────────────────────────
 
     /// List all engine types, optionally filtered by technology base and/or rules level.
@@ -575,4 +825,205 @@ impl QueryRoot {
                 .collect(),
         })
     }
+
+    // ── Calculators ─────────────────────────────────────────────────────────
+
+    /// Calculate Battle Value 2.0 for an arbitrary mech configuration, with a full
+    /// defensive/offensive breakdown. Unknown slugs or locations return a validation error.
+    #[graphql(complexity = 10)]
+    async fn calculate_battle_value(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Mech configuration to evaluate.")] input: MechConfigInput,
+    ) -> Result<BattleValueGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let mech = calc::mech::resolve(&state.pool, input.into_spec()?).await?;
+        Ok(calc::bv::calculate(&mech).into())
+    }
+
+    /// Validate a mech design against TechManual construction rules: weight budget,
+    /// critical slots and armor per location, heat sink minimum, tech base mixing and
+    /// availability in a given year. Rule violations are returned as structured errors;
+    /// unknown slugs or locations fail the request.
+    #[graphql(complexity = 10)]
+    async fn validate_mech_design(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Mech design to validate.")] design: MechDesignInput,
+    ) -> Result<MechDesignValidationGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let (spec, rules) = design.into_parts()?;
+        let rating = spec.engine_rating;
+        let (mech, engine_weight) = tokio::try_join!(
+            calc::mech::resolve(&state.pool, spec),
+            construction::get_engine_weight(&state.pool, rating.clamp(0, i16::MAX as i32) as i16),
+        )?;
+        let engine_weight = engine_weight.map(|w| w.standard_weight.to_f64().unwrap_or(0.0));
+        Ok(calc::validate::validate(&mech, &rules, engine_weight).into())
+    }
+
+    /// Assemble random forces from the units a faction fields in an era, ranked by
+    /// how closely they hit a BV or PV budget. Sampling is deterministic for a given seed.
+    #[graphql(complexity = 10)]
+    async fn build_force(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Faction, era, budget, size and role constraints.")] constraints: ForceConstraintsInput,
+    ) -> Result<ForceBuildGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let request = constraints.to_request()?;
+        ensure_faction_and_era(state, &constraints.faction_slug, &constraints.era_slug).await?;
+
+        let rows = units::get_force_pool(
+            &state.pool,
+            &constraints.faction_slug,
+            &constraints.era_slug,
+            constraints.budget_type == BudgetType::Pv,
+            constraints.unit_type.as_deref(),
+        )
+        .await?;
+        let pool: Vec<calc::force::PoolUnit> = rows.iter().map(Into::into).collect();
+        if let Some((role, available)) = calc::force::unmet_role(&pool, &request.roles) {
+            return Err(AppError::Validation(format!(
+                "only {available} available units have role '{role}'"
+            )));
+        }
+
+        let forces = calc::force::build(&pool, &request);
+        Ok(ForceBuildGql {
+            budget_type: constraints.budget_type,
+            budget: request.budget,
+            pool_size: pool.len() as i32,
+            forces: forces
+                .into_iter()
+                .enumerate()
+                .map(|(rank, f)| ForceGql {
+                    rank: rank as i32 + 1,
+                    total_value: f.total,
+                    difference: f.difference(request.budget),
+                    members: f
+                        .members
+                        .iter()
+                        .map(|&i| ForceMemberGql {
+                            unit: UnitGql(rows[i].unit.clone()),
+                            value: rows[i].value,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+    }
+
+    // ── Random assignment ───────────────────────────────────────────────────
+
+    /// Build a weighted random assignment table (RAT) for a faction and era from
+    /// MegaMek force generator availability. Optionally narrowed to one unit type
+    /// and weight class.
+    #[graphql(complexity = 10)]
+    async fn random_assignment_table(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Faction slug (e.g. \"davion\").")] faction_slug: String,
+        #[graphql(desc = "Era slug (e.g. \"renaissance\").")] era_slug: String,
+        #[graphql(desc = "Chassis unit type (e.g. \"mech\", \"vehicle\").")] unit_type: Option<String>,
+        #[graphql(desc = "Weight class by tonnage.")] weight_class: Option<WeightClass>,
+        #[graphql(desc = "TWO_D6 (default) or PERCENTAGE.", default_with = "RatTableType::TwoD6")] table_type: RatTableType,
+    ) -> Result<RandomAssignmentTableGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        ensure_faction_and_era(state, &faction_slug, &era_slug).await?;
+        let rows = units::get_rat_entries(
+            &state.pool,
+            &faction_slug,
+            &era_slug,
+            unit_type.as_deref(),
+            weight_class.map(WeightClass::tonnage_range),
+        )
+        .await?;
+        let weights: Vec<f64> = rows.iter().map(|r| r.weight).collect();
+        let table = match table_type {
+            RatTableType::TwoD6 => calc::rat::two_d6(&weights),
+            RatTableType::Percentage => calc::rat::percentage(&weights),
+        };
+        Ok(RandomAssignmentTableGql {
+            faction_slug,
+            era_slug,
+            table_type,
+            unit_count: rows.len() as i32,
+            total_weight: weights.iter().sum(),
+            rows: table
+                .into_iter()
+                .map(|t| RatRowGql {
+                    roll: match table_type {
+                        RatTableType::TwoD6 => t.low.to_string(),
+                        RatTableType::Percentage => format!("{:02}-{:02}", t.low, t.high % 100),
+                    },
+                    roll_low: t.low,
+                    roll_high: t.high,
+                    probability: t.probability,
+                    weight: rows[t.member].weight,
+                    unit: UnitGql(rows[t.member].unit.clone()),
+                })
+                .collect(),
+        })
+    }
+
+    /// Draw units at random from a faction's weighted availability in an era.
+    /// Draws are with replacement and reproducible: the same arguments and seed
+    /// always return the same units.
+    #[graphql(complexity = 10)]
+    #[allow(clippy::too_many_arguments)]
+    async fn roll_units(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Faction slug (e.g. \"davion\").")] faction_slug: String,
+        #[graphql(desc = "Era slug (e.g. \"renaissance\").")] era_slug: String,
+        #[graphql(desc = "Chassis unit type (e.g. \"mech\", \"vehicle\").")] unit_type: Option<String>,
+        #[graphql(desc = "Weight class by tonnage.")] weight_class: Option<WeightClass>,
+        #[graphql(desc = "Number of units to draw. Default 1, max 100.", default = 1)] count: i32,
+        #[graphql(desc = "Random seed.", default)] seed: i64,
+    ) -> Result<Vec<RolledUnitGql>, AppError> {
+        if !(1..=100).contains(&count) {
+            return Err(AppError::Validation("count must be between 1 and 100".into()));
+        }
+        let state = ctx.data::<AppState>().unwrap();
+        ensure_faction_and_era(state, &faction_slug, &era_slug).await?;
+        let rows = units::get_rat_entries(
+            &state.pool,
+            &faction_slug,
+            &era_slug,
+            unit_type.as_deref(),
+            weight_class.map(WeightClass::tonnage_range),
+        )
+        .await?;
+        let weights: Vec<f64> = rows.iter().map(|r| r.weight).collect();
+        let total: f64 = weights.iter().sum();
+        Ok(calc::rat::roll(&weights, count as usize, seed as u64)
+            .into_iter()
+            .enumerate()
+            .map(|(n, i)| RolledUnitGql {
+                draw: n as i32 + 1,
+                probability: rows[i].weight / total,
+                unit: UnitGql(rows[i].unit.clone()),
+            })
+            .collect())
+    }
+}
+
+/// Reject unknown faction or era slugs with a validation error.
+async fn ensure_faction_and_era(
+    state: &AppState,
+    faction_slug: &str,
+    era_slug: &str,
+) -> Result<(), AppError> {
+    let (faction, era) = tokio::try_join!(
+        factions::get_by_slug(&state.pool, faction_slug),
+        eras::get_by_slug(&state.pool, era_slug),
+    )?;
+    if faction.is_none() {
+        return Err(AppError::Validation(format!("unknown faction '{faction_slug}'")));
+    }
+    if era.is_none() {
+        return Err(AppError::Validation(format!("unknown era '{era_slug}'")));
+    }
+    Ok(())
 }
@@ -3,9 +3,9 @@ use async_graphql::{dataloader::DataLoader, EmptyMutation, EmptySubscription, Sc
 use crate::{
     graphql::{
         loaders::{
-            AmmoForLoader, AmmoTypesLoader, ArmorTypeLoader, CockpitTypeLoader, EngineTypeLoader,
-            GyroTypeLoader, HeatsinkTypeLoader, MechDataLoader, MyomerTypeLoader,
-            StructureTypeLoader,
+            AeroDataLoader, AlphaStrikeLoader, AmmoForLoader, AmmoTypesLoader, ArmorTypeLoader, CockpitTypeLoader, EngineTypeLoader, FactionLoader,
+            GyroTypeLoader, HeatsinkTypeLoader, ImportRunLoader, InfantryDataLoader, LamDataLoader, LargeCraftDataLoader, MechDataLoader, MyomerTypeLoader, OmniBaseLoader, PlanetOwnershipLoader, ProtoMechDataLoader, QuadVeeDataLoader,
+            StructureTypeLoader, TechProgressionLoader, VehicleDataLoader,
         },
         query::QueryRoot,
     },
@@ -17,6 +17,15 @@ pub type AppSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
 pub fn build(state: AppState) -> AppSchema {
     let pool = &state.pool;
     let mech_loader = DataLoader::new(MechDataLoader { pool: pool.clone() }, tokio::spawn);
+    let protomech_loader = DataLoader::new(ProtoMechDataLoader { pool: pool.clone() }, tokio::spawn);
+    let omni_base_loader = DataLoader::new(OmniBaseLoader { pool: pool.clone() }, tokio::spawn);
+    let lam_loader = DataLoader::new(LamDataLoader { pool: pool.clone() }, tokio::spawn);
+    let quadvee_loader = DataLoader::new(QuadVeeDataLoader { pool: pool.clone() }, tokio::spawn);
+    let vehicle_loader = DataLoader::new(VehicleDataLoader { pool: pool.clone() }, tokio::spawn);
+    let aero_loader = DataLoader::new(AeroDataLoader { pool: pool.clone() }, tokio::spawn);
+    let infantry_loader = DataLoader::new(InfantryDataLoader { pool: pool.clone() }, tokio::spawn);
+    let large_craft_loader = DataLoader::new(LargeCraftDataLoader { pool: pool.clone() }, tokio::spawn);
+    let alpha_strike_loader = DataLoader::new(AlphaStrikeLoader { pool: pool.clone() }, tokio::spawn);
     let ammo_for_loader = DataLoader::new(AmmoForLoader { pool: pool.clone() }, tokio::spawn);
     let ammo_types_loader = DataLoader::new(AmmoTypesLoader { pool: pool.clone() }, tokio::spawn);
     let engine_type_loader = DataLoader::new(EngineTypeLoader { pool: pool.clone() }, tokio::spawn);
@@ -26,10 +35,23 @@ pub fn build(state: AppState) -> AppSchema {
     let gyro_type_loader = DataLoader::new(GyroTypeLoader { pool: pool.clone() }, tokio::spawn);
     let cockpit_type_loader = DataLoader::new(CockpitTypeLoader { pool: pool.clone() }, tokio::spawn);
     let myomer_type_loader = DataLoader::new(MyomerTypeLoader { pool: pool.clone() }, tokio::spawn);
+    let import_run_loader = DataLoader::new(ImportRunLoader { pool: pool.clone() }, tokio::spawn);
+    let faction_loader = DataLoader::new(FactionLoader { pool: pool.clone() }, tokio::spawn);
+    let planet_ownership_loader = DataLoader::new(PlanetOwnershipLoader { pool: pool.clone() }, tokio::spawn);
+    let tech_progression_loader = DataLoader::new(TechProgressionLoader { pool: pool.clone() }, tokio::spawn);
 
     Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
         .data(state)
         .data(mech_loader)
+        .data(protomech_loader)
+        .data(omni_base_loader)
+        .data(lam_loader)
+        .data(quadvee_loader)
+        .data(vehicle_loader)
+        .data(aero_loader)
+        .data(infantry_loader)
+        .data(large_craft_loader)
+        .data(alpha_strike_loader)
         .data(ammo_for_loader)
         .data(ammo_types_loader)
         .data(engine_type_loader)
@@ -39,6 +61,10 @@ pub fn build(state: AppState) -> AppSchema {
         .data(gyro_type_loader)
         .data(cockpit_type_loader)
         .data(myomer_type_loader)
+        .data(import_run_loader)
+        .data(faction_loader)
+        .data(planet_ownership_loader)
+        .data(tech_progression_loader)
         .limit_depth(20)
         .limit_complexity(500)
         .finish()
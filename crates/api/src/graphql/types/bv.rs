@@ -0,0 +1,219 @@
+use async_graphql::{InputObject, SimpleObject};
+
+use crate::{
+    calc::{
+        bv::{BvBreakdown, WeaponBv},
+        mech::{MechConfig, MechSpec, Mount},
+    },
+    error::AppError,
+    graphql::types::construction::TechBaseFilter,
+};
+
+// ── Inputs ───────────────────────────────────────────────────────────────────
+
+/// A piece of equipment mounted in a mech location.
+#[derive(InputObject, Clone)]
+pub struct MountedEquipmentInput {
+    /// Equipment slug (e.g. "medium-laser", "is-ammo-ac-20-15").
+    pub equipment_slug: String,
+    /// Mounting location, using LocationGql values (e.g. "left_torso", "right_arm").
+    pub location: String,
+    /// Number of identical items mounted in this location.
+    #[graphql(default = 1)]
+    pub quantity: i32,
+    /// True if the item is rear-facing (torso-mounted weapons only).
+    #[graphql(default)]
+    pub is_rear_facing: bool,
+}
+
+impl From<MountedEquipmentInput> for Mount {
+    fn from(m: MountedEquipmentInput) -> Self {
+        Mount {
+            equipment_slug: m.equipment_slug,
+            location: m.location,
+            quantity: m.quantity,
+            is_rear: m.is_rear_facing,
+        }
+    }
+}
+
+/// A complete mech configuration for Battle Value calculation.
+/// Component fields take slugs from the construction reference queries.
+#[derive(InputObject)]
+pub struct MechConfigInput {
+    /// Mech tonnage (20-100 in steps of 5).
+    pub tonnage: i32,
+    /// Technology base. Clan mechs get implicit CASE in every location.
+    #[graphql(default_with = "TechBaseFilter::InnerSphere")]
+    pub tech_base: TechBaseFilter,
+    /// Chassis configuration: "Biped", "Quad" or "Tripod".
+    #[graphql(default_with = "String::from(\"Biped\")")]
+    pub config: String,
+    /// Engine type slug (e.g. "standard-fusion", "xl-is").
+    pub engine_type: String,
+    /// Engine rating. Walk MP = rating / tonnage.
+    pub engine_rating: i32,
+    /// Jump MP (0 if the mech has no jump jets).
+    #[graphql(default)]
+    pub jump_mp: i32,
+    /// Armor type slug (e.g. "standard", "ferro-fibrous-is").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub armor_type: String,
+    /// Total armor points across all locations.
+    pub armor_points: i32,
+    /// Internal structure type slug (e.g. "standard", "endo-steel-is").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub structure_type: String,
+    /// Gyro type slug (e.g. "standard", "xl").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub gyro_type: String,
+    /// Cockpit type slug (e.g. "standard", "small").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub cockpit_type: String,
+    /// Heat sink type slug (e.g. "single", "double-is").
+    #[graphql(default_with = "String::from(\"single\")")]
+    pub heatsink_type: String,
+    /// Total number of heat sinks, including engine-integrated ones.
+    #[graphql(default = 10)]
+    pub heat_sink_count: i32,
+    /// Myomer type slug (e.g. "standard", "tsm").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub myomer_type: String,
+    /// Mounted weapons, ammunition and equipment.
+    #[graphql(default)]
+    pub equipment: Vec<MountedEquipmentInput>,
+}
+
+impl MechConfigInput {
+    pub fn into_spec(self) -> Result<MechSpec, AppError> {
+        let config = MechConfig::parse(&self.config).ok_or_else(|| {
+            AppError::Validation(format!(
+                "unknown config '{}' (expected Biped, Quad or Tripod)",
+                self.config
+            ))
+        })?;
+        Ok(MechSpec {
+            tonnage: self.tonnage,
+            tech_base: self.tech_base.as_db_str().to_string(),
+            config,
+            engine_type: self.engine_type,
+            engine_rating: self.engine_rating,
+            walk_mp: None,
+            jump_mp: self.jump_mp.max(0),
+            armor_type: self.armor_type,
+            armor_points: self.armor_points.max(0),
+            structure_type: self.structure_type,
+            gyro_type: self.gyro_type,
+            cockpit_type: self.cockpit_type,
+            heatsink_type: self.heatsink_type,
+            heat_sink_count: self.heat_sink_count.max(0),
+            myomer_type: self.myomer_type,
+            mounts: self.equipment.into_iter().map(Mount::from).collect(),
+        })
+    }
+}
+
+// ── Outputs ──────────────────────────────────────────────────────────────────
+
+/// BV contribution of one weapon after targeting computer, facing and heat adjustments.
+#[derive(SimpleObject)]
+pub struct WeaponBattleValueGql {
+    /// Equipment slug of the weapon.
+    pub equipment_slug: String,
+    /// Mounting location.
+    pub location: String,
+    /// True if rear-facing (counted at half BV).
+    pub is_rear_facing: bool,
+    /// Weapon BV before the heat adjustment.
+    pub base_bv: f64,
+    /// Heat per turn used for the heat efficiency check (Ultra ×2, Rotary ×6).
+    pub heat: i32,
+    /// BV actually counted toward the offensive rating.
+    pub bv: f64,
+    /// True if this weapon was fired past the heat efficiency threshold and counted at half BV.
+    pub exceeds_heat_efficiency: bool,
+}
+
+impl From<WeaponBv> for WeaponBattleValueGql {
+    fn from(w: WeaponBv) -> Self {
+        Self {
+            equipment_slug: w.equipment_slug,
+            location: w.location,
+            is_rear_facing: w.is_rear,
+            base_bv: w.base_bv,
+            heat: w.heat,
+            bv: w.bv,
+            exceeds_heat_efficiency: w.exceeds_heat,
+        }
+    }
+}
+
+/// Battle Value 2.0 result with the full defensive/offensive breakdown.
+/// battle_value = round(round(defensive_rating + offensive_rating) * cockpit_modifier).
+#[derive(SimpleObject)]
+pub struct BattleValueGql {
+    /// Final Battle Value 2.0.
+    pub battle_value: i32,
+    /// Defensive battle rating (after defensive factor).
+    pub defensive_rating: f64,
+    /// Offensive battle rating (after speed factor).
+    pub offensive_rating: f64,
+    /// Armor points × 2.5 × armor type multiplier.
+    pub armor_bv: f64,
+    /// Internal structure points × 1.5 × structure and engine type multipliers.
+    pub structure_bv: f64,
+    /// Tonnage × gyro type multiplier.
+    pub gyro_bv: f64,
+    /// BV of defensive equipment (AMS, ECM, active probes).
+    pub defensive_equipment_bv: f64,
+    /// Penalty for explosive ammunition and Gauss weapons in unprotected locations.
+    pub explosive_ammo_penalty: f64,
+    /// Best target movement modifier from running or jumping.
+    pub target_movement_modifier: i32,
+    /// 1 + TMM / 10.
+    pub defensive_factor: f64,
+    /// Sum of weapon BV after heat and facing adjustments.
+    pub weapon_bv: f64,
+    /// Ammunition BV, capped by the BV of the weapons that use it.
+    pub ammo_bv: f64,
+    /// BV of other offensive equipment.
+    pub offensive_equipment_bv: f64,
+    /// Tonnage × myomer weight multiplier (1.5 for TSM).
+    pub weight_bv: f64,
+    /// 6 + heat dissipation − movement heat. Weapons beyond this threshold count at half BV.
+    pub heat_efficiency: i32,
+    /// Speed factor from run MP and half jump MP.
+    pub speed_factor: f64,
+    /// Cockpit type multiplier applied to the final total.
+    pub cockpit_modifier: f64,
+    /// Per-weapon contributions, in heat-sorting order (highest BV first).
+    pub weapons: Vec<WeaponBattleValueGql>,
+    /// Non-fatal issues, e.g. equipment without BV data counted as 0.
+    pub warnings: Vec<String>,
+}
+
+impl From<BvBreakdown> for BattleValueGql {
+    fn from(b: BvBreakdown) -> Self {
+        Self {
+            battle_value: b.battle_value,
+            defensive_rating: b.defensive_rating,
+            offensive_rating: b.offensive_rating,
+            armor_bv: b.armor_bv,
+            structure_bv: b.structure_bv,
+            gyro_bv: b.gyro_bv,
+            defensive_equipment_bv: b.defensive_equipment_bv,
+            explosive_ammo_penalty: b.explosive_penalty,
+            target_movement_modifier: b.target_movement_modifier,
+            defensive_factor: b.defensive_factor,
+            weapon_bv: b.weapon_bv,
+            ammo_bv: b.ammo_bv,
+            offensive_equipment_bv: b.offensive_equipment_bv,
+            weight_bv: b.weight_bv,
+            heat_efficiency: b.heat_efficiency,
+            speed_factor: b.speed_factor,
+            cockpit_modifier: b.cockpit_modifier,
+            weapons: b.weapons.into_iter().map(Into::into).collect(),
+            warnings: b.warnings,
+        }
+    }
+}
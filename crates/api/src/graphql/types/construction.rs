@@ -1,9 +1,14 @@
 use async_graphql::{Enum, Object, SimpleObject, ID};
 use rust_decimal::prelude::ToPrimitive;
 
-use crate::db::models::{
-    DbArmorType, DbCockpitType, DbEngineType, DbGyroType, DbHeatsinkType,
-    DbMyomerType, DbStructureType,
+use crate::{
+    db::models::{
+        DbArmorType, DbCockpitType, DbEngineType, DbGyroType, DbHeatsinkType,
+        DbMyomerType, DbStructureType,
+    },
+    graphql::types::tech::{
+        availability_ratings, construction_progression, AvailabilityRatingsGql, TechProgressionGql,
+    },
 };
 
 // ── Filter Enums ─────────────────────────────────────────────────────────────
@@ -111,6 +116,26 @@ impl EngineTypeGql {
     async fn intro_year(&self) -> Option<i32> {
         self.0.intro_year
     }
+
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(Some(&self.0.tech_base), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
+    /// Battle Value 2.0 multiplier applied to internal structure BV (e.g. 0.5 for IS XL).
+    async fn bv_multiplier(&self) -> f64 {
+        self.0.bv_multiplier.to_f64().unwrap_or(1.0)
+    }
 }
 
 // ── Armor Type ───────────────────────────────────────────────────────────────
@@ -160,6 +185,26 @@ impl ArmorTypeGql {
     async fn intro_year(&self) -> Option<i32> {
         self.0.intro_year
     }
+
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(Some(&self.0.tech_base), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
+    /// Battle Value 2.0 multiplier applied to armor BV (e.g. 2.0 for hardened).
+    async fn bv_multiplier(&self) -> f64 {
+        self.0.bv_multiplier.to_f64().unwrap_or(1.0)
+    }
 }
 
 // ── Structure Type ───────────────────────────────────────────────────────────
@@ -209,6 +254,26 @@ impl StructureTypeGql {
     async fn intro_year(&self) -> Option<i32> {
         self.0.intro_year
     }
+
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(Some(&self.0.tech_base), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
+    /// Battle Value 2.0 multiplier applied to internal structure BV (e.g. 0.5 for composite).
+    async fn bv_multiplier(&self) -> f64 {
+        self.0.bv_multiplier.to_f64().unwrap_or(1.0)
+    }
 }
 
 // ── Heatsink Type ────────────────────────────────────────────────────────────
@@ -262,6 +327,21 @@ impl HeatsinkTypeGql {
     async fn intro_year(&self) -> Option<i32> {
         self.0.intro_year
     }
+
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(Some(&self.0.tech_base), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
 }
 
 // ── Gyro Type ────────────────────────────────────────────────────────────────
@@ -316,6 +396,26 @@ impl GyroTypeGql {
     async fn intro_year(&self) -> Option<i32> {
         self.0.intro_year
     }
+
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(self.0.tech_base.as_deref(), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
+    /// Battle Value 2.0 defensive value per ton of mech (0.5 for most gyros, 1.0 for heavy-duty).
+    async fn bv_multiplier(&self) -> f64 {
+        self.0.bv_multiplier.to_f64().unwrap_or(1.0)
+    }
 }
 
 // ── Cockpit Type ─────────────────────────────────────────────────────────────
@@ -364,6 +464,26 @@ impl CockpitTypeGql {
     async fn intro_year(&self) -> Option<i32> {
         self.0.intro_year
     }
+
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(self.0.tech_base.as_deref(), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
+    /// Battle Value 2.0 multiplier applied to the final BV (e.g. 0.95 for small cockpit).
+    async fn bv_multiplier(&self) -> f64 {
+        self.0.bv_multiplier.to_f64().unwrap_or(1.0)
+    }
 }
 
 // ── Myomer Type ──────────────────────────────────────────────────────────────
@@ -403,6 +523,21 @@ impl MyomerTypeGql {
         self.0.intro_year
     }
 
+    /// TechManual prototype, production, common, extinction and reintroduction years. Null if unknown.
+    async fn tech_progression(&self) -> Option<TechProgressionGql> {
+        construction_progression(self.0.tech_base.as_deref(), &self.0.dates)
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
     /// Type-specific properties as a JSON object (e.g. {"tonnage_fraction": 0.05} for MASC).
     async fn properties(&self) -> &serde_json::Value {
         &self.0.properties
@@ -0,0 +1,45 @@
+use async_graphql::SimpleObject;
+
+use crate::graphql::types::{omni::OmniEquipmentGql, unit::UnitGql};
+
+// ── Dataset Changes ──────────────────────────────────────────────────────────
+
+/// Units that differ between two imported dataset versions.
+#[derive(SimpleObject)]
+pub struct DatasetChangesGql {
+    /// Dataset version compared from.
+    pub from_version: String,
+    /// Dataset version compared to.
+    pub to_version: String,
+    /// Units in `toVersion` but not `fromVersion`.
+    pub added: Vec<UnitChangeGql>,
+    /// Units in `fromVersion` but not `toVersion`.
+    pub removed: Vec<UnitChangeGql>,
+    /// Units in both versions whose stats, armor, loadout or BV changed.
+    pub changed: Vec<UnitChangeGql>,
+}
+
+/// One unit's differences between two dataset versions.
+#[derive(SimpleObject)]
+pub struct UnitChangeGql {
+    /// Lowercase, hyphen-separated unit identifier.
+    pub slug: String,
+    /// Full unit name as of the newer version it appears in.
+    pub full_name: String,
+    /// The unit as currently stored. Null if it has since been deleted.
+    pub unit: Option<UnitGql>,
+    /// True if name, tonnage, tech base, rules level or introduction year changed.
+    pub core_changed: bool,
+    /// True if any location's armor or internal structure changed.
+    pub armor_changed: bool,
+    /// True if any equipment was added, removed, moved or re-counted.
+    pub loadout_changed: bool,
+    /// Battle value in `fromVersion`. Null for added units or if unknown.
+    pub bv_from: Option<i32>,
+    /// Battle value in `toVersion`. Null for removed units or if unknown.
+    pub bv_to: Option<i32>,
+    /// Equipment in `toVersion` that `fromVersion` lacks; quantity is the number of extra items.
+    pub loadout_added: Vec<OmniEquipmentGql>,
+    /// Equipment in `fromVersion` that `toVersion` lacks; quantity is the number of missing items.
+    pub loadout_removed: Vec<OmniEquipmentGql>,
+}
@@ -1,9 +1,19 @@
 use async_graphql::{dataloader::DataLoader, Context, Object, ID};
 
 use crate::{
-    db::models::DbEquipment,
+    db::{
+        models::DbEquipment,
+        provenance,
+    },
     error::AppError,
-    graphql::loaders::{AmmoForLoader, AmmoTypesLoader},
+    graphql::{
+        loaders::{AmmoForLoader, AmmoTypesLoader, TechProgressionLoader},
+        types::{
+            provenance::EquipmentProvenanceGql,
+            tech::{availability_ratings, AvailabilityRatingsGql, TechProgressionGql},
+        },
+    },
+    state::AppState,
 };
 
 pub struct EquipmentGql(pub DbEquipment);
@@ -26,7 +36,12 @@ impl EquipmentGql {
         &self.0.name
     }
 
-    /// Equipment category in snake_case. One of: energy_weapon, ballistic_weapon, missile_weapon, ammo, physical_weapon, equipment, armor, structure, engine, targeting_system, myomer, heat_sink, jump_jet, communications.
+    /// MegaMek internal name of the catalog entry (e.g. "ISERLargeLaser"). Null for equipment the catalog does not know.
+    async fn internal_name(&self) -> Option<&str> {
+        self.0.internal_name.as_deref()
+    }
+
+    /// Equipment category in snake_case. One of: energy_weapon, ballistic_weapon, missile_weapon, physical_weapon, ammunition, equipment, armor, structure, engine, gyro, cockpit, actuator, heat_sink, jump_jet, targeting_computer, targeting_system, communications, myomer.
     async fn category(&self) -> &str {
         &self.0.category
     }
@@ -94,6 +109,43 @@ impl EquipmentGql {
         self.0.intro_year
     }
 
+    /// TechManual tech progression, one entry per tech base the item exists in. Without progression data, a single production entry from introYear; empty if that is unknown too.
+    #[graphql(complexity = 3)]
+    async fn tech_progression(&self, ctx: &Context<'_>) -> Result<Vec<TechProgressionGql>, AppError> {
+        let loader = ctx.data::<DataLoader<TechProgressionLoader>>().unwrap();
+        let rows = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?
+            .unwrap_or_default();
+        if rows.is_empty() {
+            return Ok(self
+                .0
+                .intro_year
+                .map(|year| TechProgressionGql {
+                    tech_base: Some(self.0.tech_base.clone()),
+                    prototype: None,
+                    production: Some(year),
+                    common: None,
+                    extinct: None,
+                    reintroduced: None,
+                })
+                .into_iter()
+                .collect());
+        }
+        Ok(rows.into_iter().map(TechProgressionGql::from).collect())
+    }
+
+    /// TechManual technology rating, A (primitive) to F (most advanced). Null if not rated.
+    async fn tech_rating(&self) -> Option<&str> {
+        self.0.ratings.tech_rating.as_deref()
+    }
+
+    /// TechManual availability rating in each era period. Null if not rated.
+    async fn availability(&self) -> Option<AvailabilityRatingsGql> {
+        availability_ratings(&self.0.ratings)
+    }
+
     /// Source book or technical readout where this equipment is published.
     async fn source_book(&self) -> Option<&str> {
         self.0.source_book.as_deref()
@@ -115,6 +167,17 @@ impl EquipmentGql {
         self.0.stats_source.as_deref()
     }
 
+    /// Where the stats came from: source and the equipment seed run that wrote them.
+    #[graphql(complexity = 3)]
+    async fn provenance(&self, ctx: &Context<'_>) -> Result<EquipmentProvenanceGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let stats_run_id = provenance::get_equipment_stats_run_id(&state.pool, self.0.id).await?;
+        Ok(EquipmentProvenanceGql {
+            stats_source: self.0.stats_source.clone(),
+            stats_run_id,
+        })
+    }
+
     /// The weapon this ammo is compatible with. Null for non-ammo equipment.
     #[graphql(complexity = 3)]
     async fn ammo_for(&self, ctx: &Context<'_>) -> Result<Option<EquipmentGql>, AppError> {
@@ -1,6 +1,13 @@
-use async_graphql::{Context, Object, ID};
+use async_graphql::{Context, Object, SimpleObject, ID};
 
-use crate::{db::models::DbFaction, error::AppError, state::AppState};
+use crate::{
+    db::{
+        factions,
+        models::{DbFaction, DbFactionName},
+    },
+    error::AppError,
+    state::AppState,
+};
 
 use super::era::EraGql;
 
@@ -71,4 +78,90 @@ impl FactionGql {
         .await?;
         Ok(rows.into_iter().map(EraGql).collect())
     }
+
+    /// The faction this one sat inside in the given year (e.g. "federated-commonwealth" for "steiner" in 3040, "general" for any Great House). Without a year, the current parent. Null for top-level factions.
+    async fn parent(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "In-universe year (e.g. 3040). Omit for the current parent.")] year: Option<i32>,
+    ) -> Result<Option<FactionGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let row = factions::get_parent(&state.pool, self.0.id, year).await?;
+        Ok(row.map(|r| FactionGql(r.faction)))
+    }
+
+    /// Every faction above this one in the parent hierarchy, nearest first.
+    #[graphql(complexity = 5)]
+    async fn ancestors(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "In-universe year (e.g. 3040). Only parent relations covering it are followed. Omit for all of them.")] year: Option<i32>,
+    ) -> Result<Vec<FactionGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = factions::get_ancestors(&state.pool, self.0.id, year).await?;
+        Ok(rows.into_iter().map(|r| FactionGql(r.faction)).collect())
+    }
+
+    /// Factions that took over from this one (e.g. "raven-alliance" for "outworlds-alliance" in 3083), by year.
+    #[graphql(complexity = 3)]
+    async fn successors(&self, ctx: &Context<'_>) -> Result<Vec<FactionSuccessorGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = factions::list_successors(&state.pool, self.0.id).await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| FactionSuccessorGql {
+                year: r.start_year,
+                faction: FactionGql(r.faction),
+            })
+            .collect())
+    }
+
+    /// Names the faction went by over time, oldest first (e.g. Lyran Commonwealth, Lyran Alliance, Lyran Commonwealth). A single entry with the current name if it was never renamed.
+    #[graphql(complexity = 3)]
+    async fn names_by_year(&self, ctx: &Context<'_>) -> Result<Vec<FactionNameGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let mut rows = factions::list_names(&state.pool, self.0.id).await?;
+        if rows.is_empty() {
+            rows.push(DbFactionName {
+                name: self.0.name.clone(),
+                short_name: self.0.short_name.clone(),
+                start_year: self.0.founding_year,
+                end_year: self.0.dissolution_year,
+            });
+        }
+        Ok(rows.into_iter().map(FactionNameGql::from).collect())
+    }
+}
+
+/// A faction that took over from another.
+#[derive(SimpleObject)]
+pub struct FactionSuccessorGql {
+    /// The successor faction.
+    pub faction: FactionGql,
+    /// In-universe year of the succession. Null if unknown.
+    pub year: Option<i32>,
+}
+
+/// A name a faction went by over a span of in-universe years.
+#[derive(SimpleObject)]
+pub struct FactionNameGql {
+    /// Full faction name (e.g. "Lyran Alliance").
+    pub name: String,
+    /// Abbreviated name (e.g. "LA"). Null if none.
+    pub short_name: Option<String>,
+    /// First year the name was used. Null if since founding.
+    pub start_year: Option<i32>,
+    /// Last year the name was used. Null if still in use.
+    pub end_year: Option<i32>,
+}
+
+impl From<DbFactionName> for FactionNameGql {
+    fn from(n: DbFactionName) -> Self {
+        Self {
+            name: n.name,
+            short_name: n.short_name,
+            start_year: n.start_year,
+            end_year: n.end_year,
+        }
+    }
 }
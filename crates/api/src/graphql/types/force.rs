@@ -0,0 +1,163 @@
+use async_graphql::{Enum, InputObject, SimpleObject};
+
+use crate::{
+    calc::force::{ForceRequest, PoolUnit},
+    db::models::DbForcePoolUnit,
+    error::AppError,
+    graphql::types::unit::UnitGql,
+};
+
+/// Largest force `buildForce` will assemble.
+const MAX_UNIT_COUNT: i32 = 12;
+
+/// Most candidate forces returned per request.
+const MAX_RESULTS: i32 = 20;
+
+// ── Inputs ───────────────────────────────────────────────────────────────────
+
+/// Point system the force budget is measured in.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum BudgetType {
+    /// Battle Value 2.0 (Classic BattleTech).
+    Bv,
+    /// Alpha Strike Point Value. Only units with a MUL PV are eligible.
+    Pv,
+}
+
+/// A minimum number of units with a given tactical role.
+#[derive(InputObject)]
+pub struct RoleCountInput {
+    /// Tactical role from MUL (e.g. "Sniper", "Scout", "Juggernaut"). Case-sensitive.
+    pub role: String,
+    /// Number of units with this role.
+    #[graphql(default = 1)]
+    pub count: i32,
+}
+
+/// Constraints for assembling a random force from a faction's availability list.
+#[derive(InputObject)]
+pub struct ForceConstraintsInput {
+    /// Faction slug (e.g. "clan-wolf").
+    pub faction_slug: String,
+    /// Era slug (e.g. "clan-invasion").
+    pub era_slug: String,
+    /// Point system for the budget.
+    #[graphql(default_with = "BudgetType::Bv")]
+    pub budget_type: BudgetType,
+    /// Target total in the chosen point system.
+    pub budget: i32,
+    /// Number of units in the force (1-12). Default 4, a lance.
+    #[graphql(default = 4)]
+    pub unit_count: i32,
+    /// Required roles. Slots not covered by a role are filled from any role.
+    #[graphql(default)]
+    pub roles: Vec<RoleCountInput>,
+    /// Restrict to one unit type (e.g. "mech", "vehicle").
+    pub unit_type: Option<String>,
+    /// Random seed. The same seed and constraints always return the same forces.
+    #[graphql(default)]
+    pub seed: i64,
+    /// Number of candidate forces to return (1-20).
+    #[graphql(default = 5)]
+    pub results: i32,
+}
+
+impl ForceConstraintsInput {
+    pub fn to_request(&self) -> Result<ForceRequest, AppError> {
+        if !(1..=MAX_UNIT_COUNT).contains(&self.unit_count) {
+            return Err(AppError::Validation(format!(
+                "unitCount must be between 1 and {MAX_UNIT_COUNT}"
+            )));
+        }
+        if !(1..=MAX_RESULTS).contains(&self.results) {
+            return Err(AppError::Validation(format!(
+                "results must be between 1 and {MAX_RESULTS}"
+            )));
+        }
+        if self.budget <= 0 {
+            return Err(AppError::Validation("budget must be positive".into()));
+        }
+        if self.roles.iter().any(|r| r.count < 0) {
+            return Err(AppError::Validation(
+                "role counts must not be negative".into(),
+            ));
+        }
+        let role_total = self
+            .roles
+            .iter()
+            .try_fold(0i32, |total, r| total.checked_add(r.count));
+        match role_total {
+            Some(total) if total <= self.unit_count => {}
+            Some(total) => {
+                return Err(AppError::Validation(format!(
+                    "roles require {total} units but unitCount is {}",
+                    self.unit_count
+                )));
+            }
+            None => {
+                return Err(AppError::Validation(format!(
+                    "roles require more units than unitCount ({})",
+                    self.unit_count
+                )));
+            }
+        }
+        Ok(ForceRequest {
+            budget: self.budget,
+            unit_count: self.unit_count as usize,
+            roles: self
+                .roles
+                .iter()
+                .filter(|r| r.count > 0)
+                .map(|r| (r.role.clone(), r.count as usize))
+                .collect(),
+            seed: self.seed as u64,
+            max_results: self.results as usize,
+        })
+    }
+}
+
+impl From<&DbForcePoolUnit> for PoolUnit {
+    fn from(u: &DbForcePoolUnit) -> Self {
+        PoolUnit {
+            value: u.value,
+            role: u.unit.role.clone(),
+        }
+    }
+}
+
+// ── Outputs ──────────────────────────────────────────────────────────────────
+
+/// A unit in an assembled force.
+#[derive(SimpleObject)]
+pub struct ForceMemberGql {
+    /// The unit variant.
+    pub unit: UnitGql,
+    /// The unit's cost in the requested point system (BV or PV).
+    pub value: i32,
+}
+
+/// One candidate force.
+#[derive(SimpleObject)]
+pub struct ForceGql {
+    /// 1-based rank; 1 is closest to the budget.
+    pub rank: i32,
+    /// Total cost of all units.
+    pub total_value: i32,
+    /// Total minus budget (negative = under budget).
+    pub difference: i32,
+    /// Units in the force, role-constrained slots first.
+    pub members: Vec<ForceMemberGql>,
+}
+
+/// Result of a force build.
+#[derive(SimpleObject)]
+pub struct ForceBuildGql {
+    /// Point system of the budget.
+    pub budget_type: BudgetType,
+    /// Requested budget.
+    pub budget: i32,
+    /// Number of eligible units the forces were drawn from.
+    pub pool_size: i32,
+    /// Distinct candidate forces, best match first. Empty if the pool has fewer units than requested.
+    pub forces: Vec<ForceGql>,
+}
@@ -1,6 +1,15 @@
+pub mod bv;
 pub mod construction;
+pub mod dataset;
 pub mod equipment;
 pub mod era;
 pub mod faction;
+pub mod force;
 pub mod metadata;
+pub mod omni;
+pub mod planet;
+pub mod provenance;
+pub mod rat;
+pub mod tech;
 pub mod unit;
+pub mod validation;
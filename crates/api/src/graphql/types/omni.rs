@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use async_graphql::{Context, Object, SimpleObject};
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{
+    db::{
+        models::{DbOmniBase, DbOmniEquipment, DbPodSpace},
+        units,
+    },
+    error::AppError,
+    graphql::types::unit::UnitGql,
+    state::AppState,
+};
+
+// ── Equipment ────────────────────────────────────────────────────────────────
+
+/// Mounts of one equipment item in one location on an OmniMech.
+#[derive(SimpleObject, Clone)]
+pub struct OmniEquipmentGql {
+    /// Lowercase, hyphen-separated identifier of the equipment item.
+    pub equipment_slug: String,
+    /// Human-readable name of the equipment item.
+    pub equipment_name: String,
+    /// Body location in snake_case (e.g. "right_arm"). Null if unspecified.
+    pub location: Option<String>,
+    /// Number of items mounted.
+    pub quantity: i32,
+    /// True if rear-facing.
+    pub is_rear_facing: bool,
+}
+
+impl From<DbOmniEquipment> for OmniEquipmentGql {
+    fn from(e: DbOmniEquipment) -> Self {
+        Self {
+            equipment_slug: e.equipment_slug,
+            equipment_name: e.equipment_name,
+            location: e.location,
+            quantity: e.quantity,
+            is_rear_facing: e.is_rear_facing,
+        }
+    }
+}
+
+// ── Pod Space ────────────────────────────────────────────────────────────────
+
+/// Critical slot budget of one location on an OmniMech configuration.
+#[derive(SimpleObject)]
+pub struct PodSpaceGql {
+    /// Body location in snake_case (e.g. "left_torso").
+    pub location: String,
+    /// Critical slots in the location.
+    pub total_slots: i32,
+    /// Slots taken by the base chassis: actuators, engine, gyro, structure and armor filler, and fixed equipment.
+    pub fixed_slots: i32,
+    /// Slots available to pods with every pod removed (total minus fixed).
+    pub pod_capacity: i32,
+    /// Slots used by this configuration's pods.
+    pub used_pod_slots: i32,
+    /// Empty slots left for more pods in this configuration.
+    pub remaining_slots: i32,
+}
+
+impl From<DbPodSpace> for PodSpaceGql {
+    fn from(p: DbPodSpace) -> Self {
+        Self {
+            pod_capacity: p.total_slots - p.fixed_slots,
+            location: p.location,
+            total_slots: p.total_slots,
+            fixed_slots: p.fixed_slots,
+            used_pod_slots: p.pod_slots,
+            remaining_slots: p.empty_slots,
+        }
+    }
+}
+
+// ── Base Chassis ─────────────────────────────────────────────────────────────
+
+/// The fixed base chassis shared by OmniMech configurations: engine, armor,
+/// internal structure and fixed equipment, without any pods.
+pub struct OmniBaseGql(pub DbOmniBase);
+
+#[Object]
+impl OmniBaseGql {
+    /// Internal base chassis ID.
+    async fn id(&self) -> i32 {
+        self.0.id
+    }
+
+    /// Mass in tons.
+    async fn tonnage(&self) -> f64 {
+        self.0.tonnage.to_f64().unwrap_or(0.0)
+    }
+
+    /// Engine rating (e.g. 300).
+    async fn engine_rating(&self) -> Option<i32> {
+        self.0.engine_rating
+    }
+
+    /// Engine type as written in the source file (e.g. "XL Engine(Clan)").
+    async fn engine_type(&self) -> Option<&str> {
+        self.0.engine_type.as_deref()
+    }
+
+    /// Walking MP.
+    async fn walk_mp(&self) -> Option<i32> {
+        self.0.walk_mp
+    }
+
+    /// Internal structure type as written in the source file.
+    async fn structure_type(&self) -> Option<&str> {
+        self.0.structure_type.as_deref()
+    }
+
+    /// Armor type as written in the source file.
+    async fn armor_type(&self) -> Option<&str> {
+        self.0.armor_type.as_deref()
+    }
+
+    /// Heat sink type (e.g. "Double").
+    async fn heat_sink_type(&self) -> Option<&str> {
+        self.0.heat_sink_type.as_deref()
+    }
+
+    /// Gyro type as written in the source file.
+    async fn gyro_type(&self) -> Option<&str> {
+        self.0.gyro_type.as_deref()
+    }
+
+    /// Cockpit type as written in the source file.
+    async fn cockpit_type(&self) -> Option<&str> {
+        self.0.cockpit_type.as_deref()
+    }
+
+    /// Equipment fixed to the base chassis, present in every configuration.
+    #[graphql(complexity = 5)]
+    async fn fixed_equipment(&self, ctx: &Context<'_>) -> Result<Vec<OmniEquipmentGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = units::get_omni_base_equipment(&state.pool, self.0.id).await?;
+        Ok(rows.into_iter().map(OmniEquipmentGql::from).collect())
+    }
+
+    /// Slug of the configuration the base was read from (the Prime when there is one).
+    async fn reference_configuration_slug(&self, ctx: &Context<'_>) -> Result<Option<String>, AppError> {
+        let Some(id) = self.0.reference_unit_id else {
+            return Ok(None);
+        };
+        let state = ctx.data::<AppState>().unwrap();
+        let slug = sqlx::query_scalar::<_, String>("SELECT slug FROM units WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&state.pool)
+            .await?;
+        Ok(slug)
+    }
+
+    /// Every configuration built on this base chassis, ordered by name.
+    #[graphql(complexity = 10)]
+    async fn configurations(&self, ctx: &Context<'_>) -> Result<Vec<UnitGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = units::get_omni_configurations(&state.pool, self.0.id).await?;
+        Ok(rows.into_iter().map(UnitGql).collect())
+    }
+}
+
+// ── Configuration ────────────────────────────────────────────────────────────
+
+/// One OmniMech configuration (Prime, A, B...) split into its base chassis and pods.
+pub struct OmniConfigurationGql {
+    pub unit_id: i32,
+    pub name: String,
+    pub base: DbOmniBase,
+}
+
+#[Object]
+impl OmniConfigurationGql {
+    /// Configuration name (e.g. "Prime", "A").
+    async fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The base chassis this configuration is built on.
+    async fn base(&self) -> OmniBaseGql {
+        OmniBaseGql(self.base.clone())
+    }
+
+    /// Equipment fixed to the base chassis.
+    #[graphql(complexity = 5)]
+    async fn fixed_equipment(&self, ctx: &Context<'_>) -> Result<Vec<OmniEquipmentGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = units::get_omni_base_equipment(&state.pool, self.base.id).await?;
+        Ok(rows.into_iter().map(OmniEquipmentGql::from).collect())
+    }
+
+    /// Pod-mounted equipment of this configuration.
+    #[graphql(complexity = 5)]
+    async fn pod_equipment(&self, ctx: &Context<'_>) -> Result<Vec<OmniEquipmentGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = units::get_pod_equipment(&state.pool, self.unit_id).await?;
+        Ok(rows.into_iter().map(OmniEquipmentGql::from).collect())
+    }
+
+    /// Critical slots per location: fixed, pod capacity, used by pods and remaining. Empty without a critical hit table.
+    #[graphql(complexity = 5)]
+    async fn pod_space(&self, ctx: &Context<'_>) -> Result<Vec<PodSpaceGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = units::get_pod_space(&state.pool, self.unit_id).await?;
+        Ok(rows.into_iter().map(PodSpaceGql::from).collect())
+    }
+}
+
+// ── Configuration Diff ───────────────────────────────────────────────────────
+
+/// Pod equipment differences between two OmniMech configurations.
+#[derive(SimpleObject)]
+pub struct ConfigurationDiffGql {
+    /// The configuration compared from.
+    pub a: UnitGql,
+    /// The configuration compared to.
+    pub b: UnitGql,
+    /// True if both configurations share the same base chassis, so the pods are interchangeable.
+    pub same_base: bool,
+    /// Pods on `b` that `a` lacks; quantity is the number of extra items.
+    pub added: Vec<OmniEquipmentGql>,
+    /// Pods on `a` that `b` lacks; quantity is the number of missing items.
+    pub removed: Vec<OmniEquipmentGql>,
+}
+
+/// Split the pod difference between two configurations into items added
+/// going from `a` to `b` and items removed, matched by equipment, location
+/// and facing.
+pub fn diff_pods(
+    a: Vec<DbOmniEquipment>,
+    b: Vec<DbOmniEquipment>,
+) -> (Vec<OmniEquipmentGql>, Vec<OmniEquipmentGql>) {
+    let mut counts: BTreeMap<(Option<String>, String, bool), (DbOmniEquipment, i32)> = BTreeMap::new();
+    for (items, sign) in [(a, -1), (b, 1)] {
+        for item in items {
+            let key = (item.location.clone(), item.equipment_slug.clone(), item.is_rear_facing);
+            let quantity = item.quantity * sign;
+            counts
+                .entry(key)
+                .and_modify(|(_, q)| *q += quantity)
+                .or_insert((item, quantity));
+        }
+    }
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    for (item, delta) in counts.into_values() {
+        let entry = OmniEquipmentGql {
+            quantity: delta.abs(),
+            ..OmniEquipmentGql::from(item)
+        };
+        match delta {
+            d if d > 0 => added.push(entry),
+            d if d < 0 => removed.push(entry),
+            _ => {}
+        }
+    }
+    (added, removed)
+}
@@ -0,0 +1,254 @@
+use std::collections::VecDeque;
+
+use async_graphql::{dataloader::DataLoader, Context, Object, SimpleObject, ID};
+
+use crate::{
+    db::{
+        models::{DbPlanet, DbPlanetFactory, DbPlanetOwnership},
+        planets,
+    },
+    error::AppError,
+    graphql::loaders::{FactionLoader, PlanetOwnershipLoader},
+    state::AppState,
+};
+
+use super::faction::FactionGql;
+
+/// Maximum distance of a single KF jump, in light years.
+pub const JUMP_RANGE_LY: f64 = 30.0;
+
+async fn load_faction(ctx: &Context<'_>, id: Option<i32>) -> Result<Option<FactionGql>, AppError> {
+    let Some(id) = id else {
+        return Ok(None);
+    };
+    let loader = ctx.data::<DataLoader<FactionLoader>>().unwrap();
+    let faction = loader
+        .load_one(id)
+        .await
+        .map_err(|e| AppError::Internal(e.message))?;
+    Ok(faction.map(FactionGql))
+}
+
+// ── Planet ───────────────────────────────────────────────────────────────────
+
+/// A planet on the Inner Sphere and Periphery map.
+pub struct PlanetGql(pub DbPlanet);
+
+#[Object]
+impl PlanetGql {
+    /// Unique identifier (same as slug).
+    async fn id(&self) -> ID {
+        ID(self.0.slug.clone())
+    }
+
+    /// Lowercase, hyphen-separated identifier (e.g. "hesperus-ii").
+    async fn slug(&self) -> &str {
+        &self.0.slug
+    }
+
+    /// Planet name (e.g. "Hesperus II").
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// Map X coordinate in light years from Terra.
+    async fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    /// Map Y coordinate in light years from Terra.
+    async fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    /// Spectral type of the primary star (e.g. "G2V"). Null if unknown.
+    async fn spectral_type(&self) -> Option<&str> {
+        self.0.spectral_type.as_deref()
+    }
+
+    /// Population. Null if unknown.
+    async fn population(&self) -> Option<i64> {
+        self.0.population
+    }
+
+    /// Socio-Industrial Levels (technology, industry, raw materials, output, agriculture). Null if not rated.
+    async fn socio_industrial(&self) -> Option<SocioIndustrialGql> {
+        let p = &self.0;
+        Some(SocioIndustrialGql {
+            technology: p.technology.clone()?,
+            industry: p.industry.clone()?,
+            raw_materials: p.raw_materials.clone()?,
+            output: p.output.clone()?,
+            agriculture: p.agriculture.clone()?,
+        })
+    }
+
+    /// Flavor text or lore description of the planet.
+    async fn description(&self) -> Option<&str> {
+        self.0.description.as_deref()
+    }
+
+    /// Faction holding the planet in the given year. Without a year, the current owner. Null if unowned.
+    async fn owner(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "In-universe year (e.g. 3025). Omit for the current owner.")] year: Option<i32>,
+    ) -> Result<Option<FactionGql>, AppError> {
+        let history = self.ownership(ctx).await?;
+        let span = history.iter().find(|o| match year {
+            Some(y) => o.start_year <= y && o.end_year.is_none_or(|end| end >= y),
+            None => o.end_year.is_none(),
+        });
+        load_faction(ctx, span.and_then(|o| o.faction_id)).await
+    }
+
+    /// Every change of hands, oldest first.
+    #[graphql(complexity = 5)]
+    async fn ownership_history(&self, ctx: &Context<'_>) -> Result<Vec<PlanetOwnershipGql>, AppError> {
+        Ok(self
+            .ownership(ctx)
+            .await?
+            .into_iter()
+            .map(PlanetOwnershipGql)
+            .collect())
+    }
+
+    /// Manufacturers with factories on the planet.
+    #[graphql(complexity = 3)]
+    async fn factories(&self, ctx: &Context<'_>) -> Result<Vec<PlanetFactoryGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = planets::list_factories(&state.pool, self.0.id).await?;
+        Ok(rows.into_iter().map(PlanetFactoryGql::from).collect())
+    }
+}
+
+impl PlanetGql {
+    async fn ownership(&self, ctx: &Context<'_>) -> Result<Vec<DbPlanetOwnership>, AppError> {
+        let loader = ctx.data::<DataLoader<PlanetOwnershipLoader>>().unwrap();
+        let rows = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(rows.unwrap_or_default())
+    }
+}
+
+// ── Socio-Industrial Levels ──────────────────────────────────────────────────
+
+/// A planet's Socio-Industrial Levels, each rated A (best) to D, or F.
+#[derive(SimpleObject)]
+pub struct SocioIndustrialGql {
+    /// Technological sophistication.
+    pub technology: String,
+    /// Industrial development.
+    pub industry: String,
+    /// Raw material dependence (A = self-sufficient).
+    pub raw_materials: String,
+    /// Industrial output.
+    pub output: String,
+    /// Agricultural dependence (A = self-sufficient).
+    pub agriculture: String,
+}
+
+// ── Ownership ────────────────────────────────────────────────────────────────
+
+/// A span of years during which one faction held a planet.
+pub struct PlanetOwnershipGql(pub DbPlanetOwnership);
+
+#[Object]
+impl PlanetOwnershipGql {
+    /// The owning faction. Null while the planet was unowned or abandoned.
+    async fn faction(&self, ctx: &Context<'_>) -> Result<Option<FactionGql>, AppError> {
+        load_faction(ctx, self.0.faction_id).await
+    }
+
+    /// First in-universe year of ownership.
+    async fn start_year(&self) -> i32 {
+        self.0.start_year
+    }
+
+    /// Last in-universe year of ownership. Null for the current owner.
+    async fn end_year(&self) -> Option<i32> {
+        self.0.end_year
+    }
+}
+
+// ── Factory ──────────────────────────────────────────────────────────────────
+
+/// A manufacturer with a factory on a planet.
+#[derive(SimpleObject)]
+pub struct PlanetFactoryGql {
+    /// Lowercase, hyphen-separated manufacturer identifier (e.g. "defiance-industries").
+    pub slug: String,
+    /// Manufacturer name (e.g. "Defiance Industries").
+    pub name: String,
+    /// "file" if listed in the planets data, "fluff" if a MegaMek unit names the planet as its factory.
+    pub source: String,
+}
+
+impl From<DbPlanetFactory> for PlanetFactoryGql {
+    fn from(f: DbPlanetFactory) -> Self {
+        Self {
+            slug: f.slug,
+            name: f.name,
+            source: f.source,
+        }
+    }
+}
+
+// ── Jump Distance ────────────────────────────────────────────────────────────
+
+/// A planet reachable from another in a number of jumps.
+#[derive(SimpleObject)]
+pub struct PlanetJumpGql {
+    /// The reachable planet.
+    pub planet: PlanetGql,
+    /// Fewest jumps of at most 30 light years needed to reach it.
+    pub jumps: i32,
+    /// Straight-line distance from the origin in light years.
+    pub distance: f64,
+}
+
+/// Planets reachable from `origin` in at most `max_jumps` jumps of
+/// `JUMP_RANGE_LY`, by breadth-first search over `candidates`. Ordered by
+/// jumps, then distance; the origin is excluded.
+pub fn within_jumps(origin: &DbPlanet, candidates: Vec<DbPlanet>, max_jumps: i32) -> Vec<PlanetJumpGql> {
+    let distance = |a: &DbPlanet, b: &DbPlanet| ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+
+    let mut jumps: Vec<Option<i32>> = candidates
+        .iter()
+        .map(|p| (p.id == origin.id).then_some(0))
+        .collect();
+    let mut queue: VecDeque<usize> = jumps
+        .iter()
+        .position(|j| j.is_some())
+        .into_iter()
+        .collect();
+    while let Some(i) = queue.pop_front() {
+        let next = jumps[i].unwrap_or_default() + 1;
+        if next > max_jumps {
+            continue;
+        }
+        for j in 0..candidates.len() {
+            if jumps[j].is_none() && distance(&candidates[i], &candidates[j]) <= JUMP_RANGE_LY {
+                jumps[j] = Some(next);
+                queue.push_back(j);
+            }
+        }
+    }
+
+    let mut reachable: Vec<PlanetJumpGql> = candidates
+        .into_iter()
+        .zip(jumps)
+        .filter_map(|(p, j)| match j {
+            Some(j) if p.id != origin.id => Some(PlanetJumpGql {
+                distance: distance(origin, &p),
+                jumps: j,
+                planet: PlanetGql(p),
+            }),
+            _ => None,
+        })
+        .collect();
+    reachable.sort_by(|a, b| a.jumps.cmp(&b.jumps).then(a.distance.total_cmp(&b.distance)));
+    reachable
+}
@@ -0,0 +1,154 @@
+use async_graphql::{dataloader::DataLoader, Context, Object};
+
+use crate::{
+    db::models::{DbImportRun, DbUnitProvenance},
+    error::AppError,
+    graphql::loaders::ImportRunLoader,
+};
+
+async fn load_run(ctx: &Context<'_>, id: Option<i32>) -> Result<Option<ImportRunGql>, AppError> {
+    let Some(id) = id else {
+        return Ok(None);
+    };
+    let loader = ctx.data::<DataLoader<ImportRunLoader>>().unwrap();
+    let run = loader
+        .load_one(id)
+        .await
+        .map_err(|e| AppError::Internal(e.message))?;
+    Ok(run.map(ImportRunGql))
+}
+
+// ── Import Run ───────────────────────────────────────────────────────────────
+
+/// One scraper run that wrote unit or equipment data.
+pub struct ImportRunGql(pub DbImportRun);
+
+#[Object]
+impl ImportRunGql {
+    /// Internal import run ID.
+    async fn id(&self) -> i32 {
+        self.0.id
+    }
+
+    /// Data source. One of: megamek, mul, equipment_seed, forcegen, planets.
+    async fn source(&self) -> &str {
+        &self.0.source
+    }
+
+    /// Source version (MegaMek release for megamek, seed file name for equipment_seed, data file name for planets). Null if not recorded.
+    async fn version(&self) -> Option<&str> {
+        self.0.version.as_deref()
+    }
+
+    /// Run status. One of: running, succeeded, failed.
+    async fn status(&self) -> &str {
+        &self.0.status
+    }
+
+    /// Start time, RFC 3339.
+    async fn started_at(&self) -> String {
+        self.0.started_at.to_rfc3339()
+    }
+
+    /// Finish time, RFC 3339. Null while running or if the run was interrupted.
+    async fn finished_at(&self) -> Option<String> {
+        self.0.finished_at.map(|t| t.to_rfc3339())
+    }
+
+    /// Records read from the source (unit files, MUL records, seed entries...).
+    async fn processed(&self) -> i32 {
+        self.0.processed
+    }
+
+    /// Records written to the database.
+    async fn imported(&self) -> i32 {
+        self.0.imported
+    }
+
+    /// Records read but not written (unparseable files, unmatched MUL units...).
+    async fn skipped(&self) -> i32 {
+        self.0.skipped
+    }
+
+    /// Number of errors, including the one that failed the run.
+    async fn error_count(&self) -> i32 {
+        self.0.error_count
+    }
+
+    /// The first 100 error messages.
+    async fn error_messages(&self) -> &[String] {
+        &self.0.error_messages
+    }
+}
+
+// ── Unit Provenance ──────────────────────────────────────────────────────────
+
+/// Where a unit's data came from: the import runs that last wrote each part
+/// of it and the source of fields MUL can override.
+pub struct UnitProvenanceGql(pub DbUnitProvenance);
+
+#[Object]
+impl UnitProvenanceGql {
+    /// MegaMek import that last wrote the unit's core fields (name, tonnage, tech base, rules level).
+    async fn unit_run(&self, ctx: &Context<'_>) -> Result<Option<ImportRunGql>, AppError> {
+        load_run(ctx, self.0.import_run_id).await
+    }
+
+    /// MUL import that last wrote the MUL ID, BV, cost, role and Clan name. Null if never matched.
+    async fn mul_run(&self, ctx: &Context<'_>) -> Result<Option<ImportRunGql>, AppError> {
+        load_run(ctx, self.0.mul_import_run_id).await
+    }
+
+    /// Import that last wrote the armor and structure locations.
+    async fn locations_run(&self, ctx: &Context<'_>) -> Result<Option<ImportRunGql>, AppError> {
+        load_run(ctx, self.0.locations_run_id).await
+    }
+
+    /// Import that last wrote the loadout.
+    async fn loadout_run(&self, ctx: &Context<'_>) -> Result<Option<ImportRunGql>, AppError> {
+        load_run(ctx, self.0.loadout_run_id).await
+    }
+
+    /// Imports that wrote the faction/era availability rows (MUL and force generator).
+    async fn availability_runs(&self, ctx: &Context<'_>) -> Result<Vec<ImportRunGql>, AppError> {
+        let loader = ctx.data::<DataLoader<ImportRunLoader>>().unwrap();
+        let runs = loader
+            .load_many(self.0.availability_run_ids.iter().copied())
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        let mut runs: Vec<ImportRunGql> = runs.into_values().map(ImportRunGql).collect();
+        runs.sort_by_key(|r| r.0.id);
+        Ok(runs)
+    }
+
+    /// Source of the battle value: "mul", or null if computed or from MegaMek.
+    async fn bv_source(&self) -> Option<&str> {
+        self.0.bv_source.as_deref()
+    }
+
+    /// Source of the introduction year: "mul", or null if from the MegaMek file.
+    async fn intro_year_source(&self) -> Option<&str> {
+        self.0.intro_year_source.as_deref()
+    }
+}
+
+// ── Equipment Provenance ─────────────────────────────────────────────────────
+
+/// Where an equipment item's stats came from.
+pub struct EquipmentProvenanceGql {
+    pub stats_source: Option<String>,
+    pub stats_run_id: Option<i32>,
+}
+
+#[Object]
+impl EquipmentProvenanceGql {
+    /// Source of the latest stats update (e.g. "seed", "manual"). Null if stats are from the MegaMek import.
+    async fn stats_source(&self) -> Option<&str> {
+        self.stats_source.as_deref()
+    }
+
+    /// Equipment seed run that last wrote the stats. Null if never seeded.
+    async fn stats_run(&self, ctx: &Context<'_>) -> Result<Option<ImportRunGql>, AppError> {
+        load_run(ctx, self.stats_run_id).await
+    }
+}
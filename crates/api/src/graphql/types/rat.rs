@@ -0,0 +1,89 @@
+use async_graphql::{Enum, SimpleObject};
+
+use crate::graphql::types::unit::UnitGql;
+
+// ── Inputs ───────────────────────────────────────────────────────────────────
+
+/// Dice used to read a random assignment table.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum RatTableType {
+    /// Classic 2d6 table with one unit per result from 2 to 12.
+    TwoD6,
+    /// Percentile (d100) table with a roll range per unit.
+    Percentage,
+}
+
+/// Weight class by tonnage, using the ground unit brackets.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum WeightClass {
+    /// Under 40 tons.
+    Light,
+    /// 40 to under 60 tons.
+    Medium,
+    /// 60 to under 80 tons.
+    Heavy,
+    /// 80 to 100 tons.
+    Assault,
+    /// Over 100 tons.
+    SuperHeavy,
+}
+
+impl WeightClass {
+    /// Inclusive lower and exclusive upper tonnage bound.
+    pub fn tonnage_range(self) -> (f64, Option<f64>) {
+        match self {
+            Self::Light => (0.0, Some(40.0)),
+            Self::Medium => (40.0, Some(60.0)),
+            Self::Heavy => (60.0, Some(80.0)),
+            Self::Assault => (80.0, Some(100.5)),
+            Self::SuperHeavy => (100.5, None),
+        }
+    }
+}
+
+// ── Outputs ──────────────────────────────────────────────────────────────────
+
+/// One row of a random assignment table.
+#[derive(SimpleObject)]
+pub struct RatRowGql {
+    /// Roll as printed: "7" for 2d6, "01-23" for percentage tables.
+    pub roll: String,
+    /// Lowest roll selecting this row.
+    pub roll_low: i32,
+    /// Highest roll selecting this row.
+    pub roll_high: i32,
+    /// Chance of rolling this row.
+    pub probability: f64,
+    /// The unit's availability weight.
+    pub weight: f64,
+    /// The unit assigned to this row.
+    pub unit: UnitGql,
+}
+
+/// A weighted random assignment table for a faction, era and unit type.
+#[derive(SimpleObject)]
+pub struct RandomAssignmentTableGql {
+    /// Faction slug.
+    pub faction_slug: String,
+    /// Era slug.
+    pub era_slug: String,
+    /// Dice the table is read with.
+    pub table_type: RatTableType,
+    /// Number of units with a weighted rating that the table was built from.
+    pub unit_count: i32,
+    /// Sum of all unit weights.
+    pub total_weight: f64,
+    /// Table rows in roll order. Empty if no weighted availability is imported.
+    pub rows: Vec<RatRowGql>,
+}
+
+/// One unit drawn by `rollUnits`.
+#[derive(SimpleObject)]
+pub struct RolledUnitGql {
+    /// 1-based draw number.
+    pub draw: i32,
+    /// Chance of drawing this unit on any single draw.
+    pub probability: f64,
+    /// The unit drawn.
+    pub unit: UnitGql,
+}
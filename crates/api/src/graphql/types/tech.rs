@@ -0,0 +1,77 @@
+use async_graphql::SimpleObject;
+
+use crate::db::models::{DbTechDates, DbTechProgression, DbTechRatings};
+
+// ── Tech Progression ─────────────────────────────────────────────────────────
+
+/// When an item moved through the TechManual development stages in one tech
+/// base. All years are in-universe; null if the stage never happened or is
+/// unknown.
+#[derive(SimpleObject)]
+pub struct TechProgressionGql {
+    /// Technology base. One of: inner_sphere, clan, mixed, primitive. Null for components shared by all tech bases.
+    pub tech_base: Option<String>,
+    /// First prototype.
+    pub prototype: Option<i32>,
+    /// Start of production.
+    pub production: Option<i32>,
+    /// Common availability.
+    pub common: Option<i32>,
+    /// Year the item went extinct.
+    pub extinct: Option<i32>,
+    /// Year an extinct item was reintroduced.
+    pub reintroduced: Option<i32>,
+}
+
+impl From<DbTechProgression> for TechProgressionGql {
+    fn from(p: DbTechProgression) -> Self {
+        Self {
+            tech_base: Some(p.tech_base),
+            prototype: p.prototype_year,
+            production: p.production_year,
+            common: p.common_year,
+            extinct: p.extinction_year,
+            reintroduced: p.reintro_year,
+        }
+    }
+}
+
+/// Progression of a construction type in its own tech base. None if no
+/// stage year is known.
+pub fn construction_progression(tech_base: Option<&str>, d: &DbTechDates) -> Option<TechProgressionGql> {
+    let years = [d.prototype_year, d.production_year, d.common_year, d.extinction_year, d.reintro_year];
+    years.iter().any(Option::is_some).then(|| TechProgressionGql {
+        tech_base: tech_base.map(str::to_string),
+        prototype: d.prototype_year,
+        production: d.production_year,
+        common: d.common_year,
+        extinct: d.extinction_year,
+        reintroduced: d.reintro_year,
+    })
+}
+
+// ── Availability Ratings ─────────────────────────────────────────────────────
+
+/// TechManual availability ratings per era period: A (very common) to F
+/// (unique), or X (unavailable).
+#[derive(SimpleObject)]
+pub struct AvailabilityRatingsGql {
+    /// Star League era, up to 2780.
+    pub star_league: String,
+    /// Succession Wars, 2781-3049.
+    pub succession_wars: String,
+    /// Clan Invasion, 3050-3080.
+    pub clan_invasion: String,
+    /// Dark Age, 3081 on.
+    pub dark_age: String,
+}
+
+/// Availability ratings, or None unless all four periods are rated.
+pub fn availability_ratings(r: &DbTechRatings) -> Option<AvailabilityRatingsGql> {
+    Some(AvailabilityRatingsGql {
+        star_league: r.availability_sl.clone()?,
+        succession_wars: r.availability_sw.clone()?,
+        clan_invasion: r.availability_clan.clone()?,
+        dark_age: r.availability_da.clone()?,
+    })
+}
@@ -2,16 +2,24 @@ use async_graphql::{dataloader::DataLoader, Context, Object, SimpleObject, ID};
 use rust_decimal::prelude::ToPrimitive;
 
 use crate::{
-    db::models::{DbMechData, DbUnit, DbUnitChassis},
+    db::models::{
+        DbAeroData, DbAlphaStrike, DbInfantryData, DbLamData, DbLargeCraftData, DbMechData, DbProtoMechData, DbQuadVeeData, DbTransportBay, DbUnit,
+        DbUnitChassis, DbVehicleData,
+    },
     error::AppError,
     graphql::{
         loaders::{
-            ArmorTypeLoader, CockpitTypeLoader, EngineTypeLoader, GyroTypeLoader,
-            HeatsinkTypeLoader, MechDataLoader, MyomerTypeLoader, StructureTypeLoader,
+            AeroDataLoader, AlphaStrikeLoader, ArmorTypeLoader, CockpitTypeLoader, EngineTypeLoader, GyroTypeLoader,
+            HeatsinkTypeLoader, InfantryDataLoader, LamDataLoader, LargeCraftDataLoader, MechDataLoader, MyomerTypeLoader, OmniBaseLoader, ProtoMechDataLoader, QuadVeeDataLoader, StructureTypeLoader,
+            VehicleDataLoader,
         },
-        types::construction::{
-            ArmorTypeGql, CockpitTypeGql, EngineTypeGql, GyroTypeGql, HeatsinkTypeGql,
-            MyomerTypeGql, StructureTypeGql,
+        types::{
+            construction::{
+                ArmorTypeGql, CockpitTypeGql, EngineTypeGql, GyroTypeGql, HeatsinkTypeGql,
+                MyomerTypeGql, StructureTypeGql,
+            },
+            omni::OmniConfigurationGql,
+            provenance::UnitProvenanceGql,
         },
     },
     state::AppState,
@@ -34,16 +42,16 @@ pub struct QuirkGql {
 
 // ── Location ───────────────────────────────────────────────────────────────
 
-/// An armor/structure location on a unit (e.g. head, center_torso, left_arm).
+/// An armor/structure location on a unit (e.g. head, center_torso, left_arm for mechs; front, turret for vehicles; nose, left_wing for aerospace).
 #[derive(SimpleObject)]
 pub struct LocationGql {
-    /// Body location name in snake_case (e.g. "center_torso", "left_arm", "head").
+    /// Location name in snake_case (e.g. "center_torso", "left_arm", "front", "rotor", "nose", "aft_left_side").
     pub location: String,
     /// Front armor points at this location. Null if the location has no armor.
     pub armor_points: Option<i32>,
     /// Rear armor points at this location. Only applicable to torso locations; null otherwise.
     pub rear_armor: Option<i32>,
-    /// Internal structure points at this location. Null if not applicable.
+    /// Internal structure points at this location. Null if not applicable (aerospace units use structural integrity instead).
     pub structure_points: Option<i32>,
 }
 
@@ -62,10 +70,65 @@ pub struct LoadoutEntryGql {
     pub quantity: i32,
     /// True if the weapon is rear-facing (fires into the rear arc).
     pub is_rear_facing: bool,
+    /// True if pod-mounted on an OmniMech (changes between configurations); false for fixed equipment.
+    pub is_omnipod: bool,
     /// Additional notes about this loadout entry, if any.
     pub notes: Option<String>,
 }
 
+// ── Fluff ──────────────────────────────────────────────────────────────────
+
+/// Lore text for a unit variant, as written in its MegaMek file.
+#[derive(SimpleObject)]
+pub struct UnitFluffGql {
+    /// General description of the design.
+    pub overview: Option<String>,
+    /// Combat capabilities and armament.
+    pub capabilities: Option<String>,
+    /// Where and by whom the unit is fielded.
+    pub deployment: Option<String>,
+    /// Development and service history.
+    pub history: Option<String>,
+    /// Designer or rules notes.
+    pub notes: Option<String>,
+    /// Image file name referenced by MegaMek (e.g. "Atlas.png").
+    pub image_file: Option<String>,
+}
+
+/// A company that builds the unit or one of its components.
+#[derive(SimpleObject)]
+pub struct UnitManufacturerGql {
+    /// Lowercase, hyphen-separated manufacturer identifier (e.g. "defiance-industries").
+    pub slug: String,
+    /// Manufacturer or component brand name (e.g. "Defiance Industries", "Vlar 300").
+    pub name: String,
+    /// Primary factory location for the unit (e.g. "Hesperus II"). Null for components and when unknown.
+    pub factory: Option<String>,
+    /// Component supplied, lowercase (e.g. "chassis", "engine", "armor", "jumpjet", "communications", "targeting"). Null for the unit's own manufacturers.
+    pub system: Option<String>,
+}
+
+// ── Critical Slot ──────────────────────────────────────────────────────────
+
+/// One slot of a mech's critical hit table, in record-sheet order.
+#[derive(SimpleObject)]
+pub struct CriticalSlotGql {
+    /// Body location name in snake_case (e.g. "right_torso", "front_left_leg").
+    pub location: String,
+    /// 1-based slot number within the location (1–6 for head/legs, 1–12 for arms/torsos).
+    pub slot_index: i32,
+    /// Component name as written by MegaMek (e.g. "Medium Laser", "Fusion Engine", "Shoulder"). Null for an empty slot.
+    pub content: Option<String>,
+    /// Slug of the linked equipment item. Null for empty slots and fixed components (engine, gyro, actuators, structure/armor filler).
+    pub equipment_slug: Option<String>,
+    /// True if the slot is empty.
+    pub is_empty: bool,
+    /// True if the component is rear-mounted.
+    pub is_rear: bool,
+    /// True if the component is mounted in an OmniMech pod.
+    pub is_omnipod: bool,
+}
+
 // ── Unit Chassis ───────────────────────────────────────────────────────────
 
 pub struct UnitChassisGql(pub DbUnitChassis);
@@ -88,7 +151,7 @@ impl UnitChassisGql {
         &self.0.name
     }
 
-    /// Unit type category (e.g. "BattleMech", "Vehicle", "AeroSpaceFighter", "DropShip").
+    /// Unit type category. One of: mech, industrialmech, protomech, vehicle, fighter, small_craft, dropship, jumpship, warship, space_station, battle_armor, infantry, other.
     async fn unit_type(&self) -> &str {
         &self.0.unit_type
     }
@@ -140,7 +203,7 @@ pub struct MechDataGql(pub DbMechData);
 /// Mech-specific technical data: engine, movement, heat management, armor/structure type, and chassis configuration.
 #[Object]
 impl MechDataGql {
-    /// Chassis layout: "Biped", "Quad", "Tripod", or "LAM".
+    /// Chassis layout: "Biped", "Quad", "Tripod", "LAM" or "QuadVee".
     async fn config(&self) -> &str {
         &self.0.config
     }
@@ -150,6 +213,38 @@ impl MechDataGql {
         self.0.is_omnimech
     }
 
+    /// True if the mech mounts environmental sealing (IndustrialMechs need it to operate in vacuum or underwater).
+    async fn is_environmentally_sealed(&self) -> bool {
+        self.0.is_environmentally_sealed
+    }
+
+    /// Industrial equipment in the critical hit table, as named by MegaMek (e.g. "LiftHoist", "Backhoe", "ISCargo"). Empty for most BattleMechs.
+    async fn industrial_equipment(&self) -> &[String] {
+        &self.0.industrial_equipment
+    }
+
+    /// AirMech and fighter mode data for land-air mechs. Null unless config is "LAM".
+    #[graphql(complexity = 5)]
+    async fn lam_data(&self, ctx: &Context<'_>) -> Result<Option<LamDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<LamDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.unit_id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(LamDataGql))
+    }
+
+    /// Vehicle mode data for QuadVees. Null unless config is "QuadVee".
+    #[graphql(complexity = 5)]
+    async fn quad_vee_data(&self, ctx: &Context<'_>) -> Result<Option<QuadVeeDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<QuadVeeDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.unit_id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(QuadVeeDataGql))
+    }
+
     /// Engine power rating (e.g. 300 for a 100-ton mech with walk 3).
     async fn engine_rating(&self) -> Option<i32> {
         self.0.engine_rating
@@ -316,6 +411,719 @@ impl MechDataGql {
     }
 }
 
+// ── LAM Data ──────────────────────────────────────────────────────────────
+
+pub struct LamDataGql(pub DbLamData);
+
+/// Land-air mech modes. BattleMech-mode movement is on the parent mech data;
+/// AirMech and fighter movement derive from jump MP.
+#[Object]
+impl LamDataGql {
+    /// "standard" (BattleMech, AirMech and fighter modes) or "bimodal" (no AirMech mode).
+    async fn lam_type(&self) -> &str {
+        &self.0.lam_type
+    }
+
+    /// True if the LAM can convert to AirMech mode (standard LAMs only).
+    async fn has_airmech_mode(&self) -> bool {
+        self.0.lam_type == "standard"
+    }
+
+    /// Fighter-mode fuel points. Null if the source file does not record them.
+    async fn fuel(&self) -> Option<i32> {
+        self.0.fuel
+    }
+
+    /// AirMech flight cruising MP (three times jump MP). Null for bimodal LAMs.
+    async fn airmech_cruise_mp(&self) -> Option<i32> {
+        self.0.airmech_cruise_mp
+    }
+
+    /// AirMech flight flanking MP (1.5 × cruise, rounded up). Null for bimodal LAMs.
+    async fn airmech_flank_mp(&self) -> Option<i32> {
+        self.0.airmech_flank_mp
+    }
+
+    /// Fighter-mode safe thrust (equal to jump MP).
+    async fn fighter_safe_thrust(&self) -> Option<i32> {
+        self.0.fighter_safe_thrust
+    }
+
+    /// Fighter-mode maximum thrust (1.5 × safe thrust, rounded up).
+    async fn fighter_max_thrust(&self) -> Option<i32> {
+        self.0.fighter_max_thrust
+    }
+
+    /// Critical slots taken by landing gear (conversion equipment).
+    async fn landing_gear_slots(&self) -> i32 {
+        self.0.landing_gear_slots
+    }
+
+    /// Critical slots taken by avionics (conversion equipment).
+    async fn avionics_slots(&self) -> i32 {
+        self.0.avionics_slots
+    }
+}
+
+// ── QuadVee Data ──────────────────────────────────────────────────────────
+
+pub struct QuadVeeDataGql(pub DbQuadVeeData);
+
+/// QuadVee vehicle mode: motive system and conversion equipment.
+#[Object]
+impl QuadVeeDataGql {
+    /// Vehicle-mode motive type: "tracked" or "wheeled".
+    async fn motive_type(&self) -> &str {
+        &self.0.motive_type
+    }
+
+    /// Critical slots taken by conversion gear.
+    async fn conversion_slots(&self) -> i32 {
+        self.0.conversion_slots
+    }
+
+    /// Critical slots taken by tracks or wheels in the legs.
+    async fn motive_slots(&self) -> i32 {
+        self.0.motive_slots
+    }
+}
+
+// ── ProtoMech Data ────────────────────────────────────────────────────────
+
+pub struct ProtoMechDataGql(pub DbProtoMechData);
+
+/// ProtoMech-specific data: configuration, movement, main gun and cockpit.
+#[Object]
+impl ProtoMechDataGql {
+    /// Configuration: "biped", "quad" or "glider".
+    async fn config(&self) -> &str {
+        &self.0.config
+    }
+
+    /// True for ultraheavy ProtoMechs (10-15 tons).
+    async fn is_ultraheavy(&self) -> bool {
+        self.0.is_ultraheavy
+    }
+
+    /// Walking movement points per turn.
+    async fn walk_mp(&self) -> Option<i32> {
+        self.0.walk_mp
+    }
+
+    /// Running movement points per turn (walk * 1.5, rounded up).
+    async fn run_mp(&self) -> Option<i32> {
+        self.0.walk_mp.map(|w| ((w as f64) * 1.5).ceil() as i32)
+    }
+
+    /// Jumping movement points per turn. Null or 0 for non-jumpers.
+    async fn jump_mp(&self) -> Option<i32> {
+        self.0.jump_mp
+    }
+
+    /// True if the ProtoMech has a main gun location.
+    async fn has_main_gun(&self) -> bool {
+        self.0.has_main_gun
+    }
+
+    /// Armor type (e.g. "Standard"). Null when the unit file does not specify one.
+    async fn armor_type(&self) -> Option<&str> {
+        self.0.armor_type.as_deref()
+    }
+
+    /// True if the ProtoMech has an interface cockpit for a non-ProtoMech pilot.
+    async fn has_interface_cockpit(&self) -> bool {
+        self.0.has_interface_cockpit
+    }
+}
+
+// ── Vehicle Data ──────────────────────────────────────────────────────────
+
+pub struct VehicleDataGql(pub DbVehicleData);
+
+/// Vehicle-specific technical data: motive system, movement, engine, armor/structure type, and turret configuration.
+#[Object]
+impl VehicleDataGql {
+    /// Motive system in lowercase: "tracked", "wheeled", "hover", "vtol", "naval", "hydrofoil", "submarine", "wige", "rail", "maglev".
+    async fn motion_type(&self) -> &str {
+        &self.0.motion_type
+    }
+
+    /// Cruising movement points per turn.
+    async fn cruise_mp(&self) -> Option<i32> {
+        self.0.cruise_mp
+    }
+
+    /// Flank movement points per turn (cruise * 1.5, rounded up).
+    async fn flank_mp(&self) -> Option<i32> {
+        self.0.cruise_mp.map(|c| ((c as f64) * 1.5).ceil() as i32)
+    }
+
+    /// Engine type (e.g. "Fusion", "ICE", "XL", "Fuel Cell").
+    async fn engine_type(&self) -> Option<&str> {
+        self.0.engine_type.as_deref()
+    }
+
+    /// Armor type (e.g. "Standard", "Ferro-Fibrous").
+    async fn armor_type(&self) -> Option<&str> {
+        self.0.armor_type.as_deref()
+    }
+
+    /// Internal structure type (e.g. "Standard", "Endo Steel").
+    async fn structure_type(&self) -> Option<&str> {
+        self.0.structure_type.as_deref()
+    }
+
+    /// Turret configuration: "none", "single", or "dual".
+    async fn turret_type(&self) -> &str {
+        &self.0.turret_type
+    }
+
+    /// Fuel capacity where the unit file specifies one (mostly support vehicles). Null otherwise.
+    async fn fuel(&self) -> Option<f64> {
+        self.0.fuel.and_then(|f| f.to_f64())
+    }
+}
+
+// ── Infantry Data ─────────────────────────────────────────────────────────
+
+pub struct InfantryDataGql(pub DbInfantryData);
+
+/// Battle armor and conventional infantry data: trooper count, movement, armor, manipulators and infantry weapons.
+#[Object]
+impl InfantryDataGql {
+    /// "battle_armor" or "conventional".
+    async fn kind(&self) -> &str {
+        &self.0.infantry_kind
+    }
+
+    /// Troopers in the unit: per squad or point for battle armor, per platoon for conventional infantry.
+    async fn trooper_count(&self) -> i32 {
+        self.0.trooper_count
+    }
+
+    /// Troopers per squad. Conventional infantry only.
+    async fn squad_size(&self) -> Option<i32> {
+        self.0.squad_size
+    }
+
+    /// Squads per platoon. Conventional infantry only.
+    async fn squad_count(&self) -> Option<i32> {
+        self.0.squad_count
+    }
+
+    /// Battle armor weight class: "PA(L)", "Light", "Medium", "Heavy" or "Assault". Null for conventional infantry.
+    async fn weight_class(&self) -> Option<&str> {
+        self.0.weight_class.as_deref()
+    }
+
+    /// Battle armor chassis: "biped" or "quad". Null for conventional infantry.
+    async fn chassis_type(&self) -> Option<&str> {
+        self.0.chassis_type.as_deref()
+    }
+
+    /// Motive type in lowercase (e.g. "leg", "jump", "umu", "vtol", "motorized", "wheeled", "tracked", "hover").
+    async fn motive_type(&self) -> &str {
+        &self.0.motive_type
+    }
+
+    /// Ground movement points.
+    async fn ground_mp(&self) -> Option<i32> {
+        self.0.ground_mp
+    }
+
+    /// Jump movement points. Null or 0 for units that cannot jump.
+    async fn jump_mp(&self) -> Option<i32> {
+        self.0.jump_mp
+    }
+
+    /// Underwater (UMU) movement points. Battle armor only.
+    async fn umu_mp(&self) -> Option<i32> {
+        self.0.umu_mp
+    }
+
+    /// VTOL movement points. Battle armor and VTOL infantry only.
+    async fn vtol_mp(&self) -> Option<i32> {
+        self.0.vtol_mp
+    }
+
+    /// Armor points per trooper, not counting the trooper's own point. Battle armor only.
+    async fn armor_per_trooper(&self) -> Option<i32> {
+        self.0.armor_per_trooper
+    }
+
+    /// Battle armor type (e.g. "BA Standard", "BA Stealth"). Battle armor only.
+    async fn armor_type(&self) -> Option<&str> {
+        self.0.armor_type.as_deref()
+    }
+
+    /// Left arm manipulator (e.g. "Battle Claw", "Basic Manipulator"). Battle armor only.
+    async fn left_manipulator(&self) -> Option<&str> {
+        self.0.left_manipulator.as_deref()
+    }
+
+    /// Right arm manipulator. Battle armor only.
+    async fn right_manipulator(&self) -> Option<&str> {
+        self.0.right_manipulator.as_deref()
+    }
+
+    /// Whether the unit can make anti-'Mech leg and swarm attacks.
+    async fn is_anti_mech(&self) -> bool {
+        self.0.is_anti_mech
+    }
+
+    /// Primary infantry weapon (MegaMek name). Conventional infantry only.
+    async fn primary_weapon(&self) -> Option<&str> {
+        self.0.primary_weapon.as_deref()
+    }
+
+    /// Secondary infantry weapon (MegaMek name). Conventional infantry only.
+    async fn secondary_weapon(&self) -> Option<&str> {
+        self.0.secondary_weapon.as_deref()
+    }
+
+    /// Secondary weapons carried per squad. Conventional infantry only.
+    async fn secondary_count(&self) -> Option<i32> {
+        self.0.secondary_count
+    }
+
+    /// Armor kit name (e.g. "Flak", "Sneak Suit (Camo)"). Conventional infantry only.
+    async fn armor_kit(&self) -> Option<&str> {
+        self.0.armor_kit.as_deref()
+    }
+}
+
+// ── Aero Data ─────────────────────────────────────────────────────────────
+
+pub struct AeroDataGql(pub DbAeroData);
+
+/// Aerospace technical data: thrust, structural integrity, fuel, heat management, and weapon bays.
+#[Object]
+impl AeroDataGql {
+    /// Safe thrust points per turn.
+    async fn safe_thrust(&self) -> Option<i32> {
+        self.0.safe_thrust
+    }
+
+    /// Maximum thrust points per turn (safe thrust * 1.5, rounded up).
+    async fn max_thrust(&self) -> Option<i32> {
+        self.0.safe_thrust.map(|t| ((t as f64) * 1.5).ceil() as i32)
+    }
+
+    /// Structural integrity. Taken from the unit file for large craft; derived for fighters as max(10% of tonnage, safe thrust).
+    async fn structural_integrity(&self) -> Option<i32> {
+        self.0.structural_integrity
+    }
+
+    /// Fuel capacity in fuel points.
+    async fn fuel_points(&self) -> Option<i32> {
+        self.0.fuel_points
+    }
+
+    /// Total number of heat sinks.
+    async fn heat_sink_count(&self) -> Option<i32> {
+        self.0.heat_sink_count
+    }
+
+    /// Heat sink type: "Single" or "Double".
+    async fn heat_sink_type(&self) -> Option<&str> {
+        self.0.heat_sink_type.as_deref()
+    }
+
+    /// Cockpit type: "Standard", "Small", "Command Console", or "Primitive".
+    async fn cockpit_type(&self) -> Option<&str> {
+        self.0.cockpit_type.as_deref()
+    }
+
+    /// Engine type (e.g. "Fusion", "XL", "ICE").
+    async fn engine_type(&self) -> Option<&str> {
+        self.0.engine_type.as_deref()
+    }
+
+    /// Armor type (e.g. "Standard", "Ferro-Fibrous").
+    async fn armor_type(&self) -> Option<&str> {
+        self.0.armor_type.as_deref()
+    }
+
+    /// Weapon bays by firing arc, ordered by arc then bay number. Large craft use the bays from their unit file; for fighters each weapon type in an arc is reported as one group.
+    #[graphql(complexity = 5)]
+    async fn weapon_bays(&self, ctx: &Context<'_>) -> Result<Vec<WeaponBayGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        load_weapon_bays(&state.pool, self.0.unit_id).await
+    }
+}
+
+/// Weapon bay rows for a unit, grouped into bays.
+async fn load_weapon_bays(pool: &sqlx::PgPool, unit_id: i32) -> Result<Vec<WeaponBayGql>, AppError> {
+    let rows = crate::db::units::get_aero_weapon_bays(pool, unit_id).await?;
+    let mut bays: Vec<WeaponBayGql> = Vec::new();
+    for r in rows {
+        let weapon = BayWeaponGql {
+            equipment_slug: r.equipment_slug,
+            equipment_name: r.equipment_name,
+            quantity: r.quantity,
+        };
+        match bays.last_mut() {
+            Some(b) if b.location == r.location && b.bay_number == r.bay_number as i32 => {
+                b.weapons.push(weapon)
+            }
+            _ => bays.push(WeaponBayGql {
+                location: r.location,
+                bay_number: r.bay_number as i32,
+                weapons: vec![weapon],
+            }),
+        }
+    }
+    Ok(bays)
+}
+
+/// A group of weapons in one firing arc that fire together.
+#[derive(SimpleObject)]
+pub struct WeaponBayGql {
+    /// Firing arc in snake_case (e.g. "nose", "left_wing", "aft", "front_left_side").
+    pub location: String,
+    /// 1-based bay number within the arc.
+    pub bay_number: i32,
+    /// Weapons mounted in this bay.
+    pub weapons: Vec<BayWeaponGql>,
+}
+
+/// A weapon type and count within a weapon bay.
+#[derive(SimpleObject)]
+pub struct BayWeaponGql {
+    /// Lowercase, hyphen-separated identifier of the weapon (e.g. "large-laser").
+    pub equipment_slug: String,
+    /// Human-readable name of the weapon.
+    pub equipment_name: String,
+    /// Number of this weapon in the bay.
+    pub quantity: i32,
+}
+
+// ── Large Craft Data ──────────────────────────────────────────────────────
+
+pub struct LargeCraftDataGql(pub DbLargeCraftData);
+
+/// Small craft, DropShip, JumpShip, WarShip and space station data: crew, transport bays, docking collars and jump drive.
+#[Object]
+impl LargeCraftDataGql {
+    /// Craft type: "small_craft", "dropship", "jumpship", "warship" or "space_station".
+    async fn craft_type(&self) -> &str {
+        &self.0.craft_type
+    }
+
+    /// Hull design: "aerodyne" or "spheroid". Small craft and DropShips only.
+    async fn design_type(&self) -> Option<&str> {
+        self.0.design_type.as_deref()
+    }
+
+    /// Crew, not counting officers and gunners.
+    async fn crew(&self) -> Option<i32> {
+        self.0.crew
+    }
+
+    /// Officers.
+    async fn officers(&self) -> Option<i32> {
+        self.0.officers
+    }
+
+    /// Gunners.
+    async fn gunners(&self) -> Option<i32> {
+        self.0.gunners
+    }
+
+    /// Passenger berths.
+    async fn passengers(&self) -> Option<i32> {
+        self.0.passengers
+    }
+
+    /// Marines carried.
+    async fn marines(&self) -> Option<i32> {
+        self.0.marines
+    }
+
+    /// Battle armor troopers carried as marines.
+    async fn battle_armor_troops(&self) -> Option<i32> {
+        self.0.battle_armor_troops
+    }
+
+    /// Other passengers, such as bay personnel.
+    async fn other_passengers(&self) -> Option<i32> {
+        self.0.other_passengers
+    }
+
+    /// Life boats.
+    async fn life_boats(&self) -> Option<i32> {
+        self.0.life_boats
+    }
+
+    /// Escape pods.
+    async fn escape_pods(&self) -> Option<i32> {
+        self.0.escape_pods
+    }
+
+    /// DropShip docking collar: "standard", "prototype" or "no_boom". Null for other craft.
+    async fn collar_type(&self) -> Option<&str> {
+        self.0.collar_type.as_deref()
+    }
+
+    /// Docking collars for carrying DropShips. JumpShips, WarShips and space stations.
+    async fn docking_collars(&self) -> i32 {
+        self.0.docking_collars
+    }
+
+    /// Whether the craft has a working Kearny-Fuchida jump drive.
+    async fn has_kf_drive(&self) -> bool {
+        self.0.has_kf_drive
+    }
+
+    /// K-F drive core: "standard", "compact", "subcompact", "primitive" or "none". JumpShips and WarShips only.
+    async fn drive_core_type(&self) -> Option<&str> {
+        self.0.drive_core_type.as_deref()
+    }
+
+    /// Maximum jump distance in light years, where the unit file limits it (primitive JumpShips). Null otherwise.
+    async fn jump_range(&self) -> Option<i32> {
+        self.0.jump_range
+    }
+
+    /// Whether the craft has lithium-fusion batteries for a second jump.
+    async fn has_lithium_fusion(&self) -> bool {
+        self.0.has_lithium_fusion
+    }
+
+    /// Whether the craft has a jump sail for recharging the drive.
+    async fn has_jump_sail(&self) -> bool {
+        self.0.has_jump_sail
+    }
+
+    /// Whether the craft carries a hyperpulse generator.
+    async fn has_hpg(&self) -> bool {
+        self.0.has_hpg
+    }
+
+    /// Number of gravity decks.
+    async fn grav_decks(&self) -> i32 {
+        self.0.grav_decks
+    }
+
+    /// Transport bays in unit file order.
+    #[graphql(complexity = 5)]
+    async fn transport_bays(&self, ctx: &Context<'_>) -> Result<Vec<TransportBayGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = crate::db::units::get_transport_bays(&state.pool, self.0.unit_id).await?;
+        Ok(rows.into_iter().map(TransportBayGql).collect())
+    }
+
+    /// Totals of what the transport bays can carry, by unit type.
+    #[graphql(complexity = 5)]
+    async fn capacity(&self, ctx: &Context<'_>) -> Result<TransportCapacityGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = crate::db::units::get_transport_bays(&state.pool, self.0.unit_id).await?;
+        let mut cap = TransportCapacityGql::default();
+        for bay in rows {
+            let size = bay.size.to_f64().unwrap_or(0.0);
+            let count = size.round() as i32;
+            match bay.bay_type.as_str() {
+                "mech" => cap.mechs += count,
+                "protomech" => cap.protomechs += count,
+                "light_vehicle" => cap.light_vehicles += count,
+                "heavy_vehicle" => cap.heavy_vehicles += count,
+                "super_heavy_vehicle" => cap.super_heavy_vehicles += count,
+                "asf" => cap.fighters += count,
+                "small_craft" => cap.small_craft += count,
+                "battle_armor" => cap.battle_armor_squads += count,
+                "infantry" => cap.infantry_platoons += count,
+                t if t.ends_with("cargo") => cap.cargo_tons += size,
+                _ => {}
+            }
+        }
+        Ok(cap)
+    }
+
+    /// Weapon bays by firing arc, ordered by arc then bay number. Same as `aeroData.weaponBays`.
+    #[graphql(complexity = 5)]
+    async fn weapon_bays(&self, ctx: &Context<'_>) -> Result<Vec<WeaponBayGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        load_weapon_bays(&state.pool, self.0.unit_id).await
+    }
+}
+
+pub struct TransportBayGql(pub DbTransportBay);
+
+/// A transport bay, cargo hold or quarters on a large craft.
+#[Object]
+impl TransportBayGql {
+    /// Bay number from the unit file. Null if the file does not number its bays.
+    async fn bay_number(&self) -> Option<i32> {
+        self.0.bay_number.map(i32::from)
+    }
+
+    /// Bay type in snake_case (e.g. "mech", "asf", "small_craft", "heavy_vehicle", "battle_armor", "infantry", "cargo", "liquid_cargo", "crew_quarters").
+    async fn bay_type(&self) -> &str {
+        &self.0.bay_type
+    }
+
+    /// Capacity, in the unit given by `sizeUnit`.
+    async fn size(&self) -> f64 {
+        self.0.size.to_f64().unwrap_or(0.0)
+    }
+
+    /// Unit of `size`: "units" for 'Mech, ProtoMech, vehicle, fighter and small craft bays, "squads" for battle armor, "platoons" for infantry, "tons" for cargo bays and quarters.
+    async fn size_unit(&self) -> &str {
+        match self.0.bay_type.as_str() {
+            "battle_armor" => "squads",
+            "infantry" => "platoons",
+            t if t.ends_with("cargo") || t.ends_with("quarters") => "tons",
+            _ => "units",
+        }
+    }
+
+    /// Number of bay doors.
+    async fn doors(&self) -> i32 {
+        self.0.doors as i32
+    }
+}
+
+/// What a large craft can carry, summed over its transport bays.
+#[derive(SimpleObject, Default)]
+pub struct TransportCapacityGql {
+    /// 'Mechs (including IndustrialMechs and LAMs).
+    pub mechs: i32,
+    /// ProtoMechs.
+    pub protomechs: i32,
+    /// Light vehicles (up to 50 tons).
+    pub light_vehicles: i32,
+    /// Heavy vehicles (up to 100 tons).
+    pub heavy_vehicles: i32,
+    /// Super-heavy vehicles (up to 200 tons).
+    pub super_heavy_vehicles: i32,
+    /// Aerospace and conventional fighters.
+    pub fighters: i32,
+    /// Small craft.
+    pub small_craft: i32,
+    /// Battle armor squads or points.
+    pub battle_armor_squads: i32,
+    /// Infantry platoons.
+    pub infantry_platoons: i32,
+    /// Cargo space in tons across all cargo bay types.
+    pub cargo_tons: f64,
+}
+
+// ── Alpha Strike ──────────────────────────────────────────────────────────
+
+pub struct AlphaStrikeGql(pub DbAlphaStrike);
+
+/// Card text for one damage value: "0*" for minimal damage, "-" if unknown.
+fn as_damage(value: Option<i16>, minimal: bool) -> String {
+    match value {
+        _ if minimal => "0*".to_string(),
+        Some(v) => v.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+/// Alpha Strike statistics: the unit's card values for the abstract, fast-play ruleset.
+#[Object]
+impl AlphaStrikeGql {
+    /// Alpha Strike unit type (e.g. "BM" BattleMech, "CV" combat vehicle, "AF" aerospace fighter).
+    async fn unit_type(&self) -> Option<&str> {
+        self.0.as_type.as_deref()
+    }
+
+    /// Size class from 1 (light) to 4 (assault); larger for large craft.
+    async fn size(&self) -> Option<i32> {
+        self.0.size.map(i32::from)
+    }
+
+    /// Movement as printed on the card (e.g. 10"j, 8"/6"j, 10"t).
+    async fn movement(&self) -> Option<&str> {
+        self.0.movement.as_deref()
+    }
+
+    /// Primary movement in inches (thrust for aerospace).
+    async fn move_inches(&self) -> Option<i32> {
+        self.0.move_inches.map(i32::from)
+    }
+
+    /// Jump movement in inches. Null if the unit cannot jump.
+    async fn jump_inches(&self) -> Option<i32> {
+        self.0.jump_inches.map(i32::from)
+    }
+
+    /// Target movement modifier.
+    async fn tmm(&self) -> Option<i32> {
+        self.0.tmm.map(i32::from)
+    }
+
+    /// Armor value.
+    async fn armor(&self) -> Option<i32> {
+        self.0.armor.map(i32::from)
+    }
+
+    /// Structure value.
+    async fn structure(&self) -> Option<i32> {
+        self.0.structure.map(i32::from)
+    }
+
+    /// Damage threshold (aerospace units only).
+    async fn threshold(&self) -> Option<i32> {
+        self.0.threshold.map(i32::from)
+    }
+
+    /// Damage at short range (0-6").
+    async fn damage_short(&self) -> Option<i32> {
+        self.0.damage_short.map(i32::from)
+    }
+
+    /// Damage at medium range (6-24").
+    async fn damage_medium(&self) -> Option<i32> {
+        self.0.damage_medium.map(i32::from)
+    }
+
+    /// Damage at long range (24-42").
+    async fn damage_long(&self) -> Option<i32> {
+        self.0.damage_long.map(i32::from)
+    }
+
+    /// Damage at extreme range (aerospace units only).
+    async fn damage_extreme(&self) -> Option<i32> {
+        self.0.damage_extreme.map(i32::from)
+    }
+
+    /// Damage as printed on the card, e.g. "3/3/1" or "2/2/0*" ("0*" is minimal damage). Aerospace units include a fourth, extreme-range value.
+    async fn damage(&self) -> String {
+        let d = &self.0;
+        let mut parts = vec![
+            as_damage(d.damage_short, d.damage_short_min),
+            as_damage(d.damage_medium, d.damage_medium_min),
+            as_damage(d.damage_long, d.damage_long_min),
+        ];
+        if d.damage_extreme.is_some() || d.damage_extreme_min {
+            parts.push(as_damage(d.damage_extreme, d.damage_extreme_min));
+        }
+        parts.join("/")
+    }
+
+    /// Overheat value (OV).
+    async fn overheat(&self) -> i32 {
+        self.0.overheat as i32
+    }
+
+    /// Point Value (PV). Null for converted stats, which do not derive PV.
+    async fn point_value(&self) -> Option<i32> {
+        self.0.point_value
+    }
+
+    /// Special abilities as printed on the card (e.g. "CASE", "IF1", "LRM1/1/1", "REAR1/1/-").
+    async fn specials(&self) -> &[String] {
+        &self.0.specials
+    }
+
+    /// Where the stats come from: "mul" (official Master Unit List card) or "conversion" (derived from construction data).
+    async fn source(&self) -> &str {
+        &self.0.source
+    }
+}
+
 // ── Unit ───────────────────────────────────────────────────────────────────
 
 pub struct UnitGql(pub DbUnit);
@@ -383,6 +1191,36 @@ impl UnitGql {
         self.0.reintro_year
     }
 
+    /// Whether the variant is in service in the given year (introduced and not extinct, or reintroduced) and, with a faction, available to it in the era covering that year. False if the introduction year is unknown.
+    #[graphql(complexity = 3)]
+    async fn is_available_in(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "In-universe year (e.g. 3052).")] year: i32,
+        #[graphql(desc = "Faction slug (e.g. \"marik\"). Omit to check only introduction and extinction.")] faction_slug: Option<String>,
+        #[graphql(desc = "With factionSlug, also count availability inherited from the faction's parents that year. Default false.")] include_parent_factions: Option<bool>,
+    ) -> Result<bool, AppError> {
+        let u = &self.0;
+        let in_service = u.intro_year.is_some_and(|intro| intro <= year)
+            && (u.extinction_year.is_none_or(|ext| ext > year)
+                || u.reintro_year.is_some_and(|re| re <= year));
+        let Some(faction) = faction_slug.as_deref().filter(|_| in_service) else {
+            return Ok(in_service);
+        };
+        let state = ctx.data::<AppState>().unwrap();
+        if crate::db::factions::get_by_slug(&state.pool, faction).await?.is_none() {
+            return Err(AppError::Validation(format!("unknown faction '{faction}'")));
+        }
+        crate::db::units::is_available_in(
+            &state.pool,
+            u.id,
+            year,
+            Some(faction),
+            include_parent_factions.unwrap_or(false),
+        )
+        .await
+    }
+
     /// Source book or technical readout where this unit is published.
     async fn source_book(&self) -> Option<&str> {
         self.0.source_book.as_deref()
@@ -452,11 +1290,72 @@ impl UnitGql {
                 location: e.location,
                 quantity: e.quantity,
                 is_rear_facing: e.is_rear_facing,
+                is_omnipod: e.is_omnipod,
                 notes: e.notes,
             })
             .collect())
     }
 
+    /// Full critical hit table, ordered by location then slot. Includes empty and fixed slots. Empty for non-mech units.
+    #[graphql(complexity = 10)]
+    async fn critical_slots(&self, ctx: &Context<'_>) -> Result<Vec<CriticalSlotGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = crate::db::units::get_critical_slots(&state.pool, self.0.id).await?;
+        Ok(rows
+            .into_iter()
+            .map(|c| CriticalSlotGql {
+                location: c.location,
+                slot_index: c.slot_index as i32,
+                is_empty: c.content.is_none(),
+                content: c.content,
+                equipment_slug: c.equipment_slug,
+                is_rear: c.is_rear,
+                is_omnipod: c.is_omnipod,
+            })
+            .collect())
+    }
+
+    /// Lore text: overview, capabilities, deployment and history. Null if the source file has none.
+    #[graphql(complexity = 3)]
+    async fn fluff(&self, ctx: &Context<'_>) -> Result<Option<UnitFluffGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let row = crate::db::units::get_fluff(&state.pool, self.0.id).await?;
+        Ok(row.map(|f| UnitFluffGql {
+            overview: f.overview,
+            capabilities: f.capabilities,
+            deployment: f.deployment,
+            history: f.history,
+            notes: f.notes,
+            image_file: f.image_file,
+        }))
+    }
+
+    /// Manufacturers with their primary factories, followed by component manufacturers (`system` set).
+    #[graphql(complexity = 3)]
+    async fn manufacturers(&self, ctx: &Context<'_>) -> Result<Vec<UnitManufacturerGql>, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let rows = crate::db::units::get_manufacturers(&state.pool, self.0.id).await?;
+        Ok(rows
+            .into_iter()
+            .map(|m| UnitManufacturerGql {
+                slug: m.slug,
+                name: m.name,
+                factory: m.factory,
+                system: m.system,
+            })
+            .collect())
+    }
+
+    /// Where this unit's data came from: the import runs that last wrote it and per-field sources.
+    #[graphql(complexity = 5)]
+    async fn provenance(&self, ctx: &Context<'_>) -> Result<UnitProvenanceGql, AppError> {
+        let state = ctx.data::<AppState>().unwrap();
+        let row = crate::db::provenance::get_unit_provenance(&state.pool, self.0.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("unit '{}' not found", self.0.slug)))?;
+        Ok(UnitProvenanceGql(row))
+    }
+
     /// Positive and negative quirks unique to this unit variant.
     #[graphql(complexity = 3)]
     async fn quirks(&self, ctx: &Context<'_>) -> Result<Vec<QuirkGql>, AppError> {
@@ -480,7 +1379,7 @@ impl UnitGql {
         let rows = sqlx::query!(
             r#"SELECT f.slug AS faction_slug, f.name AS faction_name,
                       e.slug AS era_slug, e.name AS era_name,
-                      ua.availability_code, ua.notes
+                      ua.availability_code, ua.weight, ua.notes
                FROM unit_availability ua
                JOIN factions f ON f.id = ua.faction_id
                JOIN eras e ON e.id = ua.era_id
@@ -499,6 +1398,7 @@ impl UnitGql {
                 era_slug: r.era_slug,
                 era_name: r.era_name,
                 availability_code: r.availability_code,
+                weight: r.weight,
                 notes: r.notes,
             })
             .collect())
@@ -515,9 +1415,85 @@ impl UnitGql {
         Ok(data.map(MechDataGql))
     }
 
-    /// Reserved for future vehicle-specific data (motive type, turret, etc.).
-    async fn vehicle_data(&self) -> Option<bool> {
-        None
+    /// OmniMech configuration split into base chassis and pod-mounted equipment. Null for units that are not OmniMechs.
+    #[graphql(complexity = 5)]
+    async fn omni_configuration(&self, ctx: &Context<'_>) -> Result<Option<OmniConfigurationGql>, AppError> {
+        let loader = ctx.data::<DataLoader<OmniBaseLoader>>().unwrap();
+        let base = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(base.map(|base| OmniConfigurationGql {
+            unit_id: self.0.id,
+            name: self.0.variant.clone(),
+            base,
+        }))
+    }
+
+    /// ProtoMech-specific data. Null for other unit types.
+    #[graphql(complexity = 5)]
+    async fn protomech_data(&self, ctx: &Context<'_>) -> Result<Option<ProtoMechDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<ProtoMechDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(ProtoMechDataGql))
+    }
+
+    /// Vehicle-specific technical data. Null for non-vehicle units (mechs, aerospace, etc.).
+    #[graphql(complexity = 5)]
+    async fn vehicle_data(&self, ctx: &Context<'_>) -> Result<Option<VehicleDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<VehicleDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(VehicleDataGql))
+    }
+
+    /// Battle armor and conventional infantry data. Null for other unit types.
+    #[graphql(complexity = 5)]
+    async fn infantry_data(&self, ctx: &Context<'_>) -> Result<Option<InfantryDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<InfantryDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(InfantryDataGql))
+    }
+
+    /// Large craft data: crew, transport bays, docking collars and jump drive. Null for units other than small craft, DropShips, JumpShips, WarShips and space stations.
+    #[graphql(complexity = 5)]
+    async fn large_craft_data(&self, ctx: &Context<'_>) -> Result<Option<LargeCraftDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<LargeCraftDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(LargeCraftDataGql))
+    }
+
+    /// Aerospace technical data. Null for non-aerospace units (mechs, vehicles, etc.).
+    #[graphql(complexity = 5)]
+    async fn aero_data(&self, ctx: &Context<'_>) -> Result<Option<AeroDataGql>, AppError> {
+        let loader = ctx.data::<DataLoader<AeroDataLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(AeroDataGql))
+    }
+
+    /// Alpha Strike card. Official MUL values where available, otherwise a conversion from construction data. Null if neither exists.
+    #[graphql(complexity = 5)]
+    async fn alpha_strike(&self, ctx: &Context<'_>) -> Result<Option<AlphaStrikeGql>, AppError> {
+        let loader = ctx.data::<DataLoader<AlphaStrikeLoader>>().unwrap();
+        let data = loader
+            .load_one(self.0.id)
+            .await
+            .map_err(|e| AppError::Internal(e.message))?;
+        Ok(data.map(AlphaStrikeGql))
     }
 }
 
@@ -532,8 +1508,10 @@ pub struct AvailabilityGql {
     pub era_slug: String,
     /// Human-readable era name.
     pub era_name: String,
-    /// MegaMek availability rating code (e.g. "A", "B", "F"). Null if unrated.
+    /// MegaMek force generator availability rating, from "0" (very rare) to "10" (very common). Null if unrated.
     pub availability_code: Option<String>,
+    /// Relative frequency for random assignment tables: 2^(rating / 2), scaled by this variant's share of its chassis. Null if unrated.
+    pub weight: Option<f64>,
     /// Additional notes about this availability entry.
     pub notes: Option<String>,
 }
@@ -0,0 +1,297 @@
+use async_graphql::{InputObject, SimpleObject};
+
+use crate::{
+    calc::{
+        mech::{MechConfig, MechSpec, Mount},
+        validate::{ArmorAllocation, DesignReport, DesignRules, Issue, LocationReport},
+    },
+    error::AppError,
+    graphql::types::{bv::MountedEquipmentInput, construction::TechBaseFilter},
+};
+
+// ── Inputs ───────────────────────────────────────────────────────────────────
+
+/// Armor points assigned to one location. Only torsos may carry rear armor.
+#[derive(InputObject)]
+pub struct LocationArmorInput {
+    /// Location, using LocationGql values (e.g. "center_torso").
+    pub location: String,
+    /// Front armor points.
+    pub front: i32,
+    /// Rear armor points (torsos only).
+    #[graphql(default)]
+    pub rear: i32,
+}
+
+/// A mech design to check against TechManual construction rules.
+/// Component fields take slugs from the construction reference queries.
+#[derive(InputObject)]
+pub struct MechDesignInput {
+    /// Mech tonnage (20-100 in steps of 5).
+    pub tonnage: i32,
+    /// Technology base. Use MIXED to allow Inner Sphere and Clan components together.
+    #[graphql(default_with = "TechBaseFilter::InnerSphere")]
+    pub tech_base: TechBaseFilter,
+    /// Chassis configuration: "Biped", "Quad" or "Tripod".
+    #[graphql(default_with = "String::from(\"Biped\")")]
+    pub config: String,
+    /// In-universe year the design must be buildable in. Omit to skip availability checks.
+    pub year: Option<i32>,
+    /// Engine type slug (e.g. "standard-fusion", "xl-is").
+    pub engine_type: String,
+    /// Engine rating. Walk MP = rating / tonnage.
+    pub engine_rating: i32,
+    /// Jump MP. Jump jets are weighed from this value; mounting "jump-jet" equipment only places their slots.
+    #[graphql(default)]
+    pub jump_mp: i32,
+    /// Armor type slug (e.g. "standard", "ferro-fibrous-is").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub armor_type: String,
+    /// Armor points per location. Locations not listed have no armor.
+    #[graphql(default)]
+    pub armor: Vec<LocationArmorInput>,
+    /// Internal structure type slug (e.g. "standard", "endo-steel-is").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub structure_type: String,
+    /// Gyro type slug (e.g. "standard", "xl").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub gyro_type: String,
+    /// Cockpit type slug (e.g. "standard", "small").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub cockpit_type: String,
+    /// Heat sink type slug (e.g. "single", "double-is").
+    #[graphql(default_with = "String::from(\"single\")")]
+    pub heatsink_type: String,
+    /// Total number of heat sinks, including engine-integrated ones.
+    #[graphql(default = 10)]
+    pub heat_sink_count: i32,
+    /// Myomer type slug (e.g. "standard", "tsm").
+    #[graphql(default_with = "String::from(\"standard\")")]
+    pub myomer_type: String,
+    /// True if both arms have lower arm actuators.
+    #[graphql(default = true)]
+    pub lower_arm_actuators: bool,
+    /// True if both arms have hand actuators.
+    #[graphql(default = true)]
+    pub hand_actuators: bool,
+    /// Mounted weapons, ammunition and equipment.
+    #[graphql(default)]
+    pub equipment: Vec<MountedEquipmentInput>,
+}
+
+impl MechDesignInput {
+    pub fn into_parts(self) -> Result<(MechSpec, DesignRules), AppError> {
+        let config = MechConfig::parse(&self.config).ok_or_else(|| {
+            AppError::Validation(format!(
+                "unknown config '{}' (expected Biped, Quad or Tripod)",
+                self.config
+            ))
+        })?;
+        let armor: Vec<ArmorAllocation> = self
+            .armor
+            .into_iter()
+            .map(|a| ArmorAllocation {
+                location: a.location,
+                front: a.front,
+                rear: a.rear,
+            })
+            .collect();
+        let spec = MechSpec {
+            tonnage: self.tonnage,
+            tech_base: self.tech_base.as_db_str().to_string(),
+            config,
+            engine_type: self.engine_type,
+            engine_rating: self.engine_rating,
+            walk_mp: None,
+            jump_mp: self.jump_mp.max(0),
+            armor_type: self.armor_type,
+            armor_points: armor.iter().map(|a| a.front + a.rear).sum(),
+            structure_type: self.structure_type,
+            gyro_type: self.gyro_type,
+            cockpit_type: self.cockpit_type,
+            heatsink_type: self.heatsink_type,
+            heat_sink_count: self.heat_sink_count.max(0),
+            myomer_type: self.myomer_type,
+            mounts: self.equipment.into_iter().map(Mount::from).collect(),
+        };
+        let rules = DesignRules {
+            armor,
+            year: self.year,
+            lower_arm_actuators: self.lower_arm_actuators,
+            hand_actuators: self.hand_actuators,
+        };
+        Ok((spec, rules))
+    }
+}
+
+// ── Outputs ──────────────────────────────────────────────────────────────────
+
+/// A single construction rule violation or advisory.
+#[derive(SimpleObject)]
+pub struct DesignIssueGql {
+    /// Machine-readable code (e.g. "overweight", "crits_exceeded", "armor_exceeded",
+    /// "heat_sink_minimum", "tech_base_mismatch", "not_yet_available").
+    pub code: String,
+    /// Human-readable description.
+    pub message: String,
+    /// Location the issue applies to, if any.
+    pub location: Option<String>,
+}
+
+impl From<Issue> for DesignIssueGql {
+    fn from(i: Issue) -> Self {
+        Self {
+            code: i.code.to_string(),
+            message: i.message,
+            location: i.location,
+        }
+    }
+}
+
+/// Weight budget in tons. Each component is rounded up to the nearest half ton.
+#[derive(SimpleObject)]
+pub struct DesignWeightGql {
+    /// Internal structure weight.
+    pub structure: f64,
+    /// Engine weight (standard weight for the rating × engine type multiplier).
+    pub engine: f64,
+    /// Gyro weight (ceil(rating / 100) × gyro type multiplier).
+    pub gyro: f64,
+    /// Cockpit weight.
+    pub cockpit: f64,
+    /// Weight of heat sinks beyond the 10 free ones.
+    pub heat_sinks: f64,
+    /// Armor weight (total points / points per ton).
+    pub armor: f64,
+    /// Jump jet weight.
+    pub jump_jets: f64,
+    /// Weight of mounted weapons, ammunition and equipment.
+    pub equipment: f64,
+    /// Sum of all components.
+    pub total: f64,
+    /// Maximum weight (the mech's tonnage).
+    pub maximum: f64,
+    /// Unused tonnage (negative if overweight).
+    pub remaining: f64,
+}
+
+/// Critical slot and armor usage for one location.
+#[derive(SimpleObject)]
+pub struct DesignLocationGql {
+    /// Location name (e.g. "left_torso").
+    pub location: String,
+    /// Total critical slots in this location (6 for head and legs, 12 otherwise).
+    pub slots_total: i32,
+    /// Slots taken by fixed components (engine, gyro, cockpit, actuators).
+    pub slots_fixed: i32,
+    /// Slots taken by mounted equipment.
+    pub slots_equipment: i32,
+    /// Slots left free in this location (negative if over capacity).
+    pub slots_free: i32,
+    /// Front armor points assigned.
+    pub armor_front: i32,
+    /// Rear armor points assigned (torsos only).
+    pub armor_rear: i32,
+    /// Maximum armor points (2 × structure points, or 9 for the head).
+    pub armor_max: i32,
+    /// Internal structure points.
+    pub structure_points: i32,
+}
+
+impl From<LocationReport> for DesignLocationGql {
+    fn from(l: LocationReport) -> Self {
+        Self {
+            slots_free: l.slots_total - l.slots_fixed - l.slots_equipment,
+            location: l.location,
+            slots_total: l.slots_total,
+            slots_fixed: l.slots_fixed,
+            slots_equipment: l.slots_equipment,
+            armor_front: l.armor_front,
+            armor_rear: l.armor_rear,
+            armor_max: l.armor_max,
+            structure_points: l.structure_points,
+        }
+    }
+}
+
+/// Heat sink requirements and heat balance.
+#[derive(SimpleObject)]
+pub struct DesignHeatGql {
+    /// Heat sinks installed.
+    pub heat_sink_count: i32,
+    /// Minimum required heat sinks (10 for fusion engines).
+    pub minimum_heat_sinks: i32,
+    /// Heat sinks the engine can hold without using critical slots (rating / 25).
+    pub engine_heat_sink_capacity: i32,
+    /// Total heat dissipation per turn.
+    pub dissipation: i32,
+    /// Heat generated by firing all weapons once.
+    pub weapon_heat: i32,
+}
+
+/// Result of validating a mech design against TechManual construction rules.
+#[derive(SimpleObject)]
+pub struct MechDesignValidationGql {
+    /// True if there are no errors. Warnings do not affect validity.
+    pub is_valid: bool,
+    /// Rule violations that make the design illegal.
+    pub errors: Vec<DesignIssueGql>,
+    /// Advisories (unused tonnage, missing equipment data, heat overload).
+    pub warnings: Vec<DesignIssueGql>,
+    /// Weight budget.
+    pub weight: DesignWeightGql,
+    /// Per-location critical slot and armor usage.
+    pub locations: Vec<DesignLocationGql>,
+    /// Slots still to be placed for structure, armor, heat sinks and jump jets not mounted explicitly.
+    pub unallocated_slots: i32,
+    /// Free critical slots remaining after placing unallocated slots.
+    pub free_slots: i32,
+    /// Heat sink check and heat balance.
+    pub heat: DesignHeatGql,
+    /// Walking MP.
+    pub walk_mp: i32,
+    /// Running MP (including MASC/TSM).
+    pub run_mp: i32,
+    /// Jumping MP.
+    pub jump_mp: i32,
+    /// Total armor points.
+    pub armor_points: i32,
+}
+
+impl From<DesignReport> for MechDesignValidationGql {
+    fn from(r: DesignReport) -> Self {
+        let w = r.weight;
+        Self {
+            is_valid: r.errors.is_empty(),
+            errors: r.errors.into_iter().map(Into::into).collect(),
+            warnings: r.warnings.into_iter().map(Into::into).collect(),
+            weight: DesignWeightGql {
+                structure: w.structure,
+                engine: w.engine,
+                gyro: w.gyro,
+                cockpit: w.cockpit,
+                heat_sinks: w.heat_sinks,
+                armor: w.armor,
+                jump_jets: w.jump_jets,
+                equipment: w.equipment,
+                total: w.total,
+                maximum: w.maximum,
+                remaining: w.maximum - w.total,
+            },
+            locations: r.locations.into_iter().map(Into::into).collect(),
+            unallocated_slots: r.unallocated_slots,
+            free_slots: r.free_slots,
+            heat: DesignHeatGql {
+                heat_sink_count: r.heat.heat_sink_count,
+                minimum_heat_sinks: r.heat.minimum_heat_sinks,
+                engine_heat_sink_capacity: r.heat.engine_heat_sink_capacity,
+                dissipation: r.heat.dissipation,
+                weapon_heat: r.heat.weapon_heat,
+            },
+            walk_mp: r.walk_mp,
+            run_mp: r.run_mp,
+            jump_mp: r.jump_mp,
+            armor_points: r.armor_points,
+        }
+    }
+}
@@ -2,7 +2,7 @@ pub fn generate_llms_txt(base_url: &str) -> String {
     format!(
         r#"# BattleTech Data API
 
-> GraphQL API for BattleTech tabletop game data: units (mechs, vehicles, fighters, dropships), equipment (weapons, armor, engines), construction reference tables, factions, and eras. Data sourced from MegaMek 0.50.11 (~6,500 units, ~2,875 equipment items) enriched with Master Unit List (MUL) data (BV, roles, availability). Includes construction reference data for unit builders (engine/armor/structure/heatsink/gyro/cockpit/myomer types with weights and crit slots).
+> GraphQL API for BattleTech tabletop game data: units (mechs, vehicles, fighters, dropships), equipment (weapons, armor, engines), construction reference tables, factions, and eras. Data sourced from MegaMek 0.50.11 (~6,500 units, ~950 equipment items) enriched with Master Unit List (MUL) data (BV, roles, availability). Includes construction reference data for unit builders (engine/armor/structure/heatsink/gyro/cockpit/myomer types with weights and crit slots).
 
 ## Endpoint
 
@@ -14,13 +14,21 @@ Body: {{"query": "...", "variables": {{...}}}}
 
 GET {base_url}/schema.graphql
 
+## Record Sheets
+
+GET {base_url}/units/{{slug}}/record-sheet.svg
+GET {base_url}/units/{{slug}}/record-sheet.pdf
+
+Printable 'Mech record sheet (US Letter) with armor and internal structure pips, weapons inventory (location, heat, damage, ranges), heat sinks and heat scale, BV and cost. Returns 404 for unknown slugs and 400 for units that are not mechs.
+
 ## Key Concepts
 
 - **Slugs**: lowercase, hyphen-separated identifiers. Examples: "atlas-as7-d", "clan-wolf", "medium-laser". Chassis slugs include the unit type suffix: "atlas-mech", "demolisher-vehicle"
 - **Years**: in-universe BattleTech timeline years (e.g. 3025, 3055), not real-world dates
 - **Tech base** values (snake_case): inner_sphere, clan, mixed, primitive
 - **Rules level** values (snake_case): introductory, standard, advanced, experimental, unofficial
-- **Equipment category** values (snake_case): energy_weapon, ballistic_weapon, missile_weapon, ammo, physical_weapon, equipment, armor, structure, engine, targeting_system, myomer, heat_sink, jump_jet, communications
+- **Equipment category** values (snake_case): energy_weapon, ballistic_weapon, missile_weapon, physical_weapon, ammunition, equipment, armor, structure, engine, gyro, cockpit, actuator, heat_sink, jump_jet, targeting_computer, targeting_system, communications, myomer
+- **Equipment catalog**: equipment category, tech base and slug come from a catalog of MegaMek items; `equipment.internalName` is the MegaMek internal name (e.g. "ISERLargeLaser"). Names the catalog does not know have a null `internalName` and category `equipment`, and are listed in the `megamek` import run's `errorMessages`
 - **Faction type** values: great_house, clan, periphery, mercenary, other
 - **BV** (Battle Value): composite combat effectiveness score used for game balancing
 - **MUL ID**: numeric identifier from the official Master Unit List (masterunitlist.info). Null for units not in MUL
@@ -29,8 +37,29 @@ GET {base_url}/schema.graphql
 - **Tonnage**: weight in metric tons (20–100 for mechs, up to 500,000+ for jumpships)
 - **Range values**: measured in tabletop hexes
 - **Crits**: number of critical hit slots an equipment item occupies
+- **Location** values (snake_case): mechs use head, center_torso, left_torso, right_torso, left_arm, right_arm, left_leg, right_leg (quads/tripods: front_left_leg, front_right_leg, rear_left_leg, rear_right_leg, center_leg); vehicles use front, left_side, right_side, rear, turret, body (plus rotor for VTOLs, front_turret/rear_turret for dual turrets, front_left/front_right/rear_left/rear_right for super-heavy and large support tanks); ProtoMechs use head, torso, right_arm, left_arm, legs, main_gun (quads have no arms); battle armor uses body, left_arm, right_arm, turret; conventional infantry weapons have no location; aerospace fighters, small craft and DropShips use nose, left_wing, right_wing, aft; JumpShips, WarShips and space stations use nose, front_left_side, front_right_side, aft, aft_left_side, aft_right_side (plus left_broadside/right_broadside)
+- **Critical slots**: `criticalSlots` on a mech lists every slot of its critical hit table in record-sheet order (location, 1-based `slotIndex`), including empty slots and fixed components (engine, gyro, actuators). Quad/tripod legs use `front_left_leg`, `front_right_leg`, `rear_left_leg`, `rear_right_leg`, `center_leg`
+- **Lore and manufacturers**: `fluff` on a unit gives `overview`, `capabilities`, `deployment`, `history`, `notes` and `imageFile` (null when the source file has no lore). `manufacturers` lists {{ slug, name, factory, system }}: the unit's makers with their primary `factory` (system null) first, then component makers with `system` set (chassis, engine, armor, jumpjet, communications, targeting). Filter with `units(manufacturer: "Defiance")` (substring of a unit maker's name)
+- **LAMs and QuadVees**: `mechData.config` is "LAM" or "QuadVee" for these. `mechData.lamData` (null for other mechs) gives `lamType` (standard, bimodal), `hasAirmechMode` (bimodal LAMs have none), `fuel`, AirMech flight `airmechCruiseMp` (3 × jump MP) and `airmechFlankMp`, fighter `fighterSafeThrust` (= jump MP) and `fighterMaxThrust`, and the conversion equipment crits `landingGearSlots`/`avionicsSlots`. `mechData.quadVeeData` gives the vehicle-mode `motiveType` (tracked, wheeled), `conversionSlots` and `motiveSlots` (track/wheel crits)
+- **OmniMechs**: each configuration (Prime, A, B...) is its own unit. `omniConfiguration` on an OmniMech (null otherwise) gives the configuration `name`, its `base` chassis (engine, structure, armor, `fixedEquipment` and all `configurations` sharing it), `fixedEquipment`, `podEquipment` and `podSpace` per location ({{ location, totalSlots, fixedSlots, podCapacity, usedPodSlots, remainingSlots }}; `podCapacity` is the slots free with every pod removed). Loadout entries and critical slots carry `isOmnipod`. `diffConfigurations(a, b)` takes two configuration slugs and returns the pods `added` and `removed` going from a to b, plus `sameBase`; non-OmniMechs are a validation error
+- **Vehicle data**: `vehicleData` on combat vehicles gives `motionType` (tracked, wheeled, hover, vtol, naval, hydrofoil, submarine, wige, rail, maglev), `cruiseMp`/`flankMp`, engine/armor/structure type and `turretType` (none, single, dual). Null for non-vehicles. Filter with `units(motionType: "hover")`
+- **Unit types**: chassis `unitType` is one of mech, industrialmech, protomech, vehicle, fighter, small_craft, dropship, jumpship, warship, space_station, battle_armor, infantry, other. Chassis slugs end in the type with hyphens (e.g. "atlas-mech", "union-dropship", "minotaur-protomech"). Filter units with `units(unitType: "protomech")`; an unknown type is a validation error. IndustrialMechs (industrial structure or cockpit) also have `mechData`, with `isEnvironmentallySealed` and `industrialEquipment` (e.g. "LiftHoist"). ProtoMechs have `protomechData`: `config` (biped, quad, glider), `isUltraheavy`, `walkMp`/`runMp`/`jumpMp`, `hasMainGun`, `armorType`, `hasInterfaceCockpit`
+- **Infantry data**: `infantryData` on battle armor and conventional infantry gives `kind` (battle_armor, conventional), `trooperCount` (per squad/point for battle armor, per platoon for infantry), `squadSize`/`squadCount`, `weightClass` (PA(L), Light, Medium, Heavy, Assault), `chassisType` (biped, quad), `motiveType`, `groundMp`/`jumpMp`/`umuMp`/`vtolMp`, `armorPerTrooper`, `armorType`, `leftManipulator`/`rightManipulator`, `isAntiMech` and the conventional `primaryWeapon`/`secondaryWeapon`/`armorKit`. Null for other units. Chassis `unitType` is "battle_armor" or "infantry". Filter with `troopersMin`/`troopersMax`, `jumpMpMin`, `umuMpMin`, `manipulator` (substring of either arm) and `isAntiMech`
+- **Aero data**: `aeroData` on aerospace fighters, small craft and DropShips gives `safeThrust`/`maxThrust`, `structuralIntegrity`, `fuelPoints`, heat sinks, cockpit/engine/armor type and `weaponBays` grouped by firing arc. Null for non-aerospace units. Filter with `safeThrustMin`/`safeThrustMax` and `structuralIntegrityMin`/`structuralIntegrityMax`
+- **Large craft data**: `largeCraftData` on small craft, DropShips, JumpShips, WarShips and space stations gives `craftType`, `designType` (aerodyne, spheroid), crew (`crew`, `officers`, `gunners`, `passengers`, `marines`, `battleArmorTroops`, `otherPassengers`), `lifeBoats`/`escapePods`, `collarType` (DropShips), `dockingCollars`, the jump drive (`hasKfDrive`, `driveCoreType`, `jumpRange`, `hasLithiumFusion`, `hasJumpSail`), `hasHpg`, `gravDecks`, `transportBays` ({{ bayNumber, bayType, size, sizeUnit, doors }}; `sizeUnit` is units, squads, platoons or tons), `capacity` (mechs, vehicles, fighters, smallCraft, battleArmorSquads, infantryPlatoons, cargoTons summed over bays) and `weaponBays`. Null for other units
+- **Alpha Strike**: `alphaStrike` on a unit gives its Alpha Strike card: `unitType` (BM, CV, AF, ...), `size`, `movement` as printed (e.g. `10"j`), `tmm`, `armor`, `structure`, `damage` as printed (e.g. "3/3/1", "0*" = minimal damage) plus per-range integers, `overheat`, `pointValue` and `specials` (e.g. "CASE", "IF1", "LRM1/1/1"). `source` is "mul" for official cards or "conversion" for stats derived from construction data; conversions have no `pointValue`. Filter with `pvMin`/`pvMax` and `asSpecials` (all listed abilities required; "IF" matches "IF1")
+- **Force builder**: `buildForce(constraints: ForceConstraintsInput)` draws random forces from the units a faction fields in an era (`factionSlug`, `eraSlug`). Set `budgetType` (BV or PV), `budget`, `unitCount` (1-12, default 4), optional `roles` ({{ role, count }}) and `unitType` (e.g. "mech"). Returns up to `results` (default 5, max 20) distinct forces ranked by `difference` from the budget. The same `seed` always returns the same forces. PV budgets only draw from units with a MUL Point Value
+- **Random assignment tables**: availability entries carry an `availabilityCode` (MegaMek force generator rating, "0" very rare to "10" very common) and a `weight` (relative frequency). `randomAssignmentTable(factionSlug, eraSlug, unitType, weightClass, tableType)` builds a TWO_D6 (rows 2-12) or PERCENTAGE (ranges like "01-23") table from those weights; `weightClass` is LIGHT (<40t), MEDIUM (40-55), HEAVY (60-75), ASSAULT (80-100) or SUPER_HEAVY. `rollUnits(..., count, seed)` draws units with replacement in proportion to weight; the same seed always returns the same draws. Units without a weight (MUL-only availability) are not included
+- **Provenance**: every scraper run is recorded as an import run ({{ id, source, version, status, startedAt, finishedAt, processed, imported, skipped, errorCount, errorMessages }}); `source` is megamek, mul, equipment_seed, forcegen or planets and `status` is running, succeeded or failed. `provenance` on a unit gives the runs that last wrote it: `unitRun` (MegaMek core fields), `mulRun` (MUL ID, BV, cost, role), `locationsRun`, `loadoutRun`, `availabilityRuns`, plus `bvSource`/`introYearSource` ("mul" when MUL supplied the value). `provenance` on equipment gives `statsSource` and the seed `statsRun`. `importRuns(source, limit)` lists recent runs, newest first
+- **Timeline**: eras are buckets; for a specific year use `year` on `units` (in service that year: `introYear` reached and not past `extinctionYear` unless `reintroYear` is reached; with `factionSlug`, availability must be in the era covering the year) and on `allEquipment`. `unit.isAvailableIn(year, factionSlug)` answers the same for one variant (factionSlug optional, `includeParentFactions` as on `units`). `equipment.techProgression` lists ({{ techBase, prototype, production, common, extinct, reintroduced }}) per tech base; equipment counts as in service from its first prototype. Units or equipment without a known introduction year never match a year filter
+- **Tech ratings**: equipment and construction types (`engineTypes`, `armorTypes`, ...) carry `techRating` (A-F) and `availability` ({{ starLeague, successionWars, clanInvasion, darkAge }}, each A-F or X = unavailable); construction types also have `techProgression` (techBase null when shared by all tech bases). `allEquipment(legalInEra: "late-succession-wars")` keeps equipment rated other than X for the era's period (Star League to 2780, Succession Wars 2781-3049, Clan Invasion 3050-3080, Dark Age after) and introduced by the era's end; unrated equipment is excluded
+- **Faction relations**: `parent(year)` is the faction a faction sat inside in that year (omit for the current one), e.g. "federated-commonwealth" for "steiner" in 3040 and "general" for any Great House; `ancestors(year)` walks the whole chain, nearest first. `successors` lists factions that took over ({{ faction, year }}); `namesByYear` lists renames ({{ name, shortName, startYear, endYear }}), e.g. Lyran Alliance 3057-3084. The `units` filter `includeParentFactions: true` adds availability inherited from parents (such as MUL "Inner Sphere General" rows on `general`) in the eras each relation covers
+- **Planets**: `planet(slug)` has map coordinates (`x`, `y` in light years from Terra), `socioIndustrial` ratings A-D/F, `owner(year)` (omit for the current owner), `ownershipHistory` ({{ faction, startYear, endYear }}; a null faction is unowned) and `factories` ({{ slug, name, source }}; source "file" from the planets data or "fluff" from MegaMek unit factories). `planetsOwnedBy(factionSlug, year, includeChildFactions)` lists a faction's worlds; `planetsWithinJumps(slug, jumps, factionSlug, year)` returns ({{ planet, jumps, distance }}) for jumps of at most 30 light years, nearest first
+- **Dataset changes**: each MegaMek import snapshots its version (per-unit hashes of core fields, armor and loadout, plus BV). `datasetChanges(fromVersion, toVersion)` returns `added`, `removed` and `changed` units ({{ slug, fullName, unit, coreChanged, armorChanged, loadoutChanged, bvFrom, bvTo, loadoutAdded, loadoutRemoved }}); loadout items have the `diffConfigurations` shape. Versions are the `version` of megamek import runs; an unknown version is a not-found error
 - **Resolved component types**: `mechData` provides both raw MegaMek strings (e.g. `engineTypeRaw`) and resolved references (e.g. `engine`) with full construction properties (weight multipliers, crit slots, etc.)
-- **Construction reference**: prescriptive data for unit builders — component types with weights, crit slots, and rules; engine weight table; internal structure table
+- **Construction reference**: prescriptive data for unit builders — component types with weights, crit slots, and rules; engine weight table; internal structure table. Engine, armor, structure, gyro and cockpit types carry a `bvMultiplier` used by the BV calculator
+- **BV calculator**: `calculateBattleValue(input: MechConfigInput)` computes Battle Value 2.0 for any mech configuration from component slugs, total armor points and mounted equipment (`equipmentSlug`, `location`, `quantity`, `isRearFacing`). Returns defensive/offensive breakdown, heat efficiency, speed factor and per-weapon BV. Equipment without BV data is counted as 0 and listed in `warnings`
+- **Design validator**: `validateMechDesign(design: MechDesignInput)` checks a mech build against TechManual construction rules. Takes the same component slugs and equipment mounts as the BV calculator plus per-location `armor` ({{ location, front, rear }}), an optional in-universe `year`, and arm actuator flags. Returns `isValid`, structured `errors`/`warnings` ({{ code, message, location }}), a weight budget, critical slot and armor usage per location, and the heat sink check. Error codes include overweight, crits_exceeded, total_crits_exceeded, armor_exceeded, rear_armor_not_allowed, heat_sink_minimum, jump_exceeds_walk, tech_base_mismatch and not_yet_available
 
 ## Pagination
 
@@ -53,7 +82,7 @@ To paginate: pass `endCursor` from the previous response as `after` in the next
 ## Limits
 
 - Query depth limit: 20
-- Query complexity limit: 500 (expensive fields: loadout=10, locations=5, availability=5, variants=5, mechData=5, quirks=3, eras=5)
+- Query complexity limit: 500 (expensive fields: loadout=10, criticalSlots=10, locations=5, availability=5, variants=5, mechData=5, lamData=5, quadVeeData=5, protomechData=5, omniConfiguration=5, podEquipment=5, podSpace=5, fixedEquipment=5, configurations=10, vehicleData=5, infantryData=5, largeCraftData=5, aeroData=5, transportBays=5, capacity=5, alphaStrike=5, weaponBays=5, quirks=3, fluff=3, manufacturers=3, provenance=5 (units) / 3 (equipment), eras=5, ancestors=5, successors=3, namesByYear=3, isAvailableIn=3, techProgression=3, ownershipHistory=5, factories=3, planetsOwnedBy=10, planetsWithinJumps=10, calculateBattleValue=10, validateMechDesign=10, diffConfigurations=10, datasetChanges=20, buildForce=10, randomAssignmentTable=10, rollUnits=10)
 - `unitsByIds`: max 24 slugs per call
 - Pagination: max 100 items per page
 - Rate limit: 100 request burst / ~120 requests/min sustained (per IP)
@@ -143,6 +172,23 @@ To paginate: pass `endCursor` from the previous response as `after` in the next
 }}
 ```
 
+### Get a mech's critical hit table
+```graphql
+{{
+  unit(slug: "atlas-as7-d") {{
+    criticalSlots {{
+      location
+      slotIndex
+      content
+      equipmentSlug
+      isEmpty
+      isRear
+      isOmnipod
+    }}
+  }}
+}}
+```
+
 ### Filter units by faction and era
 ```graphql
 {{
@@ -164,6 +210,31 @@ To paginate: pass `endCursor` from the previous response as `after` in the next
 }}
 ```
 
+### What a faction could field in a given year
+```graphql
+{{
+  units(first: 20, factionSlug: "marik", year: 3052, includeParentFactions: true) {{
+    edges {{ node {{ slug fullName introYear }} }}
+    pageInfo {{ totalCount }}
+  }}
+  unit(slug: "atlas-as7-d") {{
+    isAvailableIn(year: 3052, factionSlug: "marik")
+  }}
+  allEquipment(year: 3052, category: "energy_weapon") {{
+    edges {{ node {{ slug techProgression {{ techBase prototype production extinct reintroduced }} }} }}
+  }}
+}}
+```
+
+### Equipment legal in an era
+```graphql
+{{
+  allEquipment(legalInEra: "late-succession-wars", category: "energy_weapon") {{
+    edges {{ node {{ slug techRating availability {{ starLeague successionWars clanInvasion darkAge }} }} }}
+  }}
+}}
+```
+
 ### Filter OmniMechs with jump capability
 ```graphql
 {{
@@ -189,6 +260,189 @@ To paginate: pass `endCursor` from the previous response as `after` in the next
 }}
 ```
 
+### Filter combat vehicles by motive type
+```graphql
+{{
+  units(first: 10, motionType: "hover") {{
+    edges {{
+      node {{
+        slug
+        fullName
+        tonnage
+        vehicleData {{
+          motionType
+          cruiseMp
+          flankMp
+          engineType
+          armorType
+          structureType
+          turretType
+        }}
+      }}
+    }}
+    pageInfo {{
+      totalCount
+    }}
+  }}
+}}
+```
+
+### Filter aerospace units by thrust and structural integrity
+```graphql
+{{
+  units(first: 10, safeThrustMin: 6, structuralIntegrityMin: 8) {{
+    edges {{
+      node {{
+        slug
+        fullName
+        tonnage
+        aeroData {{
+          safeThrust
+          maxThrust
+          structuralIntegrity
+          fuelPoints
+          heatSinkCount
+          heatSinkType
+          cockpitType
+          weaponBays {{
+            location
+            bayNumber
+            weapons {{ equipmentSlug equipmentName quantity }}
+          }}
+        }}
+      }}
+    }}
+    pageInfo {{
+      totalCount
+    }}
+  }}
+}}
+```
+
+### Lore for units built by a manufacturer
+```graphql
+{{
+  units(first: 5, manufacturer: "Defiance") {{
+    edges {{
+      node {{
+        slug
+        fluff {{ overview capabilities deployment history }}
+        manufacturers {{ name factory system }}
+      }}
+    }}
+  }}
+}}
+```
+
+### Where a unit's values came from
+```graphql
+{{
+  unit(slug: "atlas-as7-d") {{
+    bv
+    provenance {{
+      bvSource
+      unitRun {{ source version finishedAt }}
+      mulRun {{ source finishedAt }}
+      loadoutRun {{ id source version }}
+    }}
+  }}
+  importRuns(limit: 5) {{ id source version status imported skipped errorCount }}
+}}
+```
+
+### Units changed between two MegaMek releases
+```graphql
+{{
+  datasetChanges(fromVersion: "0.50.10", toVersion: "0.50.11") {{
+    added {{ slug fullName }}
+    removed {{ slug fullName }}
+    changed {{
+      slug coreChanged armorChanged bvFrom bvTo
+      loadoutAdded {{ equipmentSlug location quantity }}
+      loadoutRemoved {{ equipmentSlug location quantity }}
+    }}
+  }}
+}}
+```
+
+### OmniMech pod space and configuration differences
+```graphql
+{{
+  unit(slug: "mad-cat-prime") {{
+    omniConfiguration {{
+      name
+      base {{ engineRating structureType armorType fixedEquipment {{ equipmentName location quantity }} }}
+      podEquipment {{ equipmentName location quantity }}
+      podSpace {{ location podCapacity usedPodSlots remainingSlots }}
+    }}
+  }}
+  diffConfigurations(a: "mad-cat-prime", b: "mad-cat-a") {{
+    sameBase
+    added {{ equipmentName location quantity }}
+    removed {{ equipmentName location quantity }}
+  }}
+}}
+```
+
+### ProtoMechs fielded in the Jihad
+```graphql
+{{
+  units(first: 20, unitType: "protomech", eraSlug: "jihad") {{
+    edges {{
+      node {{
+        slug
+        fullName
+        tonnage
+        protomechData {{ config isUltraheavy walkMp jumpMp hasMainGun }}
+        locations {{ location armorPoints structurePoints }}
+      }}
+    }}
+  }}
+}}
+```
+
+### Battle armor with battle claws that can jump
+```graphql
+{{
+  units(first: 10, manipulator: "Battle Claw", jumpMpMin: 3) {{
+    edges {{
+      node {{
+        slug
+        fullName
+        infantryData {{
+          kind
+          trooperCount
+          weightClass
+          jumpMp
+          armorPerTrooper
+          leftManipulator
+          rightManipulator
+          isAntiMech
+        }}
+      }}
+    }}
+  }}
+}}
+```
+
+### What a DropShip can carry
+```graphql
+{{
+  unit(slug: "union-2708") {{
+    fullName
+    largeCraftData {{
+      craftType
+      crew
+      officers
+      gunners
+      transportBays {{ bayNumber bayType size sizeUnit doors }}
+      capacity {{ mechs heavyVehicles fighters cargoTons }}
+      weaponBays {{ location bayNumber weapons {{ equipmentName quantity }} }}
+    }}
+  }}
+}}
+```
+
 ### Filter units by tactical role
 ```graphql
 {{
@@ -315,6 +569,103 @@ To paginate: pass `endCursor` from the previous response as `after` in the next
 }}
 ```
 
+### Calculate Battle Value for a custom mech
+```graphql
+{{
+  calculateBattleValue(input: {{
+    tonnage: 65
+    engineType: "standard-fusion"
+    engineRating: 260
+    armorPoints: 184
+    heatsinkType: "single"
+    heatSinkCount: 10
+    equipment: [
+      {{ equipmentSlug: "ppc", location: "right_arm" }}
+      {{ equipmentSlug: "medium-laser", location: "left_arm", quantity: 2 }}
+    ]
+  }}) {{
+    battleValue
+    defensiveRating
+    offensiveRating
+    heatEfficiency
+    speedFactor
+    weapons {{ equipmentSlug bv exceedsHeatEfficiency }}
+    warnings
+  }}
+}}
+```
+
+### Validate a mech design
+```graphql
+{{
+  validateMechDesign(design: {{
+    tonnage: 50
+    year: 3025
+    engineType: "standard-fusion"
+    engineRating: 200
+    armor: [
+      {{ location: "head", front: 9 }}
+      {{ location: "center_torso", front: 20, rear: 6 }}
+    ]
+    equipment: [{{ equipmentSlug: "ppc", location: "right_arm" }}]
+  }}) {{
+    isValid
+    errors {{ code message location }}
+    warnings {{ code message }}
+    weight {{ total remaining }}
+    locations {{ location slotsFree armorMax }}
+    heat {{ heatSinkCount minimumHeatSinks dissipation }}
+  }}
+}}
+```
+
+### Build a random lance against a BV budget
+```graphql
+{{
+  buildForce(constraints: {{
+    factionSlug: "clan-wolf"
+    eraSlug: "clan-invasion"
+    budget: 6000
+    roles: [{{ role: "Sniper", count: 1 }}]
+    unitType: "mech"
+    seed: 42
+  }}) {{
+    poolSize
+    forces {{
+      rank totalValue difference
+      members {{ value unit {{ slug fullName role }} }}
+    }}
+  }}
+}}
+```
+
+### Random assignment table and reproducible draws
+```graphql
+{{
+  randomAssignmentTable(factionSlug: "davion", eraSlug: "renaissance", unitType: "mech", weightClass: HEAVY) {{
+    unitCount
+    rows {{ roll probability unit {{ slug fullName }} }}
+  }}
+  rollUnits(factionSlug: "davion", eraSlug: "renaissance", unitType: "mech", count: 4, seed: 7) {{
+    draw probability unit {{ slug fullName }}
+  }}
+}}
+```
+
+### Find Alpha Strike units by Point Value and special abilities
+```graphql
+{{
+  units(asSpecials: ["IF", "CASE"], pvMin: 30, pvMax: 45, first: 10) {{
+    edges {{
+      node {{
+        fullName
+        alphaStrike {{ size movement tmm armor structure damage overheat pointValue specials source }}
+      }}
+    }}
+  }}
+}}
+```
+
 ### Look up engine weight for a specific rating
 ```graphql
 {{
@@ -338,6 +689,36 @@ To paginate: pass `endCursor` from the previous response as `after` in the next
 }}
 ```
 
+### Faction hierarchy and inherited availability
+```graphql
+{{
+  faction(slug: "steiner") {{
+    parent(year: 3040) {{ slug }}
+    ancestors {{ slug }}
+    namesByYear {{ name startYear endYear }}
+  }}
+  units(first: 20, factionSlug: "davion", includeParentFactions: true) {{
+    edges {{ node {{ slug fullName }} }}
+  }}
+}}
+```
+
+### Planets near a world and who held them
+```graphql
+{{
+  planet(slug: "hesperus-ii") {{
+    owner(year: 3025) {{ slug }}
+    ownershipHistory {{ faction {{ slug }} startYear endYear }}
+    factories {{ name source }}
+  }}
+  planetsWithinJumps(slug: "tikonov", jumps: 2, factionSlug: "davion", year: 3025) {{
+    jumps
+    distance
+    planet {{ slug name }}
+  }}
+}}
+```
+
 ### List all Clan factions
 ```graphql
 {{
@@ -2,3 +2,4 @@ pub mod graphql;
 pub mod health;
 pub mod llms_txt;
 pub mod ready;
+pub mod record_sheet;
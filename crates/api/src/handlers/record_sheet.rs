@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    error::AppError,
+    render::{pdf, record_sheet, svg},
+    state::AppState,
+};
+
+const CACHE_CONTROL: HeaderValue = HeaderValue::from_static("public, max-age=3600");
+
+fn disposition(slug: &str, ext: &str) -> HeaderValue {
+    HeaderValue::from_str(&format!("inline; filename=\"{slug}.{ext}\""))
+        .unwrap_or_else(|_| HeaderValue::from_static("inline"))
+}
+
+/// `GET /units/{slug}/record-sheet.svg`
+pub async fn record_sheet_svg(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Result<Response, AppError> {
+    let sheet = record_sheet::load(&state.pool, &slug).await?;
+    let body = svg::write(&record_sheet::render(&sheet));
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("image/svg+xml"),
+            ),
+            (header::CACHE_CONTROL, CACHE_CONTROL),
+            (header::CONTENT_DISPOSITION, disposition(&slug, "svg")),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// `GET /units/{slug}/record-sheet.pdf`
+pub async fn record_sheet_pdf(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Result<Response, AppError> {
+    let sheet = record_sheet::load(&state.pool, &slug).await?;
+    let body = pdf::write(&record_sheet::render(&sheet));
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/pdf"),
+            ),
+            (header::CACHE_CONTROL, CACHE_CONTROL),
+            (header::CONTENT_DISPOSITION, disposition(&slug, "pdf")),
+        ],
+        body,
+    )
+        .into_response())
+}
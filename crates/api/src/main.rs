@@ -19,12 +19,14 @@ use tower_http::{
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+mod calc;
 mod config;
 mod db;
 mod error;
 mod graphql;
 mod handlers;
 mod metrics;
+mod render;
 mod state;
 
 use config::Config;
@@ -114,6 +116,17 @@ async fn main() -> anyhow::Result<()> {
         .route("/llms.txt", get(static_text_handler))
         .with_state(llms_txt_body);
 
+    let record_sheet_router = Router::new()
+        .route(
+            "/units/{slug}/record-sheet.svg",
+            get(handlers::record_sheet::record_sheet_svg),
+        )
+        .route(
+            "/units/{slug}/record-sheet.pdf",
+            get(handlers::record_sheet::record_sheet_pdf),
+        )
+        .with_state(state.clone());
+
     let ready_router = Router::new()
         .route("/ready", get(handlers::ready::ready_handler))
         .with_state(state);
@@ -128,6 +141,7 @@ async fn main() -> anyhow::Result<()> {
         .merge(graphql_router)
         .merge(sdl_router)
         .merge(llms_router)
+        .merge(record_sheet_router)
         .merge(ready_router)
         .merge(metrics_router)
         .layer(GovernorLayer::new(governor_conf))
@@ -0,0 +1,175 @@
+//! Vector drawing for printable output. Layouts draw onto a [`Canvas`] in
+//! points with a top-left origin; the SVG and PDF writers serialise it.
+
+pub mod pdf;
+pub mod record_sheet;
+pub mod svg;
+
+/// US Letter, in points.
+pub const PAGE_WIDTH: f64 = 612.0;
+pub const PAGE_HEIGHT: f64 = 792.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// Helvetica at a given size. Both writers use the standard Helvetica faces,
+/// so widths from [`text_width`] hold for either output.
+#[derive(Debug, Clone, Copy)]
+pub struct Font {
+    pub size: f64,
+    pub bold: bool,
+}
+
+impl Font {
+    pub const fn regular(size: f64) -> Self {
+        Self { size, bold: false }
+    }
+
+    pub const fn bold(size: f64) -> Self {
+        Self { size, bold: true }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Shape {
+    /// Outlined rectangle, optionally filled with a gray level (0 black, 1 white).
+    Rect {
+        x: f64,
+        y: f64,
+        w: f64,
+        h: f64,
+        stroke: f64,
+        fill: Option<f64>,
+    },
+    Line {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        stroke: f64,
+    },
+    /// Outlined circle (an armor or structure pip).
+    Circle { cx: f64, cy: f64, r: f64 },
+    /// Text with its baseline at `y`.
+    Text {
+        x: f64,
+        y: f64,
+        font: Font,
+        anchor: Anchor,
+        text: String,
+    },
+}
+
+/// One page of drawing instructions.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    pub width: f64,
+    pub height: f64,
+    pub shapes: Vec<Shape>,
+}
+
+impl Canvas {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            shapes: Vec::new(),
+        }
+    }
+
+    pub fn rect(&mut self, x: f64, y: f64, w: f64, h: f64, stroke: f64) {
+        self.shapes.push(Shape::Rect {
+            x,
+            y,
+            w,
+            h,
+            stroke,
+            fill: None,
+        });
+    }
+
+    pub fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64, gray: f64) {
+        self.shapes.push(Shape::Rect {
+            x,
+            y,
+            w,
+            h,
+            stroke: 0.0,
+            fill: Some(gray),
+        });
+    }
+
+    pub fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, stroke: f64) {
+        self.shapes.push(Shape::Line {
+            x1,
+            y1,
+            x2,
+            y2,
+            stroke,
+        });
+    }
+
+    pub fn circle(&mut self, cx: f64, cy: f64, r: f64) {
+        self.shapes.push(Shape::Circle { cx, cy, r });
+    }
+
+    pub fn text(&mut self, x: f64, y: f64, font: Font, anchor: Anchor, text: impl Into<String>) {
+        self.shapes.push(Shape::Text {
+            x,
+            y,
+            font,
+            anchor,
+            text: text.into(),
+        });
+    }
+
+    /// Left-aligned text, shortened with an ellipsis to fit `max_width`.
+    pub fn text_fit(&mut self, x: f64, y: f64, font: Font, max_width: f64, text: &str) {
+        self.text(x, y, font, Anchor::Start, fit(text, font, max_width));
+    }
+}
+
+// ── Metrics ──────────────────────────────────────────────────────────────────
+
+/// Helvetica advance widths for ASCII 32..=126, in 1/1000 em (from the
+/// standard AFM). Bold is approximated by scaling.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // ' '../
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // 0-9
+    278, 278, 584, 584, 584, 556, 1015, // :..@
+    667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, // A-M
+    722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, // N-Z
+    278, 278, 278, 469, 556, 333, // [..`
+    556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, // a-m
+    556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, // n-z
+    334, 260, 334, 584, // {..~
+];
+
+/// Width of `text` in points when set in `font`.
+pub fn text_width(text: &str, font: Font) -> f64 {
+    let units: u32 = text
+        .chars()
+        .map(|c| match c as u32 {
+            n @ 32..=126 => HELVETICA_WIDTHS[(n - 32) as usize] as u32,
+            _ => 556,
+        })
+        .sum();
+    let scale = if font.bold { 1.05 } else { 1.0 };
+    units as f64 / 1000.0 * font.size * scale
+}
+
+/// `text` shortened with a trailing "..." so it fits in `max_width`.
+pub fn fit(text: &str, font: Font, max_width: f64) -> String {
+    if text_width(text, font) <= max_width {
+        return text.to_string();
+    }
+    let mut out: String = text.to_string();
+    while !out.is_empty() && text_width(&format!("{out}..."), font) > max_width {
+        out.pop();
+    }
+    format!("{}...", out.trim_end())
+}
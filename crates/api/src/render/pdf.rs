@@ -0,0 +1,174 @@
+//! Minimal single-page PDF 1.4 writer. Uses the standard Helvetica faces, so
+//! no fonts are embedded; content streams are left uncompressed.
+
+use std::fmt::Write;
+
+use crate::render::{text_width, Anchor, Canvas, Shape};
+
+/// Bezier control distance for a quarter circle.
+const KAPPA: f64 = 0.552_284_75;
+
+/// Encode text as a PDF literal string in WinAnsiEncoding. Characters outside
+/// Latin-1 become '?'.
+fn literal(text: &str) -> Vec<u8> {
+    let mut out = vec![b'('];
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                out.push(b'\\');
+                out.push(c as u8);
+            }
+            ' '..='~' => out.push(c as u8),
+            '\u{a0}'..='\u{ff}' => out.push(c as u32 as u8),
+            _ => out.push(b'?'),
+        }
+    }
+    out.push(b')');
+    out
+}
+
+fn content_stream(canvas: &Canvas) -> Vec<u8> {
+    let h = canvas.height;
+    let mut out: Vec<u8> = Vec::with_capacity(canvas.shapes.len() * 60);
+    let mut ops = String::new();
+    for shape in &canvas.shapes {
+        ops.clear();
+        match shape {
+            Shape::Rect {
+                x,
+                y,
+                w,
+                h: rh,
+                stroke,
+                fill,
+            } => {
+                let y = h - y - rh;
+                if let Some(g) = fill {
+                    let _ = writeln!(ops, "{g:.3} g {x:.2} {y:.2} {w:.2} {rh:.2} re f");
+                }
+                if *stroke > 0.0 {
+                    let _ = writeln!(ops, "{stroke} w {x:.2} {y:.2} {w:.2} {rh:.2} re S");
+                }
+            }
+            Shape::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                stroke,
+            } => {
+                let _ = writeln!(
+                    ops,
+                    "{stroke} w {x1:.2} {:.2} m {x2:.2} {:.2} l S",
+                    h - y1,
+                    h - y2
+                );
+            }
+            Shape::Circle { cx, cy, r } => {
+                let cy = h - cy;
+                let k = r * KAPPA;
+                let _ = writeln!(
+                    ops,
+                    "0.5 w {:.2} {cy:.2} m \
+                     {:.2} {:.2} {:.2} {:.2} {cx:.2} {:.2} c \
+                     {:.2} {:.2} {:.2} {:.2} {:.2} {cy:.2} c \
+                     {:.2} {:.2} {:.2} {:.2} {cx:.2} {:.2} c \
+                     {:.2} {:.2} {:.2} {:.2} {:.2} {cy:.2} c S",
+                    cx + r,
+                    cx + r,
+                    cy + k,
+                    cx + k,
+                    cy + r,
+                    cy + r,
+                    cx - k,
+                    cy + r,
+                    cx - r,
+                    cy + k,
+                    cx - r,
+                    cx - r,
+                    cy - k,
+                    cx - k,
+                    cy - r,
+                    cy - r,
+                    cx + k,
+                    cy - r,
+                    cx + r,
+                    cy - k,
+                    cx + r,
+                );
+            }
+            Shape::Text {
+                x,
+                y,
+                font,
+                anchor,
+                text,
+            } => {
+                let x = match anchor {
+                    Anchor::Start => *x,
+                    Anchor::Middle => x - text_width(text, *font) / 2.0,
+                    Anchor::End => x - text_width(text, *font),
+                };
+                let face = if font.bold { "F2" } else { "F1" };
+                let _ = write!(
+                    ops,
+                    "0 g BT /{face} {} Tf {x:.2} {:.2} Td ",
+                    font.size,
+                    h - y
+                );
+                out.extend_from_slice(ops.as_bytes());
+                out.extend_from_slice(&literal(text));
+                out.extend_from_slice(b" Tj ET\n");
+                continue;
+            }
+        }
+        out.extend_from_slice(ops.as_bytes());
+    }
+    out
+}
+
+/// Serialise a canvas as a one-page PDF document.
+pub fn write(canvas: &Canvas) -> Vec<u8> {
+    let content = content_stream(canvas);
+    let objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>",
+            canvas.width, canvas.height
+        )
+        .into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+        [
+            format!("<< /Length {} >>\nstream\n", content.len()).into_bytes(),
+            content,
+            b"\nendstream".to_vec(),
+        ]
+        .concat(),
+    ];
+
+    let mut out: Vec<u8> = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, body) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        out.extend_from_slice(body);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+    let xref = out.len();
+    let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for off in offsets {
+        let _ = writeln!(trailer, "{off:010} 00000 n ");
+    }
+    let _ = write!(
+        trailer,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+        objects.len() + 1
+    );
+    out.extend_from_slice(trailer.as_bytes());
+    out
+}
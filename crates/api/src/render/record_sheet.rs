@@ -0,0 +1,703 @@
+//! Printable 'Mech record sheet: unit data, weapons inventory, armor and
+//! internal structure diagrams with pips, heat data and a warrior block.
+
+use sqlx::PgPool;
+
+use crate::{
+    calc::mech::{dec, MechConfig},
+    db::{
+        construction,
+        models::{DbMechData, DbSheetEquipment, DbUnit},
+        units,
+    },
+    error::AppError,
+    render::{fit, Anchor, Canvas, Font, PAGE_HEIGHT, PAGE_WIDTH},
+};
+
+const MARGIN: f64 = 24.0;
+const LEFT_W: f64 = 300.0;
+const RIGHT_X: f64 = MARGIN + LEFT_W + 12.0;
+const RIGHT_W: f64 = PAGE_WIDTH - MARGIN - RIGHT_X;
+
+/// Height of a section's title band.
+const BAND: f64 = 14.0;
+
+/// Inventory lines that fit on the sheet; the rest are summarised.
+const MAX_INVENTORY_ROWS: usize = 36;
+
+/// Heat levels at which a new overheat effect starts (Total Warfare).
+const HEAT_EFFECTS: &[(i32, &str)] = &[
+    (5, "-1 MP"),
+    (8, "+1 to-hit"),
+    (10, "-2 MP"),
+    (13, "+2 to-hit"),
+    (14, "Shutdown 4+"),
+    (15, "-3 MP"),
+    (17, "+3 to-hit"),
+    (18, "Shutdown 6+"),
+    (19, "Ammo exp. 4+"),
+    (20, "-4 MP"),
+    (22, "Shutdown 8+"),
+    (23, "Ammo exp. 6+"),
+    (24, "+4 to-hit"),
+    (25, "-5 MP"),
+    (26, "Shutdown 10+"),
+    (28, "Ammo exp. 8+"),
+    (30, "Shutdown"),
+];
+
+// ── Data ─────────────────────────────────────────────────────────────────────
+
+/// Armor and internal structure for one location.
+#[derive(Debug, Clone)]
+pub struct SheetLocation {
+    pub location: String,
+    pub armor: i32,
+    pub rear: Option<i32>,
+    pub structure: i32,
+}
+
+/// Everything printed on a record sheet.
+#[derive(Debug, Clone)]
+pub struct RecordSheet {
+    pub unit: DbUnit,
+    pub mech: DbMechData,
+    pub locations: Vec<SheetLocation>,
+    pub equipment: Vec<DbSheetEquipment>,
+    /// Heat dissipated per turn by all heat sinks.
+    pub dissipation: i32,
+}
+
+/// Load the record sheet data for a mech by slug.
+pub async fn load(pool: &PgPool, slug: &str) -> Result<RecordSheet, AppError> {
+    let unit = units::get_by_slug(pool, slug)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("unit '{slug}' not found")))?;
+    let mech = units::get_mech_data_batch(pool, &[unit.id])
+        .await?
+        .pop()
+        .ok_or_else(|| {
+            AppError::Validation(format!(
+                "record sheets are only available for mechs; '{slug}' is not a mech"
+            ))
+        })?;
+
+    let tonnage = dec(unit.tonnage, 0.0).round() as i16;
+    let (locations, equipment, heatsinks, internal) = tokio::try_join!(
+        units::get_locations(pool, unit.id),
+        units::get_sheet_equipment(pool, unit.id),
+        construction::list_heatsink_types(pool, None, None),
+        construction::get_internal_structure(pool, tonnage),
+    )?;
+
+    let per_sink = mech
+        .heatsink_type_id
+        .and_then(|id| heatsinks.iter().find(|h| h.id == id))
+        .map_or(1, |h| h.dissipation as i32);
+    let locations = locations
+        .into_iter()
+        .map(|l| {
+            let table = internal.as_ref().map_or(0, |is| {
+                (match l.location.as_str() {
+                    "head" => is.head,
+                    "center_torso" => is.center_torso,
+                    "left_torso" | "right_torso" => is.side_torso,
+                    "left_arm" | "right_arm" => is.arm,
+                    _ => is.leg,
+                }) as i32
+            });
+            SheetLocation {
+                armor: l.armor_points.unwrap_or(0),
+                rear: l.rear_armor,
+                structure: l.structure_points.unwrap_or(table),
+                location: l.location,
+            }
+        })
+        .collect();
+
+    Ok(RecordSheet {
+        dissipation: mech.heat_sink_count.unwrap_or(0) * per_sink,
+        unit,
+        mech,
+        locations,
+        equipment,
+    })
+}
+
+// ── Formatting helpers ───────────────────────────────────────────────────────
+
+fn title_case(s: &str) -> String {
+    s.split('_')
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut c = w.chars();
+            match c.next() {
+                Some(f) => f.to_uppercase().chain(c).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn thousands(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::new();
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(ch);
+    }
+    if n < 0 {
+        format!("-{out}")
+    } else {
+        out
+    }
+}
+
+fn or_dash(v: Option<i32>) -> String {
+    v.filter(|&n| n > 0)
+        .map_or_else(|| "-".to_string(), |n| n.to_string())
+}
+
+/// Short location code for the inventory, by chassis configuration.
+fn location_code(location: &str, config: MechConfig) -> &'static str {
+    match (location, config) {
+        ("head", _) => "HD",
+        ("center_torso", _) => "CT",
+        ("left_torso", _) => "LT",
+        ("right_torso", _) => "RT",
+        ("left_arm" | "front_left_leg", MechConfig::Quad) => "FLL",
+        ("right_arm" | "front_right_leg", MechConfig::Quad) => "FRL",
+        ("left_leg" | "rear_left_leg", MechConfig::Quad) => "RLL",
+        ("right_leg" | "rear_right_leg", MechConfig::Quad) => "RRL",
+        ("left_arm", _) => "LA",
+        ("right_arm", _) => "RA",
+        ("left_leg", _) => "LL",
+        ("right_leg", _) => "RL",
+        ("center_leg", _) => "CL",
+        _ => "-",
+    }
+}
+
+/// Height of the front armor diagram frame; it is 236 points wide.
+const DIAGRAM_H: f64 = 210.0;
+
+/// Diagram box for each location in the front frame: (locations, x, y, w, h).
+/// Quads draw their front legs in the arm positions and rear legs as legs.
+fn diagram_boxes(config: MechConfig) -> Vec<(&'static [&'static str], f64, f64, f64, f64)> {
+    let mut boxes: Vec<(&'static [&'static str], f64, f64, f64, f64)> = vec![
+        (&["head"], 98.0, 0.0, 40.0, 28.0),
+        (&["left_arm", "front_left_leg"], 0.0, 34.0, 46.0, 82.0),
+        (&["left_torso"], 48.0, 30.0, 48.0, 80.0),
+        (&["center_torso"], 98.0, 30.0, 40.0, 92.0),
+        (&["right_torso"], 140.0, 30.0, 48.0, 80.0),
+        (&["right_arm", "front_right_leg"], 190.0, 34.0, 46.0, 82.0),
+        (&["left_leg", "rear_left_leg"], 50.0, 114.0, 46.0, 96.0),
+        (&["right_leg", "rear_right_leg"], 140.0, 114.0, 46.0, 96.0),
+    ];
+    if config == MechConfig::Tripod {
+        boxes.push((&["center_leg"], 98.0, 126.0, 40.0, 84.0));
+    }
+    boxes
+}
+
+/// Rear torso boxes, drawn below the front diagram: (location, x, w).
+const REAR_BOXES: &[(&str, f64, f64)] = &[
+    ("left_torso", 48.0, 48.0),
+    ("center_torso", 98.0, 40.0),
+    ("right_torso", 140.0, 48.0),
+];
+
+/// Height of the rear torso boxes.
+const REAR_H: f64 = 44.0;
+
+// ── Drawing ──────────────────────────────────────────────────────────────────
+
+/// Outlined section with a shaded title band.
+fn section(c: &mut Canvas, x: f64, y: f64, w: f64, h: f64, title: &str) {
+    c.fill_rect(x, y, w, BAND, 0.85);
+    c.rect(x, y, w, h, 1.0);
+    c.line(x, y + BAND, x + w, y + BAND, 0.5);
+    c.text(x + 6.0, y + 10.0, Font::bold(9.0), Anchor::Start, title);
+}
+
+/// Fill a box with `n` pips in rows, as large as will fit (at most `max`).
+fn pips(c: &mut Canvas, x: f64, y: f64, w: f64, h: f64, n: i32, max: f64) {
+    if n <= 0 || w <= 0.0 || h <= 0.0 {
+        return;
+    }
+    let n = n as usize;
+    let mut s = max;
+    while s > 1.5 {
+        let cols = (w / s).floor() as usize;
+        let rows = (h / s).floor() as usize;
+        if cols * rows >= n {
+            break;
+        }
+        s -= 0.25;
+    }
+    let cols = ((w / s).floor() as usize).max(1);
+    let r = s * 0.36;
+    for row in 0..n.div_ceil(cols) {
+        let in_row = (n - row * cols).min(cols);
+        let x0 = x + (w - in_row as f64 * s) / 2.0 + s / 2.0;
+        for col in 0..in_row {
+            c.circle(x0 + col as f64 * s, y + s / 2.0 + row as f64 * s, r);
+        }
+    }
+}
+
+/// A location box with its code, point count and pips.
+#[allow(clippy::too_many_arguments)]
+fn location_box(c: &mut Canvas, x: f64, y: f64, w: f64, h: f64, label: &str, n: i32, pip: f64) {
+    c.rect(x, y, w, h, 0.75);
+    let font = Font::bold((pip + 1.5).min(6.5));
+    c.text(
+        x + 2.0,
+        y + font.size + 1.0,
+        font,
+        Anchor::Start,
+        format!("{label} ({n})"),
+    );
+    let top = font.size + 3.0;
+    pips(c, x + 2.0, y + top, w - 4.0, h - top - 2.0, n, pip);
+}
+
+fn labelled_rows(
+    c: &mut Canvas,
+    x: f64,
+    y: f64,
+    value_x: f64,
+    width: f64,
+    rows: &[(&str, String)],
+) {
+    for (i, (label, value)) in rows.iter().enumerate() {
+        let ry = y + i as f64 * 11.0;
+        c.text(x, ry, Font::bold(7.5), Anchor::Start, format!("{label}:"));
+        c.text_fit(
+            value_x,
+            ry,
+            Font::regular(7.5),
+            width - (value_x - x),
+            value,
+        );
+    }
+}
+
+fn header(c: &mut Canvas, sheet: &RecordSheet) {
+    let u = &sheet.unit;
+    c.text(MARGIN, 40.0, Font::bold(18.0), Anchor::Start, "BATTLETECH");
+    c.text(
+        PAGE_WIDTH - MARGIN,
+        40.0,
+        Font::bold(11.0),
+        Anchor::End,
+        "'MECH RECORD SHEET",
+    );
+    c.line(MARGIN, 47.0, PAGE_WIDTH - MARGIN, 47.0, 1.5);
+    let name = fit(&u.full_name, Font::bold(13.0), PAGE_WIDTH - 2.0 * MARGIN);
+    c.text(MARGIN, 66.0, Font::bold(13.0), Anchor::Start, name);
+    let mut sub = Vec::new();
+    if let Some(clan) = &u.clan_name {
+        sub.push(format!("Clan name: {clan}"));
+    }
+    if let Some(role) = &u.role {
+        sub.push(format!("Role: {role}"));
+    }
+    if let Some(book) = &u.source_book {
+        sub.push(format!("Source: {book}"));
+    }
+    if let Some(year) = u.intro_year {
+        sub.push(format!("Introduced {year}"));
+    }
+    c.text(
+        MARGIN,
+        79.0,
+        Font::regular(8.0),
+        Anchor::Start,
+        sub.join("   "),
+    );
+}
+
+fn unit_data(c: &mut Canvas, sheet: &RecordSheet, y: f64) -> f64 {
+    let (u, m) = (&sheet.unit, &sheet.mech);
+    let tons = dec(u.tonnage, 0.0);
+    let walk = m
+        .walk_mp
+        .or_else(|| m.engine_rating.map(|r| r / (tons.max(1.0) as i32)))
+        .unwrap_or(0);
+    let engine = match (m.engine_rating, m.engine_type.as_deref()) {
+        (Some(r), Some(t)) => format!("{r} {t}"),
+        (Some(r), None) => r.to_string(),
+        (None, Some(t)) => t.to_string(),
+        (None, None) => "-".into(),
+    };
+    let text = |v: Option<&str>| v.unwrap_or("-").to_string();
+    let left = [
+        (
+            "Tonnage",
+            if tons.fract() == 0.0 {
+                format!("{tons:.0}")
+            } else {
+                format!("{tons:.1}")
+            },
+        ),
+        ("Tech Base", title_case(&u.tech_base)),
+        ("Rules Level", title_case(&u.rules_level)),
+        (
+            "Config",
+            format!("{}{}", m.config, if m.is_omnimech { " Omni" } else { "" }),
+        ),
+        ("Engine", engine),
+        ("Gyro", text(m.gyro_type.as_deref())),
+        ("Cockpit", text(m.cockpit_type.as_deref())),
+        ("Myomer", text(m.myomer_type.as_deref())),
+    ];
+    let right = [
+        ("Walking MP", walk.to_string()),
+        ("Running MP", ((walk * 3 + 1) / 2).to_string()),
+        ("Jumping MP", m.jump_mp.unwrap_or(0).to_string()),
+        ("Armor", text(m.armor_type.as_deref())),
+        ("Structure", text(m.structure_type.as_deref())),
+        (
+            "Battle Value",
+            u.bv.map_or_else(|| "-".into(), |v| thousands(v as i64)),
+        ),
+        (
+            "Cost",
+            u.cost
+                .map_or_else(|| "-".into(), |v| format!("{} C-bills", thousands(v))),
+        ),
+    ];
+    let h = BAND + 12.0 + 8.0 * 11.0;
+    section(c, MARGIN, y, LEFT_W, h, "'MECH DATA");
+    let half = LEFT_W / 2.0;
+    labelled_rows(
+        c,
+        MARGIN + 6.0,
+        y + BAND + 12.0,
+        MARGIN + 62.0,
+        half - 10.0,
+        &left,
+    );
+    labelled_rows(
+        c,
+        MARGIN + half,
+        y + BAND + 12.0,
+        MARGIN + half + 58.0,
+        half - 6.0,
+        &right,
+    );
+    y + h
+}
+
+fn inventory(c: &mut Canvas, sheet: &RecordSheet, y: f64) -> f64 {
+    let config = MechConfig::parse(&sheet.mech.config).unwrap_or(MechConfig::Biped);
+    let lines = &sheet.equipment;
+    let shown = if lines.len() > MAX_INVENTORY_ROWS {
+        MAX_INVENTORY_ROWS - 1
+    } else {
+        lines.len()
+    };
+    let row_count = lines.len().clamp(1, MAX_INVENTORY_ROWS);
+    let h = BAND + 14.0 + row_count as f64 * 10.0 + 4.0;
+    section(c, MARGIN, y, LEFT_W, h, "WEAPONS & EQUIPMENT INVENTORY");
+
+    // (title, x offset, anchor)
+    let cols: [(&str, f64, Anchor); 9] = [
+        ("Qty", 12.0, Anchor::Middle),
+        ("Type", 24.0, Anchor::Start),
+        ("Loc", 146.0, Anchor::Middle),
+        ("Ht", 172.0, Anchor::Middle),
+        ("Dmg", 198.0, Anchor::Middle),
+        ("Min", 226.0, Anchor::Middle),
+        ("Sht", 246.0, Anchor::Middle),
+        ("Med", 264.0, Anchor::Middle),
+        ("Lng", 282.0, Anchor::Middle),
+    ];
+    let hy = y + BAND + 10.0;
+    for (title, dx, anchor) in cols {
+        c.text(MARGIN + dx, hy, Font::bold(7.0), anchor, title);
+    }
+    let font = Font::regular(7.5);
+    for (i, e) in lines.iter().take(shown).enumerate() {
+        let ry = hy + 11.0 + i as f64 * 10.0;
+        let loc = e
+            .location
+            .as_deref()
+            .map_or("-", |l| location_code(l, config));
+        let loc = if e.is_rear {
+            format!("{loc} (R)")
+        } else {
+            loc.to_string()
+        };
+        let values = [
+            e.count.to_string(),
+            e.name.clone(),
+            loc,
+            e.heat.map_or_else(|| "-".into(), |h| h.to_string()),
+            e.damage.clone().unwrap_or_else(|| "-".into()),
+            or_dash(e.range_min),
+            or_dash(e.range_short),
+            or_dash(e.range_medium),
+            or_dash(e.range_long),
+        ];
+        for (j, ((_, dx, anchor), value)) in cols.iter().zip(values).enumerate() {
+            if j == 1 {
+                c.text_fit(MARGIN + dx, ry, font, 108.0, &value);
+            } else {
+                let value = fit(&value, font, if j == 4 { 30.0 } else { 24.0 });
+                c.text(MARGIN + dx, ry, font, *anchor, value);
+            }
+        }
+    }
+    if lines.is_empty() {
+        c.text(MARGIN + 24.0, hy + 11.0, font, Anchor::Start, "None");
+    } else if shown < lines.len() {
+        let ry = hy + 11.0 + shown as f64 * 10.0;
+        c.text(
+            MARGIN + 24.0,
+            ry,
+            Font::bold(7.5),
+            Anchor::Start,
+            format!("... and {} more", lines.len() - shown),
+        );
+    }
+    y + h
+}
+
+fn warrior(c: &mut Canvas, y: f64) -> f64 {
+    let h = BAND + 62.0;
+    section(c, MARGIN, y, LEFT_W, h, "WARRIOR DATA");
+    let x = MARGIN + 6.0;
+    let font = Font::bold(7.5);
+    c.text(x, y + BAND + 13.0, font, Anchor::Start, "Name:");
+    c.line(
+        x + 28.0,
+        y + BAND + 14.0,
+        MARGIN + LEFT_W - 8.0,
+        y + BAND + 14.0,
+        0.5,
+    );
+    c.text(x, y + BAND + 28.0, font, Anchor::Start, "Gunnery Skill:");
+    c.line(x + 58.0, y + BAND + 29.0, x + 100.0, y + BAND + 29.0, 0.5);
+    c.text(
+        x + 130.0,
+        y + BAND + 28.0,
+        font,
+        Anchor::Start,
+        "Piloting Skill:",
+    );
+    c.line(x + 188.0, y + BAND + 29.0, x + 230.0, y + BAND + 29.0, 0.5);
+
+    let by = y + BAND + 36.0;
+    c.text(x, by + 9.0, font, Anchor::Start, "Hits Taken");
+    c.text(x, by + 20.0, font, Anchor::Start, "Consciousness #");
+    for (i, label) in ["3", "5", "7", "10", "11", "Dead"].iter().enumerate() {
+        let bx = x + 72.0 + i as f64 * 34.0;
+        c.rect(bx, by + 1.0, 30.0, 10.0, 0.75);
+        c.text(
+            bx + 15.0,
+            by + 9.0,
+            Font::regular(7.0),
+            Anchor::Middle,
+            (i + 1).to_string(),
+        );
+        c.text(
+            bx + 15.0,
+            by + 20.0,
+            Font::regular(7.0),
+            Anchor::Middle,
+            *label,
+        );
+    }
+    y + h
+}
+
+fn armor_diagram(c: &mut Canvas, sheet: &RecordSheet, y: f64) -> f64 {
+    let config = MechConfig::parse(&sheet.mech.config).unwrap_or(MechConfig::Biped);
+    let h = BAND + 8.0 + DIAGRAM_H + 8.0 + REAR_H + 8.0;
+    let total: i32 = sheet
+        .locations
+        .iter()
+        .map(|l| l.armor + l.rear.unwrap_or(0))
+        .sum();
+    section(c, RIGHT_X, y, RIGHT_W, h, "ARMOR DIAGRAM");
+    c.text(
+        RIGHT_X + RIGHT_W - 6.0,
+        y + 10.0,
+        Font::regular(7.5),
+        Anchor::End,
+        format!("Total {total}"),
+    );
+    let ox = RIGHT_X + (RIGHT_W - 236.0) / 2.0;
+    let oy = y + BAND + 8.0;
+    for (names, bx, by, bw, bh) in diagram_boxes(config) {
+        let Some(loc) = sheet
+            .locations
+            .iter()
+            .find(|l| names.contains(&l.location.as_str()))
+        else {
+            continue;
+        };
+        let label = location_code(&loc.location, config);
+        location_box(c, ox + bx, oy + by, bw, bh, label, loc.armor, 7.0);
+    }
+
+    let ry = oy + DIAGRAM_H + 8.0;
+    c.text(
+        ox,
+        ry + REAR_H / 2.0 + 3.0,
+        Font::bold(7.5),
+        Anchor::Start,
+        "REAR",
+    );
+    for &(name, bx, bw) in REAR_BOXES {
+        let Some(loc) = sheet.locations.iter().find(|l| l.location == name) else {
+            continue;
+        };
+        let label = format!("{} (R)", location_code(name, config));
+        location_box(
+            c,
+            ox + bx,
+            ry,
+            bw,
+            REAR_H,
+            &label,
+            loc.rear.unwrap_or(0),
+            7.0,
+        );
+    }
+    y + h
+}
+
+fn structure_diagram(c: &mut Canvas, sheet: &RecordSheet, y: f64) -> f64 {
+    const SCALE: f64 = 0.62;
+    let config = MechConfig::parse(&sheet.mech.config).unwrap_or(MechConfig::Biped);
+    let h = BAND + 8.0 + DIAGRAM_H * SCALE + 8.0;
+    section(c, RIGHT_X, y, RIGHT_W, h, "INTERNAL STRUCTURE DIAGRAM");
+    let ox = RIGHT_X + (RIGHT_W - 236.0 * SCALE) / 2.0;
+    let oy = y + BAND + 8.0;
+    for (names, bx, by, bw, bh) in diagram_boxes(config) {
+        let Some(loc) = sheet
+            .locations
+            .iter()
+            .find(|l| names.contains(&l.location.as_str()))
+        else {
+            continue;
+        };
+        let label = location_code(&loc.location, config);
+        location_box(
+            c,
+            ox + bx * SCALE,
+            oy + by * SCALE,
+            bw * SCALE,
+            bh * SCALE,
+            label,
+            loc.structure,
+            5.0,
+        );
+    }
+    y + h
+}
+
+fn heat_data(c: &mut Canvas, sheet: &RecordSheet, y: f64) -> f64 {
+    let m = &sheet.mech;
+    let sinks = m.heat_sink_count.unwrap_or(0);
+    let weapon_heat: i32 = sheet
+        .equipment
+        .iter()
+        .map(|e| e.heat.unwrap_or(0) * e.count)
+        .sum();
+    let h = BAND + 92.0;
+    section(c, RIGHT_X, y, RIGHT_W, h, "HEAT DATA");
+    let x = RIGHT_X + 6.0;
+    let font = Font::regular(7.5);
+    let kind = m.heat_sink_type.as_deref().unwrap_or("Single");
+    c.text(
+        x,
+        y + BAND + 11.0,
+        Font::bold(7.5),
+        Anchor::Start,
+        format!("Heat Sinks: {sinks} ({kind})"),
+    );
+    c.text(
+        x,
+        y + BAND + 22.0,
+        font,
+        Anchor::Start,
+        format!(
+            "Dissipation {} / turn   Weapon heat {weapon_heat}",
+            sheet.dissipation
+        ),
+    );
+    pips(
+        c,
+        x + 120.0,
+        y + BAND + 3.0,
+        RIGHT_W - 132.0,
+        22.0,
+        sinks,
+        6.0,
+    );
+
+    // Heat scale: one cell per level, shaded where a new effect starts.
+    let cell = (RIGHT_W - 12.0) / 31.0;
+    let sy = y + BAND + 30.0;
+    for level in 0..=30 {
+        let cx = x + level as f64 * cell;
+        if HEAT_EFFECTS.iter().any(|&(l, _)| l == level) {
+            c.fill_rect(cx, sy, cell, 12.0, 0.8);
+        }
+        c.rect(cx, sy, cell, 12.0, 0.5);
+        c.text(
+            cx + cell / 2.0,
+            sy + 8.5,
+            Font::regular(5.5),
+            Anchor::Middle,
+            level.to_string(),
+        );
+    }
+
+    // Effects legend in three columns.
+    let per_col = HEAT_EFFECTS.len().div_ceil(3);
+    let col_w = (RIGHT_W - 12.0) / 3.0;
+    for (i, (level, effect)) in HEAT_EFFECTS.iter().enumerate() {
+        let lx = x + (i / per_col) as f64 * col_w;
+        let ly = sy + 20.0 + (i % per_col) as f64 * 7.0;
+        c.text(
+            lx,
+            ly,
+            Font::regular(5.5),
+            Anchor::Start,
+            format!("{level}: {effect}"),
+        );
+    }
+    y + h
+}
+
+/// Lay out the record sheet on one US Letter page.
+pub fn render(sheet: &RecordSheet) -> Canvas {
+    let mut c = Canvas::new(PAGE_WIDTH, PAGE_HEIGHT);
+    header(&mut c, sheet);
+
+    let top = 90.0;
+    let y = unit_data(&mut c, sheet, top);
+    let y = inventory(&mut c, sheet, y + 10.0);
+    warrior(&mut c, y + 10.0);
+
+    let y = armor_diagram(&mut c, sheet, top);
+    let y = structure_diagram(&mut c, sheet, y + 10.0);
+    heat_data(&mut c, sheet, y + 10.0);
+
+    c.text(
+        PAGE_WIDTH / 2.0,
+        PAGE_HEIGHT - 14.0,
+        Font::regular(6.0),
+        Anchor::Middle,
+        "BattleTech is a trademark of The Topps Company, Inc. Unit data from MegaMek and the Master Unit List.",
+    );
+    c
+}
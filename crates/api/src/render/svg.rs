@@ -0,0 +1,91 @@
+use std::fmt::Write;
+
+use crate::render::{Anchor, Canvas, Shape};
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn gray(level: f64) -> String {
+    let v = (level.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("#{v:02x}{v:02x}{v:02x}")
+}
+
+/// Serialise a canvas as a standalone SVG document sized in points.
+pub fn write(canvas: &Canvas) -> String {
+    let mut out = String::with_capacity(canvas.shapes.len() * 80);
+    let _ = write!(
+        out,
+        r##"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="{w}pt" height="{h}pt" viewBox="0 0 {w} {h}" font-family="Helvetica, Arial, sans-serif">
+<rect width="{w}" height="{h}" fill="#ffffff"/>
+"##,
+        w = canvas.width,
+        h = canvas.height,
+    );
+    for shape in &canvas.shapes {
+        match shape {
+            Shape::Rect {
+                x,
+                y,
+                w,
+                h,
+                stroke,
+                fill,
+            } => {
+                let fill = fill.map(gray).unwrap_or_else(|| "none".into());
+                let _ = write!(
+                    out,
+                    r#"<rect x="{x:.1}" y="{y:.1}" width="{w:.1}" height="{h:.1}" fill="{fill}""#
+                );
+                if *stroke > 0.0 {
+                    let _ = write!(out, r##" stroke="#000000" stroke-width="{stroke}""##);
+                }
+                out.push_str("/>\n");
+            }
+            Shape::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                stroke,
+            } => {
+                let _ = writeln!(
+                    out,
+                    r##"<line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="#000000" stroke-width="{stroke}"/>"##
+                );
+            }
+            Shape::Circle { cx, cy, r } => {
+                let _ = writeln!(
+                    out,
+                    r##"<circle cx="{cx:.2}" cy="{cy:.2}" r="{r:.2}" fill="none" stroke="#000000" stroke-width="0.5"/>"##
+                );
+            }
+            Shape::Text {
+                x,
+                y,
+                font,
+                anchor,
+                text,
+            } => {
+                let anchor = match anchor {
+                    Anchor::Start => "start",
+                    Anchor::Middle => "middle",
+                    Anchor::End => "end",
+                };
+                let weight = if font.bold { "bold" } else { "normal" };
+                let _ = writeln!(
+                    out,
+                    r#"<text x="{x:.1}" y="{y:.1}" font-size="{}" font-weight="{weight}" text-anchor="{anchor}">{}</text>"#,
+                    font.size,
+                    escape(text)
+                );
+            }
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
@@ -0,0 +1,494 @@
+//! Alpha Strike conversion for units without a MUL card.
+//!
+//! A simplified form of the Alpha Strike Companion conversion, driven only by
+//! data we already store (movement, armor, internal structure and weapon
+//! loadout). It covers BattleMechs, IndustrialMechs and combat vehicles. Point
+//! Value is not derived: it depends on special abilities and role modifiers
+//! that need the full conversion, so converted rows leave it NULL. MUL cards always take
+//! precedence and are never overwritten.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+use tracing::info;
+
+use crate::equipment_catalog::catalog;
+
+/// Minimum long range (in hexes) a weapon needs to count in each Alpha Strike
+/// range bracket: short 0-3, medium 4-12, long 13-21.
+const BRACKET_MIN_HEX: [i32; 3] = [1, 4, 13];
+
+/// Average fraction of a missile cluster that hits (cluster table at 7).
+const CLUSTER_FACTOR: f64 = 0.6;
+
+struct Candidate {
+    id: i32,
+    tonnage: f64,
+    unit_type: String,
+    walk_mp: Option<i32>,
+    jump_mp: Option<i32>,
+    heat_sinks: i32,
+    double_heat_sinks: bool,
+    /// Engine BV multiplier (`engine_types.bv_multiplier`). An approximation:
+    /// the Alpha Strike Companion reads mech structure from a table by tonnage
+    /// and engine type, which scaling internal structure by this multiplier
+    /// only roughly follows.
+    engine_mult: f64,
+    cruise_mp: Option<i32>,
+    motion_type: Option<String>,
+}
+
+struct Weapon {
+    slug: String,
+    damage: String,
+    /// Missiles per salvo, from the equipment catalog.
+    rack_size: Option<i32>,
+    range_long: Option<i32>,
+    heat: i32,
+    count: i32,
+    is_rear: bool,
+}
+
+struct Card {
+    size: i32,
+    movement: String,
+    move_inches: i32,
+    jump_inches: Option<i32>,
+    tmm: i32,
+    armor: i32,
+    structure: i32,
+    damage: [(i32, bool); 3],
+    overheat: i32,
+    specials: Vec<String>,
+}
+
+/// Run the alpha-strike-convert subcommand.
+pub async fn run(database_url: &str, pool_size: u32, force: bool) -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(pool_size)
+        .connect(database_url)
+        .await
+        .context("connecting to database")?;
+
+    let candidates = load_candidates(&pool, force).await?;
+    info!(count = candidates.len(), force, "units to convert");
+
+    let totals = load_location_totals(&pool).await?;
+    let mech_structure = load_mech_structure(&pool).await?;
+    let weapons = load_weapons(&pool).await?;
+
+    let mut converted = 0usize;
+    let mut skipped = 0usize;
+    let empty = Vec::new();
+
+    for c in &candidates {
+        let (armor_points, structure_points) = totals.get(&c.id).copied().unwrap_or((0, None));
+        let structure_points = match c.unit_type.as_str() {
+            "mech" | "industrialmech" => mech_structure.get(&(c.tonnage.round() as i32)).copied(),
+            _ => structure_points,
+        };
+        let Some(card) = convert(
+            c,
+            armor_points,
+            structure_points,
+            weapons.get(&c.id).unwrap_or(&empty),
+        ) else {
+            skipped += 1;
+            continue;
+        };
+        upsert_conversion(&pool, c.id, &c.unit_type, &card).await?;
+        converted += 1;
+    }
+
+    info!(converted, skipped, "alpha strike conversion complete");
+    Ok(())
+}
+
+// ── conversion ───────────────────────────────────────────────────────────────
+
+fn convert(
+    c: &Candidate,
+    armor_points: i32,
+    structure_points: Option<i32>,
+    weapons: &[Weapon],
+) -> Option<Card> {
+    let size = match c.tonnage.round() as i32 {
+        ..=35 => 1,
+        36..=55 => 2,
+        56..=75 => 3,
+        _ => 4,
+    };
+
+    let (movement, move_inches, jump_inches, structure) = match c.unit_type.as_str() {
+        "mech" | "industrialmech" => {
+            let walk = c.walk_mp?;
+            let mv = walk * 2;
+            let jump = c.jump_mp.unwrap_or(0) * 2;
+            let movement = if jump == 0 {
+                format!("{mv}\"")
+            } else if jump == mv {
+                format!("{mv}\"j")
+            } else {
+                format!("{mv}\"/{jump}\"j")
+            };
+            // Engines that lose side torsos (XL, XXL) reduce effective
+            // structure; see `Candidate::engine_mult` for how closely.
+            let structure = ((structure_points? as f64 * c.engine_mult) / 20.0).round() as i32;
+            (movement, mv, (jump > 0).then_some(jump), structure)
+        }
+        "vehicle" => {
+            let mv = c.cruise_mp? * 2;
+            let code = match c.motion_type.as_deref() {
+                Some("tracked") => "t",
+                Some("wheeled") => "w",
+                Some("hover") => "h",
+                Some("vtol") => "v",
+                Some("naval") | Some("hydrofoil") => "n",
+                Some("submarine") => "s",
+                Some("wige") => "g",
+                _ => "",
+            };
+            let structure = (structure_points.unwrap_or(0) as f64 / 10.0).round() as i32;
+            (format!("{mv}\"{code}"), mv, None, structure)
+        }
+        _ => return None,
+    };
+
+    let best_move = move_inches.max(jump_inches.unwrap_or(0));
+    let tmm = match best_move {
+        ..=4 => 0,
+        5..=8 => 1,
+        9..=12 => 2,
+        13..=18 => 3,
+        19..=34 => 4,
+        _ => 5,
+    };
+
+    // ── damage ───────────────────────────────────────────────────────────────
+    let mut raw = [0.0f64; 3];
+    let mut rear = [0.0f64; 3];
+    let mut heat = 0;
+    for w in weapons {
+        let reach = w.range_long.unwrap_or(0);
+        for (band, &min_hex) in BRACKET_MIN_HEX.iter().enumerate() {
+            if reach < min_hex {
+                continue;
+            }
+            let d = weapon_damage(w, band) * w.count as f64;
+            if w.is_rear {
+                rear[band] += d;
+            } else {
+                raw[band] += d;
+            }
+        }
+        if !w.is_rear {
+            heat += w.heat * w.count;
+        }
+    }
+
+    let dissipation = c.heat_sinks * if c.double_heat_sinks { 2 } else { 1 };
+    let overheat = if matches!(c.unit_type.as_str(), "mech" | "industrialmech") {
+        ((heat + 4 - dissipation).max(0) / 10).min(4)
+    } else {
+        0
+    };
+
+    let to_card = |v: f64| {
+        let d = v / 10.0;
+        let rounded = d.round() as i32;
+        (rounded, rounded == 0 && d > 0.0)
+    };
+    let damage = [to_card(raw[0]), to_card(raw[1]), to_card(raw[2])];
+
+    let mut specials = Vec::new();
+    if rear.iter().any(|&d| d > 0.0) {
+        let r: Vec<String> = rear
+            .iter()
+            .map(|&d| match to_card(d) {
+                (0, true) => "0*".to_string(),
+                (0, false) => "-".to_string(),
+                (n, _) => n.to_string(),
+            })
+            .collect();
+        specials.push(format!("REAR{}", r.join("/")));
+    }
+
+    Some(Card {
+        size,
+        movement,
+        move_inches,
+        jump_inches,
+        tmm,
+        armor: (armor_points as f64 / 30.0).round() as i32,
+        structure: structure.max(1),
+        damage,
+        overheat,
+        specials,
+    })
+}
+
+/// Expected damage of one weapon in a range bracket. Cluster weapons
+/// ("1/hit", "2/hit") are scaled by rack size and average hits; a launcher
+/// missing from the catalog counts as a single missile.
+fn weapon_damage(w: &Weapon, band: usize) -> f64 {
+    let parts: Vec<&str> = w.damage.split('/').collect();
+    if w.damage.ends_with("/hit") {
+        let per_missile: f64 = parts[0].trim().parse().unwrap_or(0.0);
+        let rack = f64::from(w.rack_size.unwrap_or(1));
+        let factor = if w.slug.contains("streak") {
+            1.0
+        } else {
+            CLUSTER_FACTOR
+        };
+        return per_missile * rack * factor;
+    }
+    let idx = band.min(parts.len().saturating_sub(1));
+    parts
+        .get(idx)
+        .and_then(|p| p.trim().parse().ok())
+        .unwrap_or(0.0)
+}
+
+// ── DB helpers ───────────────────────────────────────────────────────────────
+
+async fn load_candidates(pool: &PgPool, force: bool) -> anyhow::Result<Vec<Candidate>> {
+    let rows = sqlx::query(
+        r#"SELECT u.id, u.tonnage::float8 AS tonnage, c.unit_type,
+                  md.walk_mp, md.jump_mp, COALESCE(md.heat_sink_count, 10) AS heat_sinks,
+                  COALESCE(md.heat_sink_type ILIKE '%double%', FALSE) AS double_heat_sinks,
+                  COALESCE(et.bv_multiplier, 1)::float8 AS engine_mult,
+                  vd.cruise_mp, vd.motion_type
+           FROM units u
+           JOIN unit_chassis c ON c.id = u.chassis_id
+           LEFT JOIN unit_mech_data md ON md.unit_id = u.id
+           LEFT JOIN engine_types et ON et.id = md.engine_type_id
+           LEFT JOIN unit_vehicle_data vd ON vd.unit_id = u.id
+           LEFT JOIN unit_alpha_strike a ON a.unit_id = u.id
+           WHERE c.unit_type IN ('mech', 'industrialmech', 'vehicle')
+             AND (a.unit_id IS NULL OR (a.source = 'conversion' AND $1))
+           ORDER BY u.id"#,
+    )
+    .bind(force)
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter()
+        .map(|r| {
+            Ok(Candidate {
+                id: r.try_get("id")?,
+                tonnage: r.try_get("tonnage")?,
+                unit_type: r.try_get("unit_type")?,
+                walk_mp: r.try_get("walk_mp")?,
+                jump_mp: r.try_get("jump_mp")?,
+                heat_sinks: r.try_get("heat_sinks")?,
+                double_heat_sinks: r.try_get("double_heat_sinks")?,
+                engine_mult: r.try_get("engine_mult")?,
+                cruise_mp: r.try_get("cruise_mp")?,
+                motion_type: r.try_get("motion_type")?,
+            })
+        })
+        .collect()
+}
+
+/// unit_id → (total armor incl. rear, total structure if recorded).
+async fn load_location_totals(pool: &PgPool) -> anyhow::Result<HashMap<i32, (i32, Option<i32>)>> {
+    let rows = sqlx::query(
+        r#"SELECT unit_id,
+                  SUM(COALESCE(armor_points, 0) + COALESCE(rear_armor, 0))::int4 AS armor,
+                  SUM(structure_points)::int4 AS structure
+           FROM unit_locations GROUP BY unit_id"#,
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut map = HashMap::new();
+    for r in rows {
+        map.insert(
+            r.try_get("unit_id")?,
+            (r.try_get("armor")?, r.try_get("structure")?),
+        );
+    }
+    Ok(map)
+}
+
+/// Biped mech internal structure total by tonnage.
+async fn load_mech_structure(pool: &PgPool) -> anyhow::Result<HashMap<i32, i32>> {
+    let rows = sqlx::query(
+        r#"SELECT tonnage::int4 AS tonnage,
+                  (head + center_torso + 2 * side_torso + 2 * arm + 2 * leg)::int4 AS total
+           FROM mech_internal_structure"#,
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut map = HashMap::new();
+    for r in rows {
+        map.insert(r.try_get("tonnage")?, r.try_get("total")?);
+    }
+    Ok(map)
+}
+
+/// Weapons per unit. Units with a critical hit table are counted from their
+/// slots (slots / crits per weapon); others fall back to the loadout.
+async fn load_weapons(pool: &PgPool) -> anyhow::Result<HashMap<i32, Vec<Weapon>>> {
+    let rows = sqlx::query(
+        r#"WITH weapons AS (
+               SELECT id, slug, name, internal_name, damage, range_long, COALESCE(heat, 0) AS heat,
+                      GREATEST(COALESCE(crits, 1), 1) AS crits
+               FROM equipment
+               WHERE damage IS NOT NULL
+                 AND (category::text LIKE '%_weapon' OR category = 'equipment')
+           ),
+           from_slots AS (
+               SELECT s.unit_id, s.equipment_id, s.is_rear,
+                      CEIL(COUNT(*)::numeric / w.crits)::int4 AS count
+               FROM unit_critical_slots s
+               JOIN weapons w ON w.id = s.equipment_id
+               GROUP BY s.unit_id, s.equipment_id, s.location, s.is_rear, w.crits
+           ),
+           from_loadout AS (
+               SELECT l.unit_id, l.equipment_id, l.is_rear_facing AS is_rear,
+                      l.quantity AS count
+               FROM unit_loadout l
+               JOIN weapons w ON w.id = l.equipment_id
+               WHERE NOT EXISTS (SELECT 1 FROM unit_critical_slots s WHERE s.unit_id = l.unit_id)
+           )
+           SELECT x.unit_id, w.slug, w.name, w.internal_name, w.damage, w.range_long,
+                  w.heat, x.count, x.is_rear
+           FROM (SELECT * FROM from_slots UNION ALL SELECT * FROM from_loadout) x
+           JOIN weapons w ON w.id = x.equipment_id"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut map: HashMap<i32, Vec<Weapon>> = HashMap::new();
+    for r in rows {
+        let slug: String = r.try_get("slug")?;
+        let internal_name: Option<String> = r.try_get("internal_name")?;
+        let name: String = r.try_get("name")?;
+        map.entry(r.try_get("unit_id")?).or_default().push(Weapon {
+            rack_size: rack_size(&slug, internal_name.as_deref(), &name),
+            slug,
+            damage: r.try_get("damage")?,
+            range_long: r.try_get("range_long")?,
+            heat: r.try_get("heat")?,
+            count: r.try_get("count")?,
+            is_rear: r.try_get("is_rear")?,
+        });
+    }
+    Ok(map)
+}
+
+/// Rack size of a cluster launcher, found in the catalog by slug, then by
+/// internal name or display name for rows stored before the catalog.
+fn rack_size(slug: &str, internal_name: Option<&str>, name: &str) -> Option<i32> {
+    let catalog = catalog();
+    catalog
+        .by_slug(slug)
+        .or_else(|| internal_name.and_then(|n| catalog.resolve(n)))
+        .or_else(|| catalog.resolve(name))
+        .and_then(|e| e.rack_size)
+}
+
+async fn upsert_conversion(
+    pool: &PgPool,
+    unit_id: i32,
+    unit_type: &str,
+    card: &Card,
+) -> anyhow::Result<()> {
+    let as_type = match unit_type {
+        "mech" => "BM",
+        "industrialmech" => "IM",
+        _ => "CV",
+    };
+    let [short, medium, long] = card.damage;
+    sqlx::query(
+        r#"INSERT INTO unit_alpha_strike (
+               unit_id, as_type, size, movement, move_inches, jump_inches, tmm,
+               armor, structure,
+               damage_short, damage_short_min, damage_medium, damage_medium_min,
+               damage_long, damage_long_min, overheat, specials, source)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9,
+                   $10, $11, $12, $13, $14, $15, $16, $17, 'conversion')
+           ON CONFLICT (unit_id) DO UPDATE SET
+               as_type = EXCLUDED.as_type,
+               size = EXCLUDED.size,
+               movement = EXCLUDED.movement,
+               move_inches = EXCLUDED.move_inches,
+               jump_inches = EXCLUDED.jump_inches,
+               tmm = EXCLUDED.tmm,
+               armor = EXCLUDED.armor,
+               structure = EXCLUDED.structure,
+               damage_short = EXCLUDED.damage_short,
+               damage_short_min = EXCLUDED.damage_short_min,
+               damage_medium = EXCLUDED.damage_medium,
+               damage_medium_min = EXCLUDED.damage_medium_min,
+               damage_long = EXCLUDED.damage_long,
+               damage_long_min = EXCLUDED.damage_long_min,
+               overheat = EXCLUDED.overheat,
+               specials = EXCLUDED.specials
+           WHERE unit_alpha_strike.source = 'conversion'"#,
+    )
+    .bind(unit_id)
+    .bind(as_type)
+    .bind(card.size as i16)
+    .bind(&card.movement)
+    .bind(card.move_inches as i16)
+    .bind(card.jump_inches.map(|v| v as i16))
+    .bind(card.tmm as i16)
+    .bind(card.armor as i16)
+    .bind(card.structure as i16)
+    .bind(short.0 as i16)
+    .bind(short.1)
+    .bind(medium.0 as i16)
+    .bind(medium.1)
+    .bind(long.0 as i16)
+    .bind(long.1)
+    .bind(card.overheat as i16)
+    .bind(&card.specials)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert alpha strike conversion for unit {unit_id}"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weapon(slug: &str, damage: &str, rack_size: Option<i32>) -> Weapon {
+        Weapon {
+            slug: slug.to_string(),
+            damage: damage.to_string(),
+            rack_size,
+            range_long: Some(21),
+            heat: 0,
+            count: 1,
+            is_rear: false,
+        }
+    }
+
+    #[test]
+    fn rack_size_from_catalog() {
+        assert_eq!(rack_size("clan-lrm-20", None, "LRM 20"), Some(20));
+        assert_eq!(rack_size("cllrm20", Some("CLLRM20"), "LRM 20"), Some(20));
+        assert_eq!(rack_size("clstreaksrm6", None, "CLStreakSRM6"), Some(6));
+        assert_eq!(rack_size("mrm-40", None, "MRM 40"), Some(40));
+        assert_eq!(rack_size("medium-laser", None, "Medium Laser"), None);
+    }
+
+    #[test]
+    fn cluster_damage_scales_by_rack() {
+        let lrm = weapon(
+            "cllrm20",
+            "1/hit",
+            rack_size("cllrm20", Some("CLLRM20"), "LRM 20"),
+        );
+        assert!((weapon_damage(&lrm, 2) - 12.0).abs() < 1e-9);
+
+        let streak = weapon("clan-streak-srm-6", "2/hit", Some(6));
+        assert_eq!(weapon_damage(&streak, 0), 12.0);
+
+        let ppc = weapon("ppc", "10", None);
+        assert_eq!(weapon_damage(&ppc, 1), 10.0);
+    }
+}
@@ -0,0 +1,234 @@
+//! Dataset snapshots and release diffs.
+//!
+//! At the end of a MegaMek import every unit the run wrote is recorded in
+//! `dataset_snapshot_units` under the dataset version: md5 hashes of its core
+//! fields, armor and loadout, its BV and the loadout itself. `dataset-diff`
+//! compares two snapshots and prints release notes: units added, removed and
+//! changed, with BV before/after and the equipment added or removed.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+/// Record a snapshot of the units written by `run_id`, replacing any earlier
+/// snapshot of the same version. Returns the number of units recorded.
+pub async fn snapshot(pool: &PgPool, version: &str, run_id: i32) -> anyhow::Result<u64> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM dataset_snapshots WHERE version = $1")
+        .bind(version)
+        .execute(&mut *tx)
+        .await?;
+    let snapshot_id: i32 = sqlx::query_scalar(
+        "INSERT INTO dataset_snapshots (version, import_run_id) VALUES ($1, $2) RETURNING id",
+    )
+    .bind(version)
+    .bind(run_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let result = sqlx::query(
+        r#"
+        WITH armor AS (
+            SELECT unit_id,
+                   md5(string_agg(concat_ws(':', location, armor_points, rear_armor, structure_points),
+                                  ',' ORDER BY location)) AS hash
+            FROM unit_locations
+            GROUP BY unit_id
+        ),
+        loadout AS (
+            SELECT l.unit_id,
+                   md5(string_agg(concat_ws(':', e.slug, l.location, l.quantity, l.is_rear_facing, l.is_omnipod),
+                                  ',' ORDER BY e.slug, l.location, l.is_rear_facing, l.is_omnipod, l.quantity)) AS hash,
+                   jsonb_agg(jsonb_build_object(
+                                 'equipment', e.slug,
+                                 'name', e.name,
+                                 'location', l.location,
+                                 'quantity', l.quantity,
+                                 'rear', l.is_rear_facing)
+                             ORDER BY e.slug, l.location, l.is_rear_facing) AS items
+            FROM unit_loadout l
+            JOIN equipment e ON e.id = l.equipment_id
+            GROUP BY l.unit_id
+        ),
+        hashed AS (
+            SELECT u.slug, u.full_name, u.bv,
+                   md5(concat_ws('|', u.full_name, u.tonnage, u.tech_base, u.rules_level, u.intro_year)) AS core_hash,
+                   COALESCE(a.hash, md5('')) AS armor_hash,
+                   COALESCE(l.hash, md5('')) AS loadout_hash,
+                   COALESCE(l.items, '[]'::jsonb) AS items
+            FROM units u
+            LEFT JOIN armor a ON a.unit_id = u.id
+            LEFT JOIN loadout l ON l.unit_id = u.id
+            WHERE u.last_import_run_id = $2
+        )
+        INSERT INTO dataset_snapshot_units
+            (snapshot_id, unit_slug, full_name, content_hash, core_hash, armor_hash, loadout_hash, bv, loadout)
+        SELECT $1, slug, full_name, md5(core_hash || armor_hash || loadout_hash),
+               core_hash, armor_hash, loadout_hash, bv, items
+        FROM hashed
+        "#,
+    )
+    .bind(snapshot_id)
+    .bind(run_id)
+    .execute(&mut *tx)
+    .await
+    .context("recording dataset snapshot")?;
+
+    tx.commit().await?;
+    Ok(result.rows_affected())
+}
+
+/// Copy current BVs into the newest snapshot, so MUL imports after a
+/// MegaMek import show up in the next diff. Returns the rows changed.
+pub async fn refresh_snapshot_bv(pool: &PgPool) -> anyhow::Result<u64> {
+    let result = sqlx::query(
+        r#"UPDATE dataset_snapshot_units s SET bv = u.bv
+           FROM units u
+           WHERE s.unit_slug = u.slug
+             AND s.snapshot_id = (SELECT MAX(id) FROM dataset_snapshots)
+             AND s.bv IS DISTINCT FROM u.bv"#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+// ── diff ─────────────────────────────────────────────────────────────────────
+
+struct UnitChange {
+    slug: String,
+    full_name: String,
+    core_changed: bool,
+    armor_changed: bool,
+    bv_from: Option<i32>,
+    bv_to: Option<i32>,
+    loadout_from: Option<serde_json::Value>,
+    loadout_to: Option<serde_json::Value>,
+}
+
+/// Run the dataset-diff subcommand: print release notes in Markdown to stdout.
+pub async fn run_diff(database_url: &str, from: &str, to: &str) -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(database_url)
+        .await
+        .context("connecting to database")?;
+
+    let from_id = snapshot_id(&pool, from).await?;
+    let to_id = snapshot_id(&pool, to).await?;
+
+    let rows = sqlx::query(
+        r#"SELECT COALESCE(t.unit_slug, f.unit_slug) AS slug,
+                  COALESCE(t.full_name, f.full_name) AS full_name,
+                  f.core_hash IS DISTINCT FROM t.core_hash AS core_changed,
+                  f.armor_hash IS DISTINCT FROM t.armor_hash AS armor_changed,
+                  f.bv AS bv_from, t.bv AS bv_to,
+                  f.loadout AS loadout_from, t.loadout AS loadout_to
+           FROM (SELECT * FROM dataset_snapshot_units WHERE snapshot_id = $1) f
+           FULL JOIN (SELECT * FROM dataset_snapshot_units WHERE snapshot_id = $2) t
+                  ON t.unit_slug = f.unit_slug
+           WHERE f.content_hash IS DISTINCT FROM t.content_hash OR f.bv IS DISTINCT FROM t.bv
+           ORDER BY 2, 1"#,
+    )
+    .bind(from_id)
+    .bind(to_id)
+    .fetch_all(&pool)
+    .await?;
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    for row in rows {
+        let change = UnitChange {
+            slug: row.try_get("slug")?,
+            full_name: row.try_get("full_name")?,
+            core_changed: row.try_get("core_changed")?,
+            armor_changed: row.try_get("armor_changed")?,
+            bv_from: row.try_get("bv_from")?,
+            bv_to: row.try_get("bv_to")?,
+            loadout_from: row.try_get("loadout_from")?,
+            loadout_to: row.try_get("loadout_to")?,
+        };
+        match (&change.loadout_from, &change.loadout_to) {
+            (None, _) => added.push(change),
+            (_, None) => removed.push(change),
+            _ => changed.push(change),
+        }
+    }
+    println!("# Dataset changes {from} → {to}");
+    println!();
+    for (heading, units) in [("Added", &added), ("Removed", &removed)] {
+        println!("## {heading} units ({})", units.len());
+        println!();
+        for c in units {
+            println!("- {} (`{}`)", c.full_name, c.slug);
+        }
+        if !units.is_empty() {
+            println!();
+        }
+    }
+    println!("## Changed units ({})", changed.len());
+    println!();
+    for c in &changed {
+        let mut parts = Vec::new();
+        if c.core_changed {
+            parts.push("stats".to_string());
+        }
+        if c.armor_changed {
+            parts.push("armor".to_string());
+        }
+        if c.bv_from != c.bv_to {
+            let bv = |v: Option<i32>| v.map_or("-".to_string(), |v| v.to_string());
+            parts.push(format!("BV {} → {}", bv(c.bv_from), bv(c.bv_to)));
+        }
+        let (plus, minus) = loadout_delta(c.loadout_from.as_ref(), c.loadout_to.as_ref());
+        if !plus.is_empty() || !minus.is_empty() {
+            parts.push("loadout".to_string());
+        }
+        println!("- {} (`{}`): {}", c.full_name, c.slug, parts.join(", "));
+        for (item, qty) in &plus {
+            println!("  - + {qty}× {item}");
+        }
+        for (item, qty) in &minus {
+            println!("  - − {qty}× {item}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn snapshot_id(pool: &PgPool, version: &str) -> anyhow::Result<i32> {
+    sqlx::query_scalar("SELECT id FROM dataset_snapshots WHERE version = $1")
+        .bind(version)
+        .fetch_optional(pool)
+        .await?
+        .with_context(|| format!("no dataset snapshot for version '{version}'"))
+}
+
+/// ("slug @ location", quantity) pairs of a loadout difference.
+type LoadoutDelta = Vec<(String, i64)>;
+
+/// Equipment added and removed between two snapshot loadouts.
+fn loadout_delta(
+    from: Option<&serde_json::Value>,
+    to: Option<&serde_json::Value>,
+) -> (LoadoutDelta, LoadoutDelta) {
+    let mut counts: BTreeMap<String, i64> = BTreeMap::new();
+    for (items, sign) in [(from, -1), (to, 1)] {
+        for item in items.and_then(|v| v.as_array()).into_iter().flatten() {
+            let equipment = item["equipment"].as_str().unwrap_or_default();
+            let mut key = match item["location"].as_str() {
+                Some(loc) => format!("{equipment} @ {loc}"),
+                None => equipment.to_string(),
+            };
+            if item["rear"].as_bool() == Some(true) {
+                key.push_str(" (rear)");
+            }
+            *counts.entry(key).or_default() += sign * item["quantity"].as_i64().unwrap_or(1);
+        }
+    }
+    let plus = counts.iter().filter(|(_, &d)| d > 0).map(|(k, &d)| (k.clone(), d)).collect();
+    let minus = counts.iter().filter(|(_, &d)| d < 0).map(|(k, &d)| (k.clone(), -d)).collect();
+    (plus, minus)
+}
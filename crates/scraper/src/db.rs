@@ -2,12 +2,13 @@
 ///
 /// Uses runtime sqlx::query (no `!` macro) to avoid compile-time type resolution
 /// issues with custom PostgreSQL enum types.
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use anyhow::Context;
 use rust_decimal::Decimal;
 use sqlx::{PgPool, Row};
 
+use crate::equipment_catalog::catalog;
 use crate::parse::{ParsedUnit, TechBase, RulesLevel};
 
 // ── helpers ───────────────────────────────────────────────────────────────────
@@ -37,31 +38,35 @@ fn to_decimal(f: f64) -> Decimal {
 
 // ── equipment ─────────────────────────────────────────────────────────────────
 
-/// Upsert a piece of equipment by slug and return its id.
+/// Upsert a piece of equipment by slug and return its id. `internal_name` is
+/// the MegaMek internal name of a catalog entry, `None` for unknown names.
 pub async fn upsert_equipment(
     pool: &PgPool,
     slug: &str,
     name: &str,
+    internal_name: Option<&str>,
     category: &str,
-    tech_base: TechBase,
+    tech_base: &str,
     rules_level: RulesLevel,
 ) -> anyhow::Result<i32> {
     let row = sqlx::query(
         r#"
-        INSERT INTO equipment (slug, name, category, tech_base, rules_level)
-        VALUES ($1, $2, $3::equipment_category_enum, $4::tech_base_enum, $5::rules_level_enum)
+        INSERT INTO equipment (slug, name, internal_name, category, tech_base, rules_level)
+        VALUES ($1, $2, $3, $4::equipment_category_enum, $5::tech_base_enum, $6::rules_level_enum)
         ON CONFLICT (slug) DO UPDATE
-            SET name        = EXCLUDED.name,
-                category    = EXCLUDED.category,
-                tech_base   = EXCLUDED.tech_base,
-                rules_level = EXCLUDED.rules_level
+            SET name          = EXCLUDED.name,
+                internal_name = EXCLUDED.internal_name,
+                category      = EXCLUDED.category,
+                tech_base     = EXCLUDED.tech_base,
+                rules_level   = EXCLUDED.rules_level
         RETURNING id
         "#,
     )
     .bind(slug)
     .bind(name)
+    .bind(internal_name)
     .bind(category)
-    .bind(tech_base_str(tech_base))
+    .bind(tech_base)
     .bind(rules_level_str(rules_level))
     .fetch_one(pool)
     .await
@@ -80,7 +85,7 @@ pub async fn upsert_chassis(pool: &PgPool, unit: &ParsedUnit) -> anyhow::Result<
     let slug = format!(
         "{}-{}",
         crate::parse::to_slug(&unit.chassis),
-        unit.unit_type.as_str()
+        unit.unit_type.as_str().replace('_', "-")
     );
     let tonnage = to_decimal(unit.tonnage);
 
@@ -117,6 +122,7 @@ pub async fn upsert_chassis(pool: &PgPool, unit: &ParsedUnit) -> anyhow::Result<
 /// Upsert a unit variant row and return its id.
 pub async fn upsert_unit(
     pool: &PgPool,
+    run_id: i32,
     unit: &ParsedUnit,
     chassis_id: i32,
 ) -> anyhow::Result<i32> {
@@ -133,9 +139,10 @@ pub async fn upsert_unit(
         INSERT INTO units (
             slug, chassis_id, variant, full_name,
             tech_base, rules_level,
-            tonnage, intro_year, source_book, description
+            tonnage, intro_year, source_book, description, source_mul_id,
+            last_import_run_id
         )
-        VALUES ($1, $2, $3, $4, $5::tech_base_enum, $6::rules_level_enum, $7, $8, $9, $10)
+        VALUES ($1, $2, $3, $4, $5::tech_base_enum, $6::rules_level_enum, $7, $8, $9, $10, $11, $12)
         ON CONFLICT (slug) DO UPDATE
             SET chassis_id  = EXCLUDED.chassis_id,
                 variant     = EXCLUDED.variant,
@@ -145,7 +152,9 @@ pub async fn upsert_unit(
                 tonnage     = EXCLUDED.tonnage,
                 intro_year  = EXCLUDED.intro_year,
                 source_book = EXCLUDED.source_book,
-                description = EXCLUDED.description
+                description = EXCLUDED.description,
+                source_mul_id = EXCLUDED.source_mul_id,
+                last_import_run_id = EXCLUDED.last_import_run_id
         RETURNING id
         "#,
     )
@@ -159,6 +168,8 @@ pub async fn upsert_unit(
     .bind(unit.intro_year)
     .bind(unit.source.as_deref()) // source_book column
     .bind(unit.description.as_deref())
+    .bind(unit.mul_id)
+    .bind(run_id)
     .fetch_one(pool)
     .await
     .with_context(|| format!("upsert_unit: {slug}"))?;
@@ -171,6 +182,7 @@ pub async fn upsert_unit(
 /// Delete existing location rows then bulk-insert fresh ones.
 pub async fn replace_locations(
     pool: &PgPool,
+    run_id: i32,
     unit_id: i32,
     unit: &ParsedUnit,
 ) -> anyhow::Result<()> {
@@ -182,8 +194,9 @@ pub async fn replace_locations(
     for loc in &unit.locations {
         sqlx::query(
             r#"
-            INSERT INTO unit_locations (unit_id, location, armor_points, rear_armor, structure_points)
-            VALUES ($1, $2::location_name_enum, $3, $4, $5)
+            INSERT INTO unit_locations
+                (unit_id, location, armor_points, rear_armor, structure_points, last_import_run_id)
+            VALUES ($1, $2::location_name_enum, $3, $4, $5, $6)
             "#,
         )
         .bind(unit_id)
@@ -191,6 +204,7 @@ pub async fn replace_locations(
         .bind(loc.armor)
         .bind(loc.rear_armor)
         .bind(loc.structure)
+        .bind(run_id)
         .execute(pool)
         .await
         .with_context(|| format!("insert location {} for unit {unit_id}", loc.location))?;
@@ -200,13 +214,397 @@ pub async fn replace_locations(
 
 // ── loadout ───────────────────────────────────────────────────────────────────
 
+/// Equipment ids resolved during one import, and the names the catalog did
+/// not know.
+#[derive(Debug, Default)]
+pub struct EquipmentCache {
+    /// Equipment name as written in unit files → id.
+    ids: HashMap<String, i32>,
+    /// Unknown equipment name → the first unit it was seen on.
+    pub unknown: BTreeMap<String, String>,
+}
+
+/// Look up an equipment id by name via `equipment_cache`, upserting on a miss.
+///
+/// Names are resolved against the equipment catalog. A name the catalog does
+/// not know is stored under its own slug as plain equipment with the unit's
+/// tech base, and recorded in `equipment_cache.unknown` for the run report.
+async fn resolve_equipment_id(
+    pool: &PgPool,
+    name: &str,
+    unit: &ParsedUnit,
+    equipment_cache: &mut EquipmentCache,
+) -> anyhow::Result<i32> {
+    if let Some(&id) = equipment_cache.ids.get(name) {
+        return Ok(id);
+    }
+    let id = match catalog().resolve(name) {
+        Some(entry) => {
+            upsert_equipment(
+                pool,
+                &entry.slug,
+                &entry.name,
+                Some(&entry.internal_name),
+                &entry.category,
+                &entry.tech_base,
+                unit.rules_level,
+            )
+            .await?
+        }
+        None => {
+            equipment_cache
+                .unknown
+                .entry(name.to_string())
+                .or_insert_with(|| format!("{} {}", unit.chassis, unit.model).trim().to_string());
+            let slug = crate::parse::to_slug(name);
+            upsert_equipment(
+                pool,
+                &slug,
+                name,
+                None,
+                "equipment",
+                tech_base_str(unit.tech_base),
+                unit.rules_level,
+            )
+            .await?
+        }
+    };
+    equipment_cache.ids.insert(name.to_string(), id);
+    Ok(id)
+}
+
+/// Delete equipment rows that are neither in the catalog nor referenced by
+/// any unit, i.e. names an earlier import stored that nothing uses any more.
+/// Rows carrying seeded stats or tech progression are kept.
+pub async fn prune_unused_equipment(pool: &PgPool) -> anyhow::Result<u64> {
+    let result = sqlx::query(
+        r#"DELETE FROM equipment e
+           WHERE e.internal_name IS NULL
+             AND e.stats_source IS NULL
+             AND NOT EXISTS (SELECT 1 FROM equipment_tech_progression p WHERE p.equipment_id = e.id)
+             AND NOT EXISTS (SELECT 1 FROM unit_loadout l WHERE l.equipment_id = e.id)
+             AND NOT EXISTS (SELECT 1 FROM unit_critical_slots c WHERE c.equipment_id = e.id)
+             AND NOT EXISTS (SELECT 1 FROM unit_aero_weapon_bays b WHERE b.equipment_id = e.id)
+             AND NOT EXISTS (SELECT 1 FROM omni_base_equipment o WHERE o.equipment_id = e.id)"#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Counts from [`adopt_catalog_equipment`].
+#[derive(Debug, Default)]
+pub struct AdoptedEquipment {
+    /// Rows given their catalog slug, name and internal name
+    pub adopted: u64,
+    /// Duplicate rows folded into another row of the same catalog entry
+    pub merged: u64,
+}
+
+/// Match equipment rows stored before the catalog (no internal name) to their
+/// catalog entries by slug or name, so an import reuses them instead of
+/// creating new rows and pruning the old ones.
+///
+/// One row per entry is kept and renamed to the catalog slug, name, category
+/// and tech base; further rows of the same entry are merged into it. Units,
+/// bays, omni bases and ammunition are repointed, stats and tech progression
+/// the kept row lacks are copied over, and every replaced slug is recorded
+/// in `equipment_slug_aliases`.
+pub async fn adopt_catalog_equipment(pool: &PgPool) -> anyhow::Result<AdoptedEquipment> {
+    let rows = sqlx::query(
+        "SELECT id, slug, name, internal_name, stats_source IS NOT NULL AS has_stats FROM equipment",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    struct Row {
+        id: i32,
+        slug: String,
+        adopted: bool,
+        has_stats: bool,
+    }
+    // Internal name → rows of that entry; only entries with unadopted rows are kept
+    let mut groups: BTreeMap<&str, Vec<Row>> = BTreeMap::new();
+    for r in &rows {
+        let slug: String = r.try_get("slug")?;
+        let name: String = r.try_get("name")?;
+        let internal_name: Option<String> = r.try_get("internal_name")?;
+        let entry = match internal_name {
+            Some(ref n) => catalog().resolve(n),
+            None => catalog().by_slug(&slug).or_else(|| catalog().resolve(&name)),
+        };
+        let Some(entry) = entry else { continue };
+        groups.entry(&entry.internal_name).or_default().push(Row {
+            id: r.try_get("id")?,
+            slug,
+            adopted: internal_name.is_some(),
+            has_stats: r.try_get("has_stats")?,
+        });
+    }
+    groups.retain(|_, rows| rows.iter().any(|r| !r.adopted));
+
+    let mut counts = AdoptedEquipment::default();
+    for (internal_name, mut rows) in groups {
+        let entry = catalog().resolve(internal_name).expect("grouped by catalog entry");
+        // Keep the adopted row, else the one already on the catalog slug,
+        // else one with seeded stats, else the oldest
+        rows.sort_by_key(|r| (!r.adopted, r.slug != entry.slug, !r.has_stats, r.id));
+        let (keep, rest) = rows.split_first().expect("group is not empty");
+
+        let mut tx = pool.begin().await?;
+        for dup in rest {
+            merge_equipment(&mut tx, dup.id, keep.id).await?;
+            counts.merged += 1;
+        }
+        let aliases = rest.iter().chain(Some(keep)).filter(|r| r.slug != entry.slug);
+        for r in aliases {
+            sqlx::query(
+                r#"INSERT INTO equipment_slug_aliases (alias, equipment_id) VALUES ($1, $2)
+                   ON CONFLICT (alias) DO UPDATE SET equipment_id = EXCLUDED.equipment_id"#,
+            )
+            .bind(&r.slug)
+            .bind(keep.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query(
+            r#"UPDATE equipment SET
+                 slug          = $2,
+                 name          = $3,
+                 internal_name = $4,
+                 category      = $5::equipment_category_enum,
+                 tech_base     = $6::tech_base_enum
+               WHERE id = $1"#,
+        )
+        .bind(keep.id)
+        .bind(&entry.slug)
+        .bind(&entry.name)
+        .bind(&entry.internal_name)
+        .bind(&entry.category)
+        .bind(&entry.tech_base)
+        .execute(&mut *tx)
+        .await
+        .with_context(|| format!("adopt equipment {} as {}", keep.slug, entry.slug))?;
+        tx.commit().await?;
+        counts.adopted += 1;
+    }
+    Ok(counts)
+}
+
+/// Repoint everything referencing equipment `from` to `into`, copy stats and
+/// tech progression `into` lacks, then delete `from`.
+/// Convert mech loadout rows stored before loadout quantities counted items
+/// (no import run) and stamp them with `run_id`. Those rows counted every
+/// critical slot, plus one per weapon-list line, and the weapon list and the
+/// critical slots could name one weapon differently, giving it two rows.
+///
+/// Rows of the same equipment and mount are folded together first. Weapons
+/// then take `slots + 1` per item, where the slot count comes from the
+/// equipment stats or, failing that, from the smallest folded quantity seen
+/// for that weapon. Other equipment takes `crit_slots_per_item` per item, or
+/// its stats slots plus one where the quantity divides evenly by that (some
+/// weapon lists also name ECM, probes and C3) and its slots otherwise, and is
+/// one item per location when neither is known.
+/// Returns the number of rows kept.
+pub async fn convert_legacy_loadout(pool: &PgPool, run_id: i32) -> anyhow::Result<u64> {
+    let rows = sqlx::query(
+        r#"SELECT l.id, l.unit_id, l.equipment_id, l.location::text AS location,
+                  l.is_rear_facing, l.is_omnipod, l.quantity,
+                  e.name, e.category::text AS category, e.crits::int4 AS crits,
+                  e.tech_base::text = 'clan' AS is_clan
+           FROM unit_loadout l
+           JOIN equipment e ON e.id = l.equipment_id
+           JOIN unit_mech_data md ON md.unit_id = l.unit_id
+           WHERE l.last_import_run_id IS NULL
+           ORDER BY l.id"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    struct Group {
+        keep: i32,
+        drop: Vec<i32>,
+        equipment_id: i32,
+        quantity: i32,
+    }
+    struct Equipment {
+        name: String,
+        category: String,
+        crits: Option<i32>,
+        is_clan: bool,
+        min_quantity: i32,
+    }
+
+    type MountKey = (i32, i32, Option<String>, bool, bool);
+    let mut groups: HashMap<MountKey, Group> = HashMap::new();
+    let mut equipment: HashMap<i32, Equipment> = HashMap::new();
+    for r in &rows {
+        let id: i32 = r.try_get("id")?;
+        let equipment_id: i32 = r.try_get("equipment_id")?;
+        let quantity: i32 = r.try_get("quantity")?;
+        let key = (
+            r.try_get("unit_id")?,
+            equipment_id,
+            r.try_get("location")?,
+            r.try_get("is_rear_facing")?,
+            r.try_get("is_omnipod")?,
+        );
+        match groups.get_mut(&key) {
+            Some(g) => {
+                g.drop.push(id);
+                g.quantity += quantity;
+            }
+            None => {
+                groups.insert(key, Group { keep: id, drop: Vec::new(), equipment_id, quantity });
+            }
+        }
+        if let std::collections::hash_map::Entry::Vacant(v) = equipment.entry(equipment_id) {
+            v.insert(Equipment {
+                name: r.try_get("name")?,
+                category: r.try_get("category")?,
+                crits: r.try_get("crits")?,
+                is_clan: r.try_get("is_clan")?,
+                min_quantity: i32::MAX,
+            });
+        }
+    }
+    for g in groups.values() {
+        let e = equipment.get_mut(&g.equipment_id).expect("equipment of a loadout row");
+        e.min_quantity = e.min_quantity.min(g.quantity);
+    }
+
+    let mut keep = Vec::with_capacity(groups.len());
+    let mut quantities = Vec::with_capacity(groups.len());
+    let mut drop = Vec::new();
+    for g in groups.into_values() {
+        let e = &equipment[&g.equipment_id];
+        let slots = e.crits.filter(|&c| c > 0);
+        let per_item = match e.category.as_str() {
+            "energy_weapon" | "ballistic_weapon" | "missile_weapon" => {
+                Some(slots.unwrap_or(e.min_quantity - 1).max(1) + 1)
+            }
+            "ammunition" => Some(1),
+            _ => crate::parse::crit_slots_per_item(&e.name, e.is_clan)
+                .or(slots.map(|s| if g.quantity % (s + 1) == 0 { s + 1 } else { s })),
+        };
+        keep.push(g.keep);
+        quantities.push(per_item.map_or(1, |n| (g.quantity + n - 1) / n).max(1));
+        drop.extend(g.drop);
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM unit_loadout WHERE id = ANY($1)")
+        .bind(&drop)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        r#"UPDATE unit_loadout l SET quantity = v.quantity, last_import_run_id = $3
+           FROM UNNEST($1::int4[], $2::int4[]) AS v(id, quantity)
+           WHERE l.id = v.id"#,
+    )
+    .bind(&keep)
+    .bind(&quantities)
+    .bind(run_id)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(keep.len() as u64)
+}
+
+async fn merge_equipment(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    from: i32,
+    into: i32,
+) -> anyhow::Result<()> {
+    for table in ["unit_loadout", "unit_critical_slots", "omni_base_equipment", "equipment_slug_aliases"] {
+        sqlx::query(&format!("UPDATE {table} SET equipment_id = $2 WHERE equipment_id = $1"))
+            .bind(from)
+            .bind(into)
+            .execute(&mut **tx)
+            .await?;
+    }
+    // Bays are unique per equipment: fold counts into an existing bay entry
+    sqlx::query(
+        r#"UPDATE unit_aero_weapon_bays b SET quantity = b.quantity + d.quantity
+           FROM unit_aero_weapon_bays d
+           WHERE d.equipment_id = $1 AND b.equipment_id = $2
+             AND b.unit_id = d.unit_id AND b.location = d.location AND b.bay_number = d.bay_number"#,
+    )
+    .bind(from)
+    .bind(into)
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query(
+        r#"UPDATE unit_aero_weapon_bays d SET equipment_id = $2
+           WHERE d.equipment_id = $1
+             AND NOT EXISTS (SELECT 1 FROM unit_aero_weapon_bays b
+                             WHERE b.equipment_id = $2 AND b.unit_id = d.unit_id
+                               AND b.location = d.location AND b.bay_number = d.bay_number)"#,
+    )
+    .bind(from)
+    .bind(into)
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query("UPDATE equipment SET ammo_for_id = $2 WHERE ammo_for_id = $1")
+        .bind(from)
+        .bind(into)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query(
+        r#"UPDATE equipment_tech_progression p SET equipment_id = $2
+           WHERE p.equipment_id = $1
+             AND NOT EXISTS (SELECT 1 FROM equipment_tech_progression q
+                             WHERE q.equipment_id = $2 AND q.tech_base = p.tech_base)"#,
+    )
+    .bind(from)
+    .bind(into)
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query(
+        r#"UPDATE equipment e SET
+             tonnage             = COALESCE(e.tonnage, d.tonnage),
+             crits               = COALESCE(e.crits, d.crits),
+             damage              = COALESCE(e.damage, d.damage),
+             heat                = COALESCE(e.heat, d.heat),
+             range_min           = COALESCE(e.range_min, d.range_min),
+             range_short         = COALESCE(e.range_short, d.range_short),
+             range_medium        = COALESCE(e.range_medium, d.range_medium),
+             range_long          = COALESCE(e.range_long, d.range_long),
+             bv                  = COALESCE(e.bv, d.bv),
+             intro_year          = COALESCE(e.intro_year, d.intro_year),
+             source_book         = COALESCE(e.source_book, d.source_book),
+             description         = COALESCE(e.description, d.description),
+             ammo_for_id         = COALESCE(e.ammo_for_id, NULLIF(d.ammo_for_id, e.id)),
+             stats_source        = COALESCE(e.stats_source, d.stats_source),
+             stats_updated_at    = COALESCE(e.stats_updated_at, d.stats_updated_at),
+             stats_import_run_id = COALESCE(e.stats_import_run_id, d.stats_import_run_id),
+             tech_rating         = COALESCE(e.tech_rating, d.tech_rating),
+             availability_sl     = COALESCE(e.availability_sl, d.availability_sl),
+             availability_sw     = COALESCE(e.availability_sw, d.availability_sw),
+             availability_clan   = COALESCE(e.availability_clan, d.availability_clan),
+             availability_da     = COALESCE(e.availability_da, d.availability_da)
+           FROM equipment d
+           WHERE e.id = $2 AND d.id = $1"#,
+    )
+    .bind(from)
+    .bind(into)
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query("DELETE FROM equipment WHERE id = $1")
+        .bind(from)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
 /// Delete existing loadout rows then bulk-insert fresh ones.
-/// `equipment_cache` maps equipment slug → id (populated/extended in-place).
+/// `equipment_cache` maps equipment names → ids (populated/extended in-place).
 pub async fn replace_loadout(
     pool: &PgPool,
+    run_id: i32,
     unit_id: i32,
     unit: &ParsedUnit,
-    equipment_cache: &mut HashMap<String, i32>,
+    equipment_cache: &mut EquipmentCache,
 ) -> anyhow::Result<()> {
     sqlx::query("DELETE FROM unit_loadout WHERE unit_id = $1")
         .bind(unit_id)
@@ -214,27 +612,13 @@ pub async fn replace_loadout(
         .await?;
 
     for entry in &unit.loadout {
-        let eq_slug = crate::parse::to_slug(&entry.equipment);
-        let eq_id = if let Some(&id) = equipment_cache.get(&eq_slug) {
-            id
-        } else {
-            let category    = crate::parse::categorize_equipment(&entry.equipment);
-            let tech_base_s = crate::parse::equipment_tech_base(&entry.equipment);
-            let tb = match tech_base_s {
-                "clan"      => TechBase::Clan,
-                "mixed"     => TechBase::Mixed,
-                "primitive" => TechBase::Primitive,
-                _           => TechBase::InnerSphere,
-            };
-            let id = upsert_equipment(pool, &eq_slug, &entry.equipment, category, tb, unit.rules_level).await?;
-            equipment_cache.insert(eq_slug.clone(), id);
-            id
-        };
+        let eq_id = resolve_equipment_id(pool, &entry.equipment, unit, equipment_cache).await?;
 
         sqlx::query(
             r#"
-            INSERT INTO unit_loadout (unit_id, equipment_id, location, quantity, is_rear_facing)
-            VALUES ($1, $2, $3::location_name_enum, $4, $5)
+            INSERT INTO unit_loadout
+                (unit_id, equipment_id, location, quantity, is_rear_facing, is_omnipod, last_import_run_id)
+            VALUES ($1, $2, $3::location_name_enum, $4, $5, $6, $7)
             "#,
         )
         .bind(unit_id)
@@ -242,6 +626,8 @@ pub async fn replace_loadout(
         .bind(entry.location)   // Option<&'static str> → cast to enum in SQL
         .bind(entry.quantity)
         .bind(entry.is_rear)    // is_rear_facing column
+        .bind(entry.is_omnipod)
+        .bind(run_id)
         .execute(pool)
         .await
         .with_context(|| format!("insert loadout entry {} for unit {unit_id}", entry.equipment))?;
@@ -249,6 +635,52 @@ pub async fn replace_loadout(
     Ok(())
 }
 
+// ── critical slots ────────────────────────────────────────────────────────────
+
+/// Delete existing critical slot rows then bulk-insert fresh ones.
+/// Empty and structural slots are stored without an equipment link.
+pub async fn replace_critical_slots(
+    pool: &PgPool,
+    unit_id: i32,
+    unit: &ParsedUnit,
+    equipment_cache: &mut EquipmentCache,
+) -> anyhow::Result<()> {
+    sqlx::query("DELETE FROM unit_critical_slots WHERE unit_id = $1")
+        .bind(unit_id)
+        .execute(pool)
+        .await?;
+
+    for slot in &unit.critical_slots {
+        let eq_id = match slot.content {
+            Some(ref name) if !slot.is_structural => {
+                Some(resolve_equipment_id(pool, name, unit, equipment_cache).await?)
+            }
+            _ => None,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO unit_critical_slots
+                (unit_id, location, slot_index, content, equipment_id, is_rear, is_omnipod)
+            VALUES ($1, $2::location_name_enum, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(unit_id)
+        .bind(slot.location)
+        .bind(slot.slot_index as i16)
+        .bind(slot.content.as_deref())
+        .bind(eq_id)
+        .bind(slot.is_rear)
+        .bind(slot.is_omnipod)
+        .execute(pool)
+        .await
+        .with_context(|| {
+            format!("insert critical slot {} #{} for unit {unit_id}", slot.location, slot.slot_index)
+        })?;
+    }
+    Ok(())
+}
+
 // ── observed locations ────────────────────────────────────────────────────────
 
 /// Refresh observed_locations on equipment from loadout data.
@@ -269,6 +701,107 @@ pub async fn refresh_observed_locations(pool: &PgPool) -> anyhow::Result<u64> {
     Ok(result.rows_affected())
 }
 
+// ── omni bases ────────────────────────────────────────────────────────────────
+
+/// Group OmniMech configurations into base chassis.
+///
+/// Configurations of one chassis share a base when their fixed critical slots,
+/// armor allocation, tonnage and engine match. The base's fixed equipment is
+/// taken from its reference configuration (the Prime when there is one), from
+/// critical slots where present and otherwise from non-pod loadout entries.
+/// Returns the number of bases.
+pub async fn refresh_omni_bases(pool: &PgPool) -> anyhow::Result<u64> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"CREATE TEMP TABLE omni_signatures ON COMMIT DROP AS
+           SELECT u.id AS unit_id, u.chassis_id, u.variant,
+                  md5(concat_ws('|', u.tonnage, md.engine_rating, md.engine_type,
+                      md.structure_type, md.armor_type,
+                      (SELECT string_agg(concat_ws(':', l.location, l.armor_points, l.rear_armor),
+                                         ',' ORDER BY l.location)
+                       FROM unit_locations l WHERE l.unit_id = u.id),
+                      (SELECT string_agg(concat_ws(':', cs.location, cs.slot_index, cs.content),
+                                         ',' ORDER BY cs.location, cs.slot_index)
+                       FROM unit_critical_slots cs
+                       WHERE cs.unit_id = u.id AND NOT cs.is_omnipod AND cs.content IS NOT NULL)
+                  )) AS signature
+           FROM units u
+           JOIN unit_mech_data md ON md.unit_id = u.id
+           WHERE md.is_omnimech"#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"INSERT INTO omni_bases (chassis_id, signature, reference_unit_id, tonnage,
+                                   engine_rating, engine_type, walk_mp, structure_type,
+                                   armor_type, heat_sink_type, gyro_type, cockpit_type)
+           SELECT DISTINCT ON (s.chassis_id, s.signature)
+                  s.chassis_id, s.signature, u.id, u.tonnage,
+                  md.engine_rating, md.engine_type, md.walk_mp, md.structure_type,
+                  md.armor_type, md.heat_sink_type, md.gyro_type, md.cockpit_type
+           FROM omni_signatures s
+           JOIN units u ON u.id = s.unit_id
+           JOIN unit_mech_data md ON md.unit_id = s.unit_id
+           ORDER BY s.chassis_id, s.signature, s.variant ILIKE '%prime%' DESC, u.id
+           ON CONFLICT (chassis_id, signature) DO UPDATE SET
+             reference_unit_id = EXCLUDED.reference_unit_id,
+             tonnage           = EXCLUDED.tonnage,
+             engine_rating     = EXCLUDED.engine_rating,
+             engine_type       = EXCLUDED.engine_type,
+             walk_mp           = EXCLUDED.walk_mp,
+             structure_type    = EXCLUDED.structure_type,
+             armor_type        = EXCLUDED.armor_type,
+             heat_sink_type    = EXCLUDED.heat_sink_type,
+             gyro_type         = EXCLUDED.gyro_type,
+             cockpit_type      = EXCLUDED.cockpit_type"#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"UPDATE unit_mech_data md SET omni_base_id = ob.id
+           FROM omni_signatures s
+           JOIN omni_bases ob ON ob.chassis_id = s.chassis_id AND ob.signature = s.signature
+           WHERE md.unit_id = s.unit_id"#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"DELETE FROM omni_bases ob
+           WHERE NOT EXISTS (SELECT 1 FROM unit_mech_data md WHERE md.omni_base_id = ob.id)"#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM omni_base_equipment").execute(&mut *tx).await?;
+    sqlx::query(
+        r#"INSERT INTO omni_base_equipment (omni_base_id, equipment_id, location, quantity, is_rear_facing)
+           SELECT ob.id, cs.equipment_id, cs.location, CEIL(COUNT(*)::numeric / GREATEST(COALESCE(e.crits, 1), 1))::int4, cs.is_rear
+           FROM omni_bases ob
+           JOIN unit_critical_slots cs ON cs.unit_id = ob.reference_unit_id
+           JOIN equipment e ON e.id = cs.equipment_id
+           WHERE NOT cs.is_omnipod
+           GROUP BY ob.id, cs.equipment_id, cs.location, cs.is_rear, e.crits
+           UNION ALL
+           SELECT ob.id, l.equipment_id, l.location, l.quantity, l.is_rear_facing
+           FROM omni_bases ob
+           JOIN unit_loadout l ON l.unit_id = ob.reference_unit_id
+           WHERE NOT l.is_omnipod
+             AND NOT EXISTS (SELECT 1 FROM unit_critical_slots cs WHERE cs.unit_id = ob.reference_unit_id)"#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM omni_bases")
+        .fetch_one(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(count as u64)
+}
+
 // ── mech data ────────────────────────────────────────────────────────────────
 
 /// Resolve a component alias to its reference table FK ID.
@@ -323,8 +856,9 @@ pub async fn upsert_mech_data(
                walk_mp, jump_mp, heat_sink_count, heat_sink_type,
                structure_type, armor_type, gyro_type, cockpit_type, myomer_type,
                engine_type_id, armor_type_id, structure_type_id, heatsink_type_id,
-               gyro_type_id, cockpit_type_id, myomer_type_id
-           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21)
+               gyro_type_id, cockpit_type_id, myomer_type_id,
+               is_environmentally_sealed, industrial_equipment
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23)
            ON CONFLICT (unit_id) DO UPDATE SET
                config          = EXCLUDED.config,
                is_omnimech     = EXCLUDED.is_omnimech,
@@ -345,7 +879,9 @@ pub async fn upsert_mech_data(
                heatsink_type_id  = EXCLUDED.heatsink_type_id,
                gyro_type_id      = EXCLUDED.gyro_type_id,
                cockpit_type_id   = EXCLUDED.cockpit_type_id,
-               myomer_type_id    = EXCLUDED.myomer_type_id
+               myomer_type_id    = EXCLUDED.myomer_type_id,
+               is_environmentally_sealed = EXCLUDED.is_environmentally_sealed,
+               industrial_equipment      = EXCLUDED.industrial_equipment
         "#,
     )
     .bind(unit_id)
@@ -369,12 +905,449 @@ pub async fn upsert_mech_data(
     .bind(gyro_type_id)
     .bind(cockpit_type_id)
     .bind(myomer_type_id)
+    .bind(data.is_environmentally_sealed)
+    .bind(&data.industrial_equipment)
     .execute(pool)
     .await
     .with_context(|| format!("upsert_mech_data for unit {unit_id}"))?;
     Ok(())
 }
 
+// ── protomech data ────────────────────────────────────────────────────────────
+
+/// Upsert ProtoMech data for a unit.
+pub async fn upsert_protomech_data(
+    pool: &PgPool,
+    unit_id: i32,
+    data: &crate::parse::ParsedProtoMechData,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_protomech_data (
+               unit_id, config, is_ultraheavy, walk_mp, jump_mp,
+               has_main_gun, armor_type, has_interface_cockpit
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               config                = EXCLUDED.config,
+               is_ultraheavy         = EXCLUDED.is_ultraheavy,
+               walk_mp               = EXCLUDED.walk_mp,
+               jump_mp               = EXCLUDED.jump_mp,
+               has_main_gun          = EXCLUDED.has_main_gun,
+               armor_type            = EXCLUDED.armor_type,
+               has_interface_cockpit = EXCLUDED.has_interface_cockpit
+        "#,
+    )
+    .bind(unit_id)
+    .bind(data.config)
+    .bind(data.is_ultraheavy)
+    .bind(data.walk_mp)
+    .bind(data.jump_mp)
+    .bind(data.has_main_gun)
+    .bind(&data.armor_type)
+    .bind(data.has_interface_cockpit)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_protomech_data for unit {unit_id}"))?;
+    Ok(())
+}
+
+// ── LAM / QuadVee data ───────────────────────────────────────────────────────
+
+/// Upsert land-air mech mode data for a unit.
+pub async fn upsert_lam_data(
+    pool: &PgPool,
+    unit_id: i32,
+    data: &crate::parse::ParsedLamData,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_lam_data (
+               unit_id, lam_type, fuel, airmech_cruise_mp, airmech_flank_mp,
+               fighter_safe_thrust, fighter_max_thrust, landing_gear_slots, avionics_slots
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               lam_type            = EXCLUDED.lam_type,
+               fuel                = EXCLUDED.fuel,
+               airmech_cruise_mp   = EXCLUDED.airmech_cruise_mp,
+               airmech_flank_mp    = EXCLUDED.airmech_flank_mp,
+               fighter_safe_thrust = EXCLUDED.fighter_safe_thrust,
+               fighter_max_thrust  = EXCLUDED.fighter_max_thrust,
+               landing_gear_slots  = EXCLUDED.landing_gear_slots,
+               avionics_slots      = EXCLUDED.avionics_slots
+        "#,
+    )
+    .bind(unit_id)
+    .bind(data.lam_type)
+    .bind(data.fuel)
+    .bind(data.airmech_cruise_mp)
+    .bind(data.airmech_flank_mp)
+    .bind(data.fighter_safe_thrust)
+    .bind(data.fighter_max_thrust)
+    .bind(data.landing_gear_slots)
+    .bind(data.avionics_slots)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_lam_data for unit {unit_id}"))?;
+    Ok(())
+}
+
+/// Upsert QuadVee vehicle-mode data for a unit.
+pub async fn upsert_quadvee_data(
+    pool: &PgPool,
+    unit_id: i32,
+    data: &crate::parse::ParsedQuadVeeData,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_quadvee_data (unit_id, motive_type, conversion_slots, motive_slots)
+           VALUES ($1,$2,$3,$4)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               motive_type      = EXCLUDED.motive_type,
+               conversion_slots = EXCLUDED.conversion_slots,
+               motive_slots     = EXCLUDED.motive_slots
+        "#,
+    )
+    .bind(unit_id)
+    .bind(data.motive_type)
+    .bind(data.conversion_slots)
+    .bind(data.motive_slots)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_quadvee_data for unit {unit_id}"))?;
+    Ok(())
+}
+
+// ── vehicle data ─────────────────────────────────────────────────────────────
+
+/// Upsert vehicle-specific data for a unit.
+pub async fn upsert_vehicle_data(
+    pool: &PgPool,
+    unit_id: i32,
+    data: &crate::parse::ParsedVehicleData,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_vehicle_data (
+               unit_id, motion_type, cruise_mp, engine_type, armor_type,
+               structure_type, turret_type, fuel
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               motion_type    = EXCLUDED.motion_type,
+               cruise_mp      = EXCLUDED.cruise_mp,
+               engine_type    = EXCLUDED.engine_type,
+               armor_type     = EXCLUDED.armor_type,
+               structure_type = EXCLUDED.structure_type,
+               turret_type    = EXCLUDED.turret_type,
+               fuel           = EXCLUDED.fuel
+        "#,
+    )
+    .bind(unit_id)
+    .bind(&data.motion_type)
+    .bind(data.cruise_mp)
+    .bind(&data.engine_type)
+    .bind(&data.armor_type)
+    .bind(&data.structure_type)
+    .bind(data.turret_type)
+    .bind(data.fuel.map(to_decimal))
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_vehicle_data for unit {unit_id}"))?;
+    Ok(())
+}
+
+// ── infantry data ─────────────────────────────────────────────────────────────
+
+/// Upsert battle armor / conventional infantry data for a unit.
+pub async fn upsert_infantry_data(
+    pool: &PgPool,
+    unit_id: i32,
+    data: &crate::parse::ParsedInfantryData,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_infantry_data (
+               unit_id, infantry_kind, trooper_count, squad_size, squad_count,
+               weight_class, chassis_type, motive_type,
+               ground_mp, jump_mp, umu_mp, vtol_mp,
+               armor_per_trooper, armor_type, left_manipulator, right_manipulator,
+               is_anti_mech, primary_weapon, secondary_weapon, secondary_count, armor_kit
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               infantry_kind     = EXCLUDED.infantry_kind,
+               trooper_count     = EXCLUDED.trooper_count,
+               squad_size        = EXCLUDED.squad_size,
+               squad_count       = EXCLUDED.squad_count,
+               weight_class      = EXCLUDED.weight_class,
+               chassis_type      = EXCLUDED.chassis_type,
+               motive_type       = EXCLUDED.motive_type,
+               ground_mp         = EXCLUDED.ground_mp,
+               jump_mp           = EXCLUDED.jump_mp,
+               umu_mp            = EXCLUDED.umu_mp,
+               vtol_mp           = EXCLUDED.vtol_mp,
+               armor_per_trooper = EXCLUDED.armor_per_trooper,
+               armor_type        = EXCLUDED.armor_type,
+               left_manipulator  = EXCLUDED.left_manipulator,
+               right_manipulator = EXCLUDED.right_manipulator,
+               is_anti_mech      = EXCLUDED.is_anti_mech,
+               primary_weapon    = EXCLUDED.primary_weapon,
+               secondary_weapon  = EXCLUDED.secondary_weapon,
+               secondary_count   = EXCLUDED.secondary_count,
+               armor_kit         = EXCLUDED.armor_kit
+        "#,
+    )
+    .bind(unit_id)
+    .bind(data.kind)
+    .bind(data.trooper_count)
+    .bind(data.squad_size)
+    .bind(data.squad_count)
+    .bind(data.weight_class)
+    .bind(&data.chassis_type)
+    .bind(&data.motive_type)
+    .bind(data.ground_mp)
+    .bind(data.jump_mp)
+    .bind(data.umu_mp)
+    .bind(data.vtol_mp)
+    .bind(data.armor_per_trooper)
+    .bind(&data.armor_type)
+    .bind(&data.left_manipulator)
+    .bind(&data.right_manipulator)
+    .bind(data.is_anti_mech)
+    .bind(&data.primary_weapon)
+    .bind(&data.secondary_weapon)
+    .bind(data.secondary_count)
+    .bind(&data.armor_kit)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_infantry_data for unit {unit_id}"))?;
+    Ok(())
+}
+
+// ── large craft data ──────────────────────────────────────────────────────────
+
+/// Upsert large craft data for a unit and replace its transport bays.
+pub async fn upsert_large_craft_data(
+    pool: &PgPool,
+    unit_id: i32,
+    data: &crate::parse::ParsedLargeCraftData,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_large_craft_data (
+               unit_id, craft_type, design_type, crew, officers, gunners, passengers,
+               marines, battle_armor_troops, other_passengers, life_boats, escape_pods,
+               collar_type, docking_collars, has_kf_drive, drive_core_type, jump_range,
+               has_lithium_fusion, has_jump_sail, has_hpg, grav_decks
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               craft_type          = EXCLUDED.craft_type,
+               design_type         = EXCLUDED.design_type,
+               crew                = EXCLUDED.crew,
+               officers            = EXCLUDED.officers,
+               gunners             = EXCLUDED.gunners,
+               passengers          = EXCLUDED.passengers,
+               marines             = EXCLUDED.marines,
+               battle_armor_troops = EXCLUDED.battle_armor_troops,
+               other_passengers    = EXCLUDED.other_passengers,
+               life_boats          = EXCLUDED.life_boats,
+               escape_pods         = EXCLUDED.escape_pods,
+               collar_type         = EXCLUDED.collar_type,
+               docking_collars     = EXCLUDED.docking_collars,
+               has_kf_drive        = EXCLUDED.has_kf_drive,
+               drive_core_type     = EXCLUDED.drive_core_type,
+               jump_range          = EXCLUDED.jump_range,
+               has_lithium_fusion  = EXCLUDED.has_lithium_fusion,
+               has_jump_sail       = EXCLUDED.has_jump_sail,
+               has_hpg             = EXCLUDED.has_hpg,
+               grav_decks          = EXCLUDED.grav_decks
+        "#,
+    )
+    .bind(unit_id)
+    .bind(data.craft_type)
+    .bind(data.design_type)
+    .bind(data.crew)
+    .bind(data.officers)
+    .bind(data.gunners)
+    .bind(data.passengers)
+    .bind(data.marines)
+    .bind(data.battle_armor_troops)
+    .bind(data.other_passengers)
+    .bind(data.life_boats)
+    .bind(data.escape_pods)
+    .bind(data.collar_type)
+    .bind(data.docking_collars)
+    .bind(data.has_kf_drive)
+    .bind(data.drive_core_type)
+    .bind(data.jump_range)
+    .bind(data.has_lithium_fusion)
+    .bind(data.has_jump_sail)
+    .bind(data.has_hpg)
+    .bind(data.grav_decks)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_large_craft_data for unit {unit_id}"))?;
+
+    sqlx::query("DELETE FROM unit_transport_bays WHERE unit_id = $1")
+        .bind(unit_id)
+        .execute(pool)
+        .await?;
+
+    for (i, bay) in data.transport_bays.iter().enumerate() {
+        sqlx::query(
+            r#"
+            INSERT INTO unit_transport_bays (unit_id, sort_order, bay_number, bay_type, size, doors)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(unit_id)
+        .bind(i as i16)
+        .bind(bay.bay_number.map(|n| n as i16))
+        .bind(bay.bay_type)
+        .bind(to_decimal(bay.size))
+        .bind(bay.doors as i16)
+        .execute(pool)
+        .await
+        .with_context(|| format!("insert transport bay {} for unit {unit_id}", bay.bay_type))?;
+    }
+    Ok(())
+}
+
+// ── aero data ─────────────────────────────────────────────────────────────────
+
+/// Upsert aerospace data for a unit and replace its weapon bays.
+pub async fn upsert_aero_data(
+    pool: &PgPool,
+    unit_id: i32,
+    unit: &ParsedUnit,
+    data: &crate::parse::ParsedAeroData,
+    equipment_cache: &mut EquipmentCache,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"INSERT INTO unit_aero_data (
+               unit_id, safe_thrust, structural_integrity, fuel_points,
+               heat_sink_count, heat_sink_type, cockpit_type, engine_type, armor_type
+           ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9)
+           ON CONFLICT (unit_id) DO UPDATE SET
+               safe_thrust          = EXCLUDED.safe_thrust,
+               structural_integrity = EXCLUDED.structural_integrity,
+               fuel_points          = EXCLUDED.fuel_points,
+               heat_sink_count      = EXCLUDED.heat_sink_count,
+               heat_sink_type       = EXCLUDED.heat_sink_type,
+               cockpit_type         = EXCLUDED.cockpit_type,
+               engine_type          = EXCLUDED.engine_type,
+               armor_type           = EXCLUDED.armor_type
+        "#,
+    )
+    .bind(unit_id)
+    .bind(data.safe_thrust)
+    .bind(data.structural_integrity)
+    .bind(data.fuel_points)
+    .bind(data.heat_sink_count)
+    .bind(&data.heat_sink_type)
+    .bind(&data.cockpit_type)
+    .bind(&data.engine_type)
+    .bind(&data.armor_type)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_aero_data for unit {unit_id}"))?;
+
+    sqlx::query("DELETE FROM unit_aero_weapon_bays WHERE unit_id = $1")
+        .bind(unit_id)
+        .execute(pool)
+        .await?;
+
+    for bay in &data.weapon_bays {
+        for (weapon, quantity) in &bay.weapons {
+            let eq_id = resolve_equipment_id(pool, weapon, unit, equipment_cache).await?;
+            sqlx::query(
+                r#"
+                INSERT INTO unit_aero_weapon_bays (unit_id, location, bay_number, equipment_id, quantity)
+                VALUES ($1, $2::location_name_enum, $3, $4, $5)
+                "#,
+            )
+            .bind(unit_id)
+            .bind(bay.location)
+            .bind(bay.bay_number as i16)
+            .bind(eq_id)
+            .bind(quantity)
+            .execute(pool)
+            .await
+            .with_context(|| {
+                format!("insert weapon bay {} #{} for unit {unit_id}", bay.location, bay.bay_number)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+// ── fluff and manufacturers ──────────────────────────────────────────────────
+
+/// Upsert lore fields (or drop the row when the file has none) and replace
+/// the unit's manufacturer links.
+pub async fn replace_fluff(
+    pool: &PgPool,
+    unit_id: i32,
+    fluff: &crate::parse::ParsedFluff,
+) -> anyhow::Result<()> {
+    if fluff.has_text() {
+        sqlx::query(
+            r#"INSERT INTO unit_fluff (unit_id, overview, capabilities, deployment, history, notes, image_file)
+               VALUES ($1,$2,$3,$4,$5,$6,$7)
+               ON CONFLICT (unit_id) DO UPDATE SET
+                   overview     = EXCLUDED.overview,
+                   capabilities = EXCLUDED.capabilities,
+                   deployment   = EXCLUDED.deployment,
+                   history      = EXCLUDED.history,
+                   notes        = EXCLUDED.notes,
+                   image_file   = EXCLUDED.image_file
+            "#,
+        )
+        .bind(unit_id)
+        .bind(&fluff.overview)
+        .bind(&fluff.capabilities)
+        .bind(&fluff.deployment)
+        .bind(&fluff.history)
+        .bind(&fluff.notes)
+        .bind(&fluff.image_file)
+        .execute(pool)
+        .await
+        .with_context(|| format!("upsert unit_fluff for unit {unit_id}"))?;
+    } else {
+        sqlx::query("DELETE FROM unit_fluff WHERE unit_id = $1")
+            .bind(unit_id)
+            .execute(pool)
+            .await?;
+    }
+
+    sqlx::query("DELETE FROM unit_manufacturers WHERE unit_id = $1")
+        .bind(unit_id)
+        .execute(pool)
+        .await?;
+
+    for (i, m) in fluff.manufacturers.iter().enumerate() {
+        let slug = crate::parse::to_slug(&m.name);
+        if slug.is_empty() {
+            continue;
+        }
+        let manufacturer_id: i32 = sqlx::query_scalar(
+            r#"INSERT INTO manufacturers (slug, name) VALUES ($1, $2)
+               ON CONFLICT (slug) DO UPDATE SET name = manufacturers.name
+               RETURNING id"#,
+        )
+        .bind(&slug)
+        .bind(&m.name)
+        .fetch_one(pool)
+        .await
+        .with_context(|| format!("upsert manufacturer {}", m.name))?;
+
+        sqlx::query(
+            r#"INSERT INTO unit_manufacturers (unit_id, manufacturer_id, factory, system, sort_order)
+               VALUES ($1, $2, $3, $4, $5)"#,
+        )
+        .bind(unit_id)
+        .bind(manufacturer_id)
+        .bind(&m.factory)
+        .bind(&m.system)
+        .bind(i as i32)
+        .execute(pool)
+        .await?;
+    }
+    Ok(())
+}
+
 // ── quirks ────────────────────────────────────────────────────────────────────
 
 /// Ensure quirk row exists; return its id.
@@ -0,0 +1,157 @@
+//! Canonical equipment catalog.
+//!
+//! `data/equipment_catalog.json` lists every piece of equipment the importer
+//! knows, keyed on its MegaMek internal name (`ISERLargeLaser`) with the other
+//! spellings unit files use (`ER Large Laser`, `IS ER Large Laser`) as lookup
+//! names. Each entry fixes the equipment's slug, display name, category and
+//! tech base, so the importer never has to guess them from the name. Aliases
+//! are extra slugs the entry answers to in `equipment_stats.json`. Cluster
+//! missile launchers also carry their rack size.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+const CATALOG_JSON: &str = include_str!("../../../data/equipment_catalog.json");
+
+/// Values of `equipment_category_enum`.
+const CATEGORIES: &[&str] = &[
+    "energy_weapon",
+    "ballistic_weapon",
+    "missile_weapon",
+    "physical_weapon",
+    "ammunition",
+    "equipment",
+    "armor",
+    "structure",
+    "engine",
+    "gyro",
+    "cockpit",
+    "actuator",
+    "heat_sink",
+    "jump_jet",
+    "targeting_computer",
+    "targeting_system",
+    "communications",
+    "myomer",
+];
+
+const TECH_BASES: &[&str] = &["inner_sphere", "clan", "mixed", "primitive"];
+
+#[derive(Debug, Deserialize)]
+pub struct CatalogEntry {
+    pub internal_name: String,
+    pub slug: String,
+    pub name: String,
+    pub category: String,
+    pub tech_base: String,
+    /// Other spellings of the equipment in unit files.
+    #[serde(default)]
+    pub lookup_names: Vec<String>,
+    /// Other slugs the entry answers to.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Missiles per salvo of a cluster launcher (LRM, SRM, ATM, MRM, ...).
+    #[serde(default)]
+    pub rack_size: Option<i32>,
+}
+
+impl CatalogEntry {
+    pub fn is_weapon(&self) -> bool {
+        self.category.ends_with("_weapon")
+    }
+}
+
+pub struct Catalog {
+    entries: Vec<CatalogEntry>,
+    /// Lookup key of the internal name and each lookup name → entry index.
+    by_name: HashMap<String, usize>,
+    /// Slug and aliases → entry index.
+    by_slug: HashMap<String, usize>,
+}
+
+static CATALOG: LazyLock<Catalog> =
+    LazyLock::new(|| Catalog::parse(CATALOG_JSON).expect("data/equipment_catalog.json is invalid"));
+
+/// The catalog embedded at build time.
+pub fn catalog() -> &'static Catalog {
+    &CATALOG
+}
+
+impl Catalog {
+    fn parse(json: &str) -> anyhow::Result<Self> {
+        let entries: Vec<CatalogEntry> = serde_json::from_str(json)?;
+        let mut by_name = HashMap::new();
+        let mut by_slug = HashMap::new();
+        for (i, entry) in entries.iter().enumerate() {
+            anyhow::ensure!(
+                CATEGORIES.contains(&entry.category.as_str()),
+                "{}: unknown category {}",
+                entry.internal_name,
+                entry.category
+            );
+            anyhow::ensure!(
+                TECH_BASES.contains(&entry.tech_base.as_str()),
+                "{}: unknown tech base {}",
+                entry.internal_name,
+                entry.tech_base
+            );
+            for name in std::iter::once(&entry.internal_name).chain(&entry.lookup_names) {
+                if let Some(j) = by_name.insert(lookup_key(name), i) {
+                    anyhow::ensure!(
+                        j == i,
+                        "{name} is listed by {} and {}",
+                        entries[j].internal_name,
+                        entry.internal_name
+                    );
+                }
+            }
+            for slug in std::iter::once(&entry.slug).chain(&entry.aliases) {
+                if let Some(j) = by_slug.insert(slug.clone(), i) {
+                    anyhow::bail!(
+                        "slug {slug} is used by {} and {}",
+                        entries[j].internal_name,
+                        entry.internal_name
+                    );
+                }
+            }
+        }
+        Ok(Self { entries, by_name, by_slug })
+    }
+
+    /// Find the entry for an equipment name as written in a unit file.
+    pub fn resolve(&self, name: &str) -> Option<&CatalogEntry> {
+        self.by_name.get(&lookup_key(name)).map(|&i| &self.entries[i])
+    }
+
+    /// Find the entry for a slug or alias.
+    pub fn by_slug(&self, slug: &str) -> Option<&CatalogEntry> {
+        self.by_slug.get(slug).map(|&i| &self.entries[i])
+    }
+}
+
+/// Normalise an equipment name for lookup: drop bay and rear markers, mount
+/// suffixes such as "(omnipod)" or "(T)", anything after a ':' and case.
+fn lookup_key(name: &str) -> String {
+    const PREFIXES: &[&str] = &["(b)", "(r)"];
+    const SUFFIXES: &[&str] = &["(r)", "(omnipod)", "(t)", "(st)", "(pt)", "(armored)", "(split)"];
+
+    let mut s = name.split(':').next().unwrap_or(name).trim().to_lowercase();
+    loop {
+        let before = s.len();
+        for p in PREFIXES {
+            if let Some(rest) = s.strip_prefix(p) {
+                s = rest.trim_start().to_string();
+            }
+        }
+        for p in SUFFIXES {
+            if let Some(rest) = s.strip_suffix(p) {
+                s = rest.trim_end().to_string();
+            }
+        }
+        if s.len() == before {
+            return s;
+        }
+    }
+}
@@ -1,12 +1,15 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::path::Path;
 
-use anyhow::Context;
+use anyhow::{bail, Context};
 use rust_decimal::Decimal;
 use serde::Deserialize;
-use sqlx::Row;
+use sqlx::{PgPool, Row};
 use tracing::{info, warn};
 
+use crate::equipment_catalog::catalog;
+use crate::provenance::{self, RunCounts};
+
 #[derive(Debug, Deserialize)]
 pub struct EquipmentStats {
     pub slug: String,
@@ -19,74 +22,28 @@ pub struct EquipmentStats {
     pub range_medium: Option<i32>,
     pub range_long: Option<i32>,
     pub bv: Option<i32>,
+    /// TechManual tech rating, A-F.
+    pub tech_rating: Option<String>,
+    /// TechManual availability ratings "SL-SW-CI-DA" (Star League, Succession
+    /// Wars, Clan Invasion, Dark Age), each A-F or X (e.g. "X-X-E-D").
+    pub availability: Option<String>,
+    /// Progression per tech base ("inner_sphere", "clan", ...).
+    #[serde(default)]
+    pub tech_progression: BTreeMap<String, TechProgression>,
 }
 
-/// Build a mapping from clean JSON slugs to MegaMek DB slugs.
-///
-/// MegaMek uses internal names like `CLERLargeLaser` or `ISUltraAC5` which get
-/// slugified to `clerlargelaser` / `isultraac5`.  The JSON uses human-readable
-/// slugs like `clan-er-large-laser` / `ultra-autocannon-5`.  This table bridges
-/// the two naming conventions.
-fn slug_aliases() -> HashMap<&'static str, &'static str> {
-    HashMap::from([
-        // Clan energy weapons
-        ("clan-er-large-laser",     "clerlargelaser"),
-        ("clan-er-medium-laser",    "clermediumlaser"),
-        ("clan-er-small-laser",     "clersmalllaser"),
-        ("clan-er-ppc",             "clerppc"),
-        ("clan-large-pulse-laser",  "cllargepulselaser"),
-        ("clan-medium-pulse-laser", "clmediumpulselaser"),
-        ("clan-small-pulse-laser",  "clsmallpulselaser"),
-        ("clan-er-flamer",          "clerflamer"),
-        ("clan-plasma-cannon",      "clplasmacannon"),
-        // IS energy weapons (pulse)
-        ("pulse-large-laser",       "islargepulselaser"),
-        ("pulse-medium-laser",      "ismediumpulselaser"),
-        ("pulse-small-laser",       "issmallpulselaser"),
-        // IS ballistic weapons
-        ("ultra-autocannon-2",      "isultraac2"),
-        ("ultra-autocannon-5",      "isultraac5"),
-        ("ultra-autocannon-10",     "isultraac10"),
-        ("ultra-autocannon-20",     "isultraac20"),
-        ("rotary-autocannon-5",     "isrotaryac5"),
-        ("light-autocannon-5",      "light-ac-5"),
-        // Clan ballistic weapons
-        ("clan-ultra-autocannon-2",  "clultraac2"),
-        ("clan-ultra-autocannon-5",  "clultraac5"),
-        ("clan-ultra-autocannon-10", "clultraac10"),
-        ("clan-ultra-autocannon-20", "clultraac20"),
-        ("clan-lb-2-x-ac",          "cllbxac2"),
-        ("clan-lb-5-x-ac",          "cllbxac5"),
-        ("clan-lb-10-x-ac",         "cllbxac10"),
-        ("clan-lb-20-x-ac",         "cllbxac20"),
-        ("clan-gauss-rifle",        "clgaussrifle"),
-        // Clan missile weapons
-        ("clan-srm-2",              "clsrm2"),
-        ("clan-srm-4",              "clsrm4"),
-        ("clan-srm-6",              "clsrm6"),
-        ("clan-lrm-5",              "cllrm5"),
-        ("clan-lrm-10",             "cllrm10"),
-        ("clan-lrm-15",             "cllrm15"),
-        ("clan-lrm-20",             "cllrm20"),
-        ("clan-streak-srm-2",       "clstreaksrm2"),
-        ("clan-streak-srm-4",       "clstreaksrm4"),
-        ("clan-streak-srm-6",       "clstreaksrm6"),
-        ("clan-arrow-iv",           "clarrowiv"),
-        // IS missile
-        ("narc-missile-beacon",     "narc"),
-        // Electronics / equipment
-        ("guardian-ecm-suite",      "isguardianecmsuite"),
-        ("clan-ecm-suite",          "clecmsuite"),
-        ("beagle-active-probe",     "beagleactiveprobe"),
-        ("clan-active-probe",       "clactiveprobe"),
-        ("clan-anti-missile-system","clantimissilesystem"),
-        ("targeting-computer",      "istargeting-computer"),
-        ("artemis-iv-fcs",          "isartemisiv"),
-        ("c3-master-computer",      "isc3mastercomputer"),
-        ("c3-slave-unit",           "isc3slaveunit"),
-    ])
+/// TechManual progression years in one tech base.
+#[derive(Debug, Deserialize)]
+pub struct TechProgression {
+    pub prototype: Option<i32>,
+    pub production: Option<i32>,
+    pub common: Option<i32>,
+    pub extinct: Option<i32>,
+    pub reintroduced: Option<i32>,
 }
 
+const TECH_BASES: &[&str] = &["inner_sphere", "clan", "mixed", "primitive"];
+
 pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -> anyhow::Result<()> {
     let pool = sqlx::postgres::PgPoolOptions::new()
         .max_connections(pool_size)
@@ -94,6 +51,14 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
         .await
         .context("connecting to database")?;
 
+    let version = file.file_name().map(|n| n.to_string_lossy().into_owned());
+    let run_id = provenance::start_run(&pool, "equipment_seed", version.as_deref()).await?;
+    let result = seed(&pool, run_id, file, force).await;
+    provenance::finish_run(&pool, run_id, &result).await?;
+    result.map(|_| ())
+}
+
+async fn seed(pool: &PgPool, run_id: i32, file: &Path, force: bool) -> anyhow::Result<RunCounts> {
     let content = std::fs::read_to_string(file)
         .with_context(|| format!("reading {:?}", file))?;
     let entries: Vec<EquipmentStats> =
@@ -101,24 +66,30 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
 
     info!(count = entries.len(), "loaded equipment stats entries");
 
-    let aliases = slug_aliases();
+    let adopted = crate::db::adopt_catalog_equipment(pool).await?;
+    info!(adopted = adopted.adopted, merged = adopted.merged, "equipment adopted into catalog");
+
+    let multipliers = crate::seed::seed_bv_multipliers(pool).await?;
+    info!(rows = multipliers, "BV multipliers seeded");
+
     let mut updated = 0u32;
     let mut not_found = 0u32;
     let mut unchanged = 0u32;
     let mut alias_hits = 0u32;
+    let mut errors = Vec::new();
 
     for entry in &entries {
-        // Try exact slug match first, then alias fallback
+        // Try exact slug match first, then the catalog slug it is an alias of
         let mut row = sqlx::query("SELECT id FROM equipment WHERE slug = $1")
             .bind(&entry.slug)
-            .fetch_optional(&pool)
+            .fetch_optional(pool)
             .await?;
 
         if row.is_none() {
-            if let Some(&alt) = aliases.get(entry.slug.as_str()) {
+            if let Some(canonical) = catalog().by_slug(&entry.slug).filter(|c| c.slug != entry.slug) {
                 row = sqlx::query("SELECT id FROM equipment WHERE slug = $1")
-                    .bind(alt)
-                    .fetch_optional(&pool)
+                    .bind(&canonical.slug)
+                    .fetch_optional(pool)
                     .await?;
                 if row.is_some() {
                     alias_hits += 1;
@@ -133,7 +104,7 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
         };
         let eq_id: i32 = r.try_get("id")?;
 
-        if force {
+        let stats_changed = if force {
             let result = sqlx::query(
                 r#"UPDATE equipment SET
                      tonnage      = $2,
@@ -146,7 +117,8 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
                      range_long   = $9,
                      bv           = $10,
                      stats_source = 'seed',
-                     stats_updated_at = now()
+                     stats_updated_at = now(),
+                     stats_import_run_id = $11
                    WHERE id = $1"#,
             )
             .bind(eq_id)
@@ -159,14 +131,10 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
             .bind(entry.range_medium)
             .bind(entry.range_long)
             .bind(entry.bv)
-            .execute(&pool)
+            .bind(run_id)
+            .execute(pool)
             .await?;
-
-            if result.rows_affected() > 0 {
-                updated += 1;
-            } else {
-                unchanged += 1;
-            }
+            result.rows_affected() > 0
         } else {
             // Only update NULL columns
             let result = sqlx::query(
@@ -181,7 +149,8 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
                      range_long   = COALESCE(range_long, $9),
                      bv           = COALESCE(bv, $10),
                      stats_source = COALESCE(stats_source, 'seed'),
-                     stats_updated_at = COALESCE(stats_updated_at, now())
+                     stats_updated_at = COALESCE(stats_updated_at, now()),
+                     stats_import_run_id = $11
                    WHERE id = $1
                      AND (tonnage IS NULL OR crits IS NULL OR damage IS NULL
                           OR heat IS NULL OR range_min IS NULL OR range_short IS NULL
@@ -197,14 +166,25 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
             .bind(entry.range_medium)
             .bind(entry.range_long)
             .bind(entry.bv)
-            .execute(&pool)
+            .bind(run_id)
+            .execute(pool)
             .await?;
+            result.rows_affected() > 0
+        };
 
-            if result.rows_affected() > 0 {
-                updated += 1;
-            } else {
-                unchanged += 1;
+        let tech_changed = match seed_tech(pool, run_id, eq_id, entry, force).await {
+            Ok(changed) => changed,
+            Err(e) => {
+                warn!(slug = %entry.slug, error = %e, "skipping tech data");
+                errors.push(format!("{}: {e:#}", entry.slug));
+                false
             }
+        };
+
+        if stats_changed || tech_changed {
+            updated += 1;
+        } else {
+            unchanged += 1;
         }
     }
 
@@ -216,5 +196,160 @@ pub async fn run(file: &Path, database_url: &str, pool_size: u32, force: bool) -
         "equipment seed complete"
     );
 
-    Ok(())
+    let converted = crate::db::convert_legacy_loadout(pool, run_id).await?;
+    info!(rows = converted, "legacy loadout quantities converted");
+
+    Ok(RunCounts {
+        processed: entries.len(),
+        imported: updated as usize,
+        skipped: (not_found + unchanged) as usize,
+        errors,
+    })
+}
+
+/// Write an entry's tech rating, availability ratings and progression, and
+/// set `intro_year` from the earliest production (or prototype) year. Without
+/// `force` only NULL columns are filled. Returns whether a row was written.
+async fn seed_tech(
+    pool: &PgPool,
+    run_id: i32,
+    eq_id: i32,
+    entry: &EquipmentStats,
+    force: bool,
+) -> anyhow::Result<bool> {
+    if let Some(r) = entry.tech_rating.as_deref() {
+        if !["A", "B", "C", "D", "E", "F"].contains(&r) {
+            bail!("invalid tech rating '{r}', expected A-F");
+        }
+    }
+    let availability = entry
+        .availability
+        .as_deref()
+        .map(parse_availability)
+        .transpose()?;
+    if let Some(tb) = entry
+        .tech_progression
+        .keys()
+        .find(|tb| !TECH_BASES.contains(&tb.as_str()))
+    {
+        bail!("unknown tech base '{tb}' in tech_progression");
+    }
+    let progression = entry.tech_progression.values();
+    let intro_year = progression
+        .clone()
+        .filter_map(|p| p.production)
+        .min()
+        .or_else(|| progression.filter_map(|p| p.prototype).min());
+
+    let mut tx = pool.begin().await?;
+    let mut written = 0;
+
+    let sql = if force {
+        r#"UPDATE equipment SET
+             tech_rating       = COALESCE($2, tech_rating),
+             availability_sl   = COALESCE($3, availability_sl),
+             availability_sw   = COALESCE($4, availability_sw),
+             availability_clan = COALESCE($5, availability_clan),
+             availability_da   = COALESCE($6, availability_da),
+             intro_year        = COALESCE($7, intro_year),
+             stats_import_run_id = $8
+           WHERE id = $1
+             AND (tech_rating, availability_sl, availability_sw, availability_clan,
+                  availability_da, intro_year)
+                 IS DISTINCT FROM
+                 (COALESCE($2, tech_rating), COALESCE($3, availability_sl),
+                  COALESCE($4, availability_sw), COALESCE($5, availability_clan),
+                  COALESCE($6, availability_da), COALESCE($7, intro_year))"#
+    } else {
+        r#"UPDATE equipment SET
+             tech_rating       = COALESCE(tech_rating, $2),
+             availability_sl   = COALESCE(availability_sl, $3),
+             availability_sw   = COALESCE(availability_sw, $4),
+             availability_clan = COALESCE(availability_clan, $5),
+             availability_da   = COALESCE(availability_da, $6),
+             intro_year        = COALESCE(intro_year, $7),
+             stats_import_run_id = $8
+           WHERE id = $1
+             AND ((tech_rating IS NULL AND $2 IS NOT NULL)
+                  OR (availability_sl IS NULL AND $3 IS NOT NULL)
+                  OR (availability_sw IS NULL AND $4 IS NOT NULL)
+                  OR (availability_clan IS NULL AND $5 IS NOT NULL)
+                  OR (availability_da IS NULL AND $6 IS NOT NULL)
+                  OR (intro_year IS NULL AND $7 IS NOT NULL))"#
+    };
+    let result = sqlx::query(sql)
+        .bind(eq_id)
+        .bind(&entry.tech_rating)
+        .bind(availability.as_ref().map(|a| &a[0]))
+        .bind(availability.as_ref().map(|a| &a[1]))
+        .bind(availability.as_ref().map(|a| &a[2]))
+        .bind(availability.as_ref().map(|a| &a[3]))
+        .bind(intro_year)
+        .bind(run_id)
+        .execute(&mut *tx)
+        .await?;
+    written += result.rows_affected();
+
+    let conflict = if force {
+        r#"DO UPDATE SET
+               prototype_year  = EXCLUDED.prototype_year,
+               production_year = EXCLUDED.production_year,
+               common_year     = EXCLUDED.common_year,
+               extinction_year = EXCLUDED.extinction_year,
+               reintro_year    = EXCLUDED.reintro_year
+           WHERE (t.prototype_year, t.production_year, t.common_year,
+                  t.extinction_year, t.reintro_year)
+                 IS DISTINCT FROM
+                 (EXCLUDED.prototype_year, EXCLUDED.production_year, EXCLUDED.common_year,
+                  EXCLUDED.extinction_year, EXCLUDED.reintro_year)"#
+    } else {
+        r#"DO UPDATE SET
+               prototype_year  = COALESCE(t.prototype_year, EXCLUDED.prototype_year),
+               production_year = COALESCE(t.production_year, EXCLUDED.production_year),
+               common_year     = COALESCE(t.common_year, EXCLUDED.common_year),
+               extinction_year = COALESCE(t.extinction_year, EXCLUDED.extinction_year),
+               reintro_year    = COALESCE(t.reintro_year, EXCLUDED.reintro_year)
+           WHERE (t.prototype_year IS NULL AND EXCLUDED.prototype_year IS NOT NULL)
+              OR (t.production_year IS NULL AND EXCLUDED.production_year IS NOT NULL)
+              OR (t.common_year IS NULL AND EXCLUDED.common_year IS NOT NULL)
+              OR (t.extinction_year IS NULL AND EXCLUDED.extinction_year IS NOT NULL)
+              OR (t.reintro_year IS NULL AND EXCLUDED.reintro_year IS NOT NULL)"#
+    };
+    for (tech_base, p) in &entry.tech_progression {
+        let result = sqlx::query(&format!(
+            r#"INSERT INTO equipment_tech_progression AS t
+                   (equipment_id, tech_base, prototype_year, production_year,
+                    common_year, extinction_year, reintro_year)
+               VALUES ($1, $2::tech_base_enum, $3, $4, $5, $6, $7)
+               ON CONFLICT (equipment_id, tech_base) {conflict}"#
+        ))
+        .bind(eq_id)
+        .bind(tech_base)
+        .bind(p.prototype)
+        .bind(p.production)
+        .bind(p.common)
+        .bind(p.extinct)
+        .bind(p.reintroduced)
+        .execute(&mut *tx)
+        .await?;
+        written += result.rows_affected();
+    }
+
+    tx.commit().await?;
+    Ok(written > 0)
+}
+
+/// Split availability ratings ("X-X-E-D") into the SL, SW, CI and DA ratings.
+fn parse_availability(code: &str) -> anyhow::Result<[String; 4]> {
+    let ratings: Vec<String> = code.split('-').map(|r| r.trim().to_uppercase()).collect();
+    match <[String; 4]>::try_from(ratings) {
+        Ok(ratings)
+            if ratings
+                .iter()
+                .all(|r| ["A", "B", "C", "D", "E", "F", "X"].contains(&r.as_str())) =>
+        {
+            Ok(ratings)
+        }
+        _ => bail!("invalid availability '{code}', expected four of A-F/X like \"X-X-E-D\""),
+    }
 }
@@ -0,0 +1,632 @@
+//! Weighted availability from the MegaMek force generator.
+//!
+//! A MegaMek release ships `data/forcegenerator/`: `factions.xml` plus one
+//! file per snapshot year (`3025.xml`, `3050.xml`, ...). Each year file lists
+//! chassis and models with per-faction availability ratings:
+//!
+//! ```xml
+//! <chassis name="Atlas" unitType="Mek">
+//!   <availability>FS:5,LA:6,DC:4-,CS:3</availability>
+//!   <model name="AS7-D">
+//!     <availability>FS:6,LA:6,DC:5</availability>
+//!   </model>
+//! </chassis>
+//! ```
+//!
+//! Ratings run from 0 (very rare) to 10 (very common); each two points double
+//! the frequency. A variant's weight is its chassis weight times its share of
+//! the chassis' model weights. Factions without their own entry inherit from
+//! their parent factions. Year files are grouped into our eras and averaged.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use anyhow::{bail, Context};
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+use tracing::{debug, info, warn};
+
+use crate::{
+    mul::mappings,
+    parse::to_slug,
+    provenance::{self, RunCounts},
+};
+
+/// Rows per batched INSERT.
+const BATCH_SIZE: usize = 5000;
+
+/// Parent faction lookups stop after this many levels.
+const MAX_PARENT_DEPTH: usize = 4;
+
+/// Force generator faction keys for factions whose display names differ from
+/// the MUL names in `mul::mappings::faction_mappings`.
+fn faction_key_mappings() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("LA", "steiner"),
+        ("FS", "davion"),
+        ("FC", "davion"),
+        ("DC", "kurita"),
+        ("FWL", "marik"),
+        ("CC", "liao"),
+        ("SL", "star-league"),
+        ("CS", "comstar"),
+        ("WOB", "word-of-blake"),
+        ("ROS", "republic"),
+        ("CW", "clan-wolf"),
+        ("CWIE", "clan-wolf"),
+        ("CJF", "clan-jade-falcon"),
+        ("CGB", "clan-ghost-bear"),
+        ("RD", "clan-ghost-bear"),
+        ("CSJ", "clan-smoke-jaguar"),
+        ("CNC", "clan-nova-cat"),
+        ("CSV", "clan-steel-viper"),
+        ("CDS", "clan-diamond-shark"),
+        ("CGS", "clan-goliath-scorpion"),
+        ("CIH", "clan-ice-hellion"),
+        ("CSA", "clan-star-adder"),
+        ("CHH", "clan-hell-horses"),
+        ("CBS", "clan-blood-spirit"),
+        ("CCO", "clan-coyote"),
+        ("CFM", "clan-fire-mandrill"),
+        ("CMG", "clan-mongoose"),
+        ("CWM", "clan-widowmaker"),
+        ("CWOV", "clan-wolverine"),
+        ("TC", "taurian-concordat"),
+        ("MOC", "magistracy-canopus"),
+        ("OA", "outworlds-alliance"),
+        ("MH", "marian-hegemony"),
+        ("MERC", "mercenary"),
+    ])
+}
+
+// ── XML scanning ─────────────────────────────────────────────────────────────
+
+/// Minimal XML event stream: enough for the force generator files, which use
+/// plain elements, attributes and text only.
+#[derive(Debug)]
+enum Event {
+    Start(String, Vec<(String, String)>),
+    End(String),
+    Text(String),
+}
+
+fn unescape(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|h| u32::from_str_radix(h, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn parse_attrs(s: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = s.trim();
+    while let Some(eq) = rest.find('=') {
+        let name = rest[..eq].trim().to_string();
+        let after = rest[eq + 1..].trim_start();
+        let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(close) = after[1..].find(quote) else {
+            break;
+        };
+        attrs.push((name, unescape(&after[1..1 + close])));
+        rest = after[close + 2..].trim_start();
+    }
+    attrs
+}
+
+fn scan(xml: &str) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut rest = xml;
+    while let Some(lt) = rest.find('<') {
+        let text = rest[..lt].trim();
+        if !text.is_empty() {
+            events.push(Event::Text(unescape(text)));
+        }
+        rest = &rest[lt..];
+        if let Some(body) = rest.strip_prefix("<!--") {
+            rest = body.find("-->").map_or("", |e| &body[e + 3..]);
+            continue;
+        }
+        if let Some(body) = rest.strip_prefix("<![CDATA[") {
+            let end = body.find("]]>").unwrap_or(body.len());
+            events.push(Event::Text(body[..end].to_string()));
+            rest = body.get(end + 3..).unwrap_or("");
+            continue;
+        }
+        let Some(gt) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            events.push(Event::End(name.trim().to_string()));
+            continue;
+        }
+        let (tag, self_closing) = match tag.strip_suffix('/') {
+            Some(t) => (t, true),
+            None => (tag, false),
+        };
+        let (name, attrs) = match tag.find(char::is_whitespace) {
+            Some(sp) => (&tag[..sp], parse_attrs(&tag[sp..])),
+            None => (tag, Vec::new()),
+        };
+        events.push(Event::Start(name.to_string(), attrs));
+        if self_closing {
+            events.push(Event::End(name.to_string()));
+        }
+    }
+    events
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+// ── Force generator data ─────────────────────────────────────────────────────
+
+struct FactionRecord {
+    name: String,
+    parents: Vec<String>,
+}
+
+/// Faction key → ratings. Several entries for one faction (e.g. per equipment
+/// rating, "FS!A:6,FS!B:5") collapse to the highest rating.
+type Ratings = HashMap<String, i32>;
+
+struct ModelRecord {
+    name: String,
+    ratings: Ratings,
+}
+
+struct ChassisRecord {
+    name: String,
+    ratings: Ratings,
+    models: Vec<ModelRecord>,
+}
+
+/// Parse an availability list such as "FS:5,LA:6+,DC!A:4,CS:3:3028".
+fn parse_ratings(text: &str) -> Ratings {
+    let mut ratings = Ratings::new();
+    for entry in text.split(',') {
+        let mut fields = entry.trim().split(':');
+        let (Some(key), Some(value)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let key = key.split('!').next().unwrap_or(key).trim();
+        let digits: String = value
+            .trim()
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        let Ok(rating) = digits.parse::<i32>() else {
+            continue;
+        };
+        if key.is_empty() {
+            continue;
+        }
+        let slot = ratings.entry(key.to_string()).or_insert(rating);
+        *slot = (*slot).max(rating);
+    }
+    ratings
+}
+
+fn parse_factions(xml: &str) -> HashMap<String, FactionRecord> {
+    let mut factions = HashMap::new();
+    let mut current: Option<(String, FactionRecord)> = None;
+    let mut in_parent = false;
+    for event in scan(xml) {
+        match event {
+            Event::Start(name, attrs) if name == "faction" => {
+                if let Some(key) = attr(&attrs, "key") {
+                    let record = FactionRecord {
+                        name: attr(&attrs, "name").unwrap_or(key).to_string(),
+                        parents: Vec::new(),
+                    };
+                    current = Some((key.to_string(), record));
+                }
+            }
+            Event::Start(name, _) if name == "parentFaction" => in_parent = true,
+            Event::Text(text) if in_parent => {
+                if let Some((_, record)) = current.as_mut() {
+                    record
+                        .parents
+                        .extend(text.split(',').map(|p| p.trim().to_string()));
+                }
+            }
+            Event::End(name) if name == "parentFaction" => in_parent = false,
+            Event::End(name) if name == "faction" => {
+                if let Some((key, record)) = current.take() {
+                    factions.insert(key, record);
+                }
+            }
+            _ => {}
+        }
+    }
+    factions
+}
+
+fn parse_year_file(xml: &str) -> Vec<ChassisRecord> {
+    let mut chassis: Vec<ChassisRecord> = Vec::new();
+    let mut in_chassis = false;
+    let mut in_model = false;
+    let mut in_availability = false;
+    for event in scan(xml) {
+        match event {
+            Event::Start(name, attrs) if name == "chassis" => {
+                chassis.push(ChassisRecord {
+                    name: attr(&attrs, "name").unwrap_or_default().to_string(),
+                    ratings: Ratings::new(),
+                    models: Vec::new(),
+                });
+                in_chassis = true;
+            }
+            Event::Start(name, attrs) if name == "model" && in_chassis => {
+                if let Some(c) = chassis.last_mut() {
+                    c.models.push(ModelRecord {
+                        name: attr(&attrs, "name").unwrap_or_default().to_string(),
+                        ratings: Ratings::new(),
+                    });
+                }
+                in_model = true;
+            }
+            Event::Start(name, _) if name == "availability" => in_availability = true,
+            Event::Text(text) if in_availability && in_chassis => {
+                if let Some(c) = chassis.last_mut() {
+                    let target = match c.models.last_mut() {
+                        Some(m) if in_model => &mut m.ratings,
+                        _ => &mut c.ratings,
+                    };
+                    for (k, v) in parse_ratings(&text) {
+                        let slot = target.entry(k).or_insert(v);
+                        *slot = (*slot).max(v);
+                    }
+                }
+            }
+            Event::End(name) if name == "availability" => in_availability = false,
+            Event::End(name) if name == "model" => in_model = false,
+            Event::End(name) if name == "chassis" => in_chassis = false,
+            _ => {}
+        }
+    }
+    chassis
+}
+
+/// Rating for a faction, falling back to its parent factions.
+fn lookup(
+    ratings: &Ratings,
+    key: &str,
+    factions: &HashMap<String, FactionRecord>,
+    depth: usize,
+) -> Option<i32> {
+    if let Some(&r) = ratings.get(key) {
+        return Some(r);
+    }
+    if depth >= MAX_PARENT_DEPTH {
+        return None;
+    }
+    factions
+        .get(key)?
+        .parents
+        .iter()
+        .find_map(|p| lookup(ratings, p, factions, depth + 1))
+}
+
+fn rating_weight(rating: i32) -> f64 {
+    2f64.powf(rating as f64 / 2.0)
+}
+
+// ── entry point ──────────────────────────────────────────────────────────────
+
+/// Run the forcegen-import subcommand.
+pub async fn run(dir: &Path, database_url: &str, pool_size: u32) -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(pool_size)
+        .connect(database_url)
+        .await
+        .context("connecting to database")?;
+
+    let run_id = provenance::start_run(&pool, "forcegen", None).await?;
+    let result = import(&pool, run_id, dir).await;
+    provenance::finish_run(&pool, run_id, &result).await?;
+    result.map(|_| ())
+}
+
+async fn import(pool: &PgPool, run_id: i32, dir: &Path) -> anyhow::Result<RunCounts> {
+    let factions_xml = std::fs::read_to_string(dir.join("factions.xml"))
+        .with_context(|| format!("reading {}", dir.join("factions.xml").display()))?;
+    let factions = parse_factions(&factions_xml);
+    info!(count = factions.len(), "force generator factions loaded");
+
+    let mut year_files: Vec<(i32, std::path::PathBuf)> = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let year = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse::<i32>().ok());
+        if let (Some(year), Some("xml")) = (year, path.extension().and_then(|e| e.to_str())) {
+            year_files.push((year, path));
+        }
+    }
+    year_files.sort();
+    if year_files.is_empty() {
+        bail!("no year files (e.g. 3025.xml) found in {}", dir.display());
+    }
+
+    let faction_ids = resolve_factions(pool, &factions).await?;
+    let eras = load_eras(pool).await?;
+    let unit_ids = load_unit_slugs(pool).await?;
+
+    // Each year file belongs to the era containing it; eras without a file
+    // use the latest file before they start.
+    let mut files_per_era: HashMap<i32, Vec<usize>> = HashMap::new();
+    for &(era_id, start, end) in &eras {
+        let inside: Vec<usize> = year_files
+            .iter()
+            .enumerate()
+            .filter(|(_, (y, _))| *y >= start && end.is_none_or(|e| *y <= e))
+            .map(|(i, _)| i)
+            .collect();
+        let files = if inside.is_empty() {
+            year_files
+                .iter()
+                .rposition(|(y, _)| *y < start)
+                .into_iter()
+                .collect()
+        } else {
+            inside
+        };
+        if !files.is_empty() {
+            files_per_era.insert(era_id, files);
+        }
+    }
+
+    // (unit, faction, era) → summed weight across the era's files.
+    let mut weights: HashMap<(i32, i32, i32), f64> = HashMap::new();
+    let mut unmatched: HashSet<String> = HashSet::new();
+
+    for (idx, (year, path)) in year_files.iter().enumerate() {
+        let eras_for_file: Vec<(i32, f64)> = files_per_era
+            .iter()
+            .filter(|(_, files)| files.contains(&idx))
+            .map(|(&era, files)| (era, files.len() as f64))
+            .collect();
+        if eras_for_file.is_empty() {
+            continue;
+        }
+        let xml =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let chassis = parse_year_file(&xml);
+        debug!(year, chassis = chassis.len(), "parsed year file");
+
+        // Several keys can map to one faction: keep the highest weight.
+        let mut file_weights: HashMap<(i32, i32), f64> = HashMap::new();
+        for c in &chassis {
+            for (key, &faction_id) in &faction_ids {
+                let Some(chassis_rating) = lookup(&c.ratings, key, &factions, 0) else {
+                    continue;
+                };
+                let model_weights: Vec<(usize, f64)> = c
+                    .models
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, m)| {
+                        lookup(&m.ratings, key, &factions, 0).map(|r| (i, rating_weight(r)))
+                    })
+                    .collect();
+                let total: f64 = model_weights.iter().map(|(_, w)| w).sum();
+                if total <= 0.0 {
+                    continue;
+                }
+                for (i, mw) in model_weights {
+                    let model = &c.models[i];
+                    let full_name = if model.name.is_empty() {
+                        c.name.clone()
+                    } else {
+                        format!("{} {}", c.name, model.name)
+                    };
+                    let Some(&unit_id) = unit_ids.get(&to_slug(&full_name)) else {
+                        unmatched.insert(full_name);
+                        continue;
+                    };
+                    let weight = rating_weight(chassis_rating) * mw / total;
+                    let entry = file_weights.entry((unit_id, faction_id)).or_default();
+                    *entry = entry.max(weight);
+                }
+            }
+        }
+
+        for ((unit_id, faction_id), weight) in file_weights {
+            for &(era_id, files_in_era) in &eras_for_file {
+                *weights.entry((unit_id, faction_id, era_id)).or_default() += weight / files_in_era;
+            }
+        }
+    }
+
+    if !unmatched.is_empty() {
+        warn!(
+            count = unmatched.len(),
+            "force generator models not found in units"
+        );
+        let mut sample: Vec<&String> = unmatched.iter().collect();
+        sample.sort();
+        for name in sample.into_iter().take(20) {
+            debug!(%name, "unmatched model");
+        }
+    }
+
+    let rows = write_weights(pool, run_id, &weights).await?;
+    info!(
+        rows,
+        files = year_files.len(),
+        factions = faction_ids.len(),
+        "force generator availability import complete"
+    );
+    Ok(RunCounts {
+        processed: weights.len(),
+        imported: rows,
+        skipped: unmatched.len(),
+        errors: Vec::new(),
+    })
+}
+
+// ── DB helpers ───────────────────────────────────────────────────────────────
+
+/// Map force generator faction keys to DB faction ids: explicit key mapping
+/// first, then the MUL name mapping, then an exact name or slug match.
+async fn resolve_factions(
+    pool: &PgPool,
+    factions: &HashMap<String, FactionRecord>,
+) -> anyhow::Result<HashMap<String, i32>> {
+    let rows = sqlx::query("SELECT id, slug, name FROM factions")
+        .fetch_all(pool)
+        .await?;
+    let mut by_slug: HashMap<String, i32> = HashMap::new();
+    let mut by_name: HashMap<String, i32> = HashMap::new();
+    for r in rows {
+        let id: i32 = r.try_get("id")?;
+        by_slug.insert(r.try_get("slug")?, id);
+        by_name.insert(r.try_get::<String, _>("name")?.to_lowercase(), id);
+    }
+
+    let key_map = faction_key_mappings();
+    let name_map = mappings::faction_mappings();
+    let mut resolved = HashMap::new();
+    let mut skipped = 0usize;
+    for (key, record) in factions {
+        let id = key_map
+            .get(key.as_str())
+            .and_then(|slug| by_slug.get(*slug))
+            .or_else(|| {
+                name_map
+                    .get(record.name.as_str())
+                    .and_then(|slug| by_slug.get(*slug))
+            })
+            .or_else(|| by_name.get(&record.name.to_lowercase()))
+            .or_else(|| by_slug.get(&to_slug(&record.name)));
+        match id {
+            Some(&id) => {
+                resolved.insert(key.clone(), id);
+            }
+            None => skipped += 1,
+        }
+    }
+    info!(
+        matched = resolved.len(),
+        skipped, "force generator factions resolved"
+    );
+    Ok(resolved)
+}
+
+async fn load_eras(pool: &PgPool) -> anyhow::Result<Vec<(i32, i32, Option<i32>)>> {
+    let rows = sqlx::query("SELECT id, start_year, end_year FROM eras ORDER BY start_year")
+        .fetch_all(pool)
+        .await?;
+    rows.iter()
+        .map(|r| {
+            Ok((
+                r.try_get("id")?,
+                r.try_get("start_year")?,
+                r.try_get("end_year")?,
+            ))
+        })
+        .collect()
+}
+
+async fn load_unit_slugs(pool: &PgPool) -> anyhow::Result<HashMap<String, i32>> {
+    let rows = sqlx::query("SELECT id, slug FROM units")
+        .fetch_all(pool)
+        .await?;
+    rows.iter()
+        .map(|r| Ok((r.try_get("slug")?, r.try_get("id")?)))
+        .collect()
+}
+
+/// Replace all weighted ratings. Rows that only exist in MUL keep a NULL weight.
+async fn write_weights(
+    pool: &PgPool,
+    run_id: i32,
+    weights: &HashMap<(i32, i32, i32), f64>,
+) -> anyhow::Result<usize> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("UPDATE unit_availability SET availability_code = NULL, weight = NULL WHERE weight IS NOT NULL")
+        .execute(&mut *tx)
+        .await?;
+
+    let rows: Vec<(&(i32, i32, i32), &f64)> = weights.iter().filter(|(_, w)| **w > 0.0).collect();
+    for chunk in rows.chunks(BATCH_SIZE) {
+        let mut unit_ids = Vec::with_capacity(chunk.len());
+        let mut faction_ids = Vec::with_capacity(chunk.len());
+        let mut era_ids = Vec::with_capacity(chunk.len());
+        let mut codes = Vec::with_capacity(chunk.len());
+        let mut ws = Vec::with_capacity(chunk.len());
+        for (&(unit_id, faction_id, era_id), &w) in chunk {
+            unit_ids.push(unit_id);
+            faction_ids.push(faction_id);
+            era_ids.push(era_id);
+            // Effective rating: the inverse of 2^(rating / 2).
+            codes.push(((2.0 * w.log2()).round().max(0.0) as i32).to_string());
+            ws.push(w);
+        }
+        sqlx::query(
+            r#"INSERT INTO unit_availability
+                   (unit_id, faction_id, era_id, availability_code, weight, last_import_run_id)
+               SELECT *, $6 FROM UNNEST($1::int4[], $2::int4[], $3::int4[], $4::text[], $5::float8[])
+               ON CONFLICT (unit_id, faction_id, era_id) DO UPDATE SET
+                   availability_code = EXCLUDED.availability_code,
+                   weight = EXCLUDED.weight,
+                   last_import_run_id = EXCLUDED.last_import_run_id"#,
+        )
+        .bind(&unit_ids)
+        .bind(&faction_ids)
+        .bind(&era_ids)
+        .bind(&codes)
+        .bind(&ws)
+        .bind(run_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(rows.len())
+}
@@ -1,13 +1,18 @@
+mod alpha_strike;
+mod dataset;
 mod db;
+mod equipment_catalog;
 mod equipment_seed;
+mod forcegen;
 mod mul;
 mod parse;
+mod planets;
+mod provenance;
 mod seed;
 
 use std::{
-    collections::HashMap,
     io::{BufReader, Read},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use anyhow::{bail, Context};
@@ -38,7 +43,7 @@ enum Command {
         #[arg(long, env = "DATABASE_URL")]
         database_url: String,
 
-        /// MegaMek version string stored in dataset_metadata.
+        /// MegaMek version string stored in dataset_metadata and the dataset snapshot.
         #[arg(long, default_value = "unknown")]
         version: String,
 
@@ -70,7 +75,7 @@ enum Command {
         types: Vec<u32>,
     },
 
-    /// Seed equipment stats from a JSON file into the database.
+    /// Seed equipment stats, tech ratings and tech progression from a JSON file into the database.
     EquipmentSeed {
         /// Path to the equipment stats JSON file.
         #[arg(long, value_name = "FILE")]
@@ -115,6 +120,90 @@ enum Command {
         #[arg(long)]
         overrides: Option<PathBuf>,
     },
+
+    /// Review unmatched MUL units from mul-import and write accepted matches to the overrides file.
+    MulReview {
+        /// Directory containing unmatched_mul_units.csv from mul-import.
+        #[arg(long, value_name = "DIR")]
+        data_dir: PathBuf,
+
+        /// Override DATABASE_URL (defaults to env var).
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: String,
+
+        /// JSON overrides file to update (created if missing).
+        #[arg(long, value_name = "FILE")]
+        overrides: PathBuf,
+
+        /// Accept the best candidate without prompting when it scores at least
+        /// this (0-1); others are skipped.
+        #[arg(long)]
+        auto_accept_threshold: Option<f64>,
+
+        /// Number of candidates shown per unit.
+        #[arg(long, default_value_t = 5)]
+        candidates: usize,
+    },
+
+    /// Import weighted faction/era availability from MegaMek force generator data.
+    ForcegenImport {
+        /// Path to data/forcegenerator from a MegaMek release (factions.xml + year files).
+        #[arg(long, value_name = "DIR")]
+        dir: PathBuf,
+
+        /// Override DATABASE_URL (defaults to env var).
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: String,
+
+        /// Maximum DB connections in pool.
+        #[arg(long, default_value_t = 5)]
+        pool_size: u32,
+    },
+
+    /// Print release notes (added, removed and changed units) between two dataset snapshots.
+    DatasetDiff {
+        /// Dataset version to diff from (a previous `megamek --version`).
+        #[arg(long, value_name = "VERSION")]
+        from: String,
+
+        /// Dataset version to diff to.
+        #[arg(long, value_name = "VERSION")]
+        to: String,
+
+        /// Override DATABASE_URL (defaults to env var).
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: String,
+    },
+
+    /// Import planets (coordinates, ownership by year, industry, factories) from a JSON file.
+    PlanetsImport {
+        /// Path to the planets JSON file.
+        #[arg(long, value_name = "FILE")]
+        file: PathBuf,
+
+        /// Override DATABASE_URL (defaults to env var).
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: String,
+
+        /// Maximum DB connections in pool.
+        #[arg(long, default_value_t = 5)]
+        pool_size: u32,
+    },
+
+    /// Derive Alpha Strike stats for mechs and vehicles without a MUL card.
+    AlphaStrikeConvert {
+        /// Override DATABASE_URL (defaults to env var).
+        #[arg(long, env = "DATABASE_URL")]
+        database_url: String,
+
+        /// Maximum DB connections in pool.
+        #[arg(long, default_value_t = 5)]
+        pool_size: u32,
+
+        /// Recompute existing conversions (MUL cards are never overwritten).
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 // ── entry point ───────────────────────────────────────────────────────────────
@@ -176,6 +265,42 @@ async fn main() -> anyhow::Result<()> {
             )
             .await
         }
+        Command::MulReview {
+            data_dir,
+            database_url,
+            overrides,
+            auto_accept_threshold,
+            candidates,
+        } => {
+            mul::review::run(
+                data_dir,
+                &database_url,
+                overrides,
+                auto_accept_threshold,
+                candidates,
+            )
+            .await
+        }
+        Command::ForcegenImport {
+            dir,
+            database_url,
+            pool_size,
+        } => forcegen::run(&dir, &database_url, pool_size).await,
+        Command::PlanetsImport {
+            file,
+            database_url,
+            pool_size,
+        } => planets::run(&file, &database_url, pool_size).await,
+        Command::DatasetDiff {
+            from,
+            to,
+            database_url,
+        } => dataset::run_diff(&database_url, &from, &to).await,
+        Command::AlphaStrikeConvert {
+            database_url,
+            pool_size,
+            force,
+        } => alpha_strike::run(&database_url, pool_size, force).await,
     }
 }
 
@@ -194,14 +319,40 @@ async fn run_megamek(
         .await
         .context("connecting to database")?;
 
+    let run_id = provenance::start_run(&pool, "megamek", Some(version)).await?;
+    let result = import_zip(&pool, run_id, &zip, version, max_errors).await;
+    provenance::finish_run(&pool, run_id, &result).await?;
+    result.map(|_| ())
+}
+
+async fn import_zip(
+    pool: &sqlx::PgPool,
+    run_id: i32,
+    zip: &Path,
+    version: &str,
+    max_errors: usize,
+) -> anyhow::Result<provenance::RunCounts> {
     // ── seed reference data ──────────────────────────────────────────────────
-    let era_count = seed::seed_eras(&pool).await?;
-    let faction_count = seed::seed_factions(&pool).await?;
-    seed::seed_metadata(&pool, version).await?;
-    info!(eras = era_count, factions = faction_count, version = %version, "reference data seeded");
+    let era_count = seed::seed_eras(pool).await?;
+    let faction_count = seed::seed_factions(pool).await?;
+    let (relation_count, name_count) = seed::seed_faction_relations(pool).await?;
+    let multiplier_count = seed::seed_bv_multipliers(pool).await?;
+    seed::seed_metadata(pool, version).await?;
+    info!(
+        eras = era_count,
+        factions = faction_count,
+        relations = relation_count,
+        names = name_count,
+        bv_multipliers = multiplier_count,
+        version = %version,
+        "reference data seeded"
+    );
+
+    let adopted = db::adopt_catalog_equipment(pool).await?;
+    info!(adopted = adopted.adopted, merged = adopted.merged, "equipment adopted into catalog");
 
     // ── open zip ─────────────────────────────────────────────────────────────
-    let file = std::fs::File::open(&zip)
+    let file = std::fs::File::open(zip)
         .with_context(|| format!("opening zip {:?}", zip))?;
     let mut archive = zip::ZipArchive::new(BufReader::new(file))
         .context("reading zip archive")?;
@@ -216,12 +367,13 @@ async fn run_megamek(
         .context("listing zip entries")?;
 
     // ── pass 2: parse & import ───────────────────────────────────────────────
-    let mut equipment_cache: HashMap<String, i32> = HashMap::new();
+    let mut equipment_cache = db::EquipmentCache::default();
 
     let mut parsed   = 0usize;
     let mut skipped  = 0usize;
     let mut errors   = 0usize;
     let mut imported = 0usize;
+    let mut error_messages: Vec<String> = Vec::new();
 
     for (idx, name) in entry_names.iter().enumerate() {
         let (is_mtf, default_type) = classify(name);
@@ -266,11 +418,12 @@ async fn run_megamek(
         parsed += 1;
 
         // Import
-        match import_unit(&pool, &unit, &mut equipment_cache).await {
+        match import_unit(pool, run_id, &unit, &mut equipment_cache).await {
             Ok(()) => imported += 1,
             Err(e) => {
                 errors += 1;
                 error!(file = %name, error = %e, "import failed");
+                error_messages.push(format!("{name}: {e:#}"));
                 if max_errors > 0 && errors >= max_errors {
                     bail!("reached max_errors limit ({}) — aborting", max_errors);
                 }
@@ -291,30 +444,78 @@ async fn run_megamek(
         "import complete"
     );
 
-    Ok(())
+    for (equipment, unit) in &equipment_cache.unknown {
+        warn!(equipment = %equipment, unit = %unit, "equipment not in catalog");
+        error_messages.push(format!("unknown equipment '{equipment}' (first seen on {unit})"));
+    }
+
+    let pruned = db::prune_unused_equipment(pool).await?;
+    info!(equipment = pruned, "unused equipment pruned");
+
+    let observed = db::refresh_observed_locations(pool).await?;
+    info!(equipment = observed, "observed locations refreshed");
+
+    let omni_bases = db::refresh_omni_bases(pool).await?;
+    info!(omni_bases, "omni bases refreshed");
+
+    let snapshot_units = dataset::snapshot(pool, version, run_id).await?;
+    info!(units = snapshot_units, version = %version, "dataset snapshot recorded");
+
+    Ok(provenance::RunCounts {
+        processed: parsed,
+        imported,
+        skipped,
+        errors: error_messages,
+    })
 }
 
 // ── per-unit import ───────────────────────────────────────────────────────────
 
 async fn import_unit(
     pool: &sqlx::PgPool,
+    run_id: i32,
     unit: &parse::ParsedUnit,
-    equipment_cache: &mut HashMap<String, i32>,
+    equipment_cache: &mut db::EquipmentCache,
 ) -> anyhow::Result<()> {
     let chassis_id = db::upsert_chassis(pool, unit).await?;
-    let unit_id    = db::upsert_unit(pool, unit, chassis_id).await?;
+    let unit_id    = db::upsert_unit(pool, run_id, unit, chassis_id).await?;
 
     if !unit.locations.is_empty() {
-        db::replace_locations(pool, unit_id, unit).await?;
+        db::replace_locations(pool, run_id, unit_id, unit).await?;
     }
     if !unit.loadout.is_empty() {
-        db::replace_loadout(pool, unit_id, unit, equipment_cache).await?;
+        db::replace_loadout(pool, run_id, unit_id, unit, equipment_cache).await?;
+    }
+    if !unit.critical_slots.is_empty() {
+        db::replace_critical_slots(pool, unit_id, unit, equipment_cache).await?;
     }
     if !unit.quirks.is_empty() {
         db::replace_quirks(pool, unit_id, &unit.quirks).await?;
     }
+    db::replace_fluff(pool, unit_id, &unit.fluff).await?;
     if let Some(ref mech_data) = unit.mech_data {
         db::upsert_mech_data(pool, unit_id, mech_data).await?;
+        if let Some(ref lam_data) = mech_data.lam_data {
+            db::upsert_lam_data(pool, unit_id, lam_data).await?;
+        }
+        if let Some(ref quadvee_data) = mech_data.quadvee_data {
+            db::upsert_quadvee_data(pool, unit_id, quadvee_data).await?;
+        }
+    }
+    if let Some(ref vehicle_data) = unit.vehicle_data {
+        db::upsert_vehicle_data(pool, unit_id, vehicle_data).await?;
+    }
+    if let Some(ref aero_data) = unit.aero_data {
+        db::upsert_aero_data(pool, unit_id, unit, aero_data, equipment_cache).await?;
+    }
+    if let Some(ref infantry_data) = unit.infantry_data {
+        db::upsert_infantry_data(pool, unit_id, infantry_data).await?;
+    }
+    if let Some(ref protomech_data) = unit.protomech_data {
+        db::upsert_protomech_data(pool, unit_id, protomech_data).await?;
+    }
+    if let Some(ref large_craft_data) = unit.large_craft_data {
+        db::upsert_large_craft_data(pool, unit_id, large_craft_data).await?;
     }
 
     Ok(())
@@ -348,6 +549,12 @@ fn classify(path: &str) -> (bool, Option<UnitType>) {
                 UnitType::Vehicle
             } else if dir.contains("fighter") || dir.contains("aero") {
                 UnitType::Fighter
+            } else if dir.contains("protomech") || dir.contains("protomek") {
+                UnitType::ProtoMech
+            } else if lower.contains("battlearmor/") {
+                UnitType::BattleArmor
+            } else if lower.contains("infantry/") {
+                UnitType::Infantry
             } else {
                 UnitType::Other
             };
@@ -7,12 +7,17 @@ use anyhow::Context;
 use sqlx::{postgres::PgPoolOptions, PgPool, Row};
 use tracing::{info, warn};
 
-use crate::parse::to_slug;
+use crate::{
+    dataset,
+    parse::to_slug,
+    provenance::{self, RunCounts},
+    seed,
+};
 
 use super::{
     detail,
     mappings,
-    matcher::{self, extract_clan_name, Matcher, UnmatchedUnit},
+    matcher::{self, extract_clan_name, DeclaredUnit, Matcher, UnmatchedReason, UnmatchedUnit},
     quicklist,
 };
 
@@ -31,15 +36,34 @@ pub async fn run(
         .await
         .context("connecting to database")?;
 
+    let run_id = provenance::start_run(&pool, "mul", None).await?;
+    let result = import(&pool, run_id, data_dir, skip_availability, force, overrides_path).await;
+    provenance::finish_run(&pool, run_id, &result).await?;
+    result.map(|_| ())
+}
+
+async fn import(
+    pool: &PgPool,
+    run_id: i32,
+    data_dir: PathBuf,
+    skip_availability: bool,
+    force: bool,
+    overrides_path: Option<PathBuf>,
+) -> anyhow::Result<RunCounts> {
     // ── Step 1: Load local state ──────────────────────────────────────────
 
     // Load all DB units into HashMaps for matching
-    let (units_by_slug, units_by_name) = load_db_units(&pool).await?;
-    info!(by_slug = units_by_slug.len(), "loaded DB units for matching");
+    let (units_by_slug, units_by_name) = load_db_units(pool).await?;
+    let units_by_mul_id = load_declared_mul_ids(pool).await?;
+    info!(
+        by_slug = units_by_slug.len(),
+        by_mul_id = units_by_mul_id.len(),
+        "loaded DB units for matching"
+    );
 
     // Load era and faction maps from DB
-    let era_slug_to_id = load_era_map(&pool).await?;
-    let faction_name_to_id = load_faction_map(&pool).await?;
+    let era_slug_to_id = load_era_map(pool).await?;
+    let faction_name_to_id = load_faction_map(pool).await?;
     info!(
         eras = era_slug_to_id.len(),
         factions = faction_name_to_id.len(),
@@ -56,7 +80,7 @@ pub async fn run(
         None => HashMap::new(),
     };
 
-    let matcher = Matcher::new(overrides, units_by_slug, units_by_name);
+    let matcher = Matcher::new(overrides, units_by_mul_id, units_by_slug, units_by_name);
 
     // ── Step 2: Import QuickList data ─────────────────────────────────────
 
@@ -85,6 +109,7 @@ pub async fn run(
     let mut cost_changed = 0usize;
     let mut role_assigned = 0usize;
     let mut intro_year_changed = 0usize;
+    let mut alpha_strike_imported = 0usize;
 
     // Map MUL ID → DB id for availability step
     let mut mul_id_to_db_id: HashMap<u32, i32> = HashMap::new();
@@ -102,7 +127,8 @@ pub async fn run(
                 let clan_name = extract_clan_name(&unit.name);
 
                 let changes = update_mul_fields(
-                    &pool,
+                    pool,
+                    run_id,
                     m.db_id,
                     unit.id as i32,
                     bv,
@@ -117,6 +143,11 @@ pub async fn run(
                 cost_changed += changes.cost_changed as usize;
                 intro_year_changed += changes.intro_year_changed as usize;
                 role_assigned += changes.role_assigned as usize;
+
+                if let Some(stats) = unit.alpha_strike() {
+                    upsert_alpha_strike(pool, m.db_id, &stats).await?;
+                    alpha_strike_imported += 1;
+                }
             }
             Err(um) => {
                 unmatched.push(um);
@@ -131,9 +162,19 @@ pub async fn run(
         cost_changed,
         role_assigned,
         intro_year_changed,
+        alpha_strike = alpha_strike_imported,
         "QuickList import complete"
     );
 
+    // Files declaring a MUL ID that the QuickList no longer (or never) had
+    unmatched.extend(matcher.missing_mul_ids(&seen_ids));
+    let count_reason = |reason| unmatched.iter().filter(|u| u.reason == reason).count();
+    info!(
+        name_mismatch = count_reason(UnmatchedReason::NameMismatch),
+        mul_id_missing = count_reason(UnmatchedReason::MulIdMissing),
+        "unmatched units by reason"
+    );
+
     // Write unmatched CSV
     if !unmatched.is_empty() {
         let csv_path = data_dir.join("unmatched_mul_units.csv");
@@ -169,7 +210,7 @@ pub async fn run(
                     "SELECT COUNT(*) FROM unit_availability WHERE unit_id = $1",
                 )
                 .bind(db_id)
-                .fetch_one(&pool)
+                .fetch_one(pool)
                 .await?;
 
                 if existing > 0 {
@@ -224,7 +265,7 @@ pub async fn run(
                     let faction_type = mappings::infer_faction_type(&rec.faction_name);
 
                     let id = ensure_faction(
-                        &pool,
+                        pool,
                         &slug,
                         &rec.faction_name,
                         faction_type,
@@ -252,7 +293,7 @@ pub async fn run(
                 rows_to_insert.sort_unstable();
                 rows_to_insert.dedup();
 
-                let inserted = replace_availability(&pool, db_id, &rows_to_insert).await?;
+                let inserted = replace_availability(pool, run_id, db_id, &rows_to_insert).await?;
                 availability_units += 1;
                 availability_rows += inserted;
             }
@@ -264,8 +305,18 @@ pub async fn run(
             new_factions,
             "availability import complete"
         );
+
+        // Relations to factions created above can be linked now
+        if new_factions > 0 {
+            let (relations, names) = seed::seed_faction_relations(pool).await?;
+            info!(relations, names, "faction relations re-seeded");
+        }
     }
 
+    // MUL BVs belong to the newest dataset snapshot so diffs pick them up
+    let snapshot_bv = dataset::refresh_snapshot_bv(pool).await?;
+    info!(units = snapshot_bv, "dataset snapshot BV refreshed");
+
     // ── Summary ───────────────────────────────────────────────────────────
 
     info!(
@@ -275,11 +326,17 @@ pub async fn run(
         cost_changed,
         role_assigned,
         intro_year_changed,
+        alpha_strike = alpha_strike_imported,
         unmatched = unmatched.len(),
         "MUL import finished"
     );
 
-    Ok(())
+    Ok(RunCounts {
+        processed: mul_units.len(),
+        imported: matched_count,
+        skipped: mul_units.len() - matched_count,
+        errors: Vec::new(),
+    })
 }
 
 // ── DB helpers ───────────────────────────────────────────────────────────────
@@ -307,6 +364,35 @@ async fn load_db_units(
     Ok((by_slug, by_name))
 }
 
+/// Load units whose MegaMek file declares a MUL ID, keyed by that ID. When
+/// several files declare the same ID the lowest unit id wins.
+async fn load_declared_mul_ids(pool: &PgPool) -> anyhow::Result<HashMap<u32, DeclaredUnit>> {
+    let rows = sqlx::query(
+        r#"SELECT DISTINCT ON (source_mul_id)
+               source_mul_id, id, slug, full_name, tonnage::float8 AS tonnage
+           FROM units
+           WHERE source_mul_id IS NOT NULL
+           ORDER BY source_mul_id, id"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut map = HashMap::new();
+    for row in rows {
+        let mul_id: i32 = row.try_get("source_mul_id")?;
+        map.insert(
+            mul_id as u32,
+            DeclaredUnit {
+                db_id: row.try_get("id")?,
+                slug: row.try_get("slug")?,
+                full_name: row.try_get("full_name")?,
+                tonnage: row.try_get("tonnage")?,
+            },
+        );
+    }
+    Ok(map)
+}
+
 /// Load era slug → id map from DB.
 async fn load_era_map(pool: &PgPool) -> anyhow::Result<HashMap<String, i32>> {
     let rows = sqlx::query("SELECT id, slug FROM eras")
@@ -347,8 +433,10 @@ struct FieldChanges {
 }
 
 /// Update MUL-sourced fields on a unit row, using COALESCE to preserve existing values.
+#[allow(clippy::too_many_arguments)]
 async fn update_mul_fields(
     pool: &PgPool,
+    run_id: i32,
     db_id: i32,
     mul_id: i32,
     bv: Option<i32>,
@@ -385,7 +473,8 @@ async fn update_mul_fields(
             bv_source = CASE WHEN $2 IS NOT NULL THEN 'mul' ELSE bv_source END,
             intro_year_source = CASE WHEN $4 IS NOT NULL THEN 'mul' ELSE intro_year_source END,
             clan_name = COALESCE($6, clan_name),
-            last_mul_import_at = now()
+            last_mul_import_at = now(),
+            mul_import_run_id = $8
         WHERE id = $7"#,
     )
     .bind(mul_id)
@@ -395,6 +484,7 @@ async fn update_mul_fields(
     .bind(role)
     .bind(clan_name)
     .bind(db_id)
+    .bind(run_id)
     .execute(pool)
     .await
     .with_context(|| format!("update_mul_fields for unit {db_id}"))?;
@@ -407,6 +497,73 @@ async fn update_mul_fields(
     })
 }
 
+/// Insert or replace the MUL Alpha Strike card for a unit. MUL values always
+/// win over a previous conversion.
+async fn upsert_alpha_strike(
+    pool: &PgPool,
+    unit_id: i32,
+    stats: &quicklist::AlphaStrikeStats,
+) -> anyhow::Result<()> {
+    let [short, medium, long, extreme] = stats.damage;
+    sqlx::query(
+        r#"INSERT INTO unit_alpha_strike (
+               unit_id, as_type, size, movement, move_inches, jump_inches, tmm,
+               armor, structure, threshold,
+               damage_short, damage_short_min, damage_medium, damage_medium_min,
+               damage_long, damage_long_min, damage_extreme, damage_extreme_min,
+               overheat, point_value, specials, source)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+                   $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, 'mul')
+           ON CONFLICT (unit_id) DO UPDATE SET
+               as_type = EXCLUDED.as_type,
+               size = EXCLUDED.size,
+               movement = EXCLUDED.movement,
+               move_inches = EXCLUDED.move_inches,
+               jump_inches = EXCLUDED.jump_inches,
+               tmm = EXCLUDED.tmm,
+               armor = EXCLUDED.armor,
+               structure = EXCLUDED.structure,
+               threshold = EXCLUDED.threshold,
+               damage_short = EXCLUDED.damage_short,
+               damage_short_min = EXCLUDED.damage_short_min,
+               damage_medium = EXCLUDED.damage_medium,
+               damage_medium_min = EXCLUDED.damage_medium_min,
+               damage_long = EXCLUDED.damage_long,
+               damage_long_min = EXCLUDED.damage_long_min,
+               damage_extreme = EXCLUDED.damage_extreme,
+               damage_extreme_min = EXCLUDED.damage_extreme_min,
+               overheat = EXCLUDED.overheat,
+               point_value = EXCLUDED.point_value,
+               specials = EXCLUDED.specials,
+               source = 'mul'"#,
+    )
+    .bind(unit_id)
+    .bind(stats.as_type.as_deref())
+    .bind(stats.size.map(|v| v as i16))
+    .bind(stats.movement.as_deref())
+    .bind(stats.move_inches.map(|v| v as i16))
+    .bind(stats.jump_inches.map(|v| v as i16))
+    .bind(stats.tmm.map(|v| v as i16))
+    .bind(stats.armor.map(|v| v as i16))
+    .bind(stats.structure.map(|v| v as i16))
+    .bind(stats.threshold.map(|v| v as i16))
+    .bind(short.0.map(|v| v as i16))
+    .bind(short.1)
+    .bind(medium.0.map(|v| v as i16))
+    .bind(medium.1)
+    .bind(long.0.map(|v| v as i16))
+    .bind(long.1)
+    .bind(extreme.0.map(|v| v as i16))
+    .bind(extreme.1)
+    .bind(stats.overheat as i16)
+    .bind(stats.point_value)
+    .bind(&stats.specials)
+    .execute(pool)
+    .await
+    .with_context(|| format!("upsert_alpha_strike for unit {unit_id}"))?;
+    Ok(())
+}
+
 /// Insert or get a faction by slug, return its id.
 async fn ensure_faction(
     pool: &PgPool,
@@ -442,6 +599,7 @@ async fn ensure_faction(
 /// Replace all availability rows for a unit within a transaction.
 async fn replace_availability(
     pool: &PgPool,
+    run_id: i32,
     unit_id: i32,
     rows: &[(i32, i32)], // (faction_id, era_id)
 ) -> anyhow::Result<usize> {
@@ -455,13 +613,14 @@ async fn replace_availability(
     let mut count = 0usize;
     for &(faction_id, era_id) in rows {
         sqlx::query(
-            r#"INSERT INTO unit_availability (unit_id, faction_id, era_id)
-               VALUES ($1, $2, $3)
+            r#"INSERT INTO unit_availability (unit_id, faction_id, era_id, last_import_run_id)
+               VALUES ($1, $2, $3, $4)
                ON CONFLICT (unit_id, faction_id, era_id) DO NOTHING"#,
         )
         .bind(unit_id)
         .bind(faction_id)
         .bind(era_id)
+        .bind(run_id)
         .execute(&mut *tx)
         .await?;
         count += 1;
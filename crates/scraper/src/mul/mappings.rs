@@ -20,7 +20,7 @@ pub fn era_mappings() -> HashMap<&'static str, &'static str> {
 }
 
 /// Map MUL faction display names to our database faction slugs.
-/// This covers the 33 factions seeded in seed.rs.
+/// This covers the factions seeded in seed.rs.
 pub fn faction_mappings() -> HashMap<&'static str, &'static str> {
     let mut m = HashMap::new();
     // Inner Sphere Great Houses
@@ -66,7 +66,9 @@ pub fn faction_mappings() -> HashMap<&'static str, &'static str> {
     m.insert("Marian Hegemony", "marian-hegemony");
     // General
     m.insert("Inner Sphere General", "general");
-    m.insert("Clan General", "general");
+    m.insert("Clan General", "clan-general");
+    m.insert("IS Clan General", "is-clan-general");
+    m.insert("HW Clan General", "hw-clan-general");
     m.insert("Mercenary", "mercenary");
     m
 }
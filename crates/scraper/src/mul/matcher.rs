@@ -1,25 +1,66 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::parse::to_slug;
 
 /// A matched MUL unit to DB unit association.
 pub struct MatchResult {
+    #[allow(dead_code)] // kept for debug logging of match decisions
     pub db_slug: String,
     pub db_id: i32,
 }
 
-/// Unmatched MUL unit info for CSV output.
+/// Why a unit ended up in the unmatched report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnmatchedReason {
+    /// A MegaMek file declares this MUL ID but no MUL record carries it.
+    MulIdMissing,
+    /// No file declares the MUL record's ID and no name heuristic matched.
+    NameMismatch,
+}
+
+impl UnmatchedReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MulIdMissing => "mul_id_missing",
+            Self::NameMismatch => "name_mismatch",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "mul_id_missing" => Some(Self::MulIdMissing),
+            "name_mismatch" => Some(Self::NameMismatch),
+            _ => None,
+        }
+    }
+}
+
+/// Unmatched unit info for CSV output. For `MulIdMissing` rows the name,
+/// slug and tonnage are those of the DB unit declaring the ID.
 pub struct UnmatchedUnit {
     pub mul_id: u32,
     pub mul_name: String,
     pub computed_slug: String,
     pub tonnage: f64,
+    pub reason: UnmatchedReason,
+}
+
+/// A DB unit whose MegaMek file declares a MUL ID.
+pub struct DeclaredUnit {
+    pub db_id: i32,
+    pub slug: String,
+    pub full_name: String,
+    pub tonnage: f64,
 }
 
 /// Matcher resolves MUL unit names to DB unit slugs.
 pub struct Matcher {
     /// Manual overrides: MUL ID → DB slug
     overrides: HashMap<u32, String>,
+    /// DB units by the MUL ID declared in their source file
+    units_by_mul_id: HashMap<u32, DeclaredUnit>,
+    /// Declared MUL ID by DB unit id, to keep name matches off units claimed by another record
+    declared_by_id: HashMap<i32, u32>,
     /// DB units by slug: slug → unit id
     units_by_slug: HashMap<String, i32>,
     /// DB units by lowercased full_name: name → (slug, id)
@@ -29,11 +70,18 @@ pub struct Matcher {
 impl Matcher {
     pub fn new(
         overrides: HashMap<u32, String>,
+        units_by_mul_id: HashMap<u32, DeclaredUnit>,
         units_by_slug: HashMap<String, i32>,
         units_by_name: HashMap<String, (String, i32)>,
     ) -> Self {
+        let declared_by_id = units_by_mul_id
+            .iter()
+            .map(|(&mul_id, u)| (u.db_id, mul_id))
+            .collect();
         Self {
             overrides,
+            units_by_mul_id,
+            declared_by_id,
             units_by_slug,
             units_by_name,
         }
@@ -51,69 +99,94 @@ impl Matcher {
             }
         }
 
-        // 2. Exact slug match
+        // 2. MUL ID declared in the MegaMek file
+        if let Some(u) = self.units_by_mul_id.get(&mul_id) {
+            return Ok(MatchResult {
+                db_slug: u.slug.clone(),
+                db_id: u.db_id,
+            });
+        }
+
+        // 3. Name heuristics, skipping units whose file declares a different MUL ID
+        if let Some(m) = self.match_name(mul_name) {
+            if !self.declared_by_id.contains_key(&m.db_id) {
+                return Ok(m);
+            }
+        }
+
+        Err(UnmatchedUnit {
+            mul_id,
+            mul_name: mul_name.to_string(),
+            computed_slug: to_slug(mul_name),
+            tonnage,
+            reason: UnmatchedReason::NameMismatch,
+        })
+    }
+
+    /// DB units declaring a MUL ID that is not among `seen` MUL records, ordered by MUL ID.
+    pub fn missing_mul_ids(&self, seen: &HashSet<u32>) -> Vec<UnmatchedUnit> {
+        let mut missing: Vec<UnmatchedUnit> = self
+            .units_by_mul_id
+            .iter()
+            .filter(|(mul_id, _)| !seen.contains(mul_id))
+            .map(|(&mul_id, u)| UnmatchedUnit {
+                mul_id,
+                mul_name: u.full_name.clone(),
+                computed_slug: u.slug.clone(),
+                tonnage: u.tonnage,
+                reason: UnmatchedReason::MulIdMissing,
+            })
+            .collect();
+        missing.sort_by_key(|u| u.mul_id);
+        missing
+    }
+
+    /// Match a MUL name by slug, dual Clan/IS names, normalized slug and
+    /// case-insensitive full name, in that order.
+    fn match_name(&self, mul_name: &str) -> Option<MatchResult> {
+        // Exact slug match
         let slug = to_slug(mul_name);
         if let Some(&db_id) = self.units_by_slug.get(&slug) {
-            return Ok(MatchResult { db_slug: slug, db_id });
+            return Some(MatchResult { db_slug: slug, db_id });
         }
 
-        // 3. Dual Clan/IS name match: "Dasher (Fire Moth) A" → try "Dasher A" and "Fire Moth A"
+        // Dual Clan/IS name match: "Dasher (Fire Moth) A" → try "Dasher A" and "Fire Moth A"
         for alt in dual_name_alternatives(mul_name) {
             let alt_slug = to_slug(&alt);
             if let Some(&db_id) = self.units_by_slug.get(&alt_slug) {
-                return Ok(MatchResult { db_slug: alt_slug, db_id });
+                return Some(MatchResult { db_slug: alt_slug, db_id });
             }
         }
 
-        // 4. Normalized slug match: strip parenthetical suffixes, collapse whitespace
+        // Normalized slug match: strip parenthetical suffixes, collapse whitespace
         let normalized = normalize_name(mul_name);
         let norm_slug = to_slug(&normalized);
         if norm_slug != slug {
             if let Some(&db_id) = self.units_by_slug.get(&norm_slug) {
-                return Ok(MatchResult {
+                return Some(MatchResult {
                     db_slug: norm_slug,
                     db_id,
                 });
             }
         }
 
-        // 5. Case-insensitive full_name match
+        // Case-insensitive full_name match, then the normalized name and the
+        // dual-name alternatives
         let lower_name = mul_name.to_lowercase();
-        if let Some((ref db_slug, db_id)) = self.units_by_name.get(&lower_name) {
-            return Ok(MatchResult {
-                db_slug: db_slug.clone(),
-                db_id: *db_id,
-            });
-        }
-
-        // Also try normalized name for case-insensitive match
         let lower_norm = normalized.to_lowercase();
-        if lower_norm != lower_name {
-            if let Some((ref db_slug, db_id)) = self.units_by_name.get(&lower_norm) {
-                return Ok(MatchResult {
+        let candidates = std::iter::once(lower_name.clone())
+            .chain((lower_norm != lower_name).then_some(lower_norm))
+            .chain(dual_name_alternatives(mul_name).into_iter().map(|alt| alt.to_lowercase()));
+        for name in candidates {
+            if let Some((db_slug, db_id)) = self.units_by_name.get(&name) {
+                return Some(MatchResult {
                     db_slug: db_slug.clone(),
                     db_id: *db_id,
                 });
             }
         }
 
-        // Also try dual-name alternatives for case-insensitive match
-        for alt in dual_name_alternatives(mul_name) {
-            let alt_lower = alt.to_lowercase();
-            if let Some((ref db_slug, db_id)) = self.units_by_name.get(&alt_lower) {
-                return Ok(MatchResult {
-                    db_slug: db_slug.clone(),
-                    db_id: *db_id,
-                });
-            }
-        }
-
-        Err(UnmatchedUnit {
-            mul_id,
-            mul_name: mul_name.to_string(),
-            computed_slug: slug,
-            tonnage,
-        })
+        None
     }
 }
 
@@ -213,6 +286,14 @@ pub fn load_overrides(path: &std::path::Path) -> anyhow::Result<HashMap<u32, Str
     Ok(overrides)
 }
 
+/// Write overrides back as JSON, ordered by MUL ID.
+pub fn write_overrides(path: &std::path::Path, overrides: &HashMap<u32, String>) -> anyhow::Result<()> {
+    let sorted: std::collections::BTreeMap<u32, &String> =
+        overrides.iter().map(|(&k, v)| (k, v)).collect();
+    std::fs::write(path, serde_json::to_string_pretty(&sorted)? + "\n")?;
+    Ok(())
+}
+
 /// Write unmatched units to a CSV file for review.
 pub fn write_unmatched_csv(
     path: &std::path::Path,
@@ -220,20 +301,65 @@ pub fn write_unmatched_csv(
 ) -> anyhow::Result<()> {
     use std::io::Write;
     let mut f = std::fs::File::create(path)?;
-    writeln!(f, "mul_id,mul_name,computed_slug,tonnage")?;
+    writeln!(f, "mul_id,mul_name,computed_slug,tonnage,reason")?;
     for u in unmatched {
         writeln!(
             f,
-            "{},{},{},{}",
+            "{},{},{},{},{}",
             u.mul_id,
             escape_csv(&u.mul_name),
             u.computed_slug,
-            u.tonnage
+            u.tonnage,
+            u.reason.as_str()
         )?;
     }
     Ok(())
 }
 
+/// Read a CSV written by `write_unmatched_csv`. Files from before the
+/// `reason` column count every row as a name mismatch.
+pub fn read_unmatched_csv(path: &std::path::Path) -> anyhow::Result<Vec<UnmatchedUnit>> {
+    let content = std::fs::read_to_string(path)?;
+    let mut units = Vec::new();
+    for (i, line) in content.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv(line);
+        let field = |n: usize| fields.get(n).map(String::as_str).unwrap_or("");
+        let parse_err = || anyhow::anyhow!("{}:{}: malformed row", path.display(), i + 1);
+        units.push(UnmatchedUnit {
+            mul_id: field(0).parse().map_err(|_| parse_err())?,
+            mul_name: field(1).to_string(),
+            computed_slug: field(2).to_string(),
+            tonnage: field(3).parse().map_err(|_| parse_err())?,
+            reason: UnmatchedReason::from_str(field(4)).unwrap_or(UnmatchedReason::NameMismatch),
+        });
+    }
+    Ok(units)
+}
+
+/// Split one CSV line, honouring double-quoted fields as written by `escape_csv`.
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut cur = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cur.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut cur)),
+            _ => cur.push(c),
+        }
+    }
+    fields.push(cur);
+    fields
+}
+
 fn escape_csv(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') {
         format!("\"{}\"", s.replace('"', "\"\""))
@@ -5,3 +5,4 @@ pub mod import;
 pub mod mappings;
 pub mod matcher;
 pub mod quicklist;
+pub mod review;
@@ -9,6 +9,7 @@ pub struct QuickListResponse {
 
 /// A single unit from the MUL QuickList JSON endpoint.
 #[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)] // mirrors the MUL JSON shape; not every field is imported
 pub struct MulUnit {
     #[serde(alias = "Id")]
     pub id: u32,
@@ -34,9 +35,66 @@ pub struct MulUnit {
     pub role: Option<IdName>,
     #[serde(alias = "Type")]
     pub unit_type: Option<IdName>,
+
+    // ── Alpha Strike ("BF" = BattleForce) ──
+    #[serde(alias = "BFType")]
+    pub bf_type: Option<String>,
+    #[serde(alias = "BFSize")]
+    pub bf_size: Option<i32>,
+    #[serde(alias = "BFMove")]
+    pub bf_move: Option<String>,
+    #[serde(alias = "BFTMM")]
+    pub bf_tmm: Option<i32>,
+    #[serde(alias = "BFArmor")]
+    pub bf_armor: Option<i32>,
+    #[serde(alias = "BFStructure")]
+    pub bf_structure: Option<i32>,
+    #[serde(alias = "BFThreshold")]
+    pub bf_threshold: Option<i32>,
+    #[serde(alias = "BFDamageShort")]
+    pub bf_damage_short: Option<f64>,
+    #[serde(alias = "BFDamageShortMin", default)]
+    pub bf_damage_short_min: bool,
+    #[serde(alias = "BFDamageMedium")]
+    pub bf_damage_medium: Option<f64>,
+    #[serde(alias = "BFDamageMediumMin", default)]
+    pub bf_damage_medium_min: bool,
+    #[serde(alias = "BFDamageLong")]
+    pub bf_damage_long: Option<f64>,
+    #[serde(alias = "BFDamageLongMin", default)]
+    pub bf_damage_long_min: bool,
+    #[serde(alias = "BFDamageExtreme")]
+    pub bf_damage_extreme: Option<f64>,
+    #[serde(alias = "BFDamageExtremeMin", default)]
+    pub bf_damage_extreme_min: bool,
+    #[serde(alias = "BFOverheat")]
+    pub bf_overheat: Option<i32>,
+    #[serde(alias = "BFPointValue")]
+    pub bf_point_value: Option<i32>,
+    #[serde(alias = "BFAbilities")]
+    pub bf_abilities: Option<String>,
+}
+
+/// Alpha Strike card values extracted from a QuickList entry.
+#[derive(Debug, Clone)]
+pub struct AlphaStrikeStats {
+    pub as_type: Option<String>,
+    pub size: Option<i32>,
+    pub movement: Option<String>,
+    pub move_inches: Option<i32>,
+    pub jump_inches: Option<i32>,
+    pub tmm: Option<i32>,
+    pub armor: Option<i32>,
+    pub structure: Option<i32>,
+    pub threshold: Option<i32>,
+    pub damage: [(Option<i32>, bool); 4], // short, medium, long, extreme
+    pub overheat: i32,
+    pub point_value: Option<i32>,
+    pub specials: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)] // mirrors the MUL JSON shape; not every field is imported
 pub struct IdName {
     #[serde(alias = "Id")]
     pub id: Option<i32>,
@@ -74,12 +132,87 @@ impl MulUnit {
         self.battle_value.filter(|&v| v > 0)
     }
 
+    /// Alpha Strike values, or None if MUL has no card for this unit (PV missing or 0).
+    pub fn alpha_strike(&self) -> Option<AlphaStrikeStats> {
+        let pv = self.bf_point_value.filter(|&v| v > 0)?;
+        let movement = self
+            .bf_move
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        let (move_inches, jump_inches) = movement
+            .as_deref()
+            .map(parse_as_movement)
+            .unwrap_or((None, None));
+        let dmg = |v: Option<f64>, min: bool| (v.map(|d| d.round() as i32), min);
+        let as_type = self.bf_type.as_deref().map(str::trim).map(str::to_string);
+        // Only aerospace cards have an extreme range bracket.
+        let is_aero = matches!(
+            as_type.as_deref(),
+            Some("AF" | "CF" | "SC" | "DS" | "DA" | "JS" | "WS" | "SS")
+        );
+        Some(AlphaStrikeStats {
+            as_type,
+            size: self.bf_size,
+            movement,
+            move_inches,
+            jump_inches,
+            tmm: self.bf_tmm,
+            armor: self.bf_armor,
+            structure: self.bf_structure,
+            threshold: self.bf_threshold.filter(|&t| t > 0),
+            damage: [
+                dmg(self.bf_damage_short, self.bf_damage_short_min),
+                dmg(self.bf_damage_medium, self.bf_damage_medium_min),
+                dmg(self.bf_damage_long, self.bf_damage_long_min),
+                if is_aero {
+                    dmg(self.bf_damage_extreme, self.bf_damage_extreme_min)
+                } else {
+                    (None, false)
+                },
+            ],
+            overheat: self.bf_overheat.unwrap_or(0),
+            point_value: Some(pv),
+            specials: self
+                .bf_abilities
+                .as_deref()
+                .unwrap_or("")
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+
     /// Cost, treating 0 as None.
     pub fn cost_value(&self) -> Option<i64> {
         self.cost.filter(|&v| v > 0)
     }
 }
 
+/// Split an Alpha Strike movement string like `8"/6"j` or `10"j` into
+/// (primary move, jump move) in inches. A bare `6"j` is jump-only movement
+/// equal to ground movement.
+pub fn parse_as_movement(s: &str) -> (Option<i32>, Option<i32>) {
+    let mut primary = None;
+    let mut jump = None;
+    for part in s.split('/') {
+        let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let Ok(v) = digits.parse::<i32>() else { continue };
+        if part.trim_end().ends_with('j') {
+            jump = Some(v);
+            if primary.is_none() {
+                primary = Some(v);
+            }
+        } else if primary.is_none() {
+            primary = Some(v);
+        }
+    }
+    (primary, jump)
+}
+
 /// Parse a QuickList JSON file into a list of MUL units.
 pub fn parse_quicklist(json: &str) -> anyhow::Result<Vec<MulUnit>> {
     let resp: QuickListResponse = serde_json::from_str(json)?;
@@ -0,0 +1,260 @@
+//! Review of unmatched MUL units.
+//!
+//! Reads `unmatched_mul_units.csv` from a previous `mul-import`, ranks DB
+//! units for each name mismatch and writes accepted pairs to the overrides
+//! JSON that `mul-import --overrides` loads. Candidates are scored by name
+//! similarity (the mean of trigram similarity and normalised Levenshtein
+//! similarity) weighted with tonnage proximity. Units already linked to a
+//! MUL record, or whose file declares a MUL ID, are never offered.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{BufRead, Write},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use sqlx::{postgres::PgPoolOptions, Row};
+use tracing::{info, warn};
+
+use super::matcher::{self, UnmatchedReason};
+
+/// Share of the score taken by name similarity; the rest is tonnage proximity.
+const NAME_WEIGHT: f64 = 0.8;
+
+struct DbUnit {
+    slug: String,
+    full_name: String,
+    tonnage: f64,
+    trigrams: HashSet<String>,
+}
+
+struct Candidate<'a> {
+    unit: &'a DbUnit,
+    score: f64,
+}
+
+/// Run the mul-review subcommand. With `auto_accept_threshold` set, the best
+/// candidate is accepted when it scores at least the threshold and nothing
+/// is asked on stdin.
+pub async fn run(
+    data_dir: PathBuf,
+    database_url: &str,
+    overrides_path: PathBuf,
+    auto_accept_threshold: Option<f64>,
+    candidates: usize,
+) -> anyhow::Result<()> {
+    if let Some(t) = auto_accept_threshold {
+        anyhow::ensure!((0.0..=1.0).contains(&t), "--auto-accept-threshold must be between 0 and 1");
+    }
+
+    let csv_path = data_dir.join("unmatched_mul_units.csv");
+    let unmatched = matcher::read_unmatched_csv(&csv_path)
+        .with_context(|| format!("reading {}", csv_path.display()))?;
+
+    let mut overrides = if overrides_path.exists() {
+        matcher::load_overrides(&overrides_path)?
+    } else {
+        HashMap::new()
+    };
+
+    let pending: Vec<_> = unmatched
+        .iter()
+        .filter(|u| u.reason == UnmatchedReason::NameMismatch && !overrides.contains_key(&u.mul_id))
+        .collect();
+    info!(
+        unmatched = unmatched.len(),
+        pending = pending.len(),
+        overrides = overrides.len(),
+        "loaded unmatched MUL units"
+    );
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(database_url)
+        .await
+        .context("connecting to database")?;
+
+    // Units already taken by a MUL record or a declared MUL ID are not candidates
+    let rows = sqlx::query(
+        r#"SELECT slug, full_name, tonnage::float8 AS tonnage
+           FROM units
+           WHERE mul_id IS NULL AND source_mul_id IS NULL"#,
+    )
+    .fetch_all(&pool)
+    .await?;
+    let mut db_units = Vec::with_capacity(rows.len());
+    for row in rows {
+        let full_name: String = row.try_get("full_name")?;
+        db_units.push(DbUnit {
+            slug: row.try_get("slug")?,
+            trigrams: trigrams(&full_name),
+            full_name,
+            tonnage: row.try_get("tonnage")?,
+        });
+    }
+
+    // Slugs already used by an override can't be offered again
+    let mut taken: HashSet<String> = overrides.values().cloned().collect();
+    let mut accepted = 0usize;
+    let mut skipped = 0usize;
+    let stdin = std::io::stdin();
+    let mut input = stdin.lock().lines();
+
+    'review: for (i, u) in pending.iter().enumerate() {
+        let ranked = rank(&db_units, &taken, &u.mul_name, u.tonnage, candidates);
+
+        let choice = match auto_accept_threshold {
+            Some(threshold) => ranked.first().filter(|c| c.score >= threshold).map(|c| c.unit),
+            None => {
+                println!();
+                println!(
+                    "[{}/{}] MUL {} \"{}\" ({}t)",
+                    i + 1,
+                    pending.len(),
+                    u.mul_id,
+                    u.mul_name,
+                    u.tonnage
+                );
+                if ranked.is_empty() {
+                    println!("  no candidates");
+                    skipped += 1;
+                    continue;
+                }
+                for (n, c) in ranked.iter().enumerate() {
+                    println!(
+                        "  {}) {:.2}  {} ({}t)  [{}]",
+                        n + 1,
+                        c.score,
+                        c.unit.full_name,
+                        c.unit.tonnage,
+                        c.unit.slug
+                    );
+                }
+                loop {
+                    print!("accept 1-{}, [s]kip, [q]uit: ", ranked.len());
+                    std::io::stdout().flush()?;
+                    let Some(line) = input.next().transpose()? else {
+                        break 'review;
+                    };
+                    match line.trim() {
+                        "" | "s" => break None,
+                        "q" => break 'review,
+                        n => match n.parse::<usize>() {
+                            Ok(n) if (1..=ranked.len()).contains(&n) => break Some(ranked[n - 1].unit),
+                            _ => println!("  unrecognised choice"),
+                        },
+                    }
+                }
+            }
+        };
+
+        match choice {
+            Some(unit) => {
+                info!(mul_id = u.mul_id, mul_name = %u.mul_name, slug = %unit.slug, "accepted");
+                overrides.insert(u.mul_id, unit.slug.clone());
+                taken.insert(unit.slug.clone());
+                accepted += 1;
+            }
+            None => skipped += 1,
+        }
+    }
+
+    if accepted > 0 {
+        matcher::write_overrides(&overrides_path, &overrides)
+            .with_context(|| format!("writing {}", overrides_path.display()))?;
+    } else {
+        warn!("no matches accepted, overrides file left unchanged");
+    }
+    info!(
+        accepted,
+        skipped,
+        overrides = overrides.len(),
+        path = %overrides_path.display(),
+        "MUL review finished"
+    );
+
+    Ok(())
+}
+
+/// Best `limit` candidates for a MUL unit, highest score first.
+fn rank<'a>(
+    db_units: &'a [DbUnit],
+    taken: &HashSet<String>,
+    mul_name: &str,
+    tonnage: f64,
+    limit: usize,
+) -> Vec<Candidate<'a>> {
+    let name = mul_name.to_lowercase();
+    let grams = trigrams(&name);
+    let mut ranked: Vec<Candidate> = db_units
+        .iter()
+        .filter(|u| !taken.contains(&u.slug))
+        .map(|u| {
+            let trigram = trigram_similarity(&grams, &u.trigrams);
+            let edit = levenshtein_similarity(&name, &u.full_name.to_lowercase());
+            let name_score = (trigram + edit) / 2.0;
+            Candidate {
+                unit: u,
+                score: NAME_WEIGHT * name_score + (1.0 - NAME_WEIGHT) * tonnage_proximity(tonnage, u.tonnage),
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Trigrams of each word, padded the way pg_trgm does ("  a", " ab", "abc", "bc ").
+fn trigrams(name: &str) -> HashSet<String> {
+    let lower = name.to_lowercase();
+    let mut out = HashSet::new();
+    for word in lower.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        let padded: Vec<char> = format!("  {word} ").chars().collect();
+        for w in padded.windows(3) {
+            out.insert(w.iter().collect());
+        }
+    }
+    out
+}
+
+/// Shared trigrams over all distinct trigrams (pg_trgm `similarity`).
+fn trigram_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let shared = a.intersection(b).count();
+    let total = a.len() + b.len() - shared;
+    if total == 0 {
+        0.0
+    } else {
+        shared as f64 / total as f64
+    }
+}
+
+/// One minus the edit distance over the longer name's length.
+fn levenshtein_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    1.0 - prev[b.len()] as f64 / longest as f64
+}
+
+/// 1.0 for equal tonnage, falling linearly to 0.0 at a 25% difference.
+fn tonnage_proximity(a: f64, b: f64) -> f64 {
+    let heavier = a.max(b);
+    if heavier <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - (a - b).abs() / heavier / 0.25).max(0.0)
+}
@@ -1,3 +1,5 @@
+use crate::equipment_catalog::{catalog, CatalogEntry};
+
 /// Common parsed representation of any unit (mech or vehicle).
 #[derive(Debug, Clone)]
 pub struct ParsedUnit {
@@ -8,16 +10,69 @@ pub struct ParsedUnit {
     pub rules_level: RulesLevel,
     pub intro_year: Option<i32>,
     pub source: Option<String>,
+    /// Master Unit List ID declared by the file ("mul id:")
+    pub mul_id: Option<i32>,
     pub tonnage: f64,
     /// Armor by location
     pub locations: Vec<ParsedLocation>,
     /// Weapon/equipment loadout (name, location, qty, rear_facing)
     pub loadout: Vec<ParsedLoadoutEntry>,
+    /// Per-slot critical hit table in file order (MTF only)
+    pub critical_slots: Vec<ParsedCriticalSlot>,
     /// Quirk slugs
     pub quirks: Vec<String>,
     pub description: Option<String>,
+    /// Lore text, notes and manufacturers
+    pub fluff: ParsedFluff,
     /// Mech-specific structural data (None for non-mech units)
     pub mech_data: Option<ParsedMechData>,
+    /// Vehicle-specific data (None for non-vehicle units)
+    pub vehicle_data: Option<ParsedVehicleData>,
+    /// Aerospace data (None for non-aerospace units)
+    pub aero_data: Option<ParsedAeroData>,
+    /// ProtoMech data (None for other units)
+    pub protomech_data: Option<ParsedProtoMechData>,
+    /// Battle armor / conventional infantry data (None for other units)
+    pub infantry_data: Option<ParsedInfantryData>,
+    /// Small craft, DropShip, JumpShip, WarShip and space station data (None for other units)
+    pub large_craft_data: Option<ParsedLargeCraftData>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ParsedFluff {
+    pub overview: Option<String>,
+    pub capabilities: Option<String>,
+    pub deployment: Option<String>,
+    pub history: Option<String>,
+    pub notes: Option<String>,
+    pub image_file: Option<String>,
+    /// Unit manufacturers paired with their primary factories, then component
+    /// (system) manufacturers
+    pub manufacturers: Vec<ParsedManufacturer>,
+}
+
+impl ParsedFluff {
+    /// True when the file carries any lore text (manufacturers aside).
+    pub fn has_text(&self) -> bool {
+        [
+            &self.overview,
+            &self.capabilities,
+            &self.deployment,
+            &self.history,
+            &self.notes,
+            &self.image_file,
+        ]
+        .iter()
+        .any(|f| f.is_some())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedManufacturer {
+    pub name: String,
+    pub factory: Option<String>,
+    /// Component made by this manufacturer, lowercase (e.g. "engine", "armor"); None for the unit itself
+    pub system: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +90,166 @@ pub struct ParsedMechData {
     pub gyro_type: Option<String>,
     pub cockpit_type: Option<String>,
     pub myomer_type: Option<String>,
+    pub is_environmentally_sealed: bool,
+    /// Industrial equipment names as they appear in the crit table (e.g. "LiftHoist")
+    pub industrial_equipment: Vec<String>,
+    /// Land-air mech modes (None unless config is "LAM")
+    pub lam_data: Option<ParsedLamData>,
+    /// QuadVee vehicle mode (None unless config is "QuadVee")
+    pub quadvee_data: Option<ParsedQuadVeeData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedLamData {
+    /// "standard" (BattleMech, AirMech and fighter modes) or "bimodal" (no AirMech mode)
+    pub lam_type: &'static str,
+    /// Fighter-mode fuel points, when the file records them
+    pub fuel: Option<i32>,
+    /// AirMech flight MP: three times jump MP. None for bimodal LAMs.
+    pub airmech_cruise_mp: Option<i32>,
+    pub airmech_flank_mp: Option<i32>,
+    /// Fighter-mode thrust: safe thrust equals jump MP
+    pub fighter_safe_thrust: Option<i32>,
+    pub fighter_max_thrust: Option<i32>,
+    /// Conversion equipment crits
+    pub landing_gear_slots: i32,
+    pub avionics_slots: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedQuadVeeData {
+    /// Vehicle-mode motive type: "tracked" or "wheeled"
+    pub motive_type: &'static str,
+    /// Conversion gear crits
+    pub conversion_slots: i32,
+    /// Track or wheel crits in the legs
+    pub motive_slots: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedProtoMechData {
+    /// "biped", "quad" or "glider"
+    pub config: &'static str,
+    /// Over 9 tons
+    pub is_ultraheavy: bool,
+    pub walk_mp: Option<i32>,
+    pub jump_mp: Option<i32>,
+    pub has_main_gun: bool,
+    pub armor_type: Option<String>,
+    pub has_interface_cockpit: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedVehicleData {
+    /// Normalized motive type: "tracked", "wheeled", "hover", "vtol", "naval", "wige", ...
+    pub motion_type: String,
+    pub cruise_mp: Option<i32>,
+    pub engine_type: Option<String>,
+    pub armor_type: Option<String>,
+    pub structure_type: Option<String>,
+    /// "none", "single" or "dual"
+    pub turret_type: &'static str,
+    pub fuel: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedAeroData {
+    pub safe_thrust: Option<i32>,
+    pub structural_integrity: Option<i32>,
+    pub fuel_points: Option<i32>,
+    pub heat_sink_count: Option<i32>,
+    pub heat_sink_type: Option<String>,
+    pub cockpit_type: Option<String>,
+    pub engine_type: Option<String>,
+    pub armor_type: Option<String>,
+    pub weapon_bays: Vec<ParsedWeaponBay>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedInfantryData {
+    /// "battle_armor" or "conventional"
+    pub kind: &'static str,
+    /// Troopers per squad/point (battle armor) or per platoon (conventional)
+    pub trooper_count: i32,
+    /// Conventional infantry: troopers per squad and squads per platoon
+    pub squad_size: Option<i32>,
+    pub squad_count: Option<i32>,
+    /// Battle armor: "PA(L)", "Light", "Medium", "Heavy" or "Assault"
+    pub weight_class: Option<&'static str>,
+    /// Battle armor: "biped" or "quad"
+    pub chassis_type: Option<String>,
+    /// Normalized motive type: "leg", "jump", "vtol", "umu", "motorized", "wheeled", ...
+    pub motive_type: String,
+    pub ground_mp: Option<i32>,
+    pub jump_mp: Option<i32>,
+    pub umu_mp: Option<i32>,
+    pub vtol_mp: Option<i32>,
+    /// Battle armor: armor points per trooper
+    pub armor_per_trooper: Option<i32>,
+    pub armor_type: Option<String>,
+    /// Battle armor: manipulator on each arm (e.g. "Battle Claw")
+    pub left_manipulator: Option<String>,
+    pub right_manipulator: Option<String>,
+    /// Able to make anti-'Mech (leg and swarm) attacks
+    pub is_anti_mech: bool,
+    /// Conventional infantry: primary and secondary weapon names
+    pub primary_weapon: Option<String>,
+    pub secondary_weapon: Option<String>,
+    /// Conventional infantry: secondary weapons per squad
+    pub secondary_count: Option<i32>,
+    /// Conventional infantry: armor kit name
+    pub armor_kit: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedLargeCraftData {
+    /// "small_craft", "dropship", "jumpship", "warship" or "space_station"
+    pub craft_type: &'static str,
+    /// Small craft and DropShips: "aerodyne" or "spheroid"
+    pub design_type: Option<&'static str>,
+    pub crew: Option<i32>,
+    pub officers: Option<i32>,
+    pub gunners: Option<i32>,
+    pub passengers: Option<i32>,
+    pub marines: Option<i32>,
+    pub battle_armor_troops: Option<i32>,
+    pub other_passengers: Option<i32>,
+    pub life_boats: Option<i32>,
+    pub escape_pods: Option<i32>,
+    /// DropShips: "standard", "prototype" or "no_boom"
+    pub collar_type: Option<&'static str>,
+    /// JumpShips, WarShips and stations: DropShip docking collars
+    pub docking_collars: i32,
+    pub has_kf_drive: bool,
+    /// "standard", "compact", "subcompact", "primitive" or "none"
+    pub drive_core_type: Option<&'static str>,
+    /// Primitive JumpShips: maximum jump distance in light years
+    pub jump_range: Option<i32>,
+    pub has_lithium_fusion: bool,
+    pub has_jump_sail: bool,
+    pub has_hpg: bool,
+    pub grav_decks: i32,
+    pub transport_bays: Vec<ParsedTransportBay>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedTransportBay {
+    /// Bay number from the unit file, if given
+    pub bay_number: Option<i32>,
+    /// Normalized bay type (e.g. "mech", "asf", "heavy_vehicle", "cargo")
+    pub bay_type: &'static str,
+    /// Units, squads, platoons or tons depending on the bay type
+    pub size: f64,
+    pub doors: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedWeaponBay {
+    pub location: &'static str,
+    /// 1-based bay number within the arc
+    pub bay_number: i32,
+    /// (weapon name, count)
+    pub weapons: Vec<(String, i32)>,
 }
 
 #[derive(Debug, Clone)]
@@ -51,13 +266,37 @@ pub struct ParsedLoadoutEntry {
     pub location: Option<&'static str>,
     pub quantity: i32,
     pub is_rear: bool,
+    /// Pod-mounted on an OmniMech (swapped between configurations)
+    pub is_omnipod: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedCriticalSlot {
+    pub location: &'static str,
+    /// 1-based slot number within the location
+    pub slot_index: i32,
+    /// Component name with mount flags stripped; None for an empty slot
+    pub content: Option<String>,
+    pub is_rear: bool,
+    pub is_omnipod: bool,
+    /// Engine, gyro, actuators, structure/armor filler — never linked to equipment
+    pub is_structural: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UnitType {
     Mech,
+    IndustrialMech,
+    ProtoMech,
     Vehicle,
     Fighter,
+    SmallCraft,
+    DropShip,
+    JumpShip,
+    WarShip,
+    SpaceStation,
+    BattleArmor,
+    Infantry,
     Other,
 }
 
@@ -65,8 +304,17 @@ impl UnitType {
     pub fn as_str(self) -> &'static str {
         match self {
             UnitType::Mech => "mech",
+            UnitType::IndustrialMech => "industrialmech",
+            UnitType::ProtoMech => "protomech",
             UnitType::Vehicle => "vehicle",
             UnitType::Fighter => "fighter",
+            UnitType::SmallCraft => "small_craft",
+            UnitType::DropShip => "dropship",
+            UnitType::JumpShip => "jumpship",
+            UnitType::WarShip => "warship",
+            UnitType::SpaceStation => "space_station",
+            UnitType::BattleArmor => "battle_armor",
+            UnitType::Infantry => "infantry",
             UnitType::Other => "other",
         }
     }
@@ -152,13 +400,21 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
     let mut gyro_type: Option<String> = None;
     let mut cockpit_type: Option<String> = None;
     let mut myomer_type: Option<String> = None;
+    let mut lam_type: Option<String> = None;
+    let mut fuel: Option<i32> = None;
+    let mut motive: Option<String> = None;
     let mut tech_base = TechBase::InnerSphere;
     let mut rules_level = RulesLevel::Standard;
     let mut intro_year: Option<i32> = None;
     let mut source: Option<String> = None;
+    let mut mul_id: Option<i32> = None;
     let mut tonnage: Option<f64> = None;
     let mut description: Option<String> = None;
     let mut quirks: Vec<String> = Vec::new();
+    let mut fluff = ParsedFluff::default();
+    let mut manufacturer: Option<String> = None;
+    let mut primary_factory: Option<String> = None;
+    let mut system_manufacturers: Vec<String> = Vec::new();
 
     // Armor values keyed by short location code
     let mut armor: std::collections::HashMap<String, (Option<i32>, Option<i32>)> =
@@ -167,6 +423,9 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
     // Weapons loadout
     let mut loadout: Vec<ParsedLoadoutEntry> = Vec::new();
 
+    // Critical slots in file order
+    let mut critical_slots: Vec<ParsedCriticalSlot> = Vec::new();
+
     // Location section parsing
     let mut current_loc: Option<&'static str> = None;
 
@@ -179,29 +438,24 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
         }
 
         // Check for location section header (e.g. "Left Arm:", "Right Torso:")
-        if !line.contains(':') {
+        // Variable-size equipment ("ISCargo:SIZE:2.0") is still a crit line.
+        if !line.contains(':') || (current_loc.is_some() && line.contains(":SIZE:")) {
             // Could be a continuation line in a location section
             if let Some(loc) = current_loc {
-                let equip = line.trim_end_matches(" (R)").trim().to_string();
-                let is_rear = line.ends_with("(R)");
-                if !equip.is_empty()
-                    && equip != "-Empty-"
-                    && !is_structural_component(&equip)
-                {
-                    // Find if already in loadout at same loc+rear
-                    if let Some(entry) = loadout.iter_mut().find(|e| {
-                        e.equipment == equip && e.location == Some(loc) && e.is_rear == is_rear
-                    }) {
-                        entry.quantity += 1;
-                    } else {
-                        loadout.push(ParsedLoadoutEntry {
-                            equipment: equip,
-                            location: Some(loc),
-                            quantity: 1,
-                            is_rear,
-                        });
-                    }
-                }
+                let (content, slot_rear, is_omnipod) = split_mount_flags(line);
+                let slot_index = critical_slots
+                    .iter()
+                    .filter(|s| s.location == loc)
+                    .count() as i32
+                    + 1;
+                critical_slots.push(ParsedCriticalSlot {
+                    location: loc,
+                    slot_index,
+                    is_structural: content != "-Empty-" && is_structural_component(&content),
+                    content: (content != "-Empty-").then_some(content),
+                    is_rear: slot_rear,
+                    is_omnipod,
+                });
             }
             continue;
         }
@@ -232,6 +486,7 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
             "techbase" | "tech base" => tech_base = TechBase::from_str(&val),
             "era" => intro_year = val.parse().ok(),
             "source" => source = Some(val.clone()),
+            "mul id" => mul_id = parse_mul_id(&val),
             "rules level" => {
                 rules_level = val
                     .parse::<i32>()
@@ -255,6 +510,9 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
             }
             "walk mp" => walk_mp = val.parse().ok(),
             "jump mp" => jump_mp = val.parse().ok(),
+            "lam" => lam_type = Some(val.clone()),
+            "fuel" => fuel = val.parse().ok(),
+            "motive" => motive = Some(val.clone()),
             "heat sinks" => {
                 // e.g. "16 Single", "10 Double", "10 Clan Double Heat Sink"
                 let parts: Vec<&str> = val.splitn(2, ' ').collect();
@@ -280,7 +538,16 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
             "quirk" => quirks.push(to_slug(&val)),
             "overview" => {
                 description = Some(val.trim_matches('"').to_string());
+                fluff.overview = description.clone();
             }
+            "capabilities" => fluff.capabilities = fluff_text(&val),
+            "deployment" => fluff.deployment = fluff_text(&val),
+            "history" => fluff.history = fluff_text(&val),
+            "notes" => fluff.notes = fluff_text(&val),
+            "imagefile" => fluff.image_file = fluff_text(&val),
+            "manufacturer" => manufacturer = Some(val.clone()),
+            "primaryfactory" => primary_factory = Some(val.clone()),
+            "systemmanufacturer" => system_manufacturers.push(val.clone()),
             _ => {}
         }
 
@@ -340,10 +607,73 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
         return None;
     }
 
-    let unit_type = UnitType::Mech;
+    // IndustrialMechs use industrial internal structure or an industrial cockpit
+    let is_industrial = [&structure_type, &cockpit_type]
+        .iter()
+        .any(|v| v.as_deref().is_some_and(|s| s.to_lowercase().contains("industrial")));
+    let unit_type = if is_industrial {
+        UnitType::IndustrialMech
+    } else {
+        UnitType::Mech
+    };
+
+    let slot_names = || critical_slots.iter().filter_map(|s| s.content.as_deref());
+    let is_environmentally_sealed = slot_names()
+        .any(|c| c.to_lowercase().replace(' ', "").contains("environmentalsealing"));
+    let mut industrial_equipment: Vec<String> = Vec::new();
+    for name in slot_names().filter(|c| is_industrial_equipment(c)) {
+        if !industrial_equipment.iter().any(|e| e == name) {
+            industrial_equipment.push(name.to_string());
+        }
+    }
+
+    let count_slots = |name: &str| {
+        slot_names().filter(|c| c.eq_ignore_ascii_case(name)).count() as i32
+    };
+    let lam_data = config.eq_ignore_ascii_case("lam").then(|| {
+        let lam_type = match lam_type.as_deref().map(str::to_lowercase).as_deref() {
+            Some("bimodal") => "bimodal",
+            _ => "standard",
+        };
+        let airmech_cruise_mp = jump_mp.filter(|_| lam_type == "standard").map(|j| j * 3);
+        ParsedLamData {
+            lam_type,
+            fuel,
+            airmech_cruise_mp,
+            airmech_flank_mp: airmech_cruise_mp.map(|c| (c * 3 + 1) / 2),
+            fighter_safe_thrust: jump_mp,
+            fighter_max_thrust: jump_mp.map(|j| (j * 3 + 1) / 2),
+            landing_gear_slots: count_slots("Landing Gear"),
+            avionics_slots: count_slots("Avionics"),
+        }
+    });
+    let quadvee_data = config.eq_ignore_ascii_case("quadvee").then(|| ParsedQuadVeeData {
+        motive_type: match motive.as_deref().map(str::to_lowercase).as_deref() {
+            Some(m) if m.starts_with("wheel") => "wheeled",
+            _ => "tracked",
+        },
+        conversion_slots: count_slots("Conversion Gear"),
+        motive_slots: slot_names()
+            .filter(|c| {
+                let lower = c.to_lowercase();
+                lower.contains("tracks") || lower.contains("wheels")
+            })
+            .count() as i32,
+    });
+
+    fluff.manufacturers = parse_manufacturers(
+        manufacturer.as_deref(),
+        primary_factory.as_deref(),
+        system_manufacturers.iter().map(String::as_str),
+    );
 
     let locations = build_mech_locations(&armor);
 
+    add_crit_equipment(&mut loadout, &critical_slots, tech_base == TechBase::Clan);
+    if is_omnimech {
+        mark_pod_locations(&mut loadout, &critical_slots);
+    }
+
     let mech_data = Some(ParsedMechData {
         config: if config.is_empty() { "Biped".to_string() } else { config },
         is_omnimech,
@@ -358,6 +688,10 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
         gyro_type,
         cockpit_type,
         myomer_type,
+        is_environmentally_sealed,
+        industrial_equipment,
+        lam_data,
+        quadvee_data,
     });
 
     Some(ParsedUnit {
@@ -368,15 +702,55 @@ pub fn parse_mtf(content: &str) -> Option<ParsedUnit> {
         rules_level,
         intro_year,
         source,
+        mul_id,
         tonnage: tonnage.unwrap(),
         locations,
         loadout: dedup_loadout(loadout),
+        critical_slots,
         quirks,
         description,
+        fluff,
         mech_data,
+        vehicle_data: None,
+        aero_data: None,
+        large_craft_data: None,
+        protomech_data: None,
+        infantry_data: None,
     })
 }
 
+/// Industrial equipment keywords, matched against crit names with spaces and
+/// hyphens removed.
+const INDUSTRIAL_EQUIPMENT: &[&str] = &[
+    "lifthoist",
+    "cargo",
+    "backhoe",
+    "chainsaw",
+    "dualsaw",
+    "rockcutter",
+    "spotwelder",
+    "salvagearm",
+    "miningdrill",
+    "piledriver",
+    "combine",
+    "wreckingball",
+    "sprayer",
+    "fluidsuction",
+    "bulldozer",
+    "ladder",
+    "industrialmanipulator",
+    "refuelingdrogue",
+    "externalpowerpickup",
+    "liquidstorage",
+    "fieldkitchen",
+    "bridgelayer",
+];
+
+fn is_industrial_equipment(name: &str) -> bool {
+    let norm = name.to_lowercase().replace([' ', '-'], "");
+    INDUSTRIAL_EQUIPMENT.iter().any(|k| norm.contains(k))
+}
+
 fn parse_weapon_line(line: &str, loadout: &mut Vec<ParsedLoadoutEntry>) {
     // Format: "[qty] equipment_name, location [, Ammo:N]"
     let parts: Vec<&str> = line.splitn(3, ',').collect();
@@ -405,15 +779,20 @@ fn parse_weapon_line(line: &str, loadout: &mut Vec<ParsedLoadoutEntry>) {
     } else {
         return;
     };
-    let is_rear = raw_loc.ends_with("(R)");
-    let loc_clean = raw_loc.trim_end_matches("(R)").trim();
-    let loc = mtf_weapon_location(loc_clean);
+    // Mount flags may trail either the name or the location
+    let (equip_name, name_rear, name_pod) = split_mount_flags(&equip_name);
+    let (loc_clean, loc_rear, loc_pod) = split_mount_flags(&raw_loc);
+    let is_rear = name_rear || loc_rear;
+    let is_omnipod = name_pod || loc_pod;
+    let loc = mtf_weapon_location(&loc_clean);
 
     for _ in 0..qty {
-        if let Some(entry) = loadout
-            .iter_mut()
-            .find(|e| e.equipment == equip_name && e.location == loc && e.is_rear == is_rear)
-        {
+        if let Some(entry) = loadout.iter_mut().find(|e| {
+            e.equipment == equip_name
+                && e.location == loc
+                && e.is_rear == is_rear
+                && e.is_omnipod == is_omnipod
+        }) {
             entry.quantity += 1;
         } else {
             loadout.push(ParsedLoadoutEntry {
@@ -421,11 +800,98 @@ fn parse_weapon_line(line: &str, loadout: &mut Vec<ParsedLoadoutEntry>) {
                 location: loc,
                 quantity: 1,
                 is_rear,
+                is_omnipod,
+            });
+        }
+    }
+}
+
+/// Add equipment listed only in the critical hit table, one mount per item.
+/// Weapons come from the weapon list, so crit lines for weapons (or for
+/// anything else the list names) are skipped. A run of consecutive slots
+/// holding the same equipment is split into items by `crit_slots_per_item`.
+fn add_crit_equipment(
+    loadout: &mut Vec<ParsedLoadoutEntry>,
+    slots: &[ParsedCriticalSlot],
+    is_clan: bool,
+) {
+    let catalog = crate::equipment_catalog::catalog();
+    let key = |name: &str| {
+        catalog
+            .resolve(name)
+            .map_or_else(|| name.to_lowercase(), |e| e.slug.clone())
+    };
+    let listed: Vec<String> = loadout.iter().map(|e| key(&e.equipment)).collect();
+
+    let mut i = 0;
+    while i < slots.len() {
+        let first = &slots[i];
+        let run = slots[i..]
+            .iter()
+            .take_while(|s| {
+                s.location == first.location
+                    && s.content == first.content
+                    && s.is_rear == first.is_rear
+                    && s.is_omnipod == first.is_omnipod
+            })
+            .count();
+        i += run;
+
+        let Some(name) = first.content.as_deref().filter(|_| !first.is_structural) else {
+            continue;
+        };
+        let is_listed_weapon = catalog.resolve(name).is_some_and(|e| {
+            matches!(e.category.as_str(), "energy_weapon" | "ballistic_weapon" | "missile_weapon")
+        });
+        if is_listed_weapon || listed.contains(&key(name)) {
+            continue;
+        }
+        let items = crit_slots_per_item(name, is_clan)
+            .map_or(1, |size| (run as i32 + size - 1) / size);
+
+        if let Some(entry) = loadout.iter_mut().find(|e| {
+            e.equipment == name
+                && e.location == Some(first.location)
+                && e.is_rear == first.is_rear
+                && e.is_omnipod == first.is_omnipod
+        }) {
+            entry.quantity += items;
+        } else {
+            loadout.push(ParsedLoadoutEntry {
+                equipment: name.to_string(),
+                location: Some(first.location),
+                quantity: items,
+                is_rear: first.is_rear,
+                is_omnipod: first.is_omnipod,
             });
         }
     }
 }
 
+/// Critical slots one item takes, for equipment commonly mounted several to a
+/// location (ammunition, heat sinks, jump jets). `None` for anything else: its
+/// consecutive slots are one item, as variable-size equipment like MASC or a
+/// targeting computer never sits next to another of its kind.
+pub fn crit_slots_per_item(name: &str, is_clan: bool) -> Option<i32> {
+    let n = name.to_lowercase().replace([' ', '-', '_'], "");
+    let clan = n.starts_with("cl") || n.contains("clan") || (is_clan && !n.starts_with("is"));
+    if n.contains("ammo") || n.contains("compactheatsink") {
+        Some(1)
+    } else if n.contains("laserheatsink") {
+        Some(2)
+    } else if n.contains("doubleheatsink") {
+        Some(if clan { 2 } else { 3 })
+    } else if n.contains("heatsink") {
+        Some(1)
+    } else if n.contains("improvedjumpjet") {
+        Some(2)
+    } else if n.contains("jumpjet") || n.contains("umu") {
+        Some(1)
+    } else {
+        None
+    }
+}
+
 fn mtf_weapon_location(loc: &str) -> Option<&'static str> {
     match loc.to_lowercase().trim() {
         "left arm" | "la" => Some("left_arm"),
@@ -436,6 +902,11 @@ fn mtf_weapon_location(loc: &str) -> Option<&'static str> {
         "head" | "hd" => Some("head"),
         "left leg" | "ll" => Some("left_leg"),
         "right leg" | "rl" => Some("right_leg"),
+        "front left leg" | "fll" => Some("front_left_leg"),
+        "front right leg" | "frl" => Some("front_right_leg"),
+        "rear left leg" | "rll" => Some("rear_left_leg"),
+        "rear right leg" | "rrl" => Some("rear_right_leg"),
+        "center leg" | "cl" => Some("center_leg"),
         _ => None,
     }
 }
@@ -450,6 +921,11 @@ fn mtf_location_header(key: &str) -> Option<&'static str> {
         "head" => Some("head"),
         "left leg" => Some("left_leg"),
         "right leg" => Some("right_leg"),
+        "front left leg" => Some("front_left_leg"),
+        "front right leg" => Some("front_right_leg"),
+        "rear left leg" => Some("rear_left_leg"),
+        "rear right leg" => Some("rear_right_leg"),
+        "center leg" => Some("center_leg"),
         _ => None,
     }
 }
@@ -466,6 +942,11 @@ fn build_mech_locations(
         ("HD", "head"),
         ("LL", "left_leg"),
         ("RL", "right_leg"),
+        ("FLL", "front_left_leg"),
+        ("FRL", "front_right_leg"),
+        ("RLL", "rear_left_leg"),
+        ("RRL", "rear_right_leg"),
+        ("CL", "center_leg"),
     ];
     mapping
         .iter()
@@ -481,6 +962,27 @@ fn build_mech_locations(
         .collect()
 }
 
+/// Split trailing MegaMek mount flags off a crit line.
+/// Returns `(name, is_rear, is_omnipod)`; "Medium Laser (R) (omnipod)" → ("Medium Laser", true, true).
+fn split_mount_flags(line: &str) -> (String, bool, bool) {
+    let mut name = line.trim();
+    let mut is_rear = false;
+    let mut is_omnipod = false;
+    loop {
+        let lower = name.to_lowercase();
+        if lower.ends_with("(r)") {
+            is_rear = true;
+            name = name[..name.len() - 3].trim_end();
+        } else if lower.ends_with("(omnipod)") {
+            is_omnipod = true;
+            name = name[..name.len() - 9].trim_end();
+        } else {
+            break;
+        }
+    }
+    (name.to_string(), is_rear, is_omnipod)
+}
+
 fn is_structural_component(s: &str) -> bool {
     matches!(
         s,
@@ -492,6 +994,9 @@ fn is_structural_component(s: &str) -> bool {
             | "Upper Leg Actuator"
             | "Lower Leg Actuator"
             | "Foot Actuator"
+            | "Landing Gear"
+            | "Avionics"
+            | "Conversion Gear"
             | "Life Support"
             | "Sensors"
             | "Cockpit"
@@ -566,19 +1071,31 @@ pub fn parse_blk(content: &str, default_unit_type: UnitType) -> Option<ParsedUni
         .get("Model")
         .map(|s| s.trim().to_string())
         .unwrap_or_default();
-    let tonnage: f64 = tags.get("tonnage")?.trim().parse().ok()?;
+    let tonnage: Option<f64> = tags.get("tonnage").and_then(|s| s.trim().parse().ok());
     let intro_year: Option<i32> = tags.get("year").and_then(|s| s.trim().parse().ok());
     let source = tags.get("source").map(|s| s.trim().to_string());
+    let mul_id = tags
+        .iter()
+        .find(|(k, _)| k.trim_end_matches(':').eq_ignore_ascii_case("mul id"))
+        .and_then(|(_, v)| parse_mul_id(v));
 
     let blk_unit_type = tags
         .get("UnitType")
         .map(|s| s.trim().to_lowercase())
         .unwrap_or_default();
     let unit_type = match blk_unit_type.as_str() {
-        "tank" | "vtol" | "naval" | "wheeled vehicle" | "tracked vehicle" => UnitType::Vehicle,
-        "aero" | "aerospacespacefighter" | "conv_fighter" | "conventional fighter" => {
-            UnitType::Fighter
-        }
+        "tank" | "vtol" | "naval" | "wheeled vehicle" | "tracked vehicle" | "supporttank"
+        | "largesupporttank" | "superheavytank" | "supportvtol" => UnitType::Vehicle,
+        "aero" | "aerospacefighter" | "aerospacespacefighter" | "convfighter" | "conv_fighter"
+        | "conventional fighter" => UnitType::Fighter,
+        "battlearmor" => UnitType::BattleArmor,
+        "infantry" => UnitType::Infantry,
+        "protomech" | "protomek" => UnitType::ProtoMech,
+        "smallcraft" => UnitType::SmallCraft,
+        "dropship" => UnitType::DropShip,
+        "jumpship" => UnitType::JumpShip,
+        "warship" => UnitType::WarShip,
+        "spacestation" => UnitType::SpaceStation,
         _ => default_unit_type,
     };
 
@@ -586,28 +1103,149 @@ pub fn parse_blk(content: &str, default_unit_type: UnitType) -> Option<ParsedUni
     let tech_base = TechBase::from_str(&type_str);
     let rules_level = RulesLevel::from_type_str(&type_str);
 
+    let infantry_data = match unit_type {
+        UnitType::BattleArmor => Some(parse_battle_armor(&tags, &equipment_by_loc, tech_base)),
+        UnitType::Infantry => Some(parse_conventional_infantry(&tags)),
+        _ => None,
+    };
+    // Infantry files usually omit tonnage; every other unit type requires it
+    let tonnage = match (tonnage, &infantry_data) {
+        (Some(t), _) => t,
+        (None, Some(inf)) => estimate_infantry_tonnage(inf),
+        (None, None) => return None,
+    };
+
     let description = tags.get("overview").map(|s| {
         s.trim().trim_matches('"').to_string()
     });
+    let fluff_tag = |name: &str| {
+        tags.iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .and_then(|(_, v)| fluff_text(v))
+    };
+    let system_manufacturers = fluff_tag("systemManufacturers").unwrap_or_default();
+    let fluff = ParsedFluff {
+        overview: description.clone(),
+        capabilities: fluff_tag("capabilities"),
+        deployment: fluff_tag("deployment"),
+        history: fluff_tag("history"),
+        notes: fluff_tag("notes"),
+        image_file: fluff_tag("imagefile"),
+        manufacturers: parse_manufacturers(
+            fluff_tag("manufacturer").as_deref(),
+            fluff_tag("primaryFactory").as_deref(),
+            system_manufacturers.lines(),
+        ),
+    };
 
     // Build loadout from equipment tags
+    let is_aero = blk_is_aero(&blk_unit_type);
     let mut loadout: Vec<ParsedLoadoutEntry> = Vec::new();
-    for (loc_tag, equip_name) in &equipment_by_loc {
-        let loc = blk_location(loc_tag.trim());
+    for (loc_tag, equip_line) in &equipment_by_loc {
+        let mut loc = blk_location(loc_tag.trim(), is_aero);
+        let (mut equip_name, _) = strip_bay_marker(equip_line);
+        if unit_type == UnitType::BattleArmor {
+            // Battle armor lines carry their mount after the name ("CLERSmallLaser:RA")
+            let (name, mount) = split_ba_mount(equip_name);
+            equip_name = name;
+            loc = mount.or(loc);
+        }
+        let is_omnipod = equip_name.to_lowercase().ends_with("(omnipod)");
+        if is_omnipod {
+            equip_name = equip_name[..equip_name.len() - 9].trim_end();
+        }
         if let Some(entry) = loadout.iter_mut().find(|e| {
-            e.equipment == *equip_name && e.location == loc && !e.is_rear
+            e.equipment == equip_name
+                && e.location == loc
+                && !e.is_rear
+                && e.is_omnipod == is_omnipod
         }) {
             entry.quantity += 1;
         } else {
             loadout.push(ParsedLoadoutEntry {
-                equipment: equip_name.clone(),
+                equipment: equip_name.to_string(),
                 location: loc,
                 quantity: 1,
                 is_rear: false,
+                is_omnipod,
             });
         }
     }
 
+    if let Some(inf) = infantry_data.as_ref().filter(|i| i.kind == "conventional") {
+        let squads = inf.squad_count.unwrap_or(1);
+        let secondary = inf.secondary_count.unwrap_or(0);
+        let per_squad = inf.squad_size.unwrap_or(inf.trooper_count);
+        let weapons = [
+            (&inf.primary_weapon, squads * (per_squad - secondary)),
+            (&inf.secondary_weapon, squads * secondary),
+        ];
+        for (weapon, quantity) in weapons {
+            if let Some(name) = weapon.as_ref().filter(|_| quantity > 0) {
+                loadout.push(ParsedLoadoutEntry {
+                    equipment: name.clone(),
+                    location: None,
+                    quantity,
+                    is_rear: false,
+                    is_omnipod: false,
+                });
+            }
+        }
+    }
+
+    let vehicle_data = if unit_type == UnitType::Vehicle {
+        tags.get("motion_type").map(|mt| ParsedVehicleData {
+            motion_type: mt.trim().to_lowercase().replace([' ', '_'], ""),
+            cruise_mp: tags.get("cruiseMP").and_then(|s| s.trim().parse().ok()),
+            engine_type: tags.get("engine_type").map(|s| blk_engine_name(s.trim())),
+            armor_type: tags.get("armor_type").map(|s| blk_armor_name(s.trim())),
+            structure_type: tags.get("internal_type").map(|s| blk_structure_name(s.trim())),
+            turret_type: blk_turret_type(&tags, &equipment_by_loc),
+            fuel: tags.get("fuel").and_then(|s| s.trim().parse().ok()),
+        })
+    } else {
+        None
+    };
+
+    let aero_data = is_aero.then(|| {
+        let safe_thrust: Option<i32> = tags.get("SafeThrust").and_then(|s| s.trim().parse().ok());
+        // Fighters omit SI; MegaMek derives it as max(10% of tonnage, safe thrust)
+        let structural_integrity = tags
+            .get("structural_integrity")
+            .and_then(|s| s.trim().parse().ok())
+            .or_else(|| {
+                let base = (tonnage / 10.0).ceil() as i32;
+                Some(base.max(safe_thrust.unwrap_or(0)))
+            });
+        ParsedAeroData {
+            safe_thrust,
+            structural_integrity,
+            fuel_points: tags.get("fuel").and_then(|s| s.trim().parse().ok()),
+            heat_sink_count: tags.get("heatsinks").and_then(|s| s.trim().parse().ok()),
+            heat_sink_type: tags.get("sink_type").map(|s| {
+                match s.trim() {
+                    "0" => "Single",
+                    "1" => "Double",
+                    other => other,
+                }
+                .to_string()
+            }),
+            cockpit_type: Some(
+                match tags.get("cockpit_type").map(|s| s.trim()).unwrap_or("0") {
+                    "0" => "Standard",
+                    "1" => "Small",
+                    "2" => "Command Console",
+                    "3" => "Primitive",
+                    other => other,
+                }
+                .to_string(),
+            ),
+            engine_type: tags.get("engine_type").map(|s| blk_engine_name(s.trim())),
+            armor_type: tags.get("armor_type").map(|s| blk_armor_name(s.trim())),
+            weapon_bays: build_weapon_bays(&equipment_by_loc),
+        }
+    });
+
     Some(ParsedUnit {
         chassis,
         model,
@@ -616,31 +1254,738 @@ pub fn parse_blk(content: &str, default_unit_type: UnitType) -> Option<ParsedUni
         rules_level,
         intro_year,
         source,
+        mul_id,
         tonnage,
-        locations: Vec::new(), // BLK armor parsing skipped for now
+        locations: build_blk_locations(&tags, unit_type, &blk_unit_type, tonnage),
         loadout: dedup_loadout(loadout),
+        critical_slots: Vec::new(),
         quirks: Vec::new(),
         description,
+        fluff,
         mech_data: None, // BLK units are vehicles/aero, not mechs
+        vehicle_data,
+        aero_data,
+        infantry_data,
+        large_craft_data: parse_large_craft(&tags, &blk_unit_type),
+        protomech_data: (unit_type == UnitType::ProtoMech)
+            .then(|| parse_protomech(&tags, &equipment_by_loc, tonnage)),
     })
 }
 
-fn blk_location(loc: &str) -> Option<&'static str> {
+// ── Infantry ───────────────────────────────────────────────────────────────
+
+/// Split a battle armor equipment line ("CLERSmallLaser:RA:DWP") into the
+/// equipment name and its mount location.
+fn split_ba_mount(line: &str) -> (&str, Option<&'static str>) {
+    let mut parts = line.split(':');
+    let name = parts.next().unwrap_or("").trim();
+    let mount = parts.find_map(|p| match p.trim().to_lowercase().as_str() {
+        "la" => Some("left_arm"),
+        "ra" => Some("right_arm"),
+        "body" => Some("body"),
+        "tu" | "turret" => Some("turret"),
+        _ => None,
+    });
+    (name, mount)
+}
+
+/// Display name of a battle armor manipulator, or None if `name` is not one.
+fn ba_manipulator(name: &str) -> Option<String> {
+    let key: String = name
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    let (_, base) = [
+        ("heavybattleclaw", "Heavy Battle Claw"),
+        ("battleclaw", "Battle Claw"),
+        ("basicmanipulator", "Basic Manipulator"),
+        ("armoredglove", "Armored Glove"),
+        ("cargolifter", "Cargo Lifter"),
+        ("industrialdrill", "Industrial Drill"),
+        ("salvagearm", "Salvage Arm"),
+    ]
+    .into_iter()
+    .find(|(k, _)| key.contains(k))?;
+    let modifier = if key.contains("magnet") {
+        " (Magnets)"
+    } else if key.contains("vibro") {
+        " (Vibro-Claws)"
+    } else if key.contains("mine") {
+        " (Mine Clearance)"
+    } else {
+        ""
+    };
+    Some(format!("{base}{modifier}"))
+}
+
+/// Normalized BLK `motion_type` ("Motorized SCUBA" → "motorizedscuba").
+fn infantry_motive(tags: &std::collections::HashMap<String, String>) -> String {
+    tags.get("motion_type")
+        .map(|s| s.trim().to_lowercase().replace([' ', '_', '-'], ""))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "leg".to_string())
+}
+
+fn parse_battle_armor(
+    tags: &std::collections::HashMap<String, String>,
+    equipment_by_loc: &[(String, String)],
+    tech_base: TechBase,
+) -> ParsedInfantryData {
+    let int = |k: &str| tags.get(k).and_then(|s| s.trim().parse::<i32>().ok());
+    let motive_type = infantry_motive(tags);
+    let weight_class = int("weightclass").and_then(|w| match w {
+        0 => Some("PA(L)"),
+        1 => Some("Light"),
+        2 => Some("Medium"),
+        3 => Some("Heavy"),
+        4 => Some("Assault"),
+        _ => None,
+    });
+    let chassis_type = tags.get("chassis").map(|s| s.trim().to_lowercase());
+
+    let mut left_manipulator = None;
+    let mut right_manipulator = None;
+    for (_, line) in equipment_by_loc {
+        let (name, mount) = split_ba_mount(line);
+        match (ba_manipulator(name), mount) {
+            (Some(m), Some("left_arm")) => left_manipulator = Some(m),
+            (Some(m), Some("right_arm")) => right_manipulator = Some(m),
+            _ => {}
+        }
+    }
+
+    // Leg and swarm attacks need a handed manipulator on a non-quad suit no
+    // heavier than Medium (Total Warfare).
+    let handed = |m: &Option<String>| {
+        m.as_deref().is_some_and(|m| {
+            ["Battle Claw", "Basic Manipulator", "Armored Glove"]
+                .iter()
+                .any(|k| m.contains(k))
+        })
+    };
+    let is_anti_mech = chassis_type.as_deref() != Some("quad")
+        && matches!(weight_class, Some("PA(L)" | "Light" | "Medium"))
+        && (handed(&left_manipulator) || handed(&right_manipulator));
+
+    // `jumpingMP` holds the suit's secondary movement, whatever its mode
+    let extra_mp = int("jumpingMP").filter(|&mp| mp > 0);
+    let (jump_mp, umu_mp, vtol_mp) = match motive_type.as_str() {
+        "vtol" => (None, None, extra_mp),
+        "umu" | "submarine" => (None, extra_mp, None),
+        _ => (extra_mp, None, None),
+    };
+
+    ParsedInfantryData {
+        kind: "battle_armor",
+        trooper_count: int("Trooper Count").unwrap_or(if tech_base == TechBase::Clan { 5 } else { 4 }),
+        squad_size: None,
+        squad_count: None,
+        weight_class,
+        chassis_type,
+        ground_mp: int("cruiseMP"),
+        jump_mp,
+        umu_mp,
+        vtol_mp,
+        motive_type,
+        armor_per_trooper: tags
+            .get("armor")
+            .and_then(|a| a.lines().find_map(|l| l.trim().parse().ok())),
+        armor_type: tags.get("armor_type").map(|s| blk_armor_name(s.trim())),
+        left_manipulator,
+        right_manipulator,
+        is_anti_mech,
+        primary_weapon: None,
+        secondary_weapon: None,
+        secondary_count: None,
+        armor_kit: None,
+    }
+}
+
+fn parse_conventional_infantry(tags: &std::collections::HashMap<String, String>) -> ParsedInfantryData {
+    let int = |k: &str| tags.get(k).and_then(|s| s.trim().parse::<i32>().ok());
+    let text = |k: &str| tags.get(k).map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    let motive_type = infantry_motive(tags);
+    let squad_size = int("squad_size");
+    let squad_count = int("squadn");
+
+    // Conventional infantry files carry no MP; use the Total Warfare motive table
+    let (ground_mp, jump_mp, umu_mp, vtol_mp) = match motive_type.as_str() {
+        "jump" => (1, Some(3), None, None),
+        "motorized" | "tracked" => (3, None, None, None),
+        "wheeled" => (4, None, None, None),
+        "hover" => (5, None, None, None),
+        "microlite" | "microcopter" | "vtol" => (0, None, None, Some(6)),
+        "umu" | "scuba" => (0, None, Some(1), None),
+        "motorizedscuba" => (0, None, Some(2), None),
+        _ => (1, None, None, None),
+    };
+
+    ParsedInfantryData {
+        kind: "conventional",
+        trooper_count: squad_size.unwrap_or(7) * squad_count.unwrap_or(4),
+        squad_size,
+        squad_count,
+        weight_class: None,
+        chassis_type: None,
+        motive_type,
+        ground_mp: Some(ground_mp),
+        jump_mp,
+        umu_mp,
+        vtol_mp,
+        armor_per_trooper: None,
+        armor_type: None,
+        left_manipulator: None,
+        right_manipulator: None,
+        is_anti_mech: tags
+            .get("antimek")
+            .is_some_and(|v| !matches!(v.trim().to_lowercase().as_str(), "false" | "0")),
+        primary_weapon: text("Primary"),
+        secondary_weapon: text("Secondary"),
+        secondary_count: int("secondn"),
+        armor_kit: text("armorKit"),
+    }
+}
+
+/// Tonnage for infantry files that omit it: troopers times the weight-class
+/// limit per suit (battle armor) or a typical per-trooper mass by motive type
+/// (conventional infantry).
+fn estimate_infantry_tonnage(inf: &ParsedInfantryData) -> f64 {
+    let per_trooper = match (inf.kind, inf.weight_class, inf.motive_type.as_str()) {
+        (_, Some("PA(L)"), _) => 0.4,
+        (_, Some("Light"), _) => 0.75,
+        (_, Some("Heavy"), _) => 1.5,
+        (_, Some("Assault"), _) => 2.0,
+        ("battle_armor", _, _) => 1.0,
+        (_, _, "leg") => 0.085,
+        (_, _, "jump") => 0.165,
+        (_, _, "motorized") => 0.195,
+        _ => 0.5,
+    };
+    (inf.trooper_count as f64 * per_trooper * 1000.0).round() / 1000.0
+}
+
+// ── ProtoMechs ─────────────────────────────────────────────────────────────
+
+fn proto_config(tags: &std::collections::HashMap<String, String>) -> &'static str {
+    match tags.get("motion_type").map(|s| s.trim().to_lowercase()).as_deref() {
+        Some("quad") => "quad",
+        Some("glider") | Some("wige") => "glider",
+        _ => "biped",
+    }
+}
+
+fn parse_protomech(
+    tags: &std::collections::HashMap<String, String>,
+    equipment_by_loc: &[(String, String)],
+    tonnage: f64,
+) -> ParsedProtoMechData {
+    let int = |tag: &str| -> Option<i32> { tags.get(tag).and_then(|s| s.trim().parse().ok()) };
+    // The main gun is the sixth armor value; files without one list five
+    let main_gun_armor = tags
+        .get("armor")
+        .and_then(|a| a.lines().filter(|l| !l.trim().is_empty()).nth(5).map(str::to_string))
+        .and_then(|v| v.trim().parse::<i32>().ok())
+        .unwrap_or(0);
+    let has_main_gun = main_gun_armor > 0
+        || equipment_by_loc.iter().any(|(loc, _)| loc.trim() == "main gun");
+    ParsedProtoMechData {
+        config: proto_config(tags),
+        is_ultraheavy: tonnage > 9.0,
+        walk_mp: int("cruiseMP"),
+        jump_mp: int("jumpingMP"),
+        has_main_gun,
+        armor_type: tags.get("armor_type").map(|s| blk_armor_name(s.trim())),
+        has_interface_cockpit: int("interface_cockpit").is_some_and(|v| v != 0),
+    }
+}
+
+/// ProtoMech internal structure for a location (TechManual table). Quads have
+/// no arms; their legs take the arm structure as well.
+fn proto_structure(tonnage: f64, location: &str, is_quad: bool) -> i32 {
+    let t = tonnage.round() as i32;
+    let band = (t - 2).max(0) / 4 + 1; // 2-5 t → 1, 6-9 t → 2, 10-13 t → 3, 14-15 t → 4
+    let legs = (t + 2) / 2; // 2,2,3,3,4,4,5,5,6,...
+    match location {
+        "head" | "left_arm" | "right_arm" => band,
+        "torso" => t,
+        "legs" if is_quad => legs + 2 * band,
+        "legs" => legs,
+        "main_gun" => if t > 9 { 2 } else { 1 },
+        _ => 0,
+    }
+}
+
+// ── Large craft ────────────────────────────────────────────────────────────
+
+/// Transport bays, crew and drive data for small craft, DropShips, JumpShips,
+/// WarShips and space stations. None for other unit types.
+fn parse_large_craft(
+    tags: &std::collections::HashMap<String, String>,
+    blk_unit_type: &str,
+) -> Option<ParsedLargeCraftData> {
+    let craft_type = match blk_unit_type {
+        "smallcraft" => "small_craft",
+        "dropship" => "dropship",
+        "jumpship" => "jumpship",
+        "warship" => "warship",
+        "spacestation" => "space_station",
+        _ => return None,
+    };
+    let int = |tag: &str| -> Option<i32> { tags.get(tag).and_then(|s| s.trim().parse().ok()) };
+    let flag = |tag: &str| int(tag).is_some_and(|v| v != 0);
+    // Grav decks are listed one diameter per line
+    let count_lines = |tag: &str| -> i32 {
+        tags.get(tag)
+            .map(|s| s.lines().filter(|l| !l.trim().is_empty()).count() as i32)
+            .unwrap_or(0)
+    };
+
+    let mut transport_bays = Vec::new();
+    let mut docking_collars = 0;
+    for line in tags.get("transporters").map(|s| s.lines()).into_iter().flatten() {
+        let line = line.trim();
+        if line.to_lowercase().starts_with("dockingcollar") {
+            docking_collars += 1;
+        } else if let Some(bay) = parse_transport_bay(line) {
+            transport_bays.push(bay);
+        }
+    }
+
+    let has_drive = matches!(craft_type, "jumpship" | "warship");
+    let drive_core_type = has_drive.then(|| match int("drive_core_type").unwrap_or(0) {
+        1 => "compact",
+        2 => "subcompact",
+        3 => "none",
+        4 => "primitive",
+        _ => "standard",
+    });
+    let is_small = matches!(craft_type, "small_craft" | "dropship");
+
+    Some(ParsedLargeCraftData {
+        craft_type,
+        design_type: is_small.then(|| match int("designtype") {
+            Some(1) => "spheroid",
+            _ => "aerodyne",
+        }),
+        crew: int("crew"),
+        officers: int("officers"),
+        gunners: int("gunners"),
+        passengers: int("passengers"),
+        marines: int("marines"),
+        battle_armor_troops: int("battlearmor"),
+        other_passengers: int("otherpassenger"),
+        life_boats: int("life_boat").or_else(|| int("lifeboat")),
+        escape_pods: int("escape_pod").or_else(|| int("escapepod")),
+        collar_type: (craft_type == "dropship").then(|| match int("collartype") {
+            Some(1) => "prototype",
+            Some(2) => "no_boom",
+            _ => "standard",
+        }),
+        docking_collars,
+        has_kf_drive: drive_core_type.is_some_and(|t| t != "none"),
+        drive_core_type,
+        jump_range: int("jump_range"),
+        has_lithium_fusion: flag("lithium-fusion"),
+        // JumpShips and WarShips carry a sail unless the file says otherwise
+        has_jump_sail: match int("sail") {
+            Some(v) => v != 0,
+            None => has_drive,
+        },
+        has_hpg: flag("hpg"),
+        grav_decks: count_lines("grav_deck") + count_lines("grav_deck_large") + count_lines("grav_deck_huge"),
+        transport_bays,
+    })
+}
+
+/// Parse a BLK transporter line ("mechbay:12.0:2:1") into a bay. Lines that
+/// are not bays (troop space on vehicles, seating, unknown types) give None.
+fn parse_transport_bay(line: &str) -> Option<ParsedTransportBay> {
+    let mut parts = line.split(':').map(str::trim);
+    let bay_type = match parts.next()?.trim_start_matches('*').to_lowercase().as_str() {
+        "mechbay" => "mech",
+        "protomechbay" => "protomech",
+        "lightvehiclebay" => "light_vehicle",
+        "heavyvehiclebay" => "heavy_vehicle",
+        "superheavyvehiclebay" => "super_heavy_vehicle",
+        "asfbay" => "asf",
+        "smallcraftbay" => "small_craft",
+        "dropshuttlebay" => "drop_shuttle",
+        "infantrybay" => "infantry",
+        "battlearmorbay" => "battle_armor",
+        "cargobay" => "cargo",
+        "liquidcargobay" => "liquid_cargo",
+        "insulatedcargobay" => "insulated_cargo",
+        "refrigeratedcargobay" => "refrigerated_cargo",
+        "livestockcargobay" => "livestock_cargo",
+        "navalrepairfacility" => "naval_repair",
+        "reinforcedrepairfacility" => "reinforced_repair",
+        "1stclassquarters" => "first_class_quarters",
+        "2ndclassquarters" => "second_class_quarters",
+        "crewquarters" => "crew_quarters",
+        "steeragequarters" => "steerage_quarters",
+        _ => return None,
+    };
+    let size: f64 = parts.next()?.parse().ok()?;
+    let doors = parts.next().and_then(|d| d.parse().ok()).unwrap_or(0);
+    let bay_number = parts.next().and_then(|n| n.parse().ok()).filter(|n: &i32| *n >= 0);
+    Some(ParsedTransportBay {
+        bay_number,
+        bay_type,
+        size,
+        doors,
+    })
+}
+
+/// Split the large-craft "(B)" bay marker off a BLK equipment line.
+/// Returns `(name, starts_new_bay)`.
+fn strip_bay_marker(line: &str) -> (&str, bool) {
+    match line.trim().strip_prefix("(B)") {
+        Some(rest) => (rest.trim(), true),
+        None => (line.trim(), false),
+    }
+}
+
+/// Group aerospace weapons into bays per arc.
+///
+/// Large craft mark the first weapon of each bay with "(B)"; following weapons
+/// join that bay. Fighters carry no markers, so each distinct weapon in an arc
+/// becomes its own group.
+fn build_weapon_bays(equipment_by_loc: &[(String, String)]) -> Vec<ParsedWeaponBay> {
+    let has_markers = equipment_by_loc
+        .iter()
+        .any(|(_, line)| strip_bay_marker(line).1);
+
+    let mut bays: Vec<ParsedWeaponBay> = Vec::new();
+    for (loc_tag, line) in equipment_by_loc {
+        let Some(location) = blk_location(loc_tag.trim(), true) else {
+            continue;
+        };
+        let (name, new_bay) = strip_bay_marker(line);
+        if !catalog().resolve(name).is_some_and(CatalogEntry::is_weapon) {
+            continue;
+        }
+
+        let current = if has_markers {
+            if new_bay {
+                None
+            } else {
+                bays.iter().rposition(|b| b.location == location)
+            }
+        } else {
+            bays.iter()
+                .position(|b| b.location == location && b.weapons[0].0 == name)
+        };
+
+        match current {
+            Some(idx) => {
+                let bay = &mut bays[idx];
+                match bay.weapons.iter_mut().find(|(w, _)| w == name) {
+                    Some((_, count)) => *count += 1,
+                    None => bay.weapons.push((name.to_string(), 1)),
+                }
+            }
+            None => {
+                let bay_number = bays.iter().filter(|b| b.location == location).count() as i32 + 1;
+                bays.push(ParsedWeaponBay {
+                    location,
+                    bay_number,
+                    weapons: vec![(name.to_string(), 1)],
+                });
+            }
+        }
+    }
+    bays
+}
+
+/// Map a BLK `engine_type` code (MegaMek `Engine` constants) to a display name.
+fn blk_engine_name(code: &str) -> String {
+    let name = match code {
+        "0" => "ICE",
+        "1" => "Fusion",
+        "2" => "XL",
+        "3" => "XXL",
+        "4" => "Fuel Cell",
+        "5" => "Light",
+        "6" => "Compact",
+        "7" => "Fission",
+        "8" => "None",
+        "9" => "Maglev",
+        "10" => "Steam",
+        "11" => "Battery",
+        "12" => "Solar",
+        "13" => "External",
+        other => return other.to_string(),
+    };
+    name.to_string()
+}
+
+/// Map a BLK `armor_type` code (MegaMek `EquipmentType.T_ARMOR_*`) to a display name.
+fn blk_armor_name(code: &str) -> String {
+    let name = match code {
+        "0" => "Standard",
+        "1" => "Ferro-Fibrous",
+        "2" => "Reactive",
+        "3" => "Reflective",
+        "4" => "Hardened",
+        "5" => "Light Ferro-Fibrous",
+        "6" => "Heavy Ferro-Fibrous",
+        "7" => "Patchwork",
+        "8" => "Stealth",
+        "9" => "Ferro-Fibrous Prototype",
+        "10" => "Commercial",
+        "11" => "Ferro-Carbide",
+        "12" => "Lamellor Ferro-Carbide",
+        "13" => "Improved Ferro-Aluminum",
+        "14" => "Industrial",
+        "15" => "Heavy Industrial",
+        "16" => "Ferro-Lamellor",
+        "17" => "Primitive",
+        "22" => "Vehicular Stealth",
+        "28" => "BA Standard",
+        "29" => "BA Standard (Prototype)",
+        "30" => "BA Advanced",
+        "31" => "BA Stealth (Basic)",
+        "32" => "BA Stealth",
+        "33" => "BA Stealth (Improved)",
+        "34" => "BA Stealth (Prototype)",
+        "35" => "BA Fire Resistant",
+        "36" => "BA Mimetic",
+        "37" => "BA Laser Reflective",
+        "38" => "BA Reactive",
+        other => return other.to_string(),
+    };
+    name.to_string()
+}
+
+/// Map a BLK `internal_type` code (MegaMek `EquipmentType.T_STRUCTURE_*`) to a display name.
+fn blk_structure_name(code: &str) -> String {
+    let name = match code {
+        "-1" | "0" => "Standard",
+        "1" => "Industrial",
+        "2" => "Endo Steel",
+        "3" => "Endo Steel Prototype",
+        "4" => "Reinforced",
+        "5" => "Composite",
+        "6" => "Endo-Composite",
+        other => return other.to_string(),
+    };
+    name.to_string()
+}
+
+/// Work out a vehicle's turret configuration from its BLK tags and equipment blocks.
+fn blk_turret_type(
+    tags: &std::collections::HashMap<String, String>,
+    equipment_by_loc: &[(String, String)],
+) -> &'static str {
+    let has_loc = |name: &str| equipment_by_loc.iter().any(|(loc, _)| loc == name);
+    if has_loc("front turret") || has_loc("rear turret") {
+        "dual"
+    } else if tags.contains_key("hasNoTurret") {
+        "none"
+    } else if has_loc("turret") {
+        "single"
+    } else {
+        "none"
+    }
+}
+
+fn blk_is_aero(blk_unit_type: &str) -> bool {
+    matches!(
+        blk_unit_type,
+        "aero" | "aerospacefighter" | "aerospacespacefighter" | "convfighter" | "conv_fighter"
+            | "conventional fighter" | "fixedwingsupport" | "smallcraft" | "dropship"
+            | "jumpship" | "warship" | "spacestation"
+    )
+}
+
+fn blk_location(loc: &str, is_aero: bool) -> Option<&'static str> {
     match loc {
         "front" => Some("front"),
         "rear" => Some("rear"),
         "right" => Some("right_side"),
         "left" => Some("left_side"),
         "turret" => Some("turret"),
+        "front turret" => Some("front_turret"),
+        "rear turret" => Some("rear_turret"),
+        "rotor" => Some("rotor"),
+        "front right" => Some("front_right"),
+        "front left" => Some("front_left"),
+        "rear right" => Some("rear_right"),
+        "rear left" => Some("rear_left"),
         "body" => Some("body"),
+        "head" => Some("head"),
+        "torso" => Some("torso"),
+        "legs" => Some("legs"),
+        "main gun" => Some("main_gun"),
         "left arm" => Some("left_arm"),
         "right arm" => Some("right_arm"),
+        "nose" => Some("nose"),
+        "left wing" => Some("left_wing"),
+        "right wing" => Some("right_wing"),
+        // Spheroid craft call their side arcs "Left Side"/"Right Side"
+        "left side" if is_aero => Some("left_wing"),
+        "right side" if is_aero => Some("right_wing"),
+        "aft" => Some("aft"),
+        "hull" => Some("body"),
+        "front left side" | "fls" => Some("front_left_side"),
+        "front right side" | "frs" => Some("front_right_side"),
+        "aft left side" | "als" => Some("aft_left_side"),
+        "aft right side" | "ars" => Some("aft_right_side"),
+        "left broadside" | "lbs" => Some("left_broadside"),
+        "right broadside" | "rbs" => Some("right_broadside"),
         _ => None,
     }
 }
 
+/// Map the BLK `<armor>` block onto locations. The value order follows
+/// MegaMek's location constants for each unit type (body is never listed).
+fn build_blk_locations(
+    tags: &std::collections::HashMap<String, String>,
+    unit_type: UnitType,
+    blk_unit_type: &str,
+    tonnage: f64,
+) -> Vec<ParsedLocation> {
+    let armor: Vec<i32> = match tags.get("armor") {
+        Some(block) => block
+            .lines()
+            .filter_map(|l| l.trim().parse().ok())
+            .collect(),
+        None => return Vec::new(),
+    };
+
+    if unit_type == UnitType::ProtoMech {
+        let is_quad = proto_config(tags) == "quad";
+        return ["head", "torso", "right_arm", "left_arm", "legs", "main_gun"]
+            .into_iter()
+            .zip(armor)
+            .filter(|&(loc, points)| match loc {
+                "left_arm" | "right_arm" => !is_quad,
+                "main_gun" => points > 0,
+                _ => true,
+            })
+            .map(|(loc, points)| ParsedLocation {
+                location: loc,
+                armor: Some(points),
+                rear_armor: None,
+                structure: Some(proto_structure(tonnage, loc, is_quad)),
+            })
+            .collect();
+    }
+
+    let order: &[&'static str] = match blk_unit_type {
+        "tank" | "naval" | "supporttank" | "wheeled vehicle" | "tracked vehicle" => {
+            if armor.len() >= 6 {
+                &["front", "right_side", "left_side", "rear", "rear_turret", "front_turret"]
+            } else {
+                &["front", "right_side", "left_side", "rear", "turret"]
+            }
+        }
+        "vtol" | "supportvtol" => &["front", "right_side", "left_side", "rear", "rotor", "turret"],
+        "largesupporttank" | "superheavytank" => {
+            if armor.len() >= 8 {
+                &[
+                    "front", "front_right", "front_left", "rear_right", "rear_left", "rear",
+                    "rear_turret", "front_turret",
+                ]
+            } else {
+                &["front", "front_right", "front_left", "rear_right", "rear_left", "rear", "turret"]
+            }
+        }
+        "jumpship" | "warship" | "spacestation" => &[
+            "nose", "front_left_side", "front_right_side", "aft", "aft_left_side", "aft_right_side",
+        ],
+        t if blk_is_aero(t) => &["nose", "left_wing", "right_wing", "aft"],
+        _ => return Vec::new(),
+    };
+
+    // Combat vehicles get 10% of tonnage (rounded up) as internal structure per
+    // location; rotors, support vehicles and aerospace units are left unset.
+    let structure = matches!(blk_unit_type, "tank" | "naval" | "vtol" | "superheavytank")
+        .then(|| (tonnage / 10.0).ceil() as i32);
+
+    order
+        .iter()
+        .zip(armor)
+        .map(|(loc, points)| ParsedLocation {
+            location: loc,
+            armor: Some(points),
+            rear_armor: None,
+            structure: if *loc == "rotor" { None } else { structure },
+        })
+        .collect()
+}
+
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
+/// Trimmed, unquoted fluff text; None when blank.
+fn fluff_text(val: &str) -> Option<String> {
+    let text = val.trim().trim_matches('"').trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// MUL ID from a "mul id:" value; MegaMek writes -1 or 0 when the unit has none.
+fn parse_mul_id(val: &str) -> Option<i32> {
+    val.trim().parse().ok().filter(|&id: &i32| id > 0)
+}
+
+/// Pair comma-separated manufacturers with primary factories by position,
+/// then add "SYSTEM:Name" component manufacturers ("ENGINE:Vlar 300").
+fn parse_manufacturers<'a>(
+    manufacturer: Option<&str>,
+    primary_factory: Option<&str>,
+    systems: impl Iterator<Item = &'a str>,
+) -> Vec<ParsedManufacturer> {
+    let split = |s: Option<&str>| -> Vec<String> {
+        s.map(|v| {
+            v.split(',')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+    };
+    let factories = split(primary_factory);
+    let mut out: Vec<ParsedManufacturer> = split(manufacturer)
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| ParsedManufacturer {
+            name,
+            factory: factories.get(i).cloned(),
+            system: None,
+        })
+        .collect();
+    for line in systems {
+        let Some((system, name)) = line.split_once(':') else { continue };
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        out.push(ParsedManufacturer {
+            name: name.to_string(),
+            factory: None,
+            system: Some(system.trim().to_lowercase().replace(' ', "_")),
+        });
+    }
+    out
+}
+
+/// Weapon-list lines rarely carry the "(omnipod)" flag. On an OmniMech, treat
+/// an entry as pod-mounted when every equipment crit in its location is a pod;
+/// locations mixing fixed and pod crits keep the entry fixed.
+fn mark_pod_locations(loadout: &mut [ParsedLoadoutEntry], slots: &[ParsedCriticalSlot]) {
+    for entry in loadout.iter_mut().filter(|e| !e.is_omnipod) {
+        let Some(loc) = entry.location else { continue };
+        let mut equipment = slots
+            .iter()
+            .filter(|s| s.location == loc && s.content.is_some() && !s.is_structural)
+            .peekable();
+        if equipment.peek().is_some() && equipment.all(|s| s.is_omnipod) {
+            entry.is_omnipod = true;
+        }
+    }
+}
+
 fn dedup_loadout(mut entries: Vec<ParsedLoadoutEntry>) -> Vec<ParsedLoadoutEntry> {
     let mut out: Vec<ParsedLoadoutEntry> = Vec::new();
     for entry in entries.drain(..) {
@@ -648,6 +1993,7 @@ fn dedup_loadout(mut entries: Vec<ParsedLoadoutEntry>) -> Vec<ParsedLoadoutEntry
             e.equipment == entry.equipment
                 && e.location == entry.location
                 && e.is_rear == entry.is_rear
+                && e.is_omnipod == entry.is_omnipod
         }) {
             existing.quantity += entry.quantity;
         } else {
@@ -674,75 +2020,292 @@ pub fn to_slug(s: &str) -> String {
     slug.trim_end_matches('-').to_string()
 }
 
-/// Infer equipment category from its display name.
-pub fn categorize_equipment(name: &str) -> &'static str {
-    let lower = name.to_lowercase();
-    if lower.contains("ammo") || lower.starts_with("is ammo") || lower.starts_with("clan ammo") {
-        return "ammunition";
-    }
-    if lower.contains("heat sink") || lower == "double heat sink" {
-        return "heat_sink";
-    }
-    if lower.contains("jump jet") || lower.contains("improved jump jet") {
-        return "jump_jet";
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BIPED_MTF: &str = "\
+chassis:Test Mech
+model:TST-1A
+Config:Biped
+techbase:Inner Sphere
+era:3025
+rules level:2
+mass:50
+engine:200 Fusion Engine
+heat sinks:10 Single
+walk mp:4
+jump mp:0
+armor:Standard(Inner Sphere)
+LA armor:16
+RA armor:16
+LT armor:12
+RT armor:12
+CT armor:20
+HD armor:9
+LL armor:16
+RL armor:16
+RTL armor:4
+RTR armor:4
+RTC armor:6
+
+Weapons:3
+Medium Laser, Left Arm
+Medium Laser, Right Torso (R)
+LRM 10, Left Torso
+
+Left Arm:
+Shoulder
+Upper Arm Actuator
+Lower Arm Actuator
+Hand Actuator
+Medium Laser
+-Empty-
+
+Right Arm:
+Shoulder
+Upper Arm Actuator
+Lower Arm Actuator
+Hand Actuator
+-Empty-
+-Empty-
+
+Left Torso:
+LRM 10
+LRM 10
+IS Ammo LRM-10
+-Empty-
+
+Right Torso:
+Medium Laser (R)
+Heat Sink
+Heat Sink
+-Empty-
+
+Center Torso:
+Fusion Engine
+Fusion Engine
+Fusion Engine
+Gyro
+Gyro
+Gyro
+Gyro
+Fusion Engine
+Fusion Engine
+Fusion Engine
+-Empty-
+-Empty-
+
+Head:
+Life Support
+Sensors
+Cockpit
+-Empty-
+Sensors
+Life Support
+
+Left Leg:
+Hip
+Upper Leg Actuator
+Lower Leg Actuator
+Foot Actuator
+-Empty-
+-Empty-
+
+Right Leg:
+Hip
+Upper Leg Actuator
+Lower Leg Actuator
+Foot Actuator
+-Empty-
+-Empty-
+";
+
+    fn loadout_of(unit: &ParsedUnit) -> Vec<(&str, Option<&str>, i32, bool, bool)> {
+        unit.loadout
+            .iter()
+            .map(|e| (e.equipment.as_str(), e.location, e.quantity, e.is_rear, e.is_omnipod))
+            .collect()
     }
-    if lower.contains("targeting computer") {
-        return "targeting_computer";
+
+    #[test]
+    fn biped_critical_slots() {
+        let unit = parse_mtf(BIPED_MTF).unwrap();
+        let slots = &unit.critical_slots;
+
+        let per_location = |loc: &str| slots.iter().filter(|s| s.location == loc).count();
+        assert_eq!(per_location("left_arm"), 6);
+        assert_eq!(per_location("center_torso"), 12);
+        assert_eq!(per_location("head"), 6);
+        assert_eq!(slots.len(), 50);
+
+        let slot = |loc: &str, index: i32| {
+            slots.iter().find(|s| s.location == loc && s.slot_index == index).unwrap()
+        };
+        let shoulder = slot("left_arm", 1);
+        assert_eq!(shoulder.content.as_deref(), Some("Shoulder"));
+        assert!(shoulder.is_structural);
+
+        let laser = slot("left_arm", 5);
+        assert_eq!(laser.content.as_deref(), Some("Medium Laser"));
+        assert!(!laser.is_structural && !laser.is_rear);
+
+        let rear_laser = slot("right_torso", 1);
+        assert_eq!(rear_laser.content.as_deref(), Some("Medium Laser"));
+        assert!(rear_laser.is_rear);
+
+        let empty = slot("left_arm", 6);
+        assert_eq!(empty.content, None);
+        assert!(!empty.is_structural);
+
+        assert!(slot("center_torso", 4).is_structural);
+        assert!(slots.iter().all(|s| !s.is_omnipod));
     }
-    if lower.contains("gyro") {
-        return "gyro";
+
+    /// One mount per item: weapons from the weapon list, other equipment from
+    /// its critical slots.
+    #[test]
+    fn biped_loadout() {
+        let unit = parse_mtf(BIPED_MTF).unwrap();
+        assert_eq!(
+            loadout_of(&unit),
+            vec![
+                ("Medium Laser", Some("left_arm"), 1, false, false),
+                ("Medium Laser", Some("right_torso"), 1, true, false),
+                ("LRM 10", Some("left_torso"), 1, false, false),
+                ("IS Ammo LRM-10", Some("left_torso"), 1, false, false),
+                ("Heat Sink", Some("right_torso"), 2, false, false),
+            ]
+        );
     }
-    if lower.contains("cockpit") {
-        return "cockpit";
+
+    #[test]
+    fn multi_slot_equipment_is_one_item() {
+        let right_arm = "Right Arm:\nShoulder\nUpper Arm Actuator\nLower Arm Actuator\nHand Actuator\n";
+        let mtf = BIPED_MTF.replace(
+            &format!("{right_arm}-Empty-\n-Empty-"),
+            &format!(
+                "{right_arm}ISGuardianECMSuite\nISGuardianECMSuite\n{}{}IS Ammo LRM-10\nIS Ammo LRM-10\nHatchet\nHatchet\nHatchet",
+                "ISDoubleHeatSink\n".repeat(6),
+                "CLDoubleHeatSink\n".repeat(2),
+            ),
+        );
+        let unit = parse_mtf(&mtf).unwrap();
+        let right_arm: Vec<_> = loadout_of(&unit)
+            .into_iter()
+            .filter(|e| e.1 == Some("right_arm"))
+            .map(|e| (e.0, e.2))
+            .collect();
+        assert_eq!(
+            right_arm,
+            vec![
+                ("ISGuardianECMSuite", 1),
+                ("ISDoubleHeatSink", 2),
+                ("CLDoubleHeatSink", 1),
+                ("IS Ammo LRM-10", 2),
+                ("Hatchet", 1),
+            ]
+        );
     }
-    if lower.contains("endo steel") || lower.contains("structure") {
-        return "structure";
+
+    #[test]
+    fn rear_flag_on_weapon_list_name() {
+        let mtf = BIPED_MTF.replace("Medium Laser, Right Torso (R)", "Medium Laser (R), Right Torso");
+        let unit = parse_mtf(&mtf).unwrap();
+        assert!(loadout_of(&unit).contains(&("Medium Laser", Some("right_torso"), 1, true, false)));
     }
-    if lower.contains("ferro") || lower.contains("reactive armor") || lower.contains("stealth") {
-        return "armor";
+
+    #[test]
+    fn slots_per_item() {
+        assert_eq!(crit_slots_per_item("IS Ammo AC/20", false), Some(1));
+        assert_eq!(crit_slots_per_item("Heat Sink", true), Some(1));
+        assert_eq!(crit_slots_per_item("ISDoubleHeatSink", false), Some(3));
+        assert_eq!(crit_slots_per_item("CLDoubleHeatSink", false), Some(2));
+        assert_eq!(crit_slots_per_item("Double Heat Sink", true), Some(2));
+        assert_eq!(crit_slots_per_item("Improved Jump Jet", false), Some(2));
+        assert_eq!(crit_slots_per_item("Jump Jet", false), Some(1));
+        assert_eq!(crit_slots_per_item("ISMASC", false), None);
     }
-    if lower.contains("engine") {
-        return "engine";
+
+    #[test]
+    fn sized_equipment_is_a_crit_line() {
+        let mtf = BIPED_MTF.replace(
+            "Right Arm:\nShoulder\nUpper Arm Actuator\nLower Arm Actuator\nHand Actuator\n-Empty-\n-Empty-",
+            "Right Arm:\nShoulder\nUpper Arm Actuator\nLower Arm Actuator\nHand Actuator\nISCargo:SIZE:1.0\nMedium Laser",
+        );
+        let unit = parse_mtf(&mtf).unwrap();
+
+        let right_arm: Vec<_> = unit
+            .critical_slots
+            .iter()
+            .filter(|s| s.location == "right_arm")
+            .map(|s| s.content.as_deref())
+            .collect();
+        assert_eq!(right_arm[4], Some("ISCargo:SIZE:1.0"));
+        assert_eq!(right_arm[5], Some("Medium Laser"));
+        assert!(loadout_of(&unit).contains(&("ISCargo:SIZE:1.0", Some("right_arm"), 1, false, false)));
     }
-    if lower.contains("laser")
-        || lower.contains("ppc")
-        || lower.contains("flamer")
-        || lower.contains("plasma rifle")
-    {
-        return "energy_weapon";
-    }
-    if lower.contains("lrm")
-        || lower.contains("srm")
-        || lower.contains("streak")
-        || lower.contains("narc")
-        || lower.contains("ams")
-        || lower.contains("mml")
-        || lower.contains("atm")
-        || lower.contains("rocket")
-        || lower.contains("arrow")
-        || lower.contains("thunderbolt")
-    {
-        return "missile_weapon";
-    }
-    if lower.contains("autocannon")
-        || lower.contains("ac/")
-        || lower.contains("gauss")
-        || lower.contains("rifle")
-        || lower.contains("lbx")
-        || lower.contains("ultra")
-        || lower.contains("rotary")
-        || lower.contains("hag")
-    {
-        return "ballistic_weapon";
+
+    const FIGHTER_BLK: &str = "\
+<UnitType>
+Aero
+</UnitType>
+<Name>
+Test Fighter
+</Name>
+<Model>
+TF-1
+</Model>
+<year>
+3025
+</year>
+<type>
+IS Level 2
+</type>
+<tonnage>
+50
+</tonnage>
+<armor>
+20
+16
+12
+8
+</armor>
+<Left Wing Equipment>
+Medium Laser
+</Left Wing Equipment>
+<Right Wing Equipment>
+Small Laser
+</Right Wing Equipment>
+";
+
+    /// MegaMek orders aero armor nose, left wing, right wing, aft.
+    #[test]
+    fn fighter_wing_armor() {
+        let unit = parse_blk(FIGHTER_BLK, UnitType::Fighter).unwrap();
+        let armor: Vec<(&str, Option<i32>)> =
+            unit.locations.iter().map(|l| (l.location, l.armor)).collect();
+        assert_eq!(
+            armor,
+            [
+                ("nose", Some(20)),
+                ("left_wing", Some(16)),
+                ("right_wing", Some(12)),
+                ("aft", Some(8)),
+            ]
+        );
+        let loadout = loadout_of(&unit);
+        assert!(loadout.contains(&("Medium Laser", Some("left_wing"), 1, false, false)));
+        assert!(loadout.contains(&("Small Laser", Some("right_wing"), 1, false, false)));
     }
-    "equipment"
-}
 
-/// Infer tech_base of a piece of equipment from its name prefix.
-pub fn equipment_tech_base(name: &str) -> &'static str {
-    if name.starts_with("CL") || name.to_lowercase().starts_with("clan") {
-        "clan"
-    } else {
-        "inner_sphere"
+    #[test]
+    fn mount_flags() {
+        assert_eq!(split_mount_flags("Medium Laser"), ("Medium Laser".to_string(), false, false));
+        assert_eq!(split_mount_flags("Medium Laser (R)"), ("Medium Laser".to_string(), true, false));
+        assert_eq!(
+            split_mount_flags("Medium Laser (R) (omnipod)"),
+            ("Medium Laser".to_string(), true, true)
+        );
     }
 }
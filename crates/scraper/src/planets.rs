@@ -0,0 +1,272 @@
+//! Planets import.
+//!
+//! Reads a local JSON file of planets:
+//!
+//! ```json
+//! [{
+//!   "name": "Hesperus II", "x": -88.3, "y": 182.5,
+//!   "spectral_type": "G4V", "population": 4200000000,
+//!   "socio_industrial": "A-A-C-A-C",
+//!   "description": "...",
+//!   "ownership": [{"year": 2341, "faction": "steiner"}],
+//!   "manufacturers": ["Defiance Industries"]
+//! }]
+//! ```
+//!
+//! `socio_industrial` is the Socio-Industrial Level code (technology,
+//! industry, raw materials, output, agriculture). Ownership factions are
+//! matched by slug, then name or short name ("LC"); `null` marks the planet
+//! unowned from that year. Listed manufacturers become `file` factories;
+//! unit manufacturers whose MegaMek factory names the planet become `fluff`
+//! factories.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+use tracing::{info, warn};
+
+use crate::{
+    parse::to_slug,
+    provenance::{self, RunCounts},
+};
+
+#[derive(Debug, Deserialize)]
+struct PlanetRecord {
+    name: String,
+    x: f64,
+    y: f64,
+    spectral_type: Option<String>,
+    population: Option<i64>,
+    socio_industrial: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    ownership: Vec<OwnershipRecord>,
+    #[serde(default)]
+    manufacturers: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwnershipRecord {
+    year: i32,
+    faction: Option<String>,
+}
+
+/// Socio-Industrial Level ratings in code order.
+type SocioIndustrial = [String; 5];
+
+/// Run the planets-import subcommand.
+pub async fn run(file: &Path, database_url: &str, pool_size: u32) -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(pool_size)
+        .connect(database_url)
+        .await
+        .context("connecting to database")?;
+
+    let version = file.file_name().map(|n| n.to_string_lossy().into_owned());
+    let run_id = provenance::start_run(&pool, "planets", version.as_deref()).await?;
+    let result = import(&pool, run_id, file).await;
+    provenance::finish_run(&pool, run_id, &result).await?;
+    result.map(|_| ())
+}
+
+async fn import(pool: &PgPool, run_id: i32, file: &Path) -> anyhow::Result<RunCounts> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("reading {}", file.display()))?;
+    let records: Vec<PlanetRecord> =
+        serde_json::from_str(&content).context("parsing planets JSON")?;
+    info!(count = records.len(), "loaded planet records");
+
+    let factions = load_factions(pool).await?;
+    let mut counts = RunCounts {
+        processed: records.len(),
+        ..RunCounts::default()
+    };
+
+    for record in &records {
+        match import_planet(pool, run_id, record, &factions).await {
+            Ok(()) => counts.imported += 1,
+            Err(e) => {
+                warn!(planet = %record.name, error = %e, "skipping planet");
+                counts.errors.push(format!("{}: {e:#}", record.name));
+                counts.skipped += 1;
+            }
+        }
+    }
+
+    let fluff = link_fluff_factories(pool).await?;
+    info!(
+        imported = counts.imported,
+        skipped = counts.skipped,
+        fluff_factories = fluff,
+        "planets import finished"
+    );
+
+    Ok(counts)
+}
+
+async fn import_planet(
+    pool: &PgPool,
+    run_id: i32,
+    record: &PlanetRecord,
+    factions: &HashMap<String, i32>,
+) -> anyhow::Result<()> {
+    let slug = to_slug(&record.name);
+    if slug.is_empty() {
+        bail!("planet name has no usable characters");
+    }
+    let sil = record
+        .socio_industrial
+        .as_deref()
+        .map(parse_socio_industrial)
+        .transpose()?;
+
+    // Resolve every owner before writing anything
+    let mut ownership = Vec::with_capacity(record.ownership.len());
+    for o in &record.ownership {
+        let faction_id = match o.faction.as_deref() {
+            Some(f) => Some(
+                *factions
+                    .get(&f.to_lowercase())
+                    .or_else(|| factions.get(&to_slug(f)))
+                    .with_context(|| format!("unknown faction '{f}' in {}", o.year))?,
+            ),
+            None => None,
+        };
+        ownership.push((o.year, faction_id));
+    }
+    ownership.sort_by_key(|(year, _)| *year);
+    ownership.dedup_by_key(|(year, _)| *year);
+
+    let mut tx = pool.begin().await?;
+
+    let row = sqlx::query(
+        r#"INSERT INTO planets (slug, name, x, y, spectral_type, population,
+                                technology, industry, raw_materials, output, agriculture,
+                                description, last_import_run_id)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+           ON CONFLICT (slug) DO UPDATE SET
+               name = EXCLUDED.name,
+               x = EXCLUDED.x,
+               y = EXCLUDED.y,
+               spectral_type = EXCLUDED.spectral_type,
+               population = EXCLUDED.population,
+               technology = EXCLUDED.technology,
+               industry = EXCLUDED.industry,
+               raw_materials = EXCLUDED.raw_materials,
+               output = EXCLUDED.output,
+               agriculture = EXCLUDED.agriculture,
+               description = EXCLUDED.description,
+               last_import_run_id = EXCLUDED.last_import_run_id
+           RETURNING id"#,
+    )
+    .bind(&slug)
+    .bind(&record.name)
+    .bind(record.x)
+    .bind(record.y)
+    .bind(&record.spectral_type)
+    .bind(record.population)
+    .bind(sil.as_ref().map(|s| &s[0]))
+    .bind(sil.as_ref().map(|s| &s[1]))
+    .bind(sil.as_ref().map(|s| &s[2]))
+    .bind(sil.as_ref().map(|s| &s[3]))
+    .bind(sil.as_ref().map(|s| &s[4]))
+    .bind(&record.description)
+    .bind(run_id)
+    .fetch_one(&mut *tx)
+    .await?;
+    let planet_id: i32 = row.try_get("id")?;
+
+    sqlx::query("DELETE FROM planet_ownership WHERE planet_id = $1")
+        .bind(planet_id)
+        .execute(&mut *tx)
+        .await?;
+    for (i, &(start_year, faction_id)) in ownership.iter().enumerate() {
+        let end_year = ownership.get(i + 1).map(|(next, _)| next - 1);
+        sqlx::query(
+            r#"INSERT INTO planet_ownership (planet_id, faction_id, start_year, end_year)
+               VALUES ($1, $2, $3, $4)"#,
+        )
+        .bind(planet_id)
+        .bind(faction_id)
+        .bind(start_year)
+        .bind(end_year)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query("DELETE FROM planet_factories WHERE planet_id = $1 AND source = 'file'")
+        .bind(planet_id)
+        .execute(&mut *tx)
+        .await?;
+    for name in &record.manufacturers {
+        let manufacturer_id: i32 = sqlx::query_scalar(
+            r#"INSERT INTO manufacturers (slug, name) VALUES ($1, $2)
+               ON CONFLICT (slug) DO UPDATE SET name = manufacturers.name
+               RETURNING id"#,
+        )
+        .bind(to_slug(name))
+        .bind(name)
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query(
+            r#"INSERT INTO planet_factories (planet_id, manufacturer_id, source)
+               VALUES ($1, $2, 'file')
+               ON CONFLICT (planet_id, manufacturer_id) DO UPDATE SET source = 'file'"#,
+        )
+        .bind(planet_id)
+        .bind(manufacturer_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Faction lookup by slug, lowercase name and lowercase short name.
+async fn load_factions(pool: &PgPool) -> anyhow::Result<HashMap<String, i32>> {
+    let rows = sqlx::query("SELECT id, slug, name, short_name FROM factions")
+        .fetch_all(pool)
+        .await?;
+    let mut map = HashMap::new();
+    for r in rows {
+        let id: i32 = r.try_get("id")?;
+        if let Some(short) = r.try_get::<Option<String>, _>("short_name")? {
+            map.entry(short.to_lowercase()).or_insert(id);
+        }
+        map.insert(r.try_get::<String, _>("name")?.to_lowercase(), id);
+        map.insert(r.try_get("slug")?, id);
+    }
+    Ok(map)
+}
+
+/// Rebuild `fluff` factories from unit manufacturers whose factory names a planet.
+async fn link_fluff_factories(pool: &PgPool) -> anyhow::Result<u64> {
+    sqlx::query("DELETE FROM planet_factories WHERE source = 'fluff'")
+        .execute(pool)
+        .await?;
+    let result = sqlx::query(
+        r#"INSERT INTO planet_factories (planet_id, manufacturer_id, source)
+           SELECT DISTINCT p.id, um.manufacturer_id, 'fluff'
+           FROM unit_manufacturers um
+           JOIN planets p ON lower(p.name) = lower(trim(um.factory))
+           WHERE um.system IS NULL
+           ON CONFLICT (planet_id, manufacturer_id) DO NOTHING"#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Split a Socio-Industrial Level code ("A-B-C-D-F") into its five ratings.
+fn parse_socio_industrial(code: &str) -> anyhow::Result<SocioIndustrial> {
+    let ratings: Vec<String> = code.split('-').map(|r| r.trim().to_uppercase()).collect();
+    match <SocioIndustrial>::try_from(ratings) {
+        Ok(ratings) if ratings.iter().all(|r| ["A", "B", "C", "D", "F"].contains(&r.as_str())) => {
+            Ok(ratings)
+        }
+        _ => bail!("invalid socio-industrial code '{code}', expected five of A-D/F like \"A-B-C-D-F\""),
+    }
+}
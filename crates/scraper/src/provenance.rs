@@ -0,0 +1,75 @@
+//! Import run bookkeeping. Each importer opens an `import_runs` row when it
+//! starts and closes it with its counts when it finishes or fails; the rows
+//! it writes carry the run id in `last_import_run_id`.
+
+use sqlx::{PgPool, Row};
+use tracing::info;
+
+/// Error messages kept per run; `error_count` still counts every error.
+const MAX_ERROR_MESSAGES: usize = 100;
+
+/// What an import run read and wrote.
+#[derive(Debug, Default)]
+pub struct RunCounts {
+    pub processed: usize,
+    pub imported: usize,
+    pub skipped: usize,
+    pub errors: Vec<String>,
+}
+
+/// Record the start of an import run and return its id. `source` is one of
+/// "megamek", "mul", "equipment_seed", "forcegen" or "planets".
+pub async fn start_run(pool: &PgPool, source: &str, version: Option<&str>) -> anyhow::Result<i32> {
+    let row = sqlx::query("INSERT INTO import_runs (source, version) VALUES ($1, $2) RETURNING id")
+        .bind(source)
+        .bind(version)
+        .fetch_one(pool)
+        .await?;
+    let id: i32 = row.try_get("id")?;
+    info!(run = id, source, "import run started");
+    Ok(id)
+}
+
+/// Close an import run with the importer's outcome. A failed import is
+/// recorded with its error as the last message.
+pub async fn finish_run(
+    pool: &PgPool,
+    run_id: i32,
+    result: &anyhow::Result<RunCounts>,
+) -> anyhow::Result<()> {
+    let (status, counts, fatal) = match result {
+        Ok(counts) => ("succeeded", counts, None),
+        Err(e) => ("failed", &RunCounts::default(), Some(format!("{e:#}"))),
+    };
+    let error_count = counts.errors.len() + usize::from(fatal.is_some());
+    let messages: Vec<&str> = counts
+        .errors
+        .iter()
+        .map(String::as_str)
+        .chain(fatal.as_deref())
+        .take(MAX_ERROR_MESSAGES)
+        .collect();
+
+    sqlx::query(
+        r#"UPDATE import_runs SET
+               status = $2,
+               finished_at = now(),
+               processed = $3,
+               imported = $4,
+               skipped = $5,
+               error_count = $6,
+               error_messages = $7
+           WHERE id = $1"#,
+    )
+    .bind(run_id)
+    .bind(status)
+    .bind(counts.processed as i32)
+    .bind(counts.imported as i32)
+    .bind(counts.skipped as i32)
+    .bind(error_count as i32)
+    .bind(&messages)
+    .execute(pool)
+    .await?;
+    info!(run = run_id, status, "import run finished");
+    Ok(())
+}
@@ -97,6 +97,7 @@ pub const FACTIONS: &[Faction] = &[
     Faction { slug: "kurita", name: "Draconis Combine", short_name: Some("DC"), faction_type: "great_house", is_clan: false },
     Faction { slug: "marik", name: "Free Worlds League", short_name: Some("FWL"), faction_type: "great_house", is_clan: false },
     Faction { slug: "liao", name: "Capellan Confederation", short_name: Some("CC"), faction_type: "great_house", is_clan: false },
+    Faction { slug: "federated-commonwealth", name: "Federated Commonwealth", short_name: Some("FC"), faction_type: "great_house", is_clan: false },
     // Star League / Successors
     Faction { slug: "star-league", name: "Star League", short_name: Some("SL"), faction_type: "star_league", is_clan: false },
     Faction { slug: "comstar", name: "ComStar", short_name: Some("CS"), faction_type: "independent", is_clan: false },
@@ -129,6 +130,153 @@ pub const FACTIONS: &[Faction] = &[
     // Mercenaries / General
     Faction { slug: "mercenary", name: "Mercenary", short_name: Some("MER"), faction_type: "mercenary", is_clan: false },
     Faction { slug: "general", name: "General (All)", short_name: Some("GEN"), faction_type: "general", is_clan: false },
+    Faction { slug: "clan-general", name: "Clan General", short_name: Some("CG"), faction_type: "general", is_clan: true },
+    Faction { slug: "is-clan-general", name: "IS Clan General", short_name: Some("ISCG"), faction_type: "general", is_clan: false },
+    Faction { slug: "hw-clan-general", name: "HW Clan General", short_name: Some("HWCG"), faction_type: "general", is_clan: false },
+];
+
+/// A link between two factions. `parent` years bound when `faction` sat
+/// inside `related`; a `successor` takes over from `faction` in `start_year`.
+pub struct FactionRelation {
+    pub faction: &'static str,
+    pub related: &'static str,
+    pub relation: &'static str,
+    pub start_year: Option<i32>,
+    pub end_year: Option<i32>,
+}
+
+const fn parent(
+    faction: &'static str,
+    related: &'static str,
+    start_year: Option<i32>,
+    end_year: Option<i32>,
+) -> FactionRelation {
+    FactionRelation { faction, related, relation: "parent", start_year, end_year }
+}
+
+const fn successor(faction: &'static str, related: &'static str, year: i32) -> FactionRelation {
+    FactionRelation { faction, related, relation: "successor", start_year: Some(year), end_year: None }
+}
+
+/// Relations whose factions don't exist yet (MUL creates some of them) are
+/// skipped until the next seed.
+pub const FACTION_RELATIONS: &[FactionRelation] = &[
+    // Inner Sphere roll up to "General (All)", the MUL "Inner Sphere General"
+    parent("steiner", "general", None, None),
+    parent("davion", "general", None, None),
+    parent("kurita", "general", None, None),
+    parent("marik", "general", None, None),
+    parent("liao", "general", None, None),
+    parent("federated-commonwealth", "general", None, None),
+    parent("star-league", "general", None, None),
+    parent("comstar", "general", None, None),
+    parent("word-of-blake", "general", None, None),
+    parent("republic", "general", None, None),
+    parent("mercenary", "general", None, None),
+    parent("periphery-general", "general", None, None),
+    // Clans roll up to "Clan General" through the Homeworld and Inner Sphere Toumans
+    parent("is-clan-general", "clan-general", None, None),
+    parent("hw-clan-general", "clan-general", None, None),
+    // Federated Commonwealth
+    parent("steiner", "federated-commonwealth", Some(3028), Some(3056)),
+    parent("davion", "federated-commonwealth", Some(3028), Some(3067)),
+    successor("federated-commonwealth", "steiner", 3057),
+    successor("federated-commonwealth", "davion", 3067),
+    // Periphery
+    parent("taurian-concordat", "periphery-general", None, None),
+    parent("magistracy-canopus", "periphery-general", None, None),
+    parent("outworlds-alliance", "periphery-general", None, None),
+    parent("marian-hegemony", "periphery-general", None, None),
+    parent("raven-alliance", "periphery-general", Some(3083), None),
+    successor("outworlds-alliance", "raven-alliance", 3083),
+    successor("clan-snow-raven", "raven-alliance", 3083),
+    // Invading Clans move from the Homeworlds to the Inner Sphere Touman
+    parent("clan-wolf", "hw-clan-general", None, Some(3049)),
+    parent("clan-wolf", "is-clan-general", Some(3050), None),
+    parent("clan-jade-falcon", "hw-clan-general", None, Some(3049)),
+    parent("clan-jade-falcon", "is-clan-general", Some(3050), None),
+    parent("clan-ghost-bear", "hw-clan-general", None, Some(3049)),
+    parent("clan-ghost-bear", "is-clan-general", Some(3050), None),
+    parent("clan-smoke-jaguar", "hw-clan-general", None, Some(3049)),
+    parent("clan-smoke-jaguar", "is-clan-general", Some(3050), Some(3060)),
+    parent("clan-nova-cat", "hw-clan-general", None, Some(3049)),
+    parent("clan-nova-cat", "is-clan-general", Some(3050), None),
+    // Homeworld Clans
+    parent("clan-steel-viper", "hw-clan-general", None, None),
+    parent("clan-diamond-shark", "hw-clan-general", None, None),
+    parent("clan-goliath-scorpion", "hw-clan-general", None, None),
+    parent("clan-ice-hellion", "hw-clan-general", None, None),
+    parent("clan-star-adder", "hw-clan-general", None, None),
+    parent("clan-hell-horses", "hw-clan-general", None, None),
+    parent("clan-blood-spirit", "hw-clan-general", None, None),
+    parent("clan-coyote", "hw-clan-general", None, None),
+    parent("clan-fire-mandrill", "hw-clan-general", None, None),
+    parent("clan-cloud-cobra", "hw-clan-general", None, None),
+    parent("clan-snow-raven", "hw-clan-general", None, None),
+    parent("clan-burrock", "hw-clan-general", None, None),
+    parent("clan-mongoose", "hw-clan-general", None, Some(2868)),
+    parent("clan-widowmaker", "hw-clan-general", None, Some(2834)),
+    parent("clan-wolverine", "hw-clan-general", None, None),
+    // Absorptions and mergers
+    successor("star-league", "star-league-in-exile", 2784),
+    successor("clan-widowmaker", "clan-wolf", 2834),
+    successor("clan-mongoose", "clan-smoke-jaguar", 2868),
+    successor("clan-burrock", "clan-star-adder", 3059),
+    successor("free-rasalhague-republic", "clan-ghost-bear", 3103),
+];
+
+/// A name a faction went by over a span of years.
+pub struct FactionName {
+    pub faction: &'static str,
+    pub name: &'static str,
+    pub short_name: Option<&'static str>,
+    pub start_year: Option<i32>,
+    pub end_year: Option<i32>,
+}
+
+pub const FACTION_NAMES: &[FactionName] = &[
+    FactionName { faction: "steiner", name: "Lyran Commonwealth", short_name: Some("LC"), start_year: None, end_year: Some(3056) },
+    FactionName { faction: "steiner", name: "Lyran Alliance", short_name: Some("LA"), start_year: Some(3057), end_year: Some(3084) },
+    FactionName { faction: "steiner", name: "Lyran Commonwealth", short_name: Some("LC"), start_year: Some(3085), end_year: None },
+    FactionName { faction: "federated-commonwealth", name: "Federated Commonwealth", short_name: Some("FC"), start_year: Some(3028), end_year: Some(3067) },
+    FactionName { faction: "clan-ghost-bear", name: "Clan Ghost Bear", short_name: Some("CGB"), start_year: None, end_year: Some(3102) },
+    FactionName { faction: "clan-ghost-bear", name: "Rasalhague Dominion", short_name: Some("RD"), start_year: Some(3103), end_year: None },
+    FactionName { faction: "clan-diamond-shark", name: "Clan Diamond Shark", short_name: Some("CDS"), start_year: None, end_year: Some(3099) },
+    FactionName { faction: "clan-diamond-shark", name: "Clan Sea Fox", short_name: Some("CSF"), start_year: Some(3100), end_year: None },
+];
+
+/// A Battle Value 2.0 multiplier on a construction type table (TechManual).
+pub struct BvMultiplier {
+    pub table: &'static str,
+    pub slug: &'static str,
+    pub multiplier: f64,
+}
+
+/// The multipliers the BV migrations set. The seed dump reloads the
+/// construction type tables without them, so imports set them again.
+pub const BV_MULTIPLIERS: &[BvMultiplier] = &[
+    // Engine: applied to internal structure BV
+    BvMultiplier { table: "engine_types", slug: "xl-is", multiplier: 0.5 },
+    BvMultiplier { table: "engine_types", slug: "xl-clan", multiplier: 0.75 },
+    BvMultiplier { table: "engine_types", slug: "light", multiplier: 0.75 },
+    BvMultiplier { table: "engine_types", slug: "xxl-is", multiplier: 0.25 },
+    BvMultiplier { table: "engine_types", slug: "xxl-clan", multiplier: 0.5 },
+    // Armor: applied to armor BV
+    BvMultiplier { table: "armor_types", slug: "hardened", multiplier: 2.0 },
+    BvMultiplier { table: "armor_types", slug: "reactive", multiplier: 1.5 },
+    BvMultiplier { table: "armor_types", slug: "reflective-is", multiplier: 1.5 },
+    BvMultiplier { table: "armor_types", slug: "reflective-clan", multiplier: 1.5 },
+    BvMultiplier { table: "armor_types", slug: "ferro-lamellor", multiplier: 1.2 },
+    // Structure: applied to internal structure BV
+    BvMultiplier { table: "structure_types", slug: "composite", multiplier: 0.5 },
+    BvMultiplier { table: "structure_types", slug: "reinforced", multiplier: 2.0 },
+    BvMultiplier { table: "structure_types", slug: "reinforced-clan", multiplier: 2.0 },
+    // Gyro: BV per ton of mech
+    BvMultiplier { table: "gyro_types", slug: "heavy-duty", multiplier: 1.0 },
+    // Cockpit: applied to final BV
+    BvMultiplier { table: "cockpit_types", slug: "small", multiplier: 0.95 },
+    BvMultiplier { table: "cockpit_types", slug: "torso-mounted", multiplier: 0.95 },
+    BvMultiplier { table: "cockpit_types", slug: "industrial", multiplier: 0.9 },
 ];
 
 pub async fn seed_eras(pool: &PgPool) -> anyhow::Result<usize> {
@@ -171,6 +319,79 @@ pub async fn seed_factions(pool: &PgPool) -> anyhow::Result<usize> {
     Ok(count)
 }
 
+/// Replace faction relations and names with the seeded ones. Returns the
+/// relation and name rows written.
+pub async fn seed_faction_relations(pool: &PgPool) -> anyhow::Result<(usize, usize)> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM faction_relations").execute(&mut *tx).await?;
+    sqlx::query("DELETE FROM faction_names").execute(&mut *tx).await?;
+
+    let mut relations = 0usize;
+    for r in FACTION_RELATIONS {
+        let rows = sqlx::query(
+            r#"INSERT INTO faction_relations (faction_id, related_faction_id, relation, start_year, end_year)
+               SELECT f.id, rf.id, $3, $4, $5
+               FROM factions f, factions rf
+               WHERE f.slug = $1 AND rf.slug = $2"#,
+        )
+        .bind(r.faction)
+        .bind(r.related)
+        .bind(r.relation)
+        .bind(r.start_year)
+        .bind(r.end_year)
+        .execute(&mut *tx)
+        .await?;
+        relations += rows.rows_affected() as usize;
+    }
+
+    let mut names = 0usize;
+    for n in FACTION_NAMES {
+        let rows = sqlx::query(
+            r#"INSERT INTO faction_names (faction_id, name, short_name, start_year, end_year)
+               SELECT id, $2, $3, $4, $5 FROM factions WHERE slug = $1"#,
+        )
+        .bind(n.faction)
+        .bind(n.name)
+        .bind(n.short_name)
+        .bind(n.start_year)
+        .bind(n.end_year)
+        .execute(&mut *tx)
+        .await?;
+        names += rows.rows_affected() as usize;
+    }
+
+    tx.commit().await?;
+    Ok((relations, names))
+}
+
+/// Set the BV multipliers on the construction type tables, and the TSM weight
+/// multiplier on its myomer properties. Returns the rows updated.
+pub async fn seed_bv_multipliers(pool: &PgPool) -> anyhow::Result<usize> {
+    let mut tx = pool.begin().await?;
+    let mut count = 0usize;
+    for m in BV_MULTIPLIERS {
+        let rows = sqlx::query(&format!(
+            "UPDATE {} SET bv_multiplier = $2 WHERE slug = $1",
+            m.table
+        ))
+        .bind(m.slug)
+        .bind(m.multiplier)
+        .execute(&mut *tx)
+        .await?;
+        count += rows.rows_affected() as usize;
+    }
+    let rows = sqlx::query(
+        r#"UPDATE myomer_types
+           SET properties = properties || '{"bv_weight_multiplier": 1.5}'::jsonb
+           WHERE slug = 'tsm'"#,
+    )
+    .execute(&mut *tx)
+    .await?;
+    count += rows.rows_affected() as usize;
+    tx.commit().await?;
+    Ok(count)
+}
+
 pub async fn seed_metadata(pool: &PgPool, version: &str) -> anyhow::Result<()> {
     sqlx::query("DELETE FROM dataset_metadata WHERE version = $1")
         .bind(version)
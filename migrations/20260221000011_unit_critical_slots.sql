@@ -0,0 +1,27 @@
+-- ============================================================================
+-- Per-slot critical hit tables for mechs.
+-- One row per critical slot in MTF order, including empty and structural slots,
+-- so record sheets and crit-hit rolls can be reproduced exactly.
+-- ============================================================================
+
+-- Quad and tripod leg locations (MTF "Front Left Leg:", "Center Leg:", etc.)
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_left_leg';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_right_leg';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'rear_left_leg';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'rear_right_leg';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'center_leg';
+
+CREATE TABLE unit_critical_slots (
+    id              SERIAL PRIMARY KEY,
+    unit_id         INTEGER NOT NULL REFERENCES units (id) ON DELETE CASCADE,
+    location        location_name_enum NOT NULL,
+    slot_index      SMALLINT NOT NULL CHECK (slot_index > 0),  -- 1-based, as printed on record sheets
+    content         TEXT,           -- MegaMek component name; NULL = empty slot
+    equipment_id    INTEGER REFERENCES equipment (id) ON DELETE SET NULL,  -- NULL for empty/structural slots
+    is_rear         BOOLEAN NOT NULL DEFAULT FALSE,
+    is_omnipod      BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (unit_id, location, slot_index)
+);
+
+CREATE INDEX idx_unit_crit_slots_unit      ON unit_critical_slots (unit_id);
+CREATE INDEX idx_unit_crit_slots_equipment ON unit_critical_slots (equipment_id) WHERE equipment_id IS NOT NULL;
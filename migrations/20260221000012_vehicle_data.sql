@@ -0,0 +1,18 @@
+-- ============================================================================
+-- Vehicle-specific data parsed from MegaMek BLK files (tanks, VTOLs, naval,
+-- support vehicles). Mirrors unit_mech_data for combat vehicles.
+-- ============================================================================
+
+CREATE TABLE unit_vehicle_data (
+    unit_id         INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    motion_type     TEXT NOT NULL,  -- "tracked", "wheeled", "hover", "vtol", "naval", "wige", ...
+    cruise_mp       INTEGER,
+    engine_type     TEXT,           -- "Fusion", "ICE", "XL", "Fuel Cell", etc.
+    armor_type      TEXT,           -- "Standard", "Ferro-Fibrous", etc.
+    structure_type  TEXT,           -- "Standard", "Endo Steel", etc.
+    turret_type     TEXT NOT NULL DEFAULT 'none',  -- "none", "single", "dual"
+    fuel            NUMERIC(10,2)   -- fuel capacity where specified (support vehicles)
+);
+
+CREATE INDEX idx_unit_vehicle_data_motion ON unit_vehicle_data (motion_type);
+CREATE INDEX idx_unit_vehicle_data_turret ON unit_vehicle_data (turret_type);
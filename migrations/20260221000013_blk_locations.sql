@@ -0,0 +1,27 @@
+-- ============================================================================
+-- Location values for BLK armor blocks: vehicle rotors and dual turrets,
+-- super-heavy/large support tank quarters, aerospace arcs and large-craft arcs.
+-- ============================================================================
+
+-- Vehicles
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'rotor';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_turret';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'rear_turret';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_right';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_left';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'rear_right';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'rear_left';
+
+-- Aerospace fighters, small craft, DropShips
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'nose';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'left_wing';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'right_wing';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'aft';
+
+-- JumpShips, WarShips, space stations
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_left_side';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'front_right_side';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'aft_left_side';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'aft_right_side';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'left_broadside';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'right_broadside';
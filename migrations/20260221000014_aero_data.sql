@@ -0,0 +1,35 @@
+-- ============================================================================
+-- Aerospace data parsed from MegaMek BLK files (aerospace and conventional
+-- fighters, small craft, DropShips). Mirrors unit_mech_data / unit_vehicle_data.
+-- ============================================================================
+
+CREATE TABLE unit_aero_data (
+    unit_id              INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    safe_thrust          INTEGER,
+    structural_integrity INTEGER,
+    fuel_points          INTEGER,
+    heat_sink_count      INTEGER,
+    heat_sink_type       TEXT,           -- "Single", "Double"
+    cockpit_type         TEXT,           -- "Standard", "Small", "Command Console", "Primitive"
+    engine_type          TEXT,           -- "Fusion", "XL", "ICE", etc.
+    armor_type           TEXT            -- "Standard", "Ferro-Aluminum", etc.
+);
+
+CREATE INDEX idx_unit_aero_data_thrust ON unit_aero_data (safe_thrust);
+CREATE INDEX idx_unit_aero_data_si     ON unit_aero_data (structural_integrity);
+
+-- ── Weapon bays ─────────────────────────────────────────────────────────────
+-- One row per weapon type per bay. Large craft declare bays in their BLK files
+-- ("(B)" marker); fighters have none, so each weapon type in an arc is its own group.
+
+CREATE TABLE unit_aero_weapon_bays (
+    id              SERIAL PRIMARY KEY,
+    unit_id         INTEGER NOT NULL REFERENCES units (id) ON DELETE CASCADE,
+    location        location_name_enum NOT NULL,
+    bay_number      SMALLINT NOT NULL CHECK (bay_number > 0),  -- 1-based within the arc
+    equipment_id    INTEGER NOT NULL REFERENCES equipment (id),
+    quantity        INTEGER NOT NULL DEFAULT 1,
+    UNIQUE (unit_id, location, bay_number, equipment_id)
+);
+
+CREATE INDEX idx_unit_aero_bays_unit ON unit_aero_weapon_bays (unit_id);
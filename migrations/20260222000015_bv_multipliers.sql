@@ -0,0 +1,62 @@
+-- ============================================================================
+-- Battle Value 2.0 modifiers on construction reference tables
+-- (TechManual BV calculation), used by the calculateBattleValue query.
+-- ============================================================================
+
+-- ── Engine: multiplier applied to internal structure BV ─────────────────────
+
+ALTER TABLE engine_types ADD COLUMN bv_multiplier NUMERIC(4,3) NOT NULL DEFAULT 1.000;
+
+UPDATE engine_types SET bv_multiplier = CASE slug
+  WHEN 'xl-is'   THEN 0.500
+  WHEN 'xl-clan' THEN 0.750
+  WHEN 'light'   THEN 0.750
+  WHEN 'xxl-is'  THEN 0.250
+  ELSE 1.000
+END;
+
+-- ── Armor: multiplier applied to armor BV ───────────────────────────────────
+
+ALTER TABLE armor_types ADD COLUMN bv_multiplier NUMERIC(4,3) NOT NULL DEFAULT 1.000;
+
+UPDATE armor_types SET bv_multiplier = CASE slug
+  WHEN 'hardened' THEN 2.000
+  WHEN 'reactive' THEN 1.500
+  ELSE 1.000
+END;
+
+-- ── Structure: multiplier applied to internal structure BV ──────────────────
+
+ALTER TABLE structure_types ADD COLUMN bv_multiplier NUMERIC(4,3) NOT NULL DEFAULT 1.000;
+
+UPDATE structure_types SET bv_multiplier = CASE slug
+  WHEN 'composite'  THEN 0.500
+  WHEN 'reinforced' THEN 2.000
+  ELSE 1.000
+END;
+
+-- ── Gyro: BV per ton of mech ────────────────────────────────────────────────
+
+ALTER TABLE gyro_types ADD COLUMN bv_multiplier NUMERIC(4,3) NOT NULL DEFAULT 0.500;
+
+UPDATE gyro_types SET bv_multiplier = CASE slug
+  WHEN 'heavy-duty' THEN 1.000
+  ELSE 0.500
+END;
+
+-- ── Cockpit: multiplier applied to final BV ─────────────────────────────────
+
+ALTER TABLE cockpit_types ADD COLUMN bv_multiplier NUMERIC(4,3) NOT NULL DEFAULT 1.000;
+
+UPDATE cockpit_types SET bv_multiplier = CASE slug
+  WHEN 'small'         THEN 0.950
+  WHEN 'torso-mounted' THEN 0.950
+  WHEN 'industrial'    THEN 0.900
+  ELSE 1.000
+END;
+
+-- ── Myomer: tonnage multiplier for offensive BV lives in properties ─────────
+
+UPDATE myomer_types
+SET properties = properties || '{"bv_weight_multiplier": 1.5}'::jsonb
+WHERE slug = 'tsm';
@@ -0,0 +1,33 @@
+-- ============================================================================
+-- Alpha Strike stats per unit. Sourced from the MUL QuickList (source = 'mul')
+-- or, for units MUL does not cover, converted from construction data by the
+-- scraper's alpha-strike-convert command (source = 'conversion').
+-- ============================================================================
+
+CREATE TABLE unit_alpha_strike (
+    unit_id          INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    as_type          TEXT,           -- "BM", "CV", "AF", "BA", "CI", "PM", "DS", ...
+    size             SMALLINT,
+    movement         TEXT,           -- as printed on the card, e.g. "10\"j", "8\"/6\"j"
+    move_inches      SMALLINT,       -- primary (ground/thrust) movement
+    jump_inches      SMALLINT,
+    tmm              SMALLINT,
+    armor            SMALLINT,
+    structure        SMALLINT,
+    threshold        SMALLINT,       -- aerospace only
+    damage_short     SMALLINT,
+    damage_short_min BOOLEAN NOT NULL DEFAULT FALSE,   -- "0*" minimal damage
+    damage_medium    SMALLINT,
+    damage_medium_min BOOLEAN NOT NULL DEFAULT FALSE,
+    damage_long      SMALLINT,
+    damage_long_min  BOOLEAN NOT NULL DEFAULT FALSE,
+    damage_extreme   SMALLINT,       -- aerospace only
+    damage_extreme_min BOOLEAN NOT NULL DEFAULT FALSE,
+    overheat         SMALLINT NOT NULL DEFAULT 0,
+    point_value      INTEGER,
+    specials         TEXT[] NOT NULL DEFAULT '{}',     -- e.g. {CASE, IF1, LRM1/1/1}
+    source           TEXT NOT NULL CHECK (source IN ('mul', 'conversion'))
+);
+
+CREATE INDEX idx_unit_alpha_strike_pv       ON unit_alpha_strike (point_value);
+CREATE INDEX idx_unit_alpha_strike_specials ON unit_alpha_strike USING GIN (specials);
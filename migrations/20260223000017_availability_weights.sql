@@ -0,0 +1,11 @@
+-- ============================================================================
+-- Weighted availability from the MegaMek force generator (RAT) data.
+-- availability_code holds the effective availability rating (0 = very rare,
+-- 10 = very common); weight is the relative frequency used for roll tables,
+-- 2^(rating / 2) scaled by the variant's share of its chassis.
+-- ============================================================================
+
+ALTER TABLE unit_availability ADD COLUMN weight DOUBLE PRECISION;
+
+CREATE INDEX idx_unit_avail_weighted ON unit_availability (faction_id, era_id)
+    WHERE weight > 0;
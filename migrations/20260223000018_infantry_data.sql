@@ -0,0 +1,32 @@
+-- ============================================================================
+-- Battle armor and conventional infantry data parsed from MegaMek BLK files.
+-- Mirrors unit_vehicle_data / unit_aero_data.
+-- ============================================================================
+
+CREATE TABLE unit_infantry_data (
+    unit_id           INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    infantry_kind     TEXT NOT NULL,     -- "battle_armor", "conventional"
+    trooper_count     INTEGER NOT NULL,  -- per squad/point (BA) or per platoon (conventional)
+    squad_size        INTEGER,           -- conventional: troopers per squad
+    squad_count       INTEGER,           -- conventional: squads per platoon
+    weight_class      TEXT,              -- BA: "PA(L)", "Light", "Medium", "Heavy", "Assault"
+    chassis_type      TEXT,              -- BA: "biped", "quad"
+    motive_type       TEXT NOT NULL,     -- "leg", "jump", "vtol", "umu", "motorized", "wheeled", ...
+    ground_mp         INTEGER,
+    jump_mp           INTEGER,
+    umu_mp            INTEGER,
+    vtol_mp           INTEGER,
+    armor_per_trooper INTEGER,           -- BA only
+    armor_type        TEXT,              -- BA: "BA Standard", "BA Stealth", ...
+    left_manipulator  TEXT,              -- BA: "Battle Claw", "Basic Manipulator", ...
+    right_manipulator TEXT,
+    is_anti_mech      BOOLEAN NOT NULL DEFAULT FALSE,  -- can make leg/swarm attacks
+    primary_weapon    TEXT,              -- conventional: MegaMek weapon name
+    secondary_weapon  TEXT,
+    secondary_count   INTEGER,           -- conventional: secondary weapons per squad
+    armor_kit         TEXT               -- conventional: armor kit name
+);
+
+CREATE INDEX idx_unit_infantry_data_kind     ON unit_infantry_data (infantry_kind);
+CREATE INDEX idx_unit_infantry_data_troopers ON unit_infantry_data (trooper_count);
+CREATE INDEX idx_unit_infantry_data_jump     ON unit_infantry_data (jump_mp);
@@ -0,0 +1,50 @@
+-- ============================================================================
+-- Large craft data parsed from MegaMek BLK files: small craft, DropShips,
+-- JumpShips, WarShips and space stations. Thrust, SI and weapon bays stay in
+-- unit_aero_data / unit_aero_weapon_bays.
+-- ============================================================================
+
+CREATE TABLE unit_large_craft_data (
+    unit_id             INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    craft_type          TEXT NOT NULL,     -- "small_craft", "dropship", "jumpship", "warship", "space_station"
+    design_type         TEXT,              -- small craft / DropShips: "aerodyne", "spheroid"
+    crew                INTEGER,
+    officers            INTEGER,
+    gunners             INTEGER,
+    passengers          INTEGER,
+    marines             INTEGER,
+    battle_armor_troops INTEGER,
+    other_passengers    INTEGER,
+    life_boats          INTEGER,
+    escape_pods         INTEGER,
+    collar_type         TEXT,              -- DropShips: "standard", "prototype", "no_boom"
+    docking_collars     INTEGER NOT NULL DEFAULT 0,
+    has_kf_drive        BOOLEAN NOT NULL DEFAULT FALSE,
+    drive_core_type     TEXT,              -- "standard", "compact", "subcompact", "primitive", "none"
+    jump_range          INTEGER,           -- primitive JumpShips, light years
+    has_lithium_fusion  BOOLEAN NOT NULL DEFAULT FALSE,
+    has_jump_sail       BOOLEAN NOT NULL DEFAULT FALSE,
+    has_hpg             BOOLEAN NOT NULL DEFAULT FALSE,
+    grav_decks          INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_unit_large_craft_type ON unit_large_craft_data (craft_type);
+
+-- ── Transport bays ──────────────────────────────────────────────────────────
+-- One row per bay in unit file order. Size is in units for 'Mech, vehicle,
+-- fighter and small craft bays, squads for battle armor, platoons for
+-- infantry and tons for cargo bays and quarters.
+
+CREATE TABLE unit_transport_bays (
+    id          SERIAL PRIMARY KEY,
+    unit_id     INTEGER NOT NULL REFERENCES units (id) ON DELETE CASCADE,
+    sort_order  SMALLINT NOT NULL,
+    bay_number  SMALLINT,              -- from the unit file, when given
+    bay_type    TEXT NOT NULL,         -- "mech", "asf", "heavy_vehicle", "cargo", ...
+    size        NUMERIC(10,2) NOT NULL,
+    doors       SMALLINT NOT NULL DEFAULT 0,
+    UNIQUE (unit_id, sort_order)
+);
+
+CREATE INDEX idx_unit_transport_bays_unit ON unit_transport_bays (unit_id);
+CREATE INDEX idx_unit_transport_bays_type ON unit_transport_bays (bay_type);
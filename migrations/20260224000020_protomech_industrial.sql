@@ -0,0 +1,28 @@
+-- ============================================================================
+-- ProtoMechs and IndustrialMechs. Chassis unit_type gains "protomech" and
+-- "industrialmech" (plus "small_craft", "dropship", "jumpship", "warship" and
+-- "space_station" for large craft, which used to be "other").
+-- ============================================================================
+
+-- ProtoMech locations
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'torso';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'legs';
+ALTER TYPE location_name_enum ADD VALUE IF NOT EXISTS 'main_gun';
+
+CREATE TABLE unit_protomech_data (
+    unit_id               INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    config                TEXT NOT NULL,     -- "biped", "quad", "glider"
+    is_ultraheavy         BOOLEAN NOT NULL DEFAULT FALSE,  -- over 9 tons
+    walk_mp               INTEGER,
+    jump_mp               INTEGER,
+    has_main_gun          BOOLEAN NOT NULL DEFAULT FALSE,
+    armor_type            TEXT,
+    has_interface_cockpit BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_unit_protomech_data_config ON unit_protomech_data (config);
+
+-- IndustrialMech features, recorded for every mech
+ALTER TABLE unit_mech_data
+    ADD COLUMN is_environmentally_sealed BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN industrial_equipment      TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,44 @@
+-- ============================================================================
+-- OmniMech configurations. Each configuration (Prime, A, B...) stays its own
+-- units row; configurations sharing the same fixed equipment, engine, armor
+-- and internal structure are grouped under one omni_bases row.
+-- ============================================================================
+
+-- Pod-mounted loadout entries (critical slots already carry is_omnipod)
+ALTER TABLE unit_loadout
+    ADD COLUMN is_omnipod BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE omni_bases (
+    id                SERIAL PRIMARY KEY,
+    chassis_id        INTEGER NOT NULL REFERENCES unit_chassis (id) ON DELETE CASCADE,
+    signature         TEXT NOT NULL,    -- md5 of the fixed crits, armor and engine
+    reference_unit_id INTEGER REFERENCES units (id) ON DELETE SET NULL,  -- Prime when present
+    tonnage           NUMERIC(10,1) NOT NULL,
+    engine_rating     INTEGER,
+    engine_type       TEXT,
+    walk_mp           INTEGER,
+    structure_type    TEXT,
+    armor_type        TEXT,
+    heat_sink_type    TEXT,
+    gyro_type         TEXT,
+    cockpit_type      TEXT,
+    UNIQUE (chassis_id, signature)
+);
+
+-- Fixed (non-pod) equipment of a base chassis
+CREATE TABLE omni_base_equipment (
+    id             SERIAL PRIMARY KEY,
+    omni_base_id   INTEGER NOT NULL REFERENCES omni_bases (id) ON DELETE CASCADE,
+    equipment_id   INTEGER NOT NULL REFERENCES equipment (id) ON DELETE CASCADE,
+    location       location_name_enum,
+    quantity       INTEGER NOT NULL DEFAULT 1,
+    is_rear_facing BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX idx_omni_base_equipment_base ON omni_base_equipment (omni_base_id);
+
+ALTER TABLE unit_mech_data
+    ADD COLUMN omni_base_id INTEGER REFERENCES omni_bases (id) ON DELETE SET NULL;
+
+CREATE INDEX idx_unit_mech_data_omni_base ON unit_mech_data (omni_base_id)
+    WHERE omni_base_id IS NOT NULL;
@@ -0,0 +1,23 @@
+-- ============================================================================
+-- Land-air mechs and QuadVees: mode-specific data for mechs whose config is
+-- "LAM" or "QuadVee". BattleMech-mode movement stays in unit_mech_data.
+-- ============================================================================
+
+CREATE TABLE unit_lam_data (
+    unit_id             INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    lam_type            TEXT NOT NULL,     -- "standard", "bimodal" (no AirMech mode)
+    fuel                INTEGER,
+    airmech_cruise_mp   INTEGER,           -- 3 x jump MP; NULL for bimodal
+    airmech_flank_mp    INTEGER,
+    fighter_safe_thrust INTEGER,           -- jump MP
+    fighter_max_thrust  INTEGER,
+    landing_gear_slots  INTEGER NOT NULL DEFAULT 0,
+    avionics_slots      INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE unit_quadvee_data (
+    unit_id          INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    motive_type      TEXT NOT NULL,        -- vehicle mode: "tracked", "wheeled"
+    conversion_slots INTEGER NOT NULL DEFAULT 0,
+    motive_slots     INTEGER NOT NULL DEFAULT 0   -- track/wheel crits
+);
@@ -0,0 +1,36 @@
+-- ============================================================================
+-- Unit lore (fluff) and manufacturers. units.description keeps the overview
+-- for existing clients; unit_fluff holds every lore field.
+-- ============================================================================
+
+CREATE TABLE unit_fluff (
+    unit_id      INTEGER NOT NULL PRIMARY KEY REFERENCES units (id) ON DELETE CASCADE,
+    overview     TEXT,
+    capabilities TEXT,
+    deployment   TEXT,
+    history      TEXT,
+    notes        TEXT,
+    image_file   TEXT
+);
+
+CREATE TABLE manufacturers (
+    id   SERIAL PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL
+);
+
+CREATE INDEX idx_manufacturers_name_trgm ON manufacturers USING gin (name gin_trgm_ops);
+
+-- A unit's manufacturers (system NULL) with their primary factory, and the
+-- makers of its components (system "chassis", "engine", "armor", ...)
+CREATE TABLE unit_manufacturers (
+    id              SERIAL PRIMARY KEY,
+    unit_id         INTEGER NOT NULL REFERENCES units (id) ON DELETE CASCADE,
+    manufacturer_id INTEGER NOT NULL REFERENCES manufacturers (id) ON DELETE CASCADE,
+    factory         TEXT,
+    system          TEXT,
+    sort_order      INTEGER NOT NULL,
+    UNIQUE (unit_id, sort_order)
+);
+
+CREATE INDEX idx_unit_manufacturers_manufacturer ON unit_manufacturers (manufacturer_id);
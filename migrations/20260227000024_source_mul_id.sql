@@ -0,0 +1,9 @@
+-- ============================================================================
+-- MUL ID declared in the MegaMek source file ("mul id:" in MTF, <mul id:> in
+-- BLK). mul-import matches on it before falling back to name heuristics.
+-- Not unique: several files may describe the same MUL record.
+-- ============================================================================
+
+ALTER TABLE units ADD COLUMN source_mul_id INTEGER;
+
+CREATE INDEX idx_units_source_mul_id ON units (source_mul_id) WHERE source_mul_id IS NOT NULL;
@@ -0,0 +1,39 @@
+-- ============================================================================
+-- Import provenance. Every scraper run that writes unit or equipment data
+-- records an import_runs row; rows it writes point back at it.
+-- ============================================================================
+
+CREATE TABLE import_runs (
+    id             SERIAL PRIMARY KEY,
+    source         TEXT NOT NULL
+                   CHECK (source IN ('megamek', 'mul', 'equipment_seed', 'forcegen')),
+    version        TEXT,                  -- MegaMek release, seed file name...
+    status         TEXT NOT NULL DEFAULT 'running'
+                   CHECK (status IN ('running', 'succeeded', 'failed')),
+    started_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    finished_at    TIMESTAMPTZ,
+    processed      INTEGER NOT NULL DEFAULT 0,   -- records read from the source
+    imported       INTEGER NOT NULL DEFAULT 0,   -- records written
+    skipped        INTEGER NOT NULL DEFAULT 0,   -- records read but not written
+    error_count    INTEGER NOT NULL DEFAULT 0,
+    error_messages TEXT[] NOT NULL DEFAULT '{}'  -- first 100 errors
+);
+
+CREATE INDEX idx_import_runs_source ON import_runs (source, id DESC);
+
+-- Run that last wrote the MegaMek columns of the unit
+ALTER TABLE units
+    ADD COLUMN last_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL;
+-- Run that last wrote the MUL columns (mul_id, bv, cost, role, clan_name)
+ALTER TABLE units
+    ADD COLUMN mul_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL;
+
+ALTER TABLE unit_loadout
+    ADD COLUMN last_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL;
+ALTER TABLE unit_locations
+    ADD COLUMN last_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL;
+ALTER TABLE unit_availability
+    ADD COLUMN last_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL;
+-- Run that last wrote equipment stats (pairs with stats_source)
+ALTER TABLE equipment
+    ADD COLUMN stats_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL;
@@ -0,0 +1,25 @@
+-- ============================================================================
+-- Dataset snapshots. Each MegaMek import records content hashes of every unit
+-- it wrote, so two releases can be diffed after later imports have
+-- overwritten the units table.
+-- ============================================================================
+
+CREATE TABLE dataset_snapshots (
+    id            SERIAL PRIMARY KEY,
+    version       TEXT NOT NULL UNIQUE,   -- dataset_metadata.version
+    import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE dataset_snapshot_units (
+    snapshot_id  INTEGER NOT NULL REFERENCES dataset_snapshots (id) ON DELETE CASCADE,
+    unit_slug    TEXT NOT NULL,
+    full_name    TEXT NOT NULL,
+    content_hash TEXT NOT NULL,   -- md5 of core_hash, armor_hash and loadout_hash
+    core_hash    TEXT NOT NULL,   -- name, tonnage, tech base, rules level, intro year
+    armor_hash   TEXT NOT NULL,   -- unit_locations
+    loadout_hash TEXT NOT NULL,   -- unit_loadout
+    bv           INTEGER,         -- follows MUL imports for the newest snapshot
+    loadout      JSONB NOT NULL DEFAULT '[]',  -- [{equipment, name, location, quantity, rear}]
+    PRIMARY KEY (snapshot_id, unit_slug)
+);
@@ -0,0 +1,33 @@
+-- ============================================================================
+-- Faction relations and names over time. A faction can sit inside a parent
+-- (the Lyran Commonwealth inside the Federated Commonwealth, a Great House
+-- inside "General (All)") and hand over to successors; both carry the
+-- in-universe years they apply to. Names record renames such as the Lyran
+-- Alliance or Clan Sea Fox.
+-- ============================================================================
+
+CREATE TABLE faction_relations (
+    id                 SERIAL PRIMARY KEY,
+    faction_id         INTEGER NOT NULL REFERENCES factions (id) ON DELETE CASCADE,
+    related_faction_id INTEGER NOT NULL REFERENCES factions (id) ON DELETE CASCADE,
+    relation           TEXT NOT NULL CHECK (relation IN ('parent', 'successor')),
+    start_year         INTEGER,   -- null = since founding; year of succession for successors
+    end_year           INTEGER,   -- null = still applies
+    UNIQUE (faction_id, related_faction_id, relation),
+    CHECK (faction_id <> related_faction_id)
+);
+
+CREATE INDEX idx_faction_relations_faction ON faction_relations (faction_id, relation);
+CREATE INDEX idx_faction_relations_related ON faction_relations (related_faction_id, relation);
+
+CREATE TABLE faction_names (
+    id         SERIAL PRIMARY KEY,
+    faction_id INTEGER NOT NULL REFERENCES factions (id) ON DELETE CASCADE,
+    name       TEXT NOT NULL,
+    short_name TEXT,
+    start_year INTEGER,   -- null = since founding
+    end_year   INTEGER,   -- null = current name
+    UNIQUE (faction_id, name, start_year)
+);
+
+CREATE INDEX idx_faction_names_faction ON faction_names (faction_id);
@@ -0,0 +1,54 @@
+-- ============================================================================
+-- Planets: map coordinates, ownership by year, Socio-Industrial Levels and
+-- the manufacturers with factories on each world. Imported from a local
+-- JSON file by `planets-import`.
+-- ============================================================================
+
+ALTER TABLE import_runs DROP CONSTRAINT import_runs_source_check;
+ALTER TABLE import_runs ADD CONSTRAINT import_runs_source_check
+    CHECK (source IN ('megamek', 'mul', 'equipment_seed', 'forcegen', 'planets'));
+
+CREATE TABLE planets (
+    id                 SERIAL PRIMARY KEY,
+    slug               TEXT NOT NULL UNIQUE,
+    name               TEXT NOT NULL,
+    x                  DOUBLE PRECISION NOT NULL,   -- light years from Terra, coreward positive
+    y                  DOUBLE PRECISION NOT NULL,   -- light years from Terra, spinward positive
+    spectral_type      TEXT,
+    population         BIGINT,
+    -- Socio-Industrial Levels, A (best) to F
+    technology         TEXT CHECK (technology IN ('A', 'B', 'C', 'D', 'F')),
+    industry           TEXT CHECK (industry IN ('A', 'B', 'C', 'D', 'F')),
+    raw_materials      TEXT CHECK (raw_materials IN ('A', 'B', 'C', 'D', 'F')),
+    output             TEXT CHECK (output IN ('A', 'B', 'C', 'D', 'F')),
+    agriculture        TEXT CHECK (agriculture IN ('A', 'B', 'C', 'D', 'F')),
+    description        TEXT,
+    last_import_run_id INTEGER REFERENCES import_runs (id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_planets_name_trgm ON planets USING gin (name gin_trgm_ops);
+CREATE INDEX idx_planets_coords    ON planets (x, y);
+
+-- One row per change of hands; end_year is the year before the next change
+CREATE TABLE planet_ownership (
+    id         SERIAL PRIMARY KEY,
+    planet_id  INTEGER NOT NULL REFERENCES planets (id) ON DELETE CASCADE,
+    faction_id INTEGER REFERENCES factions (id) ON DELETE CASCADE,   -- NULL = unowned/abandoned
+    start_year INTEGER NOT NULL,
+    end_year   INTEGER,                                               -- NULL = current owner
+    UNIQUE (planet_id, start_year)
+);
+
+CREATE INDEX idx_planet_ownership_faction ON planet_ownership (faction_id, start_year);
+
+-- Manufacturers with factories on a planet: listed in the planets file, or
+-- found as the factory of a unit manufacturer in MegaMek fluff
+CREATE TABLE planet_factories (
+    id              SERIAL PRIMARY KEY,
+    planet_id       INTEGER NOT NULL REFERENCES planets (id) ON DELETE CASCADE,
+    manufacturer_id INTEGER NOT NULL REFERENCES manufacturers (id) ON DELETE CASCADE,
+    source          TEXT NOT NULL CHECK (source IN ('file', 'fluff')),
+    UNIQUE (planet_id, manufacturer_id)
+);
+
+CREATE INDEX idx_planet_factories_manufacturer ON planet_factories (manufacturer_id);
@@ -0,0 +1,21 @@
+-- ============================================================================
+-- Equipment tech progression: the TechManual prototype, production and common
+-- years and any extinction/reintroduction, separately for each tech base an
+-- item exists in (an ER Large Laser has both an Inner Sphere and a Clan
+-- progression). Year filters fall back to equipment.intro_year for items
+-- without progression rows.
+-- ============================================================================
+
+CREATE TABLE equipment_tech_progression (
+    id              SERIAL PRIMARY KEY,
+    equipment_id    INTEGER NOT NULL REFERENCES equipment (id) ON DELETE CASCADE,
+    tech_base       tech_base_enum NOT NULL,
+    prototype_year  INTEGER,
+    production_year INTEGER,
+    common_year     INTEGER,
+    extinction_year INTEGER,
+    reintro_year    INTEGER,
+    UNIQUE (equipment_id, tech_base)
+);
+
+CREATE INDEX idx_equipment_tech_progression_equipment ON equipment_tech_progression (equipment_id);
@@ -0,0 +1,225 @@
+-- ============================================================================
+-- Tech ratings and era availability. TechManual rates each item's technology
+-- A-F and its availability in four periods — Star League (to 2780),
+-- Succession Wars (2781-3049), Clan Invasion (3050-3080) and Dark Age
+-- (3081 on) — A-F, or X where it cannot be had. Equipment gets the ratings
+-- from the equipment seed (progression years live in equipment_tech_progression);
+-- construction types, one row per tech base already, carry their progression
+-- years directly and are filled below.
+-- ============================================================================
+
+ALTER TABLE equipment
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE engine_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE armor_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE structure_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE heatsink_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE gyro_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE cockpit_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+ALTER TABLE myomer_types
+    ADD COLUMN prototype_year    INTEGER,
+    ADD COLUMN production_year   INTEGER,
+    ADD COLUMN common_year       INTEGER,
+    ADD COLUMN extinction_year   INTEGER,
+    ADD COLUMN reintro_year      INTEGER,
+    ADD COLUMN tech_rating       TEXT CHECK (tech_rating IN ('A', 'B', 'C', 'D', 'E', 'F')),
+    ADD COLUMN availability_sl   TEXT CHECK (availability_sl IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_sw   TEXT CHECK (availability_sw IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_clan TEXT CHECK (availability_clan IN ('A', 'B', 'C', 'D', 'E', 'F', 'X')),
+    ADD COLUMN availability_da   TEXT CHECK (availability_da IN ('A', 'B', 'C', 'D', 'E', 'F', 'X'));
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE engine_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('standard-fusion', 2015, 2020, 2030, NULL, NULL, 'D', 'C', 'E', 'D', 'C'),
+    ('xl-is', 2556, 2579, 3045, 2865, 3035, 'E', 'D', 'F', 'E', 'D'),
+    ('xl-clan', NULL, 2824, 2827, NULL, NULL, 'F', 'X', 'E', 'D', 'C'),
+    ('light', 3055, 3062, 3067, NULL, NULL, 'D', 'X', 'X', 'E', 'D'),
+    ('compact', 3060, 3068, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('xxl-is', 3055, NULL, NULL, NULL, NULL, 'F', 'X', 'X', 'F', 'E'),
+    ('ice', NULL, 1950, 2020, NULL, NULL, 'C', 'A', 'A', 'A', 'A'),
+    ('fuel-cell', 2300, 2470, 2500, NULL, NULL, 'C', 'C', 'E', 'D', 'C'),
+    ('primitive-fusion', 1950, 2300, NULL, NULL, NULL, 'D', 'C', 'X', 'X', 'F'),
+    ('xxl-clan', 3055, NULL, NULL, NULL, NULL, 'F', 'X', 'X', 'F', 'E'),
+    ('fission', NULL, 2882, NULL, NULL, NULL, 'D', 'E', 'E', 'D', 'D')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE armor_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('standard', 2460, 2470, 2470, NULL, NULL, 'D', 'C', 'C', 'C', 'B'),
+    ('ferro-fibrous-is', 2557, 2571, 3055, 2810, 3040, 'E', 'D', 'F', 'D', 'C'),
+    ('ferro-fibrous-clan', NULL, 2820, 2825, NULL, NULL, 'F', 'X', 'D', 'C', 'B'),
+    ('light-ferro', 3055, 3067, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('heavy-ferro', 3056, 3069, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('stealth', 3051, 3063, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('reactive', 3063, 3065, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('hardened', 3047, 3081, NULL, NULL, NULL, 'D', 'X', 'X', 'E', 'D'),
+    ('primitive', 2290, 2300, NULL, NULL, NULL, 'C', 'A', 'B', 'A', 'A'),
+    ('industrial', NULL, 2439, 2460, NULL, NULL, 'C', 'B', 'B', 'B', 'B'),
+    ('heavy-industrial', NULL, 2460, 2470, NULL, NULL, 'C', 'B', 'C', 'B', 'B'),
+    ('commercial', NULL, 2400, 2410, NULL, NULL, 'B', 'B', 'B', 'B', 'B'),
+    ('reflective-is', 3058, 3063, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('reflective-clan', NULL, 3061, NULL, NULL, NULL, 'F', 'X', 'X', 'E', 'D'),
+    ('ferro-lamellor', 3070, 3109, NULL, NULL, NULL, 'F', 'X', 'X', 'E', 'D')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE structure_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('standard', NULL, 2439, 2470, NULL, NULL, 'D', 'C', 'C', 'C', 'C'),
+    ('endo-steel-is', 2471, 2487, 3040, 2850, 3035, 'E', 'D', 'F', 'E', 'D'),
+    ('endo-steel-clan', NULL, 2820, 2827, NULL, NULL, 'F', 'X', 'D', 'C', 'C'),
+    ('composite', 3061, 3082, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('reinforced', 3057, 3084, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('endo-composite-is', 3067, 3082, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('industrial', NULL, 2350, 2400, NULL, NULL, 'C', 'B', 'B', 'B', 'B'),
+    ('endo-composite-clan', 3073, 3085, NULL, NULL, NULL, 'F', 'X', 'X', 'E', 'D'),
+    ('reinforced-clan', 3065, 3084, NULL, NULL, NULL, 'F', 'X', 'X', 'E', 'D')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE heatsink_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('single', NULL, 2022, 2022, NULL, NULL, 'C', 'B', 'B', 'B', 'B'),
+    ('double-is', 2559, 2567, 3045, 2865, 3022, 'E', 'C', 'E', 'D', 'C'),
+    ('double-clan', NULL, 2567, 2827, NULL, NULL, 'F', 'X', 'D', 'C', 'C'),
+    ('compact', 3058, 3079, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('laser', 3051, 3075, NULL, NULL, NULL, 'F', 'X', 'X', 'E', 'D')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE gyro_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('standard', NULL, 2300, 2305, NULL, NULL, 'D', 'C', 'C', 'C', 'C'),
+    ('xl', 3055, 3067, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('compact', 3055, 3068, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('heavy-duty', 3055, 3067, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('superheavy', 3076, NULL, NULL, NULL, NULL, 'E', 'X', 'X', 'F', 'E')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE cockpit_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('standard', NULL, 2468, 2487, NULL, NULL, 'D', 'C', 'C', 'C', 'C'),
+    ('small', 3060, 3067, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('command-console', 2625, 2631, NULL, 2850, 3031, 'D', 'D', 'F', 'E', 'D'),
+    ('torso-mounted', 3053, 3075, NULL, NULL, NULL, 'E', 'X', 'X', 'F', 'F'),
+    ('industrial', NULL, 2300, 2350, NULL, NULL, 'C', 'B', 'C', 'C', 'B'),
+    ('primitive', NULL, 2300, NULL, 2520, NULL, 'C', 'D', 'X', 'X', 'F')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
+
+-- slug, prototype, production, common, extinct, reintroduced, rating, SL, SW, CI, DA
+UPDATE myomer_types t SET
+    prototype_year = v.prototype::int, production_year = v.production::int,
+    common_year = v.common::int, extinction_year = v.extinct::int, reintro_year = v.reintro::int,
+    tech_rating = v.rating, availability_sl = v.sl, availability_sw = v.sw,
+    availability_clan = v.ci, availability_da = v.da
+FROM (VALUES
+    ('standard', NULL, 2300, 2310, NULL, NULL, 'D', 'C', 'C', 'C', 'C'),
+    ('masc', 2740, 2745, 3040, 2795, 3035, 'E', 'D', 'F', 'E', 'D'),
+    ('tsm', 3028, 3050, NULL, NULL, NULL, 'E', 'X', 'X', 'E', 'D'),
+    ('industrial', NULL, 2300, 2310, NULL, NULL, 'C', 'B', 'B', 'B', 'B')
+) AS v (slug, prototype, production, common, extinct, reintro, rating, sl, sw, ci, da)
+WHERE t.slug = v.slug;
@@ -0,0 +1,14 @@
+-- ============================================================================
+-- Equipment catalog. The MegaMek importer resolves equipment names against
+-- data/equipment_catalog.json instead of guessing category and tech base from
+-- the name; catalog rows carry their MegaMek internal name. Rows without one
+-- are names the catalog does not know.
+-- ============================================================================
+
+ALTER TYPE equipment_category_enum ADD VALUE IF NOT EXISTS 'targeting_system';
+ALTER TYPE equipment_category_enum ADD VALUE IF NOT EXISTS 'communications';
+ALTER TYPE equipment_category_enum ADD VALUE IF NOT EXISTS 'myomer';
+
+ALTER TABLE equipment ADD COLUMN internal_name TEXT;
+
+CREATE UNIQUE INDEX idx_equipment_internal_name ON equipment (internal_name);
@@ -0,0 +1,13 @@
+-- ============================================================================
+-- Equipment slug aliases. Rows stored before the equipment catalog carry
+-- name-derived slugs ("ismediumpulselaser", "is-ammo-ac-2"). The importer
+-- adopts them into their catalog entries, renaming the slug and merging
+-- duplicates; the old slugs are kept here so existing links keep resolving.
+-- ============================================================================
+
+CREATE TABLE equipment_slug_aliases (
+  alias         TEXT PRIMARY KEY,
+  equipment_id  INT NOT NULL REFERENCES equipment(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_equipment_slug_aliases_equipment ON equipment_slug_aliases (equipment_id);
@@ -0,0 +1,16 @@
+-- ============================================================================
+-- Battle Value 2.0 modifiers missing from the first pass: reflective armor,
+-- ferro-lamellor, Clan XXL engines and Clan reinforced structure
+-- (TechManual BV calculation).
+-- ============================================================================
+
+UPDATE engine_types SET bv_multiplier = 0.500 WHERE slug = 'xxl-clan';
+
+UPDATE armor_types SET bv_multiplier = CASE slug
+  WHEN 'reflective-is'   THEN 1.500
+  WHEN 'reflective-clan' THEN 1.500
+  WHEN 'ferro-lamellor'  THEN 1.200
+  ELSE bv_multiplier
+END;
+
+UPDATE structure_types SET bv_multiplier = 2.000 WHERE slug = 'reinforced-clan';
@@ -35,20 +35,46 @@ fi
 echo "==> Truncating tables..."
 psql "$DB_URL" -q -c "
   TRUNCATE
+    omni_base_equipment,
+    omni_bases,
+    unit_fluff,
+    unit_manufacturers,
+    manufacturers,
+    unit_lam_data,
+    unit_quadvee_data,
     unit_mech_data,
+    unit_vehicle_data,
+    unit_aero_data,
+    unit_protomech_data,
+    unit_infantry_data,
+    unit_large_craft_data,
+    unit_transport_bays,
+    unit_aero_weapon_bays,
+    unit_alpha_strike,
+    unit_critical_slots,
     unit_quirks,
     unit_loadout,
     unit_locations,
     unit_availability,
     units,
     unit_chassis,
+    equipment_tech_progression,
+    equipment_slug_aliases,
     equipment,
     quirks,
+    planet_factories,
+    planet_ownership,
+    planets,
     faction_eras,
+    faction_relations,
+    faction_names,
     factions,
     eras,
     rulesets,
     dataset_metadata,
+    dataset_snapshot_units,
+    dataset_snapshots,
+    import_runs,
     engine_type_aliases,
     armor_type_aliases,
     structure_type_aliases,
@@ -74,6 +100,8 @@ gunzip -c "$DUMP_FILE" | psql "$DB_URL" -q -o /dev/null
 echo "==> Resetting sequences..."
 psql "$DB_URL" -q -o /dev/null -c "
   SELECT setval('dataset_metadata_id_seq', COALESCE((SELECT MAX(id) FROM dataset_metadata), 0) + 1, false);
+  SELECT setval('import_runs_id_seq',      COALESCE((SELECT MAX(id) FROM import_runs), 0) + 1, false);
+  SELECT setval('dataset_snapshots_id_seq', COALESCE((SELECT MAX(id) FROM dataset_snapshots), 0) + 1, false);
   SELECT setval('eras_id_seq',             COALESCE((SELECT MAX(id) FROM eras), 0) + 1, false);
   SELECT setval('factions_id_seq',         COALESCE((SELECT MAX(id) FROM factions), 0) + 1, false);
   SELECT setval('unit_chassis_id_seq',     COALESCE((SELECT MAX(id) FROM unit_chassis), 0) + 1, false);
@@ -81,10 +109,23 @@ psql "$DB_URL" -q -o /dev/null -c "
   SELECT setval('equipment_id_seq',        COALESCE((SELECT MAX(id) FROM equipment), 0) + 1, false);
   SELECT setval('unit_locations_id_seq',   COALESCE((SELECT MAX(id) FROM unit_locations), 0) + 1, false);
   SELECT setval('unit_loadout_id_seq',     COALESCE((SELECT MAX(id) FROM unit_loadout), 0) + 1, false);
+  SELECT setval('unit_aero_weapon_bays_id_seq', COALESCE((SELECT MAX(id) FROM unit_aero_weapon_bays), 0) + 1, false);
+  SELECT setval('unit_critical_slots_id_seq', COALESCE((SELECT MAX(id) FROM unit_critical_slots), 0) + 1, false);
+  SELECT setval('unit_transport_bays_id_seq', COALESCE((SELECT MAX(id) FROM unit_transport_bays), 0) + 1, false);
+  SELECT setval('omni_bases_id_seq',       COALESCE((SELECT MAX(id) FROM omni_bases), 0) + 1, false);
+  SELECT setval('omni_base_equipment_id_seq', COALESCE((SELECT MAX(id) FROM omni_base_equipment), 0) + 1, false);
+  SELECT setval('manufacturers_id_seq',    COALESCE((SELECT MAX(id) FROM manufacturers), 0) + 1, false);
+  SELECT setval('unit_manufacturers_id_seq', COALESCE((SELECT MAX(id) FROM unit_manufacturers), 0) + 1, false);
   SELECT setval('quirks_id_seq',           COALESCE((SELECT MAX(id) FROM quirks), 0) + 1, false);
   SELECT setval('unit_quirks_id_seq',      COALESCE((SELECT MAX(id) FROM unit_quirks), 0) + 1, false);
   SELECT setval('rulesets_id_seq',         COALESCE((SELECT MAX(id) FROM rulesets), 0) + 1, false);
   SELECT setval('faction_eras_id_seq',     COALESCE((SELECT MAX(id) FROM faction_eras), 0) + 1, false);
+  SELECT setval('faction_relations_id_seq', COALESCE((SELECT MAX(id) FROM faction_relations), 0) + 1, false);
+  SELECT setval('faction_names_id_seq',    COALESCE((SELECT MAX(id) FROM faction_names), 0) + 1, false);
+  SELECT setval('planets_id_seq',          COALESCE((SELECT MAX(id) FROM planets), 0) + 1, false);
+  SELECT setval('planet_ownership_id_seq', COALESCE((SELECT MAX(id) FROM planet_ownership), 0) + 1, false);
+  SELECT setval('planet_factories_id_seq', COALESCE((SELECT MAX(id) FROM planet_factories), 0) + 1, false);
+  SELECT setval('equipment_tech_progression_id_seq', COALESCE((SELECT MAX(id) FROM equipment_tech_progression), 0) + 1, false);
   SELECT setval('unit_availability_id_seq', COALESCE((SELECT MAX(id) FROM unit_availability), 0) + 1, false);
   SELECT setval('engine_types_id_seq',     COALESCE((SELECT MAX(id) FROM engine_types), 0) + 1, false);
   SELECT setval('armor_types_id_seq',      COALESCE((SELECT MAX(id) FROM armor_types), 0) + 1, false);
@@ -100,13 +141,30 @@ psql "$DB_URL" -t -c "
   SELECT 'unit_chassis:   ' || COUNT(*) FROM unit_chassis
   UNION ALL SELECT 'units:          ' || COUNT(*) FROM units
   UNION ALL SELECT 'unit_mech_data: ' || COUNT(*) FROM unit_mech_data
+  UNION ALL SELECT 'omni_bases:     ' || COUNT(*) FROM omni_bases
+  UNION ALL SELECT 'unit_lam_data:  ' || COUNT(*) FROM unit_lam_data
+  UNION ALL SELECT 'unit_fluff:     ' || COUNT(*) FROM unit_fluff
+  UNION ALL SELECT 'manufacturers:  ' || COUNT(*) FROM manufacturers
+  UNION ALL SELECT 'unit_quadvee:   ' || COUNT(*) FROM unit_quadvee_data
+  UNION ALL SELECT 'unit_vehicle_data:' || COUNT(*) FROM unit_vehicle_data
+  UNION ALL SELECT 'unit_aero_data: ' || COUNT(*) FROM unit_aero_data
+  UNION ALL SELECT 'unit_protomech_data:' || COUNT(*) FROM unit_protomech_data
+  UNION ALL SELECT 'unit_infantry_data:' || COUNT(*) FROM unit_infantry_data
+  UNION ALL SELECT 'unit_large_craft:' || COUNT(*) FROM unit_large_craft_data
+  UNION ALL SELECT 'transport_bays: ' || COUNT(*) FROM unit_transport_bays
+  UNION ALL SELECT 'unit_alpha_strike:' || COUNT(*) FROM unit_alpha_strike
   UNION ALL SELECT 'equipment:      ' || COUNT(*) FROM equipment
   UNION ALL SELECT 'unit_loadout:   ' || COUNT(*) FROM unit_loadout
   UNION ALL SELECT 'unit_locations: ' || COUNT(*) FROM unit_locations
+  UNION ALL SELECT 'unit_crit_slots:' || COUNT(*) FROM unit_critical_slots
   UNION ALL SELECT 'quirks:         ' || COUNT(*) FROM quirks
   UNION ALL SELECT 'unit_quirks:    ' || COUNT(*) FROM unit_quirks
+  UNION ALL SELECT 'import_runs:    ' || COUNT(*) FROM import_runs
+  UNION ALL SELECT 'snapshot_units: ' || COUNT(*) FROM dataset_snapshot_units
   UNION ALL SELECT 'eras:           ' || COUNT(*) FROM eras
   UNION ALL SELECT 'factions:       ' || COUNT(*) FROM factions
+  UNION ALL SELECT 'faction_relations:' || COUNT(*) FROM faction_relations
+  UNION ALL SELECT 'planets:        ' || COUNT(*) FROM planets
   UNION ALL SELECT 'engine_types:   ' || COUNT(*) FROM engine_types
   UNION ALL SELECT 'armor_types:    ' || COUNT(*) FROM armor_types
   UNION ALL SELECT 'structure_types:' || COUNT(*) FROM structure_types